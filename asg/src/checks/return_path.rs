@@ -24,9 +24,14 @@ use crate::{
     Node,
     Span,
 };
+use leo_ast::Warning;
+
+/// The name of the warning for statements following an unconditional return.
+pub const DEAD_CODE: &str = "dead_code";
 
 pub struct ReturnPathReducer {
     pub errors: Vec<(Span, String)>,
+    pub warnings: Vec<Warning>,
 }
 
 impl ReturnPathReducer {
@@ -34,8 +39,16 @@ impl ReturnPathReducer {
         self.errors.push((span.cloned().unwrap_or_default(), error));
     }
 
+    fn record_warning(&mut self, name: &str, span: Option<&Span>, warning: String) {
+        self.warnings
+            .push(Warning::new_from_span(name, warning, span.cloned().unwrap_or_default()));
+    }
+
     pub fn new() -> ReturnPathReducer {
-        ReturnPathReducer { errors: vec![] }
+        ReturnPathReducer {
+            errors: vec![],
+            warnings: vec![],
+        }
     }
 }
 
@@ -66,8 +79,9 @@ impl<'a> MonoidalReducerStatement<'a, BoolAnd> for ReturnPathReducer {
         if statements.is_empty() {
             BoolAnd(false)
         } else if let Some(index) = statements[..statements.len() - 1].iter().map(|x| x.0).position(|x| x) {
-            self.record_error(
-                input.statements[index].get().span(),
+            self.record_warning(
+                DEAD_CODE,
+                input.statements[index + 1].get().span(),
                 "dead code due to unconditional early return".to_string(),
             );
             BoolAnd(true)
@@ -113,6 +127,7 @@ impl<'a> MonoidalReducerStatement<'a, BoolAnd> for ReturnPathReducer {
         input: &IterationStatement,
         start: BoolAnd,
         stop: BoolAnd,
+        array: Option<BoolAnd>,
         body: BoolAnd,
     ) -> BoolAnd {
         // loops are const defined ranges, so we could probably check if they run one and emit here
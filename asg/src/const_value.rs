@@ -14,12 +14,14 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{AsgConvertError, IntegerType, Span, Type};
+use crate::{AsgConvertError, FixedType, IntegerType, Span, Type};
 
-use num_bigint::BigInt;
+use num_bigint::{BigInt, Sign};
 use std::{convert::TryInto, fmt};
 
 /// Constant integer values in a program.
+///
+/// `u256` and `i256` values are backed by a [`BigInt`] kept within the range of their type.
 #[derive(Clone, Debug, PartialEq)]
 pub enum ConstInt {
     I8(i8),
@@ -27,11 +29,13 @@ pub enum ConstInt {
     I32(i32),
     I64(i64),
     I128(i128),
+    I256(BigInt),
     U8(u8),
     U16(u16),
     U32(u32),
     U64(u64),
     U128(u128),
+    U256(BigInt),
 }
 
 /// Specifies how to calculate a group coordinate in a program.
@@ -96,6 +100,7 @@ pub enum ConstValue {
     Int(ConstInt),
     Group(GroupValue),
     Field(BigInt),
+    Fixed(ConstFixed),
     Address(String),
     Boolean(bool),
 
@@ -105,7 +110,7 @@ pub enum ConstValue {
 }
 
 macro_rules! const_int_op {
-    ($name: ident, $retType: ty, $x: ident, $transform: expr) => {
+    ($name: ident, $retType: ty, $x: ident, $transform: expr, $wide_transform: expr) => {
         pub fn $name(&self) -> $retType {
             match self {
                 ConstInt::I8($x) => $transform,
@@ -113,11 +118,13 @@ macro_rules! const_int_op {
                 ConstInt::I32($x) => $transform,
                 ConstInt::I64($x) => $transform,
                 ConstInt::I128($x) => $transform,
+                ConstInt::I256($x) => $wide_transform,
                 ConstInt::U8($x) => $transform,
                 ConstInt::U16($x) => $transform,
                 ConstInt::U32($x) => $transform,
                 ConstInt::U64($x) => $transform,
                 ConstInt::U128($x) => $transform,
+                ConstInt::U256($x) => $wide_transform,
             }
         }
     };
@@ -132,11 +139,13 @@ macro_rules! const_int_biop {
                 (ConstInt::I32($x), ConstInt::I32($y)) => $transform,
                 (ConstInt::I64($x), ConstInt::I64($y)) => $transform,
                 (ConstInt::I128($x), ConstInt::I128($y)) => $transform,
+                (ConstInt::I256($x), ConstInt::I256($y)) => $transform,
                 (ConstInt::U8($x), ConstInt::U8($y)) => $transform,
                 (ConstInt::U16($x), ConstInt::U16($y)) => $transform,
                 (ConstInt::U32($x), ConstInt::U32($y)) => $transform,
                 (ConstInt::U64($x), ConstInt::U64($y)) => $transform,
                 (ConstInt::U128($x), ConstInt::U128($y)) => $transform,
+                (ConstInt::U256($x), ConstInt::U256($y)) => $transform,
                 _ => None,
             }
         }
@@ -144,7 +153,7 @@ macro_rules! const_int_biop {
 }
 
 macro_rules! const_int_map {
-    ($name: ident, $x: ident, $transform: expr) => {
+    ($name: ident, $x: ident, $transform: expr, $wide_transform: expr) => {
         pub fn $name(&self) -> Option<ConstInt> {
             Some(match self {
                 ConstInt::I8($x) => ConstInt::I8($transform),
@@ -152,18 +161,20 @@ macro_rules! const_int_map {
                 ConstInt::I32($x) => ConstInt::I32($transform),
                 ConstInt::I64($x) => ConstInt::I64($transform),
                 ConstInt::I128($x) => ConstInt::I128($transform),
+                ConstInt::I256($x) => ConstInt::I256(bounded_i256($wide_transform)?),
                 ConstInt::U8($x) => ConstInt::U8($transform),
                 ConstInt::U16($x) => ConstInt::U16($transform),
                 ConstInt::U32($x) => ConstInt::U32($transform),
                 ConstInt::U64($x) => ConstInt::U64($transform),
                 ConstInt::U128($x) => ConstInt::U128($transform),
+                ConstInt::U256($x) => ConstInt::U256(bounded_u256($wide_transform)?),
             })
         }
     };
 }
 
 macro_rules! const_int_bimap {
-    ($name: ident, $x: ident, $y: ident, $transform: expr) => {
+    ($name: ident, $x: ident, $y: ident, $transform: expr, $wide_transform: expr) => {
         pub fn $name(&self, other: &ConstInt) -> Option<ConstInt> {
             Some(match (self, other) {
                 (ConstInt::I8($x), ConstInt::I8($y)) => ConstInt::I8($transform),
@@ -171,37 +182,73 @@ macro_rules! const_int_bimap {
                 (ConstInt::I32($x), ConstInt::I32($y)) => ConstInt::I32($transform),
                 (ConstInt::I64($x), ConstInt::I64($y)) => ConstInt::I64($transform),
                 (ConstInt::I128($x), ConstInt::I128($y)) => ConstInt::I128($transform),
+                (ConstInt::I256($x), ConstInt::I256($y)) => ConstInt::I256(bounded_i256($wide_transform)?),
                 (ConstInt::U8($x), ConstInt::U8($y)) => ConstInt::U8($transform),
                 (ConstInt::U16($x), ConstInt::U16($y)) => ConstInt::U16($transform),
                 (ConstInt::U32($x), ConstInt::U32($y)) => ConstInt::U32($transform),
                 (ConstInt::U64($x), ConstInt::U64($y)) => ConstInt::U64($transform),
                 (ConstInt::U128($x), ConstInt::U128($y)) => ConstInt::U128($transform),
+                (ConstInt::U256($x), ConstInt::U256($y)) => ConstInt::U256(bounded_u256($wide_transform)?),
                 _ => return None,
             })
         }
     };
 }
 
+/// Returns the value if it is in the range of `u256`.
+fn bounded_u256(value: BigInt) -> Option<BigInt> {
+    if value.sign() == Sign::Minus || value.bits() > 256 {
+        return None;
+    }
+    Some(value)
+}
+
+/// Returns the value if it is in the range of `i256`.
+fn bounded_i256(value: BigInt) -> Option<BigInt> {
+    let bound: BigInt = BigInt::from(1u8) << 255;
+    if value < -bound.clone() || value >= bound {
+        return None;
+    }
+    Some(value)
+}
+
+/// Divides two 256-bit values, rounding towards zero like the primitive integer types.
+fn wide_div(x: &BigInt, y: &BigInt) -> Option<BigInt> {
+    if y.sign() == Sign::NoSign {
+        return None;
+    }
+    Some(x / y)
+}
+
+/// Raises a 256-bit value to the given power, without computing results that cannot fit in 256 bits.
+fn wide_pow(x: &BigInt, y: &BigInt) -> Option<BigInt> {
+    let exponent: u32 = y.try_into().ok()?;
+    if x.bits() > 1 && exponent > 256 {
+        return None;
+    }
+    Some(x.pow(exponent))
+}
+
 #[allow(clippy::useless_conversion)]
 impl ConstInt {
-    const_int_op!(raw_value, String, x, format!("{}", x));
+    const_int_op!(raw_value, String, x, format!("{}", x), format!("{}", x));
 
-    const_int_map!(value_negate, x, x.checked_neg()?);
+    const_int_map!(value_negate, x, x.checked_neg()?, -x);
 
-    const_int_op!(to_usize, Option<usize>, x, (*x).try_into().ok());
+    const_int_op!(to_usize, Option<usize>, x, (*x).try_into().ok(), x.try_into().ok());
 
-    const_int_op!(to_string, String, x, (*x).to_string());
+    const_int_op!(to_string, String, x, (*x).to_string(), x.to_string());
 
-    const_int_bimap!(value_add, x, y, x.checked_add(*y)?);
+    const_int_bimap!(value_add, x, y, x.checked_add(*y)?, x + y);
 
-    const_int_bimap!(value_sub, x, y, x.checked_sub(*y)?);
+    const_int_bimap!(value_sub, x, y, x.checked_sub(*y)?, x - y);
 
-    const_int_bimap!(value_mul, x, y, x.checked_mul(*y)?);
+    const_int_bimap!(value_mul, x, y, x.checked_mul(*y)?, x * y);
 
-    const_int_bimap!(value_div, x, y, x.checked_div(*y)?);
+    const_int_bimap!(value_div, x, y, x.checked_div(*y)?, wide_div(x, y)?);
 
     // TODO: limited to 32 bit exponents
-    const_int_bimap!(value_pow, x, y, x.checked_pow((*y).try_into().ok()?)?);
+    const_int_bimap!(value_pow, x, y, x.checked_pow((*y).try_into().ok()?)?, wide_pow(x, y)?);
 
     const_int_biop!(value_lt, bool, x, y, Some(x < y));
 
@@ -218,11 +265,13 @@ impl ConstInt {
             ConstInt::I32(_) => IntegerType::I32,
             ConstInt::I64(_) => IntegerType::I64,
             ConstInt::I128(_) => IntegerType::I128,
+            ConstInt::I256(_) => IntegerType::I256,
             ConstInt::U8(_) => IntegerType::U8,
             ConstInt::U16(_) => IntegerType::U16,
             ConstInt::U32(_) => IntegerType::U32,
             ConstInt::U64(_) => IntegerType::U64,
             ConstInt::U128(_) => IntegerType::U128,
+            ConstInt::U256(_) => IntegerType::U256,
         }
     }
 
@@ -237,11 +286,25 @@ impl ConstInt {
             IntegerType::I32 => ConstInt::I32(value.parse().map_err(|_| AsgConvertError::invalid_int(&value, span))?),
             IntegerType::I64 => ConstInt::I64(value.parse().map_err(|_| AsgConvertError::invalid_int(&value, span))?),
             IntegerType::I128 => ConstInt::I128(value.parse().map_err(|_| AsgConvertError::invalid_int(&value, span))?),
+            IntegerType::I256 => ConstInt::I256(
+                value
+                    .parse()
+                    .ok()
+                    .and_then(bounded_i256)
+                    .ok_or_else(|| AsgConvertError::invalid_int(&value, span))?,
+            ),
             IntegerType::U8 => ConstInt::U8(value.parse().map_err(|_| AsgConvertError::invalid_int(&value, span))?),
             IntegerType::U16 => ConstInt::U16(value.parse().map_err(|_| AsgConvertError::invalid_int(&value, span))?),
             IntegerType::U32 => ConstInt::U32(value.parse().map_err(|_| AsgConvertError::invalid_int(&value, span))?),
             IntegerType::U64 => ConstInt::U64(value.parse().map_err(|_| AsgConvertError::invalid_int(&value, span))?),
             IntegerType::U128 => ConstInt::U128(value.parse().map_err(|_| AsgConvertError::invalid_int(&value, span))?),
+            IntegerType::U256 => ConstInt::U256(
+                value
+                    .parse()
+                    .ok()
+                    .and_then(bounded_u256)
+                    .ok_or_else(|| AsgConvertError::invalid_int(&value, span))?,
+            ),
         })
     }
 }
@@ -252,6 +315,7 @@ impl ConstValue {
             ConstValue::Int(i) => i.get_type(),
             ConstValue::Group(_) => Type::Group,
             ConstValue::Field(_) => Type::Field,
+            ConstValue::Fixed(x) => Type::Fixed(x.type_.clone()),
             ConstValue::Address(_) => Type::Address,
             ConstValue::Boolean(_) => Type::Boolean,
             ConstValue::Tuple(sub_consts) => {
@@ -274,4 +338,340 @@ impl ConstValue {
             _ => None,
         }
     }
+
+    pub fn fixed(&self) -> Option<&ConstFixed> {
+        match self {
+            ConstValue::Fixed(x) => Some(x),
+            _ => None,
+        }
+    }
+
+    pub fn array(&self) -> Option<&[ConstValue]> {
+        match self {
+            ConstValue::Array(x) => Some(x),
+            _ => None,
+        }
+    }
+}
+
+/// How the exact result of a fixed point operation is rounded to the scale of its type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Rounding {
+    /// Towards zero, as done by the `*` and `/` operators.
+    Trunc,
+    /// Towards negative infinity.
+    Floor,
+    /// Towards positive infinity.
+    Ceil,
+    /// To the nearest value, with ties away from zero.
+    Round,
+}
+
+impl Rounding {
+    pub fn from_name(name: &str) -> Option<Rounding> {
+        Some(match name {
+            "trunc" => Rounding::Trunc,
+            "floor" => Rounding::Floor,
+            "ceil" => Rounding::Ceil,
+            "round" => Rounding::Round,
+            _ => return None,
+        })
+    }
+
+    /// Divides `numerator` by a nonzero `denominator`, rounding the quotient with `self`.
+    pub fn divide(self, numerator: &BigInt, denominator: &BigInt) -> BigInt {
+        let quotient = numerator / denominator;
+        let remainder = numerator - &quotient * denominator;
+        if remainder.sign() == Sign::NoSign {
+            return quotient;
+        }
+        // the remainder takes the sign of the numerator
+        let negative = (remainder.sign() == Sign::Minus) != (denominator.sign() == Sign::Minus);
+        let away = match self {
+            Rounding::Trunc => false,
+            Rounding::Floor => negative,
+            Rounding::Ceil => !negative,
+            Rounding::Round => remainder.magnitude() * 2u8 >= *denominator.magnitude(),
+        };
+        match (away, negative) {
+            (false, _) => quotient,
+            (true, true) => quotient - 1,
+            (true, false) => quotient + 1,
+        }
+    }
+}
+
+/// A constant fixed point value, stored as its integer count of `10^-scale` units.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConstFixed {
+    pub type_: FixedType,
+    pub raw: i128,
+}
+
+impl ConstFixed {
+    /// Parses a decimal literal such as `-1.25`, failing if it is out of range for the type or
+    /// has more fractional digits than its scale.
+    pub fn parse(type_: &FixedType, value: &str, span: &Span) -> Result<ConstFixed, AsgConvertError> {
+        let (whole, fraction) = match value.find('.') {
+            Some(index) => (&value[..index], &value[index + 1..]),
+            None => (value, ""),
+        };
+        let scale = type_.scale as usize;
+        let raw = if fraction.len() <= scale {
+            format!("{}{:0<scale$}", whole, fraction, scale = scale).parse().ok()
+        } else {
+            None
+        };
+
+        raw.and_then(|raw| Self::bounded(type_, raw))
+            .ok_or_else(|| AsgConvertError::invalid_fixed(value, &type_.to_string(), span))
+    }
+
+    /// Returns the value with the given raw integer, if it is in range for the type.
+    fn bounded(type_: &FixedType, raw: BigInt) -> Option<ConstFixed> {
+        let bound = BigInt::from(1u8) << (type_.width - 1) as usize;
+        if raw < -&bound || raw >= bound {
+            return None;
+        }
+
+        Some(ConstFixed {
+            type_: type_.clone(),
+            raw: raw.to_string().parse().ok()?,
+        })
+    }
+
+    /// Returns the raw integer of the value `1`, i.e. `10^scale`.
+    pub fn one(type_: &FixedType) -> BigInt {
+        BigInt::from(10u8).pow(type_.scale)
+    }
+
+    pub fn from_int(type_: &FixedType, value: &ConstInt) -> Option<ConstFixed> {
+        let value: BigInt = value.raw_value().parse().ok()?;
+        Self::bounded(type_, value * Self::one(type_))
+    }
+
+    /// Returns the whole part of the value, rounded towards zero, as an integer of type `int_type`.
+    pub fn to_int(&self, int_type: &IntegerType) -> Option<ConstInt> {
+        let whole = Rounding::Trunc.divide(&BigInt::from(self.raw), &Self::one(&self.type_));
+        ConstInt::parse(int_type, &whole.to_string(), &Span::default()).ok()
+    }
+
+    fn binary(
+        &self,
+        other: &ConstFixed,
+        raw: impl FnOnce(BigInt, BigInt, BigInt) -> Option<BigInt>,
+    ) -> Option<ConstFixed> {
+        if self.type_ != other.type_ {
+            return None;
+        }
+        let raw = raw(BigInt::from(self.raw), BigInt::from(other.raw), Self::one(&self.type_))?;
+        Self::bounded(&self.type_, raw)
+    }
+
+    pub fn value_negate(&self) -> Option<ConstFixed> {
+        Self::bounded(&self.type_, -BigInt::from(self.raw))
+    }
+
+    pub fn value_add(&self, other: &ConstFixed) -> Option<ConstFixed> {
+        self.binary(other, |x, y, _| Some(x + y))
+    }
+
+    pub fn value_sub(&self, other: &ConstFixed) -> Option<ConstFixed> {
+        self.binary(other, |x, y, _| Some(x - y))
+    }
+
+    pub fn value_mul(&self, other: &ConstFixed, rounding: Rounding) -> Option<ConstFixed> {
+        self.binary(other, |x, y, one| Some(rounding.divide(&(x * y), &one)))
+    }
+
+    pub fn value_div(&self, other: &ConstFixed, rounding: Rounding) -> Option<ConstFixed> {
+        self.binary(other, |x, y, one| {
+            if y.sign() == Sign::NoSign {
+                return None;
+            }
+            Some(rounding.divide(&(x * one), &y))
+        })
+    }
+
+    /// Rounds the value to a whole number.
+    pub fn value_round(&self, rounding: Rounding) -> Option<ConstFixed> {
+        let one = Self::one(&self.type_);
+        Self::bounded(&self.type_, rounding.divide(&BigInt::from(self.raw), &one) * one)
+    }
+}
+
+impl fmt::Display for ConstFixed {
+    /// Formats the value as a decimal with exactly `scale` fractional digits.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let scale = self.type_.scale as usize;
+        let sign = if self.raw < 0 { "-" } else { "" };
+        let digits = format!("{:0>width$}", BigInt::from(self.raw).magnitude(), width = scale + 1);
+        let (whole, fraction) = digits.split_at(digits.len() - scale);
+        if fraction.is_empty() {
+            write!(f, "{}{}", sign, whole)
+        } else {
+            write!(f, "{}{}.{}", sign, whole, fraction)
+        }
+    }
+}
+
+lazy_static! {
+    /// The modulus of the scalar field of BLS12-377, over which `field` values are defined.
+    ///
+    /// The ASG is not generic over the curve, so this must match the base field of the
+    /// Edwards BLS12 group the compiler is instantiated with.
+    pub static ref FIELD_MODULUS: BigInt = "8444461749428370424248824938781546531375899335154063827935233455917409239041"
+        .parse()
+        .unwrap();
+}
+
+/// Reduces a `field` value to its canonical representative in `[0, FIELD_MODULUS)`.
+fn field_reduce(value: &BigInt, modulus: &BigInt) -> BigInt {
+    ((value % modulus) + modulus) % modulus
+}
+
+/// Returns the smaller of the two square roots of a reduced `field` value, if it is a quadratic residue.
+fn field_sqrt(value: &BigInt, modulus: &BigInt) -> Option<BigInt> {
+    let zero = BigInt::from(0u8);
+    let one = BigInt::from(1u8);
+    let two = BigInt::from(2u8);
+
+    if *value == zero {
+        return Some(zero);
+    }
+    let modulus_minus_one = modulus - &one;
+    let legendre = |x: &BigInt| x.modpow(&(&modulus_minus_one / &two), modulus);
+    if legendre(value) != one {
+        return None;
+    }
+
+    // Tonelli-Shanks, with `modulus - 1 = q * 2^s`.
+    let mut q = modulus_minus_one.clone();
+    let mut s = 0usize;
+    while &q % &two == zero {
+        q /= &two;
+        s += 1;
+    }
+    let mut non_residue = two.clone();
+    while legendre(&non_residue) != modulus_minus_one {
+        non_residue += &one;
+    }
+
+    let mut m = s;
+    let mut c = non_residue.modpow(&q, modulus);
+    let mut t = value.modpow(&q, modulus);
+    let mut root = value.modpow(&((&q + &one) / &two), modulus);
+    while t != one {
+        let mut i = 0usize;
+        let mut t_pow = t.clone();
+        while t_pow != one {
+            t_pow = &t_pow * &t_pow % modulus;
+            i += 1;
+        }
+        let b = c.modpow(&(one.clone() << (m - i - 1)), modulus);
+        m = i;
+        c = &b * &b % modulus;
+        t = t * &c % modulus;
+        root = root * &b % modulus;
+    }
+
+    let negated = modulus - &root;
+    Some(if negated < root { negated } else { root })
+}
+
+/// Evaluates the intrinsic method `name` on a constant `field` value.
+///
+/// Returns `None` if the method is unknown or has no result for the given value, e.g. `0field.inv()`.
+pub fn field_intrinsic(name: &str, value: &BigInt, arguments: &[ConstValue]) -> Option<ConstValue> {
+    let modulus = &*FIELD_MODULUS;
+    let zero = BigInt::from(0u8);
+    let value = field_reduce(value, modulus);
+
+    Some(match (name, arguments) {
+        ("inv", []) => {
+            if value == zero {
+                return None;
+            }
+            ConstValue::Field(value.modpow(&(modulus - BigInt::from(2u8)), modulus))
+        }
+        ("square", []) => ConstValue::Field(&value * &value % modulus),
+        ("double", []) => ConstValue::Field(&value * BigInt::from(2u8) % modulus),
+        ("pow", [ConstValue::Field(exponent)]) => {
+            ConstValue::Field(value.modpow(&field_reduce(exponent, modulus), modulus))
+        }
+        ("sqrt", []) => match field_sqrt(&value, modulus) {
+            Some(root) => ConstValue::Tuple(vec![ConstValue::Field(root), ConstValue::Boolean(true)]),
+            None => ConstValue::Tuple(vec![ConstValue::Field(zero), ConstValue::Boolean(false)]),
+        },
+        ("is_zero", []) => ConstValue::Boolean(value == zero),
+        ("to_u256", []) => ConstValue::Int(ConstInt::U256(value)),
+        ("to_i256", []) if value > modulus >> 1usize => ConstValue::Int(ConstInt::I256(value - modulus)),
+        ("to_i256", []) => ConstValue::Int(ConstInt::I256(value)),
+        _ => return None,
+    })
+}
+
+/// Evaluates the intrinsic method `name` on a constant integer value.
+///
+/// Returns `None` if the method is unknown or has no result for the given value, e.g. a `u256`
+/// that does not fit in a `field`.
+pub fn integer_intrinsic(name: &str, value: &ConstInt, arguments: &[ConstValue]) -> Option<ConstValue> {
+    let modulus = &*FIELD_MODULUS;
+    let value: BigInt = value.raw_value().parse().ok()?;
+
+    Some(match (name, arguments) {
+        ("to_field", []) => {
+            if value.magnitude() >= modulus.magnitude() {
+                return None;
+            }
+            ConstValue::Field(field_reduce(&value, modulus))
+        }
+        _ => return None,
+    })
+}
+
+/// Evaluates the intrinsic method `name` on a constant array.
+pub fn array_intrinsic(name: &str, elements: &[ConstValue], arguments: &[ConstValue]) -> Option<ConstValue> {
+    Some(match (name, arguments) {
+        ("len", []) => ConstValue::Int(ConstInt::U32(elements.len() as u32)),
+        ("reverse", []) => ConstValue::Array(elements.iter().rev().cloned().collect()),
+        ("contains", [element]) => ConstValue::Boolean(elements.contains(element)),
+        ("concat", [ConstValue::Array(other)]) => ConstValue::Array(elements.iter().chain(other).cloned().collect()),
+        _ => return None,
+    })
+}
+
+/// Evaluates the intrinsic method `name` on a constant fixed point value.
+///
+/// Returns `None` if the method is unknown or has no result for the given value, e.g. on overflow.
+pub fn fixed_intrinsic(name: &str, value: &ConstFixed, arguments: &[ConstValue]) -> Option<ConstValue> {
+    let int_type = |name: &str| {
+        Some(match name {
+            "to_u8" => IntegerType::U8,
+            "to_u16" => IntegerType::U16,
+            "to_u32" => IntegerType::U32,
+            "to_u64" => IntegerType::U64,
+            "to_u128" => IntegerType::U128,
+            "to_i8" => IntegerType::I8,
+            "to_i16" => IntegerType::I16,
+            "to_i32" => IntegerType::I32,
+            "to_i64" => IntegerType::I64,
+            "to_i128" => IntegerType::I128,
+            _ => return None,
+        })
+    };
+
+    let rounding = |prefix: &str| Rounding::from_name(name.strip_prefix(prefix)?);
+
+    Some(match arguments {
+        [ConstValue::Fixed(other)] if name.starts_with("mul_") => {
+            ConstValue::Fixed(value.value_mul(other, rounding("mul_")?)?)
+        }
+        [ConstValue::Fixed(other)] if name.starts_with("div_") => {
+            ConstValue::Fixed(value.value_div(other, rounding("div_")?)?)
+        }
+        [] if name.starts_with("to_") => ConstValue::Int(value.to_int(&int_type(name)?)?),
+        [] => ConstValue::Fixed(value.value_round(rounding("")?)?),
+        _ => return None,
+    })
 }
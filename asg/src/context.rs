@@ -14,15 +14,24 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use std::{cell::Cell, unimplemented};
+use std::{
+    cell::{Cell, RefCell},
+    collections::HashMap,
+    unimplemented,
+};
 
+use leo_ast::Warning;
 use typed_arena::Arena;
 
-use crate::ArenaNode;
+use crate::{ArenaNode, Circuit, Expression, Statement};
 
 pub struct AsgContextInner<'a> {
     pub arena: &'a Arena<ArenaNode<'a>>,
     pub next_id: Cell<u32>,
+    /// The core circuits holding the intrinsic methods of each kind of value, loaded on first use.
+    pub intrinsics: RefCell<HashMap<String, &'a Circuit<'a>>>,
+    /// Warnings reported during conversion, until [`Asg::new`](crate::Asg::new) collects them.
+    pub warnings: RefCell<Vec<Warning>>,
 }
 
 impl<'a> AsgContextInner<'a> {
@@ -30,6 +39,8 @@ impl<'a> AsgContextInner<'a> {
         match arena.alloc(ArenaNode::Inner(AsgContextInner {
             arena,
             next_id: Cell::new(0),
+            intrinsics: RefCell::new(HashMap::new()),
+            warnings: RefCell::new(vec![]),
         })) {
             ArenaNode::Inner(x) => x,
             _ => unimplemented!(),
@@ -41,6 +52,26 @@ impl<'a> AsgContextInner<'a> {
         self.next_id.replace(next_id + 1);
         next_id
     }
+
+    pub fn warn(&self, warning: Warning) {
+        self.warnings.borrow_mut().push(warning);
+    }
+
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_expression(&'a self, expr: Expression<'a>) -> &'a mut Expression<'a> {
+        match self.arena.alloc(ArenaNode::Expression(expr)) {
+            ArenaNode::Expression(e) => e,
+            _ => unimplemented!(),
+        }
+    }
+
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_statement(&'a self, statement: Statement<'a>) -> &'a mut Statement<'a> {
+        match self.arena.alloc(ArenaNode::Statement(statement)) {
+            ArenaNode::Statement(e) => e,
+            _ => unimplemented!(),
+        }
+    }
 }
 
 pub type AsgContext<'a> = &'a AsgContextInner<'a>;
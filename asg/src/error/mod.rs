@@ -17,9 +17,11 @@
 //! Errors encountered when attempting to convert to an asg from an ast.
 
 use crate::Span;
-use leo_ast::{AstError, Error as FormattedError};
+use leo_ast::{AstError, Diagnostic, Error as FormattedError, Report};
 use leo_grammar::ParserError;
 
+use std::path::Path;
+
 #[derive(Debug, Error)]
 pub enum AsgConvertError {
     #[error("{}", _0)]
@@ -36,23 +38,93 @@ pub enum AsgConvertError {
 
     #[error("{}", _0)]
     ParserError(#[from] ParserError),
+
+    #[error("{}", _0.iter().map(ToString::to_string).collect::<Vec<_>>().join("\n\n"))]
+    Errors(Vec<AsgConvertError>),
+}
+
+impl Diagnostic for AsgConvertError {
+    fn error_code(&self) -> &'static str {
+        match self {
+            AsgConvertError::AstError(error) => error.error_code(),
+            AsgConvertError::Error(error) => error.code.unwrap_or("E0300"),
+            AsgConvertError::ImportError(error) => error.code.unwrap_or("E0301"),
+            AsgConvertError::InternalError(_) => "E0302",
+            AsgConvertError::ParserError(error) => error.error_code(),
+            AsgConvertError::Errors(_) => "E0303",
+        }
+    }
+
+    fn reports(&self) -> Vec<Report> {
+        match self {
+            AsgConvertError::AstError(error) => error.reports(),
+            AsgConvertError::Error(error) | AsgConvertError::ImportError(error) => {
+                vec![Report::from_error(self.error_code(), error)]
+            }
+            AsgConvertError::ParserError(error) => error.reports(),
+            AsgConvertError::Errors(errors) => errors.iter().flat_map(Diagnostic::reports).collect(),
+            AsgConvertError::InternalError(_) => vec![Report::new(self.error_code(), self.to_string())],
+        }
+    }
 }
 
 impl AsgConvertError {
-    fn new_from_span(message: String, span: &Span) -> Self {
-        AsgConvertError::Error(FormattedError::new_from_span(message, span.clone()))
+    ///
+    /// Returns `Ok` if `errors` is empty, and otherwise all errors in `errors` sorted by position.
+    ///
+    pub fn from_errors(errors: Vec<AsgConvertError>) -> Result<(), Self> {
+        let mut errors = errors
+            .into_iter()
+            .flat_map(|error| match error {
+                AsgConvertError::Errors(errors) => errors,
+                error => vec![error],
+            })
+            .collect::<Vec<_>>();
+
+        match errors.len() {
+            0 => Ok(()),
+            1 => Err(errors.remove(0)),
+            _ => {
+                errors.sort_by_key(|error| error.position());
+                Err(AsgConvertError::Errors(errors))
+            }
+        }
+    }
+
+    /// Sets the path of errors that do not already point at an imported file.
+    pub fn set_path(&mut self, path: &Path) {
+        match self {
+            AsgConvertError::Error(error) | AsgConvertError::ImportError(error) if error.path.is_none() => {
+                error.set_path(path)
+            }
+            AsgConvertError::Errors(errors) => errors.iter_mut().for_each(|error| error.set_path(path)),
+            _ => {}
+        }
+    }
+
+    /// Returns the line and column the error points at, if known.
+    pub fn position(&self) -> Option<(usize, usize)> {
+        match self {
+            AsgConvertError::Error(error) | AsgConvertError::ImportError(error) => Some((error.line, error.start)),
+            _ => None,
+        }
+    }
+
+    fn new_from_span(code: &'static str, message: String, span: &Span) -> Self {
+        AsgConvertError::Error(FormattedError::new_from_span(message, span.clone()).with_code(code))
     }
 
     pub fn unresolved_circuit(name: &str, span: &Span) -> Self {
-        Self::new_from_span(format!("failed to resolve circuit: '{}'", name), span)
+        Self::new_from_span("E0304", format!("failed to resolve circuit: '{}'", name), span)
     }
 
     pub fn unresolved_import(name: &str, span: &Span) -> Self {
-        Self::new_from_span(format!("failed to resolve import: '{}'", name), span)
+        Self::new_from_span("E0305", format!("failed to resolve import: '{}'", name), span)
     }
 
     pub fn unresolved_circuit_member(circuit_name: &str, name: &str, span: &Span) -> Self {
         Self::new_from_span(
+            "E0306",
             format!(
                 "illegal reference to non-existant member '{}' of circuit '{}'",
                 name, circuit_name
@@ -63,6 +135,7 @@ impl AsgConvertError {
 
     pub fn missing_circuit_member(circuit_name: &str, name: &str, span: &Span) -> Self {
         Self::new_from_span(
+            "E0307",
             format!(
                 "missing circuit member '{}' for initialization of circuit '{}'",
                 name, circuit_name
@@ -73,6 +146,7 @@ impl AsgConvertError {
 
     pub fn overridden_circuit_member(circuit_name: &str, name: &str, span: &Span) -> Self {
         Self::new_from_span(
+            "E0308",
             format!(
                 "cannot declare circuit member '{}' more than once for initialization of circuit '{}'",
                 name, circuit_name
@@ -83,6 +157,7 @@ impl AsgConvertError {
 
     pub fn redefined_circuit_member(circuit_name: &str, name: &str, span: &Span) -> Self {
         Self::new_from_span(
+            "E0309",
             format!(
                 "cannot declare circuit member '{}' multiple times in circuit '{}'",
                 name, circuit_name
@@ -93,6 +168,7 @@ impl AsgConvertError {
 
     pub fn extra_circuit_member(circuit_name: &str, name: &str, span: &Span) -> Self {
         Self::new_from_span(
+            "E0310",
             format!(
                 "extra circuit member '{}' for initialization of circuit '{}' is not allowed",
                 name, circuit_name
@@ -102,11 +178,12 @@ impl AsgConvertError {
     }
 
     pub fn illegal_function_assign(name: &str, span: &Span) -> Self {
-        Self::new_from_span(format!("attempt to assign to function '{}'", name), span)
+        Self::new_from_span("E0311", format!("attempt to assign to function '{}'", name), span)
     }
 
     pub fn circuit_variable_call(circuit_name: &str, name: &str, span: &Span) -> Self {
         Self::new_from_span(
+            "E0312",
             format!("cannot call variable member '{}' of circuit '{}'", name, circuit_name),
             span,
         )
@@ -114,6 +191,7 @@ impl AsgConvertError {
 
     pub fn circuit_static_call_invalid(circuit_name: &str, name: &str, span: &Span) -> Self {
         Self::new_from_span(
+            "E0313",
             format!(
                 "cannot call static function '{}' of circuit '{}' from target",
                 name, circuit_name
@@ -124,6 +202,7 @@ impl AsgConvertError {
 
     pub fn circuit_member_mut_call_invalid(circuit_name: &str, name: &str, span: &Span) -> Self {
         Self::new_from_span(
+            "E0314",
             format!(
                 "cannot call mutable member function '{}' of circuit '{}' from immutable context",
                 name, circuit_name
@@ -134,6 +213,7 @@ impl AsgConvertError {
 
     pub fn circuit_member_call_invalid(circuit_name: &str, name: &str, span: &Span) -> Self {
         Self::new_from_span(
+            "E0315",
             format!(
                 "cannot call member function '{}' of circuit '{}' from static context",
                 name, circuit_name
@@ -144,6 +224,7 @@ impl AsgConvertError {
 
     pub fn circuit_function_ref(circuit_name: &str, name: &str, span: &Span) -> Self {
         Self::new_from_span(
+            "E0316",
             format!(
                 "cannot reference function member '{}' of circuit '{}' as value",
                 name, circuit_name
@@ -153,11 +234,12 @@ impl AsgConvertError {
     }
 
     pub fn index_into_non_array(name: &str, span: &Span) -> Self {
-        Self::new_from_span(format!("failed to index into non-array '{}'", name), span)
+        Self::new_from_span("E0317", format!("failed to index into non-array '{}'", name), span)
     }
 
     pub fn invalid_assign_index(name: &str, num: &str, span: &Span) -> Self {
         Self::new_from_span(
+            "E0318",
             format!("failed to index array with invalid integer '{}'[{}]", name, num),
             span,
         )
@@ -165,6 +247,7 @@ impl AsgConvertError {
 
     pub fn invalid_backwards_assignment(name: &str, left: usize, right: usize, span: &Span) -> Self {
         Self::new_from_span(
+            "E0319",
             format!(
                 "failed to index array range for assignment with left > right '{}'[{}..{}]",
                 name, left, right
@@ -174,26 +257,39 @@ impl AsgConvertError {
     }
 
     pub fn index_into_non_tuple(name: &str, span: &Span) -> Self {
-        Self::new_from_span(format!("failed to index into non-tuple '{}'", name), span)
+        Self::new_from_span("E0320", format!("failed to index into non-tuple '{}'", name), span)
     }
 
     pub fn tuple_index_out_of_bounds(index: usize, span: &Span) -> Self {
-        Self::new_from_span(format!("tuple index out of bounds: '{}'", index), span)
+        Self::new_from_span("E0321", format!("tuple index out of bounds: '{}'", index), span)
     }
 
     pub fn unexpected_call_argument_count(expected: usize, got: usize, span: &Span) -> Self {
         Self::new_from_span(
+            "E0322",
             format!("function call expected {} arguments, got {}", expected, got),
             span,
         )
     }
 
     pub fn unresolved_function(name: &str, span: &Span) -> Self {
-        Self::new_from_span(format!("failed to resolve function: '{}'", name), span)
+        Self::new_from_span("E0323", format!("failed to resolve function: '{}'", name), span)
+    }
+
+    pub fn unresolved_call_output(name: &str, expected: &str, span: &Span) -> Self {
+        Self::new_from_span(
+            "E0324",
+            format!(
+                "failed to resolve the result type of '{}': the context must expect a {} type",
+                name, expected
+            ),
+            span,
+        )
     }
 
     pub fn unresolved_type(name: &str, span: &Span) -> Self {
         Self::new_from_span(
+            "E0325",
             format!("failed to resolve type for variable definition '{}'", name),
             span,
         )
@@ -202,6 +298,7 @@ impl AsgConvertError {
     pub fn unexpected_type(expected: &str, received: Option<&str>, span: &Span) -> Self {
         // panic!(format!("unexpected type, expected: '{}', received: '{}'", expected, received.unwrap_or("unknown")));
         Self::new_from_span(
+            "E0326",
             format!(
                 "unexpected type, expected: '{}', received: '{}'",
                 expected,
@@ -212,31 +309,52 @@ impl AsgConvertError {
     }
 
     pub fn unexpected_nonconst(span: &Span) -> Self {
-        Self::new_from_span("expected const, found non-const value".to_string(), span)
+        Self::new_from_span("E0327", "expected const, found non-const value".to_string(), span)
     }
 
     pub fn unresolved_reference(name: &str, span: &Span) -> Self {
-        Self::new_from_span(format!("failed to resolve variable reference '{}'", name), span)
+        Self::new_from_span(
+            "E0328",
+            format!("failed to resolve variable reference '{}'", name),
+            span,
+        )
     }
 
     pub fn invalid_boolean(value: &str, span: &Span) -> Self {
-        Self::new_from_span(format!("failed to parse boolean value '{}'", value), span)
+        Self::new_from_span("E0329", format!("failed to parse boolean value '{}'", value), span)
     }
 
     pub fn invalid_int(value: &str, span: &Span) -> Self {
-        Self::new_from_span(format!("failed to parse int value '{}'", value), span)
+        Self::new_from_span("E0330", format!("failed to parse int value '{}'", value), span)
+    }
+
+    pub fn invalid_fixed(value: &str, type_: &str, span: &Span) -> Self {
+        Self::new_from_span(
+            "E0331",
+            format!("failed to parse fixed point value '{}' as '{}'", value, type_),
+            span,
+        )
     }
 
     pub fn immutable_assignment(name: &str, span: &Span) -> Self {
-        Self::new_from_span(format!("illegal assignment to immutable variable '{}'", name), span)
+        Self::new_from_span(
+            "E0332",
+            format!("illegal assignment to immutable variable '{}'", name),
+            span,
+        )
     }
 
     pub fn function_missing_return(name: &str, span: &Span) -> Self {
-        Self::new_from_span(format!("function '{}' missing return for all paths", name), span)
+        Self::new_from_span(
+            "E0333",
+            format!("function '{}' missing return for all paths", name),
+            span,
+        )
     }
 
     pub fn function_return_validation(name: &str, description: &str, span: &Span) -> Self {
         Self::new_from_span(
+            "E0334",
             format!("function '{}' failed to validate return path: '{}'", name, description),
             span,
         )
@@ -244,6 +362,7 @@ impl AsgConvertError {
 
     pub fn input_ref_needs_type(category: &str, name: &str, span: &Span) -> Self {
         Self::new_from_span(
+            "E0335",
             format!("could not infer type for input in '{}': '{}'", category, name),
             span,
         )
@@ -251,11 +370,20 @@ impl AsgConvertError {
 
     pub fn invalid_self_in_global(span: &Span) -> Self {
         Self::new_from_span(
+            "E0336",
             "cannot have `mut self` or `self` arguments in global functions".to_string(),
             span,
         )
     }
 
+    pub fn extern_function_outside_core(name: &str, span: &Span) -> Self {
+        Self::new_from_span(
+            "E0337",
+            format!("extern function '{}' can only be declared in the core library", name),
+            span,
+        )
+    }
+
     pub fn parse_index_error() -> Self {
         AsgConvertError::InternalError("failed to parse index".to_string())
     }
@@ -264,6 +392,66 @@ impl AsgConvertError {
         AsgConvertError::InternalError("failed to parse dimension".to_string())
     }
 
+    pub fn invalid_fixed_type(name: &str, span: &Span) -> Self {
+        Self::new_from_span("E0338", 
+            format!(
+                "invalid type '{}': the width must be 8, 16, 32, 64 or 128, and the scale must leave room for whole numbers",
+                name
+            ),
+            span,
+        )
+    }
+
+    pub fn unresolved_interface(name: &str, span: &Span) -> Self {
+        Self::new_from_span("E0339", format!("failed to resolve interface: '{}'", name), span)
+    }
+
+    pub fn unexpected_interface_type(name: &str, span: &Span) -> Self {
+        Self::new_from_span(
+            "E0340",
+            format!("interface '{}' can only be the type of a function parameter", name),
+            span,
+        )
+    }
+
+    pub fn missing_interface_function(circuit_name: &str, interface_name: &str, name: &str, span: &Span) -> Self {
+        Self::new_from_span(
+            "E0341",
+            format!(
+                "circuit '{}' does not define function '{}' of interface '{}'",
+                circuit_name, name, interface_name
+            ),
+            span,
+        )
+    }
+
+    pub fn mismatched_interface_function(circuit_name: &str, interface_name: &str, name: &str, span: &Span) -> Self {
+        Self::new_from_span(
+            "E0342",
+            format!(
+                "function '{}' of circuit '{}' does not match its signature in interface '{}'",
+                name, circuit_name, interface_name
+            ),
+            span,
+        )
+    }
+
+    pub fn unimplemented_interface(type_: &str, interface_name: &str, span: &Span) -> Self {
+        Self::new_from_span(
+            "E0343",
+            format!("type '{}' does not implement interface '{}'", type_, interface_name),
+            span,
+        )
+    }
+
+    pub fn interface_parameter_in_entry_point(name: &str, span: &Span) -> Self {
+        Self::new_from_span(
+            "E0344",
+            format!("function '{}' cannot have parameters of interface types", name),
+            span,
+        )
+    }
+
     pub fn reference_self_outside_circuit() -> Self {
         AsgConvertError::InternalError("referenced self outside of circuit function".to_string())
     }
@@ -14,7 +14,22 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{AsgConvertError, ConstValue, Expression, ExpressionNode, FromAst, Node, PartialType, Scope, Span, Type};
+use crate::{
+    AsgConvertError,
+    CallExpression,
+    ConstValue,
+    Expression,
+    ExpressionNode,
+    FromAst,
+    Node,
+    PartialType,
+    Rounding,
+    Scope,
+    Span,
+    Type,
+    UnaryExpression,
+    UnaryOperation,
+};
 pub use leo_ast::{BinaryOperation, BinaryOperationClass};
 
 use std::cell::Cell;
@@ -79,6 +94,19 @@ impl<'a> ExpressionNode<'a> for BinaryExpression<'a> {
                 Lt => ConstValue::Boolean(left.value_lt(&right)?),
                 _ => return None,
             }),
+            (ConstValue::Fixed(left), ConstValue::Fixed(right)) => Some(match self.operation {
+                Add => ConstValue::Fixed(left.value_add(&right)?),
+                Sub => ConstValue::Fixed(left.value_sub(&right)?),
+                Mul => ConstValue::Fixed(left.value_mul(&right, Rounding::Trunc)?),
+                Div => ConstValue::Fixed(left.value_div(&right, Rounding::Trunc)?),
+                Eq => ConstValue::Boolean(left == right),
+                Ne => ConstValue::Boolean(left != right),
+                Ge => ConstValue::Boolean(left.raw >= right.raw),
+                Gt => ConstValue::Boolean(left.raw > right.raw),
+                Le => ConstValue::Boolean(left.raw <= right.raw),
+                Lt => ConstValue::Boolean(left.raw < right.raw),
+                _ => return None,
+            }),
             // (ConstValue::Field(left), ConstValue::Field(right)) => {
             //     Some(match self.operation {
             //         Add => ConstValue::Field(left.checked_add(&right)?),
@@ -111,12 +139,24 @@ impl<'a> ExpressionNode<'a> for BinaryExpression<'a> {
     }
 }
 
-impl<'a> FromAst<'a, leo_ast::BinaryExpression> for BinaryExpression<'a> {
+/// Returns the name of the circuit function overloading `operation`, if it can be overloaded.
+fn overload_name(operation: &BinaryOperation) -> Option<&'static str> {
+    match operation {
+        BinaryOperation::Add => Some("add"),
+        BinaryOperation::Sub => Some("sub"),
+        BinaryOperation::Mul => Some("mul"),
+        BinaryOperation::Div => Some("div"),
+        BinaryOperation::Eq | BinaryOperation::Ne => Some("eq"),
+        _ => None,
+    }
+}
+
+impl<'a> FromAst<'a, leo_ast::BinaryExpression> for &'a Expression<'a> {
     fn from_ast(
         scope: &'a Scope<'a>,
         value: &leo_ast::BinaryExpression,
         expected_type: Option<PartialType<'a>>,
-    ) -> Result<BinaryExpression<'a>, AsgConvertError> {
+    ) -> Result<&'a Expression<'a>, AsgConvertError> {
         let class = value.op.class();
         let expected_type = match class {
             BinaryOperationClass::Boolean => match expected_type {
@@ -132,11 +172,14 @@ impl<'a> FromAst<'a, leo_ast::BinaryExpression> for BinaryExpression<'a> {
             BinaryOperationClass::Numeric => match expected_type {
                 Some(x @ PartialType::Integer(_, _)) => Some(x),
                 Some(x @ PartialType::Type(Type::Field)) => Some(x),
+                Some(x @ PartialType::Type(Type::Fixed(_))) => Some(x),
                 Some(x @ PartialType::Type(Type::Group)) => Some(x),
+                // overloaded by the circuit
+                Some(x @ PartialType::Type(Type::Circuit(_))) => Some(x),
                 Some(x) => {
                     return Err(AsgConvertError::unexpected_type(
                         &x.to_string(),
-                        Some("integer, field, or group"),
+                        Some("integer, field, fixed, or group"),
                         &value.span,
                     ));
                 }
@@ -145,7 +188,38 @@ impl<'a> FromAst<'a, leo_ast::BinaryExpression> for BinaryExpression<'a> {
         };
 
         // left
-        let (left, right) = match <&Expression<'a>>::from_ast(scope, &*value.left, expected_type.clone()) {
+        let left = <&Expression<'a>>::from_ast(scope, &*value.left, expected_type.clone());
+
+        // circuit operators are calls of the functions overloading them
+        if let (Ok(left), Some(name)) = (&left, overload_name(&value.op)) {
+            let expected_type = match class {
+                BinaryOperationClass::Boolean => Some(PartialType::Type(Type::Boolean)),
+                BinaryOperationClass::Numeric => expected_type.clone(),
+            };
+            let call = CallExpression::from_operator(
+                scope,
+                left,
+                name,
+                std::slice::from_ref(&*value.right),
+                expected_type,
+                &value.span,
+            )?;
+            if let Some(call) = call {
+                let call = scope.alloc_expression(Expression::Call(call));
+                call.enforce_parents(call);
+                if value.op != BinaryOperation::Ne {
+                    return Ok(call);
+                }
+                return Ok(scope.alloc_expression(Expression::Unary(UnaryExpression {
+                    parent: Cell::new(None),
+                    span: Some(value.span.clone()),
+                    operation: UnaryOperation::Not,
+                    inner: Cell::new(call),
+                })));
+            }
+        }
+
+        let (left, right) = match left {
             Ok(left) => {
                 if let Some(left_type) = left.get_type() {
                     let right = <&Expression<'a>>::from_ast(scope, &*value.right, Some(left_type.partial()))?;
@@ -186,6 +260,7 @@ impl<'a> FromAst<'a, leo_ast::BinaryExpression> for BinaryExpression<'a> {
                     ()
                 }
                 Some(Type::Field) if value.op == BinaryOperation::Mul || value.op == BinaryOperation::Div => (),
+                Some(Type::Fixed(_)) if value.op != BinaryOperation::Pow => (),
                 type_ => {
                     return Err(AsgConvertError::unexpected_type(
                         "integer",
@@ -207,11 +282,11 @@ impl<'a> FromAst<'a, leo_ast::BinaryExpression> for BinaryExpression<'a> {
                 },
                 BinaryOperation::Eq | BinaryOperation::Ne => (), // all types allowed
                 _ => match left_type {
-                    Some(Type::Integer(_)) | None => (),
+                    Some(Type::Integer(_)) | Some(Type::Fixed(_)) | None => (),
                     Some(x) => {
                         return Err(AsgConvertError::unexpected_type(
                             &x.to_string(),
-                            Some("integer"),
+                            Some("integer or fixed"),
                             &value.span,
                         ));
                     }
@@ -240,13 +315,13 @@ impl<'a> FromAst<'a, leo_ast::BinaryExpression> for BinaryExpression<'a> {
             }
             (_, _) => (),
         }
-        Ok(BinaryExpression {
+        Ok(scope.alloc_expression(Expression::Binary(BinaryExpression {
             parent: Cell::new(None),
             span: Some(value.span.clone()),
             operation: value.op.clone(),
             left: Cell::new(left),
             right: Cell::new(right),
-        })
+        })))
     }
 }
 
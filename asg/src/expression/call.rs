@@ -15,8 +15,18 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
+    array_intrinsic,
+    field_intrinsic,
+    fixed_intrinsic,
+    integer_intrinsic,
+    resolve_call_argument_type,
+    resolve_call_output_type,
+    resolve_intrinsics,
     AsgConvertError,
+    Circuit,
     CircuitMember,
+    ConstFixed,
+    ConstInt,
     ConstValue,
     Expression,
     ExpressionNode,
@@ -40,6 +50,8 @@ pub struct CallExpression<'a> {
     pub function: Cell<&'a Function<'a>>,
     pub target: Cell<Option<&'a Expression<'a>>>,
     pub arguments: Vec<Cell<&'a Expression<'a>>>,
+    /// The type of the call, which differs from the declared output of intrinsics returning `Self`.
+    pub output: Type<'a>,
 }
 
 impl<'a> Node for CallExpression<'a> {
@@ -48,6 +60,22 @@ impl<'a> Node for CallExpression<'a> {
     }
 }
 
+impl<'a> CallExpression<'a> {
+    /// Returns the length of the array if this is a call of its `len` method, which is known
+    /// even if the elements of the array are not.
+    fn array_len(&self) -> Option<usize> {
+        let function = self.function.get();
+        let circuit = function.circuit.get()?;
+        if circuit.core_mapping.borrow().as_deref() != Some("array") || function.name.borrow().name != "len" {
+            return None;
+        }
+        match self.target.get()?.get_type()? {
+            Type::Array(_, len) => Some(len),
+            _ => None,
+        }
+    }
+}
+
 impl<'a> ExpressionNode<'a> for CallExpression<'a> {
     fn set_parent(&self, parent: &'a Expression<'a>) {
         self.parent.replace(Some(parent));
@@ -67,7 +95,7 @@ impl<'a> ExpressionNode<'a> for CallExpression<'a> {
     }
 
     fn get_type(&self) -> Option<Type<'a>> {
-        Some(self.function.get().output.clone())
+        Some(self.output.clone())
     }
 
     fn is_mut_ref(&self) -> bool {
@@ -75,11 +103,37 @@ impl<'a> ExpressionNode<'a> for CallExpression<'a> {
     }
 
     fn const_value(&self) -> Option<ConstValue> {
-        // static function const evaluation
-        None
+        // static function const evaluation is limited to core intrinsics
+        let function = self.function.get();
+        let circuit = function.circuit.get()?;
+        let core_mapping = circuit.core_mapping.borrow().clone()?;
+        if let Some(len) = self.array_len() {
+            return Some(ConstValue::Int(ConstInt::U32(len as u32)));
+        }
+        let target = self.target.get()?.const_value()?;
+        let arguments = self
+            .arguments
+            .iter()
+            .map(|argument| argument.get().const_value())
+            .collect::<Option<Vec<_>>>()?;
+
+        let name = &function.name.borrow().name;
+        match (&*core_mapping, &self.output) {
+            ("array", _) => array_intrinsic(name, target.array()?, &arguments[..]),
+            ("field", _) => field_intrinsic(name, target.field()?, &arguments[..]),
+            ("fixed", _) => fixed_intrinsic(name, target.fixed()?, &arguments[..]),
+            ("integer", Type::Fixed(fixed_type)) => {
+                Some(ConstValue::Fixed(ConstFixed::from_int(fixed_type, target.int()?)?))
+            }
+            ("integer", _) => integer_intrinsic(name, target.int()?, &arguments[..]),
+            _ => None,
+        }
     }
 
     fn is_consty(&self) -> bool {
+        if self.array_len().is_some() {
+            return true;
+        }
         self.target.get().map(|x| x.is_consty()).unwrap_or(true) && self.arguments.iter().all(|x| x.get().is_consty())
     }
 }
@@ -105,6 +159,10 @@ impl<'a> FromAst<'a, leo_ast::CallExpression> for CallExpression<'a> {
                 let target = <&Expression<'a>>::from_ast(scope, &**ast_circuit, None)?;
                 let circuit = match target.get_type() {
                     Some(Type::Circuit(circuit)) => circuit,
+                    Some(Type::Array(..)) => resolve_intrinsics(scope.context, "array")?,
+                    Some(Type::Field) => resolve_intrinsics(scope.context, "field")?,
+                    Some(Type::Fixed(_)) => resolve_intrinsics(scope.context, "fixed")?,
+                    Some(Type::Integer(_)) => resolve_intrinsics(scope.context, "integer")?,
                     type_ => {
                         return Err(AsgConvertError::unexpected_type(
                             "circuit",
@@ -180,31 +238,72 @@ impl<'a> FromAst<'a, leo_ast::CallExpression> for CallExpression<'a> {
                 ));
             }
         };
-        if let Some(expected) = expected_type {
-            let output: Type = function.output.clone();
-            if !expected.matches(&output) {
-                return Err(AsgConvertError::unexpected_type(
-                    &expected.to_string(),
-                    Some(&*output.to_string()),
-                    &value.span,
-                ));
-            }
+        CallExpression::from_function(scope, target, function, &value.arguments, expected_type, &value.span)
+    }
+}
+
+impl<'a> CallExpression<'a> {
+    /// Converts an operator applied to `target` and `arguments` into a call of the function of the
+    /// circuit named `name`, or returns `None` if `target` is not a circuit defining it.
+    pub(crate) fn from_operator(
+        scope: &'a Scope<'a>,
+        target: &'a Expression<'a>,
+        name: &str,
+        arguments: &[leo_ast::Expression],
+        expected_type: Option<PartialType<'a>>,
+        span: &Span,
+    ) -> Result<Option<CallExpression<'a>>, AsgConvertError> {
+        let circuit = match target.get_type() {
+            Some(Type::Circuit(circuit)) => circuit,
+            _ => return Ok(None),
+        };
+        let function = match circuit.members.borrow().get(name) {
+            Some(CircuitMember::Function(function)) => *function,
+            _ => return Ok(None),
+        };
+        let circuit_name = circuit.name.borrow().name.clone();
+        if function.qualifier == FunctionQualifier::Static {
+            return Err(AsgConvertError::circuit_static_call_invalid(&circuit_name, name, span));
+        } else if function.qualifier == FunctionQualifier::MutSelfRef && !target.is_mut_ref() {
+            return Err(AsgConvertError::circuit_member_mut_call_invalid(
+                &circuit_name,
+                name,
+                span,
+            ));
         }
-        if value.arguments.len() != function.arguments.len() {
+        Self::from_function(scope, Some(target), function, arguments, expected_type, span).map(Some)
+    }
+
+    /// Converts a call of `function` on `target` with the given `arguments`, checking them against its
+    /// signature.
+    pub(crate) fn from_function(
+        scope: &'a Scope<'a>,
+        target: Option<&'a Expression<'a>>,
+        function: &'a Function<'a>,
+        arguments: &[leo_ast::Expression],
+        expected_type: Option<PartialType<'a>>,
+        span: &Span,
+    ) -> Result<CallExpression<'a>, AsgConvertError> {
+        let receiver = target.and_then(|target| target.get_type());
+        if arguments.len() != function.arguments.len() {
             return Err(AsgConvertError::unexpected_call_argument_count(
                 function.arguments.len(),
-                value.arguments.len(),
-                &value.span,
+                arguments.len(),
+                span,
             ));
         }
 
-        let arguments = value
-            .arguments
+        let arguments = arguments
             .iter()
             .zip(function.arguments.iter())
             .map(|(expr, (_, argument))| {
                 let argument = argument.get().borrow();
-                let converted = <&Expression<'a>>::from_ast(scope, expr, Some(argument.type_.clone().partial()))?;
+                let type_ = match argument.type_ {
+                    // any circuit implementing the interface, checked when the function is instantiated
+                    Type::Interface(_) => None,
+                    _ => Some(resolve_call_argument_type(function, receiver.as_ref(), &argument.type_)),
+                };
+                let converted = <&Expression<'a>>::from_ast(scope, expr, type_)?;
                 if argument.const_ && !converted.is_consty() {
                     return Err(AsgConvertError::unexpected_nonconst(&expr.span()));
                 }
@@ -212,16 +311,60 @@ impl<'a> FromAst<'a, leo_ast::CallExpression> for CallExpression<'a> {
             })
             .collect::<Result<Vec<_>, AsgConvertError>>()?;
 
+        let function = match function.template {
+            Some(_) => function.instantiate(&bind_interfaces(function, &arguments)?)?,
+            None => function,
+        };
+
+        let output = resolve_call_output_type(function, receiver.as_ref(), &arguments, expected_type.as_ref(), span)?;
+        if let Some(expected) = expected_type {
+            if !expected.matches(&output) {
+                return Err(AsgConvertError::unexpected_type(
+                    &expected.to_string(),
+                    Some(&*output.to_string()),
+                    span,
+                ));
+            }
+        }
+
         Ok(CallExpression {
             parent: Cell::new(None),
-            span: Some(value.span.clone()),
+            span: Some(span.clone()),
             arguments,
             function: Cell::new(function),
             target: Cell::new(target),
+            output,
         })
     }
 }
 
+/// Returns the circuits of the `arguments` passed to the parameters of interface types of
+/// `function`, in order, checking that each implements its interface.
+fn bind_interfaces<'a>(
+    function: &'a Function<'a>,
+    arguments: &[Cell<&'a Expression<'a>>],
+) -> Result<Vec<&'a Circuit<'a>>, AsgConvertError> {
+    let mut circuits = vec![];
+    for (argument, (_, parameter)) in arguments.iter().zip(function.arguments.iter()) {
+        let interface = match parameter.get().borrow().type_ {
+            Type::Interface(interface) => interface,
+            _ => continue,
+        };
+        let argument = argument.get();
+        match argument.get_type() {
+            Some(Type::Circuit(circuit)) if circuit.implements(interface) => circuits.push(circuit),
+            type_ => {
+                return Err(AsgConvertError::unimplemented_interface(
+                    &type_.map(|x| x.to_string()).unwrap_or_else(|| "unknown".to_string()),
+                    &interface.name.borrow().name,
+                    &argument.span().cloned().unwrap_or_default(),
+                ));
+            }
+        }
+    }
+    Ok(circuits)
+}
+
 impl<'a> Into<leo_ast::CallExpression> for &CallExpression<'a> {
     fn into(self) -> leo_ast::CallExpression {
         let target_function = if let Some(target) = self.target.get() {
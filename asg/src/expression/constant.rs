@@ -16,6 +16,7 @@
 
 use crate::{
     AsgConvertError,
+    ConstFixed,
     ConstInt,
     ConstValue,
     Expression,
@@ -118,6 +119,15 @@ impl<'a> FromAst<'a, leo_ast::ValueExpression> for Constant<'a> {
                     ),
                 }
             }
+            Decimal(value, span) => match expected_type.and_then(PartialType::full) {
+                Some(Type::Fixed(fixed_type)) => Constant {
+                    parent: Cell::new(None),
+                    span: Some(span.clone()),
+                    value: ConstValue::Fixed(ConstFixed::parse(&fixed_type, value, span)?),
+                },
+                None => return Err(AsgConvertError::unresolved_type("fixed", span)),
+                Some(x) => return Err(AsgConvertError::unexpected_type(&x.to_string(), Some("fixed"), span)),
+            },
             Field(value, span) => {
                 match expected_type.map(PartialType::full).flatten() {
                     Some(Type::Field) | None => (),
@@ -171,6 +181,11 @@ impl<'a> FromAst<'a, leo_ast::ValueExpression> for Constant<'a> {
                     span: Some(span.clone()),
                     value: ConstValue::Field(value.parse().map_err(|_| AsgConvertError::invalid_int(&value, span))?),
                 },
+                Some(PartialType::Type(Type::Fixed(fixed_type))) => Constant {
+                    parent: Cell::new(None),
+                    span: Some(span.clone()),
+                    value: ConstValue::Fixed(ConstFixed::parse(&fixed_type, value, span)?),
+                },
                 Some(PartialType::Type(Type::Group)) => Constant {
                     parent: Cell::new(None),
                     span: Some(span.clone()),
@@ -218,6 +233,9 @@ impl<'a> Into<leo_ast::ValueExpression> for &Constant<'a> {
             ConstValue::Field(value) => {
                 leo_ast::ValueExpression::Field(value.to_string(), self.span.clone().unwrap_or_default())
             }
+            ConstValue::Fixed(value) => {
+                leo_ast::ValueExpression::Decimal(value.to_string(), self.span.clone().unwrap_or_default())
+            }
             ConstValue::Group(value) => leo_ast::ValueExpression::Group(Box::new(match value {
                 GroupValue::Single(single) => {
                     leo_ast::GroupValue::Single(single.clone(), self.span.clone().unwrap_or_default())
@@ -273,11 +273,8 @@ impl<'a> FromAst<'a, leo_ast::Expression> for &'a Expression<'a> {
             Value(value) => {
                 scope.alloc_expression(Constant::from_ast(scope, value, expected_type).map(Expression::Constant)?)
             }
-            Binary(binary) => scope
-                .alloc_expression(BinaryExpression::from_ast(scope, binary, expected_type).map(Expression::Binary)?),
-            Unary(unary) => {
-                scope.alloc_expression(UnaryExpression::from_ast(scope, unary, expected_type).map(Expression::Unary)?)
-            }
+            Binary(binary) => Self::from_ast(scope, binary, expected_type)?,
+            Unary(unary) => Self::from_ast(scope, unary, expected_type)?,
             Ternary(conditional) => scope.alloc_expression(
                 TernaryExpression::from_ast(scope, conditional, expected_type).map(Expression::Ternary)?,
             ),
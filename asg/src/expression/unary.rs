@@ -14,7 +14,19 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{AsgConvertError, ConstValue, Expression, ExpressionNode, FromAst, Node, PartialType, Scope, Span, Type};
+use crate::{
+    AsgConvertError,
+    CallExpression,
+    ConstValue,
+    Expression,
+    ExpressionNode,
+    FromAst,
+    Node,
+    PartialType,
+    Scope,
+    Span,
+    Type,
+};
 pub use leo_ast::UnaryOperation;
 
 use std::cell::Cell;
@@ -64,6 +76,7 @@ impl<'a> ExpressionNode<'a> for UnaryExpression<'a> {
                 UnaryOperation::Negate => {
                     match inner {
                         ConstValue::Int(value) => Some(ConstValue::Int(value.value_negate()?)),
+                        ConstValue::Fixed(value) => Some(ConstValue::Fixed(value.value_negate()?)),
                         // ConstValue::Group(value) => Some(ConstValue::Group(value)), TODO: groups
                         // ConstValue::Field(value) => Some(ConstValue::Field(-value)),
                         _ => None,
@@ -80,12 +93,12 @@ impl<'a> ExpressionNode<'a> for UnaryExpression<'a> {
     }
 }
 
-impl<'a> FromAst<'a, leo_ast::UnaryExpression> for UnaryExpression<'a> {
+impl<'a> FromAst<'a, leo_ast::UnaryExpression> for &'a Expression<'a> {
     fn from_ast(
         scope: &'a Scope<'a>,
         value: &leo_ast::UnaryExpression,
         expected_type: Option<PartialType<'a>>,
-    ) -> Result<UnaryExpression<'a>, AsgConvertError> {
+    ) -> Result<&'a Expression<'a>, AsgConvertError> {
         let expected_type = match value.op {
             UnaryOperation::Not => match expected_type.map(|x| x.full()).flatten() {
                 Some(Type::Boolean) | None => Some(Type::Boolean),
@@ -101,26 +114,36 @@ impl<'a> FromAst<'a, leo_ast::UnaryExpression> for UnaryExpression<'a> {
                 Some(type_ @ Type::Integer(_)) => Some(type_),
                 Some(Type::Group) => Some(Type::Group),
                 Some(Type::Field) => Some(Type::Field),
+                Some(type_ @ Type::Fixed(_)) => Some(type_),
+                // overloaded by the circuit
+                Some(type_ @ Type::Circuit(_)) => Some(type_),
                 None => None,
                 Some(type_) => {
                     return Err(AsgConvertError::unexpected_type(
                         &type_.to_string(),
-                        Some("integer, group, field"),
+                        Some("integer, group, field, fixed"),
                         &value.span,
                     ));
                 }
             },
         };
-        Ok(UnaryExpression {
+        let inner = <&Expression<'a>>::from_ast(scope, &*value.inner, expected_type.clone().map(Into::into))?;
+
+        // circuit negation is a call of the function overloading it
+        if value.op == UnaryOperation::Negate {
+            let call =
+                CallExpression::from_operator(scope, inner, "neg", &[], expected_type.map(Into::into), &value.span)?;
+            if let Some(call) = call {
+                return Ok(scope.alloc_expression(Expression::Call(call)));
+            }
+        }
+
+        Ok(scope.alloc_expression(Expression::Unary(UnaryExpression {
             parent: Cell::new(None),
             span: Some(value.span.clone()),
             operation: value.op.clone(),
-            inner: Cell::new(<&Expression<'a>>::from_ast(
-                scope,
-                &*value.inner,
-                expected_type.map(Into::into),
-            )?),
-        })
+            inner: Cell::new(inner),
+        })))
     }
 }
 
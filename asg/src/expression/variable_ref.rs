@@ -80,10 +80,13 @@ impl<'a> ExpressionNode<'a> for VariableRef<'a> {
 
                     value.get().const_value()
                 } else {
-                    for defined_variable in variables.iter() {
+                    for (index, defined_variable) in variables.iter().enumerate() {
                         let defined_variable = defined_variable.borrow();
                         if defined_variable.id == variable.id {
-                            return value.get().const_value();
+                            return match value.get().const_value()? {
+                                ConstValue::Tuple(mut values) if index < values.len() => Some(values.remove(index)),
+                                _ => None,
+                            };
                         }
                     }
                     panic!("no corresponding tuple variable found during const destructuring (corrupt asg?)");
@@ -42,6 +42,7 @@ impl<'a> Input<'a> {
             id: scope.context.get_id(),
             name: RefCell::new(Identifier::new(name.to_string())),
             members: RefCell::new(IndexMap::new()),
+            implements: RefCell::new(vec![]),
             core_mapping: RefCell::new(None),
             scope,
             span: Default::default(),
@@ -71,6 +72,7 @@ impl<'a> Input<'a> {
             id: scope.context.get_id(),
             name: RefCell::new(Identifier::new(CONTAINER_PSEUDO_CIRCUIT.to_string())),
             members: RefCell::new(container_members),
+            implements: RefCell::new(vec![]),
             core_mapping: RefCell::new(None),
             scope: input_scope,
             span: Default::default(),
@@ -22,6 +22,8 @@
 //! A new [`Asg`] type can be created from an [`Ast`].
 //! Converting to an [`Asg`] provides greater type safety by canonicalizing and checking program types.
 
+#[macro_use]
+extern crate lazy_static;
 #[macro_use]
 extern crate thiserror;
 
@@ -71,10 +73,13 @@ pub use variable::*;
 pub mod pass;
 pub use pass::*;
 
+pub mod passes;
+pub use passes::*;
+
 pub mod context;
 pub use context::*;
 
-pub use leo_ast::{Ast, Identifier, Span};
+pub use leo_ast::{Ast, Diagnostics, Identifier, Span};
 
 use std::path::Path;
 
@@ -91,16 +96,17 @@ pub struct Asg<'a> {
 }
 
 impl<'a> Asg<'a> {
-    /// Creates a new ASG from a given AST and import resolver.
+    /// Creates a new ASG from a given AST and import resolver, recording any warnings in `diagnostics`.
     pub fn new<T: ImportResolver<'a>>(
         context: AsgContext<'a>,
         ast: &Ast,
         resolver: &mut T,
+        diagnostics: &mut Diagnostics,
     ) -> Result<Self, AsgConvertError> {
-        Ok(Self {
-            context,
-            asg: InternalProgram::new(context, &ast.as_repr(), resolver)?,
-        })
+        let asg = InternalProgram::new(context, ast.as_repr(), resolver);
+        diagnostics.extend(context.warnings.borrow_mut().drain(..));
+
+        Ok(Self { context, asg: asg? })
     }
 
     /// Returns the internal program ASG representation.
@@ -108,6 +114,11 @@ impl<'a> Asg<'a> {
         self.asg.clone()
     }
 
+    /// Returns the internal program ASG representation for passes that add or remove definitions.
+    pub fn as_repr_mut(&mut self) -> &mut Program<'a> {
+        &mut self.asg
+    }
+
     // /// Serializes the ast into a JSON string.
     // pub fn to_json_string(&self) -> Result<String, serde_json::Error> {
     //     serde_json::to_string_pretty(&self.asg)
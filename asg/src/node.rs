@@ -20,6 +20,7 @@ use crate::{
     Circuit,
     Expression,
     Function,
+    Interface,
     PartialType,
     Scope,
     Span,
@@ -49,5 +50,6 @@ pub enum ArenaNode<'a> {
     Variable(Variable<'a>),
     Circuit(Circuit<'a>),
     Function(Function<'a>),
+    Interface(Interface<'a>),
     Inner(AsgContextInner<'a>),
 }
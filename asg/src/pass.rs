@@ -17,6 +17,158 @@
 use crate::Program;
 pub use leo_ast::Error as FormattedError;
 
+use std::time::{Duration, Instant};
+
+/// Whether a pass inspects the asg, lowers it, or rewrites it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PassKind {
+    /// Inspects the asg without changing it.
+    Analysis,
+    /// Rewrites the asg into a simpler form, and runs even when optimizations are disabled.
+    Lowering,
+    /// Rewrites the asg, and is skipped when optimizations are disabled.
+    Transform,
+}
+
 pub trait AsgPass {
-    fn do_pass(asg: &Program) -> Result<(), FormattedError>;
+    /// The name a pass is reported under and switched on or off by.
+    const NAME: &'static str;
+
+    const KIND: PassKind;
+
+    /// The names of the passes that must run before this pass.
+    const DEPENDENCIES: &'static [&'static str] = &[];
+
+    /// Runs the pass, returning a description of each change worth reporting to the user.
+    fn do_pass(asg: &mut Program) -> Result<Vec<String>, FormattedError>;
+}
+
+/// What a pass did to the asg.
+#[derive(Clone, Debug)]
+pub struct PassReport {
+    pub name: &'static str,
+    /// The time the pass took, or `None` if the pass was skipped.
+    pub duration: Option<Duration>,
+    pub changes: Vec<String>,
+}
+
+struct RegisteredPass {
+    name: &'static str,
+    kind: PassKind,
+    dependencies: &'static [&'static str],
+    enabled: bool,
+    run: fn(&mut Program) -> Result<Vec<String>, FormattedError>,
+}
+
+/// Runs registered passes over an asg in registration order.
+///
+/// A pass only runs if it is enabled and all of its dependencies ran before it.
+#[derive(Default)]
+pub struct PassManager {
+    passes: Vec<RegisteredPass>,
+}
+
+impl PassManager {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    ///
+    /// Registers a pass to run after the passes registered so far.
+    ///
+    /// Panics if the pass is already registered or depends on a pass that is not.
+    ///
+    pub fn register<P: AsgPass>(mut self) -> Self {
+        assert!(
+            !self.contains(P::NAME),
+            "pass `{}` is registered more than once",
+            P::NAME
+        );
+        for dependency in P::DEPENDENCIES {
+            assert!(
+                self.contains(dependency),
+                "pass `{}` depends on `{}`, which must be registered before it",
+                P::NAME,
+                dependency
+            );
+        }
+
+        self.passes.push(RegisteredPass {
+            name: P::NAME,
+            kind: P::KIND,
+            dependencies: P::DEPENDENCIES,
+            enabled: true,
+            run: P::do_pass,
+        });
+        self
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.passes.iter().any(|pass| pass.name == name)
+    }
+
+    /// Returns the kind of the pass with the given name, or `None` if no such pass is registered.
+    pub fn kind(&self, name: &str) -> Option<PassKind> {
+        self.passes.iter().find(|pass| pass.name == name).map(|pass| pass.kind)
+    }
+
+    /// Returns the names of the registered passes in the order they run.
+    pub fn names(&self) -> Vec<&'static str> {
+        self.passes.iter().map(|pass| pass.name).collect()
+    }
+
+    ///
+    /// Switches the pass with the given name on or off.
+    ///
+    /// Returns `false` if no such pass is registered.
+    ///
+    pub fn set_enabled(&mut self, name: &str, enabled: bool) -> bool {
+        match self.passes.iter_mut().find(|pass| pass.name == name) {
+            Some(pass) => {
+                pass.enabled = enabled;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Switches all transform passes off, leaving the analyses and lowerings enabled.
+    pub fn disable_transforms(&mut self) {
+        for pass in self.passes.iter_mut() {
+            if pass.kind == PassKind::Transform {
+                pass.enabled = false;
+            }
+        }
+    }
+
+    /// Runs the enabled passes over the asg, returning a report for each registered pass.
+    pub fn run(&self, asg: &mut Program) -> Result<Vec<PassReport>, FormattedError> {
+        let mut reports: Vec<PassReport> = Vec::with_capacity(self.passes.len());
+
+        for pass in self.passes.iter() {
+            let dependencies_ran = pass.dependencies.iter().all(|dependency| {
+                reports
+                    .iter()
+                    .any(|report| report.name == *dependency && report.duration.is_some())
+            });
+            if !pass.enabled || !dependencies_ran {
+                reports.push(PassReport {
+                    name: pass.name,
+                    duration: None,
+                    changes: vec![],
+                });
+                continue;
+            }
+
+            let start = Instant::now();
+            let changes = (pass.run)(asg)?;
+            reports.push(PassReport {
+                name: pass.name,
+                duration: Some(start.elapsed()),
+                changes,
+            });
+        }
+
+        Ok(reports)
+    }
 }
@@ -0,0 +1,250 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    AsgPass,
+    BlockStatement,
+    CallExpression,
+    ConstValue,
+    Constant,
+    Expression,
+    ExpressionNode,
+    ExpressionVisitor,
+    FormattedError,
+    Node,
+    PassKind,
+    Program,
+    ProgramVisitor,
+    Statement,
+    StatementVisitor,
+    VariableRef,
+    VisitResult,
+    VisitorDirector,
+};
+
+use std::cell::Cell;
+
+/// Replaces constant subexpressions with [`Constant`] nodes.
+///
+/// References to immutable variables defined with a constant value are folded into that value,
+/// and conditionals with a constant condition are replaced by the branch they take.
+pub struct ConstantFolding;
+
+struct ConstantFolder<'a, 'b> {
+    program: &'b Program<'a>,
+    changes: Vec<String>,
+}
+
+/// Returns the line a node starts on, or 0 if it has no span.
+fn line<N: Node>(node: &N) -> usize {
+    node.span().map(|span| span.line).unwrap_or_default()
+}
+
+impl<'a, 'b> ConstantFolder<'a, 'b> {
+    fn fold(&mut self, input: &Cell<&'a Expression<'a>>) -> bool {
+        let expression = input.get();
+        if let Expression::Constant(_) = expression {
+            return false;
+        }
+        let value = match expression.const_value() {
+            // compound values are kept as expressions, the compiler only enforces scalar constants
+            Some(ConstValue::Tuple(_)) | Some(ConstValue::Array(_)) | None => return false,
+            Some(value) => value,
+        };
+        if has_side_effects(expression) {
+            return false;
+        }
+
+        detach(expression);
+        let folded = self.program.scope.alloc_expression(Expression::Constant(Constant {
+            parent: Cell::new(expression.get_parent()),
+            span: expression.span().cloned(),
+            value,
+        }));
+        input.set(folded);
+        self.changes.push(format!(
+            "folded expression on line {} into a constant",
+            line(expression)
+        ));
+        true
+    }
+
+    fn fold_ternary(&mut self, input: &Cell<&'a Expression<'a>>) {
+        while let Expression::Ternary(ternary) = input.get() {
+            let (taken, dropped) = match ternary.condition.get().const_value() {
+                Some(ConstValue::Boolean(true)) => (ternary.if_true.get(), ternary.if_false.get()),
+                Some(ConstValue::Boolean(false)) => (ternary.if_false.get(), ternary.if_true.get()),
+                _ => return,
+            };
+            if has_side_effects(ternary.condition.get()) {
+                return;
+            }
+
+            detach(ternary.condition.get());
+            detach(dropped);
+            if let Some(parent) = ternary.parent.get() {
+                taken.set_parent(parent);
+            }
+            input.set(taken);
+            self.changes.push(format!(
+                "folded conditional expression on line {} into its taken branch",
+                line(ternary)
+            ));
+        }
+    }
+}
+
+impl<'a, 'b> ExpressionVisitor<'a> for ConstantFolder<'a, 'b> {
+    fn visit_expression(&mut self, input: &Cell<&'a Expression<'a>>) -> VisitResult {
+        if self.fold(input) {
+            return VisitResult::SkipChildren;
+        }
+        self.fold_ternary(input);
+        if self.fold(input) {
+            return VisitResult::SkipChildren;
+        }
+        VisitResult::VisitChildren
+    }
+}
+
+impl<'a, 'b> StatementVisitor<'a> for ConstantFolder<'a, 'b> {
+    fn visit_statement(&mut self, input: &Cell<&'a Statement<'a>>) -> VisitResult {
+        while let Statement::Conditional(conditional) = input.get() {
+            let condition = match conditional.condition.get().const_value() {
+                Some(ConstValue::Boolean(condition)) => condition,
+                _ => break,
+            };
+            if has_side_effects(conditional.condition.get()) {
+                break;
+            }
+
+            let result = conditional.result.get();
+            let (taken, dropped) = if condition {
+                (Some(result), conditional.next.get())
+            } else {
+                (conditional.next.get(), Some(result))
+            };
+            detach(conditional.condition.get());
+            if let Some(dropped) = dropped {
+                detach_statement(dropped);
+            }
+
+            // a false condition without an `else` branch leaves an empty block behind
+            let taken = taken.unwrap_or_else(|| {
+                let scope = match result {
+                    Statement::Block(block) => block.scope,
+                    _ => self.program.scope,
+                };
+                self.program.scope.alloc_statement(Statement::Block(BlockStatement {
+                    parent: Cell::new(None),
+                    span: conditional.span.clone(),
+                    statements: vec![],
+                    scope,
+                    iteration: None,
+                }))
+            });
+            taken.set_parent(conditional.parent.get());
+            input.set(taken);
+            self.changes.push(format!(
+                "folded conditional statement on line {} into its taken branch",
+                line(conditional)
+            ));
+        }
+        VisitResult::VisitChildren
+    }
+}
+
+impl<'a, 'b> ProgramVisitor<'a> for ConstantFolder<'a, 'b> {}
+
+impl AsgPass for ConstantFolding {
+    const KIND: PassKind = PassKind::Transform;
+    const NAME: &'static str = "constant_folding";
+
+    fn do_pass(asg: &mut Program) -> Result<Vec<String>, FormattedError> {
+        let pass = ConstantFolder {
+            program: asg,
+            changes: vec![],
+        };
+        let mut director = VisitorDirector::new(pass);
+        director.visit_program(asg).ok();
+        Ok(director.visitor().changes)
+    }
+}
+
+/// Returns `true` if evaluating the expression may call a user-defined function.
+fn has_side_effects<'a>(expression: &'a Expression<'a>) -> bool {
+    struct CallFinder(bool);
+
+    impl<'a> ExpressionVisitor<'a> for CallFinder {
+        fn visit_call(&mut self, input: &CallExpression<'a>) -> VisitResult {
+            let function = input.function.get();
+            let is_core = function
+                .circuit
+                .get()
+                .map(|circuit| circuit.core_mapping.borrow().is_some())
+                .unwrap_or(false);
+            if is_core {
+                VisitResult::VisitChildren
+            } else {
+                self.0 = true;
+                VisitResult::Exit
+            }
+        }
+    }
+
+    let mut director = VisitorDirector::new(CallFinder(false));
+    director.visit_expression(&Cell::new(expression)).ok();
+    director.visitor().0
+}
+
+/// Removes the variable references and assignments in a subtree that is no longer part of the asg.
+struct Detach;
+
+impl<'a> ExpressionVisitor<'a> for Detach {
+    fn visit_variable_ref(&mut self, input: &VariableRef<'a>) -> VisitResult {
+        input
+            .variable
+            .borrow_mut()
+            .references
+            .retain(|reference| !matches!(reference, Expression::VariableRef(r) if std::ptr::eq(r, input)));
+        VisitResult::VisitChildren
+    }
+}
+
+impl<'a> StatementVisitor<'a> for Detach {
+    fn visit_statement(&mut self, input: &Cell<&'a Statement<'a>>) -> VisitResult {
+        let statement = input.get();
+        if let Statement::Assign(assign) = statement {
+            assign
+                .target_variable
+                .get()
+                .borrow_mut()
+                .assignments
+                .retain(|assignment| !std::ptr::eq(*assignment, statement));
+        }
+        VisitResult::VisitChildren
+    }
+}
+
+pub(super) fn detach<'a>(expression: &'a Expression<'a>) {
+    VisitorDirector::new(Detach)
+        .visit_expression(&Cell::new(expression))
+        .ok();
+}
+
+pub(super) fn detach_statement<'a>(statement: &'a Statement<'a>) {
+    VisitorDirector::new(Detach).visit_statement(&Cell::new(statement)).ok();
+}
@@ -0,0 +1,374 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::constant_folding::detach_statement;
+use crate::{
+    ArrayAccessExpression,
+    ArrayRangeAccessExpression,
+    AsgPass,
+    BinaryExpression,
+    BinaryOperation,
+    BlockStatement,
+    CallExpression,
+    Circuit,
+    CircuitAccessExpression,
+    CircuitInitExpression,
+    CircuitMember,
+    DefinitionStatement,
+    Expression,
+    ExpressionNode,
+    ExpressionVisitor,
+    FormattedError,
+    Function,
+    Node,
+    PassKind,
+    Program,
+    ProgramVisitor,
+    Statement,
+    StatementVisitor,
+    Type,
+    UnaryExpression,
+    UnaryOperation,
+    VisitResult,
+    VisitorDirector,
+};
+
+use std::{cell::Cell, collections::HashSet};
+
+/// Removes the definitions and statements that cannot affect the output of a program.
+///
+/// Functions and circuits not reachable from `main` or a test function are dropped from the program
+/// and its imports. Programs without either, such as libraries, keep all of their definitions.
+/// Definitions of variables that are never read and expression statements are dropped if evaluating
+/// them cannot fail or call a function, so arithmetic that may overflow is kept for its constraints.
+/// Console statements are always kept.
+pub struct DeadCodeElimination;
+
+impl AsgPass for DeadCodeElimination {
+    const KIND: PassKind = PassKind::Transform;
+    const NAME: &'static str = "dead_code_elimination";
+
+    fn do_pass(asg: &mut Program) -> Result<Vec<String>, FormattedError> {
+        let mut changes = vec![];
+
+        let roots = asg
+            .functions
+            .get("main")
+            .into_iter()
+            .copied()
+            .chain(asg.test_functions.values().map(|(function, _)| *function))
+            .collect::<Vec<_>>();
+        if !roots.is_empty() {
+            let mut reachable = Reachable::default();
+            for function in roots {
+                reachable.add_function(function);
+            }
+            reachable.walk();
+            remove_unreachable(asg, &reachable, None, &mut changes);
+        }
+
+        // removing a statement can leave the definitions it read from unused
+        loop {
+            let mut director = VisitorDirector::new(UnusedStatements {
+                program: &*asg,
+                changes: vec![],
+            });
+            director.visit_program(asg).ok();
+            let removed = director.visitor().changes;
+            if removed.is_empty() {
+                break;
+            }
+            changes.extend(removed);
+        }
+
+        Ok(changes)
+    }
+}
+
+/// The ids of the functions and circuits reachable from the roots of a program.
+#[derive(Default)]
+struct Reachable<'a> {
+    functions: HashSet<u32>,
+    circuits: HashSet<u32>,
+    queue: Vec<&'a Function<'a>>,
+}
+
+impl<'a> Reachable<'a> {
+    fn add_function(&mut self, function: &'a Function<'a>) {
+        if !self.functions.insert(function.id) {
+            return;
+        }
+        if let Some(circuit) = function.circuit.get() {
+            self.add_circuit(circuit);
+        }
+        for argument in function.arguments.values() {
+            self.add_type(&argument.get().borrow().type_);
+        }
+        self.add_type(&function.output);
+        self.queue.push(function);
+    }
+
+    fn add_circuit(&mut self, circuit: &'a Circuit<'a>) {
+        if !self.circuits.insert(circuit.id) {
+            return;
+        }
+        for member in circuit.members.borrow().values() {
+            match member {
+                CircuitMember::Variable(type_) => self.add_type(type_),
+                CircuitMember::Function(function) => self.add_function(function),
+            }
+        }
+    }
+
+    fn add_type(&mut self, type_: &Type<'a>) {
+        match type_ {
+            Type::Circuit(circuit) => self.add_circuit(circuit),
+            Type::Array(element, _) => self.add_type(element),
+            Type::Tuple(elements) => {
+                for element in elements {
+                    self.add_type(element);
+                }
+            }
+            _ => (),
+        }
+    }
+
+    /// Visits the bodies of the functions added so far, and of the functions they reach in turn.
+    fn walk(&mut self) {
+        while let Some(function) = self.queue.pop() {
+            VisitorDirector::new(ReachableVisitor { reachable: self })
+                .visit_function(function)
+                .ok();
+        }
+    }
+
+    fn contains_function(&self, function: &Function<'a>) -> bool {
+        self.functions.contains(&function.id)
+            || function
+                .instances
+                .borrow()
+                .values()
+                .any(|instance| self.functions.contains(&instance.id))
+    }
+}
+
+struct ReachableVisitor<'a, 'b> {
+    reachable: &'b mut Reachable<'a>,
+}
+
+impl<'a, 'b> ExpressionVisitor<'a> for ReachableVisitor<'a, 'b> {
+    fn visit_expression(&mut self, input: &Cell<&'a Expression<'a>>) -> VisitResult {
+        if let Some(type_) = input.get().get_type() {
+            self.reachable.add_type(&type_);
+        }
+        VisitResult::VisitChildren
+    }
+
+    fn visit_call(&mut self, input: &CallExpression<'a>) -> VisitResult {
+        self.reachable.add_function(input.function.get());
+        VisitResult::VisitChildren
+    }
+
+    fn visit_circuit_access(&mut self, input: &CircuitAccessExpression<'a>) -> VisitResult {
+        self.reachable.add_circuit(input.circuit.get());
+        VisitResult::VisitChildren
+    }
+
+    fn visit_circuit_init(&mut self, input: &CircuitInitExpression<'a>) -> VisitResult {
+        self.reachable.add_circuit(input.circuit.get());
+        VisitResult::VisitChildren
+    }
+}
+
+impl<'a, 'b> StatementVisitor<'a> for ReachableVisitor<'a, 'b> {
+    fn visit_definition(&mut self, input: &DefinitionStatement<'a>) -> VisitResult {
+        for variable in input.variables.iter() {
+            self.reachable.add_type(&variable.borrow().type_);
+        }
+        VisitResult::VisitChildren
+    }
+}
+
+impl<'a, 'b> ProgramVisitor<'a> for ReachableVisitor<'a, 'b> {}
+
+fn remove_unreachable<'a>(
+    program: &mut Program<'a>,
+    reachable: &Reachable<'a>,
+    module: Option<&str>,
+    changes: &mut Vec<String>,
+) {
+    let location = match module {
+        Some(module) => format!(" from `{}`", module),
+        None => String::new(),
+    };
+
+    program.functions.retain(|name, function| {
+        let keep = reachable.contains_function(function);
+        if !keep {
+            changes.push(format!("removed unused function `{}`{}", name, location));
+        }
+        keep
+    });
+    program.circuits.retain(|name, circuit| {
+        let keep = reachable.circuits.contains(&circuit.id);
+        if !keep {
+            changes.push(format!("removed unused circuit `{}`{}", name, location));
+        }
+        keep
+    });
+
+    for (name, import) in program.imported_modules.iter_mut() {
+        remove_unreachable(import, reachable, Some(name), changes);
+    }
+}
+
+/// Removes unused definitions and expression statements from blocks.
+struct UnusedStatements<'a, 'b> {
+    program: &'b Program<'a>,
+    changes: Vec<String>,
+}
+
+impl<'a, 'b> UnusedStatements<'a, 'b> {
+    fn is_unused(statement: &'a Statement<'a>) -> bool {
+        match statement {
+            Statement::Definition(definition) => {
+                !may_fail(definition.value.get())
+                    && definition.variables.iter().all(|variable| {
+                        let variable = variable.borrow();
+                        variable.references.is_empty()
+                            && variable
+                                .assignments
+                                .iter()
+                                .all(|assignment| std::ptr::eq(*assignment, statement))
+                    })
+            }
+            Statement::Expression(expression) => !may_fail(expression.expression.get()),
+            _ => false,
+        }
+    }
+
+    fn describe(statement: &'a Statement<'a>) -> String {
+        let line = statement
+            .span()
+            .map(|span| format!(" on line {}", span.line))
+            .unwrap_or_default();
+        match statement {
+            Statement::Definition(definition) => {
+                let names = definition
+                    .variables
+                    .iter()
+                    .map(|variable| format!("`{}`", variable.borrow().name.name))
+                    .collect::<Vec<_>>();
+                format!("removed unused definition of {}{}", names.join(", "), line)
+            }
+            _ => format!("removed unused expression statement{}", line),
+        }
+    }
+}
+
+impl<'a, 'b> ExpressionVisitor<'a> for UnusedStatements<'a, 'b> {}
+
+impl<'a, 'b> StatementVisitor<'a> for UnusedStatements<'a, 'b> {
+    fn visit_statement(&mut self, input: &Cell<&'a Statement<'a>>) -> VisitResult {
+        let block = match input.get() {
+            Statement::Block(block) => block,
+            _ => return VisitResult::VisitChildren,
+        };
+
+        // later statements go first, so the definitions they read from are seen without their references
+        let mut kept = vec![];
+        let mut removed = vec![];
+        for statement in block.statements.iter().rev() {
+            let statement = statement.get();
+            if Self::is_unused(statement) {
+                detach_statement(statement);
+                removed.push(Self::describe(statement));
+            } else {
+                kept.push(statement);
+            }
+        }
+        if removed.is_empty() {
+            return VisitResult::VisitChildren;
+        }
+        removed.reverse();
+        self.changes.extend(removed);
+
+        let replacement = self.program.scope.alloc_statement(Statement::Block(BlockStatement {
+            parent: Cell::new(block.parent.get()),
+            span: block.span.clone(),
+            statements: kept.into_iter().rev().map(Cell::new).collect(),
+            scope: block.scope,
+            iteration: block.iteration,
+        }));
+        if let Statement::Block(replacement_block) = &*replacement {
+            for statement in replacement_block.statements.iter() {
+                statement.get().set_parent(Some(&*replacement));
+            }
+        }
+        input.set(replacement);
+        VisitResult::VisitChildren
+    }
+}
+
+impl<'a, 'b> ProgramVisitor<'a> for UnusedStatements<'a, 'b> {}
+
+/// Returns `true` if evaluating the expression may call a function or enforce a constraint that can fail.
+pub(super) fn may_fail<'a>(expression: &'a Expression<'a>) -> bool {
+    struct FailureFinder(bool);
+
+    impl FailureFinder {
+        fn found(&mut self) -> VisitResult {
+            self.0 = true;
+            VisitResult::Exit
+        }
+    }
+
+    impl<'a> ExpressionVisitor<'a> for FailureFinder {
+        fn visit_array_access(&mut self, _input: &ArrayAccessExpression<'a>) -> VisitResult {
+            self.found()
+        }
+
+        fn visit_array_range_access(&mut self, _input: &ArrayRangeAccessExpression<'a>) -> VisitResult {
+            self.found()
+        }
+
+        fn visit_binary(&mut self, input: &BinaryExpression<'a>) -> VisitResult {
+            match input.operation {
+                BinaryOperation::Add
+                | BinaryOperation::Sub
+                | BinaryOperation::Mul
+                | BinaryOperation::Div
+                | BinaryOperation::Pow => self.found(),
+                _ => VisitResult::VisitChildren,
+            }
+        }
+
+        fn visit_call(&mut self, _input: &CallExpression<'a>) -> VisitResult {
+            self.found()
+        }
+
+        fn visit_unary(&mut self, input: &UnaryExpression<'a>) -> VisitResult {
+            match input.operation {
+                UnaryOperation::Negate => self.found(),
+                UnaryOperation::Not => VisitResult::VisitChildren,
+            }
+        }
+    }
+
+    let mut director = VisitorDirector::new(FailureFinder(false));
+    director.visit_expression(&Cell::new(expression)).ok();
+    director.visitor().0
+}
@@ -0,0 +1,286 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{constant_folding::detach, dead_code_elimination::may_fail};
+use crate::{
+    AsgContext,
+    AsgPass,
+    CallExpression,
+    ConstValue,
+    Expression,
+    ExpressionNode,
+    ExpressionVisitor,
+    FormattedError,
+    Function,
+    IdentityReducer,
+    Node,
+    PassKind,
+    Program,
+    ProgramVisitor,
+    ReconstructingDirector,
+    ReconstructingReducerExpression,
+    Statement,
+    StatementVisitor,
+    Variable,
+    VariableRef,
+    VisitResult,
+    VisitorDirector,
+};
+use leo_ast::{InlineHint, Warning};
+
+use std::cell::Cell;
+
+/// The name of the warning for an `@inline` function whose calls cannot be inlined.
+pub const INEFFECTIVE_INLINE: &str = "ineffective_inline";
+
+/// Calls are inlined for at most this many rounds, so calls exposed by inlining are inlined in turn
+/// without recursing forever.
+const MAX_INLINING_ROUNDS: usize = 8;
+
+/// Functions without an annotation are inlined if their returned expression has at most this many
+/// nodes that may generate constraints.
+const MAX_INLINED_NODES: usize = 8;
+
+/// Replaces calls to functions whose body is a single `return` with the returned expression.
+///
+/// Parameters are substituted with the arguments of the call, so constant arguments are folded into
+/// the body by the constant folding pass that follows. A call is only inlined if no argument other
+/// than a constant or variable would be evaluated more than once, and no argument that may fail would
+/// be dropped. Calls to `@noinline` functions, methods, and functions with interface parameters are
+/// kept, and so are calls to large functions unless they are annotated with `@inline`.
+pub struct Inlining;
+
+impl AsgPass for Inlining {
+    const KIND: PassKind = PassKind::Transform;
+    const NAME: &'static str = "inlining";
+
+    fn do_pass(asg: &mut Program) -> Result<Vec<String>, FormattedError> {
+        let mut changes = vec![];
+
+        for round in 0..MAX_INLINING_ROUNDS {
+            let inlined = changes.len();
+            VisitorDirector::new(Inliner {
+                context: asg.context,
+                warn: round == 0,
+                changes: &mut changes,
+                function: None,
+            })
+            .visit_program(asg)
+            .ok();
+            if changes.len() == inlined {
+                break;
+            }
+        }
+
+        Ok(changes)
+    }
+}
+
+/// The line and column of a node, which the compiler uses to name the namespace of its constraints.
+type SpanKey = (usize, usize);
+
+struct Inliner<'a, 'b> {
+    context: AsgContext<'a>,
+    /// Whether to warn about `@inline` functions, only once per function.
+    warn: bool,
+    changes: &'b mut Vec<String>,
+    function: Option<&'a Function<'a>>,
+}
+
+/// Returns the expression returned by a function if its body is nothing but a `return`.
+fn returned_expression<'a>(function: &'a Function<'a>) -> Option<&'a Expression<'a>> {
+    if function.circuit.get().is_some() || function.template.is_some() {
+        return None;
+    }
+    match function.body.get()? {
+        Statement::Block(block) => match block.statements.as_slice() {
+            [statement] => match statement.get() {
+                Statement::Return(statement) => Some(statement.expression.get()),
+                _ => None,
+            },
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+impl<'a, 'b> Inliner<'a, 'b> {
+    fn should_inline(call: &CallExpression<'a>, function: &'a Function<'a>, returned: &'a Expression<'a>) -> bool {
+        let evaluated_once = function
+            .arguments
+            .values()
+            .zip(call.arguments.iter())
+            .all(
+                |(parameter, argument)| match parameter.get().borrow().references.len() {
+                    // an unused argument is dropped, which must not drop a constraint that could fail
+                    0 => !may_fail(argument.get()),
+                    1 => true,
+                    _ => span_keys(argument.get()).is_empty(),
+                },
+            );
+        evaluated_once
+            && match function.inline {
+                Some(InlineHint::Inline) => true,
+                Some(InlineHint::NoInline) => false,
+                None => span_keys(returned).len() <= MAX_INLINED_NODES,
+            }
+    }
+
+    fn inline(&mut self, input: &Cell<&'a Expression<'a>>) -> bool {
+        let call = match input.get() {
+            Expression::Call(call) => call,
+            _ => return false,
+        };
+        let function = call.function.get();
+        if self.function.is_some_and(|current| std::ptr::eq(current, function)) {
+            return false;
+        }
+        let returned = match returned_expression(function) {
+            Some(returned) => returned,
+            None => return false,
+        };
+        if !Self::should_inline(call, function, returned) {
+            return false;
+        }
+
+        let arguments = function
+            .arguments
+            .values()
+            .map(Cell::get)
+            .zip(call.arguments.iter().map(Cell::get))
+            .collect();
+        let mut director = ReconstructingDirector::new(self.context, Substitute {
+            context: self.context,
+            arguments,
+        });
+        let inlined = director.reduce_expression(returned);
+        if let Some(parent) = call.parent.get() {
+            inlined.set_parent(parent);
+        }
+        attach(inlined);
+        detach(input.get());
+        input.set(inlined);
+
+        let line = call
+            .span
+            .as_ref()
+            .map(|span| format!(" on line {}", span.line))
+            .unwrap_or_default();
+        self.changes
+            .push(format!("inlined call to `{}`{}", function.name.borrow().name, line));
+        true
+    }
+}
+
+impl<'a, 'b> ExpressionVisitor<'a> for Inliner<'a, 'b> {
+    fn visit_expression(&mut self, input: &Cell<&'a Expression<'a>>) -> VisitResult {
+        // calls in the inlined expression are left to the next round
+        if self.inline(input) {
+            VisitResult::SkipChildren
+        } else {
+            VisitResult::VisitChildren
+        }
+    }
+}
+
+impl<'a, 'b> StatementVisitor<'a> for Inliner<'a, 'b> {}
+
+impl<'a, 'b> ProgramVisitor<'a> for Inliner<'a, 'b> {
+    fn visit_function(&mut self, input: &'a Function<'a>) -> VisitResult {
+        if self.warn && input.inline == Some(InlineHint::Inline) && returned_expression(input).is_none() {
+            self.context.warn(Warning::new_from_span(
+                INEFFECTIVE_INLINE,
+                format!(
+                    "calls to `{}` are not inlined, only functions whose body is a single `return` can be",
+                    input.name.borrow().name
+                ),
+                input.span.clone().unwrap_or_default(),
+            ));
+        }
+        self.function = Some(input);
+        VisitResult::VisitChildren
+    }
+}
+
+/// Collects the span keys of the nodes that may name a constraint namespace.
+#[derive(Default)]
+struct SpanKeys(Vec<SpanKey>);
+
+impl<'a> ExpressionVisitor<'a> for SpanKeys {
+    fn visit_expression(&mut self, input: &Cell<&'a Expression<'a>>) -> VisitResult {
+        let named = match input.get() {
+            Expression::VariableRef(_) => false,
+            Expression::Constant(constant) => matches!(
+                constant.value,
+                ConstValue::Group(_) | ConstValue::Tuple(_) | ConstValue::Array(_)
+            ),
+            _ => true,
+        };
+        if let Some(span) = input.get().span().filter(|_| named) {
+            self.0.push((span.line, span.start));
+        }
+        VisitResult::VisitChildren
+    }
+}
+
+fn span_keys<'a>(expression: &'a Expression<'a>) -> Vec<SpanKey> {
+    let mut director = VisitorDirector::new(SpanKeys::default());
+    director.visit_expression(&Cell::new(expression)).ok();
+    director.visitor().0
+}
+
+/// Copies an expression, replacing references to the parameters of a function with the arguments of a call.
+struct Substitute<'a> {
+    context: AsgContext<'a>,
+    arguments: Vec<(&'a Variable<'a>, &'a Expression<'a>)>,
+}
+
+impl<'a> ReconstructingReducerExpression<'a> for Substitute<'a> {
+    fn reduce_variable_ref(&mut self, input: &VariableRef<'a>) -> Expression<'a> {
+        match self
+            .arguments
+            .iter()
+            .find(|(parameter, _)| std::ptr::eq(*parameter, input.variable))
+        {
+            Some((_, argument)) => ReconstructingDirector::new(self.context, IdentityReducer)
+                .reduce_expression(argument)
+                .clone(),
+            None => Expression::VariableRef(VariableRef {
+                parent: Cell::new(None),
+                span: input.span.clone(),
+                variable: input.variable,
+            }),
+        }
+    }
+}
+
+/// Records the variable references in a subtree that was added to the asg.
+struct Attach;
+
+impl<'a> ExpressionVisitor<'a> for Attach {
+    fn visit_expression(&mut self, input: &Cell<&'a Expression<'a>>) -> VisitResult {
+        if let Expression::VariableRef(reference) = input.get() {
+            reference.variable.borrow_mut().references.push(input.get());
+        }
+        VisitResult::VisitChildren
+    }
+}
+
+fn attach<'a>(expression: &'a Expression<'a>) {
+    VisitorDirector::new(Attach)
+        .visit_expression(&Cell::new(expression))
+        .ok();
+}
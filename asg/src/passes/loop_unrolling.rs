@@ -0,0 +1,300 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    relink_variables,
+    ArrayAccessExpression,
+    AsgContext,
+    AsgPass,
+    AssignAccess,
+    AssignStatement,
+    BlockStatement,
+    ConstInt,
+    ConstValue,
+    Constant,
+    DefinitionStatement,
+    Expression,
+    ExpressionNode,
+    ExpressionVisitor,
+    FormattedError,
+    IdentityReducer,
+    InnerVariable,
+    IterationStatement,
+    PassKind,
+    Program,
+    ProgramVisitor,
+    ReconstructingDirector,
+    ReconstructingReducerExpression,
+    ReconstructingReducerStatement,
+    Scope,
+    Statement,
+    StatementVisitor,
+    Type,
+    Variable,
+    VariableDeclaration,
+    VariableRef,
+    VisitResult,
+    VisitorDirector,
+};
+
+use std::{
+    cell::{Cell, RefCell},
+    collections::HashMap,
+};
+
+/// Replaces loops whose bounds are known at compile time with a block for each iteration.
+///
+/// Every block defines its own copy of the loop variable, a constant of the type of the range, and
+/// holds a copy of the loop body that refers to it, so later passes can fold each iteration on its
+/// own. Loops over a mutable array are kept, since their body could change the array.
+pub struct LoopUnrolling;
+
+impl AsgPass for LoopUnrolling {
+    const KIND: PassKind = PassKind::Lowering;
+    const NAME: &'static str = "loop_unrolling";
+
+    fn do_pass(asg: &mut Program) -> Result<Vec<String>, FormattedError> {
+        let mut director = VisitorDirector::new(Unroller {
+            context: asg.context,
+            changes: vec![],
+        });
+        director.visit_program(asg).ok();
+        Ok(director.visitor().changes)
+    }
+}
+
+struct Unroller<'a> {
+    context: AsgContext<'a>,
+    changes: Vec<String>,
+}
+
+/// Returns the value of a loop bound if it is known at compile time.
+fn bound<'a>(expression: &'a Expression<'a>) -> Option<usize> {
+    match expression.const_value()? {
+        ConstValue::Int(value) => value.to_usize(),
+        _ => None,
+    }
+}
+
+impl<'a> Unroller<'a> {
+    fn unroll(
+        &mut self,
+        statement: &'a Statement<'a>,
+        iteration: &IterationStatement<'a>,
+    ) -> Option<&'a Statement<'a>> {
+        let start = bound(iteration.start.get())?;
+        let stop = bound(iteration.stop.get())?;
+        let array = match iteration.array.get() {
+            None => None,
+            Some(array @ Expression::VariableRef(reference)) if !reference.variable.borrow().mutable => Some(array),
+            Some(array @ Expression::Constant(_)) => Some(array),
+            Some(_) => return None,
+        };
+        let enclosing = match iteration.body.get() {
+            Statement::Block(body) => body.scope.parent_scope.get().unwrap_or(body.scope),
+            _ => return None,
+        };
+        let span = iteration.span.clone().unwrap_or_default();
+        let (name, type_, const_) = {
+            let variable = iteration.variable.borrow();
+            (variable.name.clone(), variable.type_.clone(), variable.const_)
+        };
+
+        let mut blocks = vec![];
+        for i in start..stop {
+            let value = match array {
+                // the loop variable is typed after the range, not the `u32` of an array index
+                None => match &type_ {
+                    Type::Integer(int_type) => Expression::Constant(Constant {
+                        parent: Cell::new(None),
+                        span: Some(span.clone()),
+                        value: ConstValue::Int(ConstInt::parse(int_type, &i.to_string(), &span).ok()?),
+                    }),
+                    _ => return None,
+                },
+                Some(array) => Expression::ArrayAccess(ArrayAccessExpression {
+                    parent: Cell::new(None),
+                    span: Some(span.clone()),
+                    array: Cell::new(
+                        ReconstructingDirector::new(self.context, IdentityReducer).reduce_expression(array),
+                    ),
+                    index: Cell::new(self.context.alloc_expression(Expression::Constant(Constant {
+                        parent: Cell::new(None),
+                        span: Some(span.clone()),
+                        value: ConstValue::Int(ConstInt::U32(i as u32)),
+                    }))),
+                }),
+            };
+            let value = &*self.context.alloc_expression(value);
+            value.enforce_parents(value);
+
+            let scope = enclosing.make_subscope();
+            let variable = define(scope, InnerVariable {
+                id: self.context.get_id(),
+                name: name.clone(),
+                type_: type_.clone(),
+                mutable: false,
+                const_,
+                declaration: VariableDeclaration::Definition,
+                references: vec![],
+                assignments: vec![],
+            });
+
+            let definition = self.context.alloc_statement(Statement::Definition(DefinitionStatement {
+                parent: Cell::new(None),
+                span: Some(span.clone()),
+                variables: vec![variable],
+                value: Cell::new(value),
+            }));
+            let body = ReconstructingDirector::new(self.context, Substitute {
+                scope,
+                variables: vec![(iteration.variable.borrow().id, variable)].into_iter().collect(),
+            })
+            .reduce_statement(iteration.body.get());
+
+            let block = &*self.context.alloc_statement(Statement::Block(BlockStatement {
+                parent: Cell::new(None),
+                span: Some(span.clone()),
+                statements: vec![Cell::new(definition), Cell::new(body)],
+                scope,
+                iteration: Some(i),
+            }));
+            definition.set_parent(Some(block));
+            body.set_parent(Some(block));
+            blocks.push(block);
+        }
+
+        let unrolled = &*self.context.alloc_statement(Statement::Block(BlockStatement {
+            parent: Cell::new(statement.get_parent()),
+            span: Some(span.clone()),
+            statements: blocks.iter().copied().map(Cell::new).collect(),
+            scope: enclosing,
+            iteration: None,
+        }));
+        for block in blocks {
+            block.set_parent(Some(unrolled));
+        }
+        relink_variables(statement, false);
+        relink_variables(unrolled, true);
+
+        let iterations = stop.saturating_sub(start);
+        self.changes.push(format!(
+            "unrolled loop on line {} into {} iteration{}",
+            span.line,
+            iterations,
+            if iterations == 1 { "" } else { "s" }
+        ));
+        Some(unrolled)
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for Unroller<'a> {}
+
+impl<'a> StatementVisitor<'a> for Unroller<'a> {
+    fn visit_statement(&mut self, input: &Cell<&'a Statement<'a>>) -> VisitResult {
+        if let Statement::Iteration(iteration) = input.get() {
+            if let Some(unrolled) = self.unroll(input.get(), iteration) {
+                input.set(unrolled);
+            }
+        }
+        // loops nested in the body are unrolled in every copy of it
+        VisitResult::VisitChildren
+    }
+}
+
+impl<'a> ProgramVisitor<'a> for Unroller<'a> {}
+
+/// Defines a variable in the scope of one iteration.
+fn define<'a>(scope: &'a Scope<'a>, variable: InnerVariable<'a>) -> &'a Variable<'a> {
+    let name = variable.name.name.clone();
+    let variable = &*scope.alloc_variable(RefCell::new(variable));
+    scope.variables.borrow_mut().insert(name, variable);
+    variable
+}
+
+/// Copies a loop body for one iteration.
+///
+/// The copy refers to the copy of the loop variable for the iteration, and every variable the body defines
+/// is replaced by a fresh one in the scope of the iteration, so each copy can be folded on its own.
+struct Substitute<'a> {
+    scope: &'a Scope<'a>,
+    /// The copies of the variables defined so far, by the ids of the variables they replace
+    variables: HashMap<u32, &'a Variable<'a>>,
+}
+
+impl<'a> Substitute<'a> {
+    fn substitute(&self, variable: &'a Variable<'a>) -> &'a Variable<'a> {
+        let id = variable.borrow().id;
+        self.variables.get(&id).copied().unwrap_or(variable)
+    }
+}
+
+impl<'a> ReconstructingReducerExpression<'a> for Substitute<'a> {
+    fn reduce_variable_ref(&mut self, input: &VariableRef<'a>) -> Expression<'a> {
+        Expression::VariableRef(VariableRef {
+            parent: Cell::new(None),
+            span: input.span.clone(),
+            variable: self.substitute(input.variable),
+        })
+    }
+}
+
+impl<'a> ReconstructingReducerStatement<'a> for Substitute<'a> {
+    fn reduce_assign(
+        &mut self,
+        input: &AssignStatement<'a>,
+        accesses: Vec<AssignAccess<'a>>,
+        value: &'a Expression<'a>,
+    ) -> Statement<'a> {
+        Statement::Assign(AssignStatement {
+            parent: Cell::new(None),
+            span: input.span.clone(),
+            operation: input.operation.clone(),
+            target_variable: Cell::new(self.substitute(input.target_variable.get())),
+            target_accesses: accesses,
+            value: Cell::new(value),
+        })
+    }
+
+    fn reduce_definition(&mut self, input: &DefinitionStatement<'a>, value: &'a Expression<'a>) -> Statement<'a> {
+        let variables = input
+            .variables
+            .iter()
+            .map(|variable| {
+                let variable = variable.borrow();
+                let copy = define(self.scope, InnerVariable {
+                    id: self.scope.context.get_id(),
+                    name: variable.name.clone(),
+                    type_: variable.type_.clone(),
+                    mutable: variable.mutable,
+                    const_: variable.const_,
+                    declaration: variable.declaration,
+                    references: vec![],
+                    assignments: vec![],
+                });
+                self.variables.insert(variable.id, copy);
+                copy
+            })
+            .collect();
+
+        Statement::Definition(DefinitionStatement {
+            parent: Cell::new(None),
+            span: input.span.clone(),
+            variables,
+            value: Cell::new(value),
+        })
+    }
+}
@@ -0,0 +1,40 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Optimization passes that rewrite an asg in place before constraint generation.
+
+mod constant_folding;
+pub use constant_folding::*;
+
+mod dead_code_elimination;
+pub use dead_code_elimination::*;
+
+mod inlining;
+pub use inlining::*;
+
+mod loop_unrolling;
+pub use loop_unrolling::*;
+
+use crate::PassManager;
+
+/// Returns the passes the compiler runs over every program, in the order they run.
+pub fn default_passes() -> PassManager {
+    PassManager::new()
+        .register::<LoopUnrolling>()
+        .register::<Inlining>()
+        .register::<ConstantFolding>()
+        .register::<DeadCodeElimination>()
+}
@@ -0,0 +1,116 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Types of intrinsic methods that depend on their call.
+//!
+//! Intrinsic methods are the functions of a core circuit called on a value of a type without
+//! circuits, such as `a.inv()` on a `field`. One declaration serves every type of receiver, so in
+//! their signatures `Self` stands for the type of the receiver. Methods whose types depend on more
+//! than the receiver have an explicit rule here.
+
+use crate::{AsgConvertError, Circuit, Expression, ExpressionNode, Function, PartialType, Span, Type};
+
+use std::cell::Cell;
+
+/// Returns the core circuit of `function` if calling it on a value of type `receiver` is a call
+/// of an intrinsic method.
+fn intrinsic_circuit<'a>(function: &'a Function<'a>, receiver: &Type<'a>) -> Option<&'a Circuit<'a>> {
+    let circuit = function.circuit.get()?;
+    circuit.core_mapping.borrow().as_ref()?;
+    if matches!(receiver, Type::Circuit(_)) {
+        return None;
+    }
+    Some(circuit)
+}
+
+/// Returns the core mapping of `circuit` and the name of `function`, which select its rule.
+fn rule_key<'a>(circuit: &'a Circuit<'a>, function: &'a Function<'a>) -> (String, String) {
+    (
+        circuit.core_mapping.borrow().clone().unwrap_or_default(),
+        function.name.borrow().name.clone(),
+    )
+}
+
+/// Returns `type_` with `Self` of the core circuit `circuit` replaced by `receiver`.
+fn resolve_self<'a>(circuit: &'a Circuit<'a>, type_: &Type<'a>, receiver: &Type<'a>) -> Type<'a> {
+    match type_ {
+        Type::Circuit(self_type) if *self_type == circuit => receiver.clone(),
+        type_ => type_.clone(),
+    }
+}
+
+/// Returns the expected type of an argument declared with type `declared` in a call of `function`
+/// on a value of type `receiver`.
+pub fn resolve_call_argument_type<'a>(
+    function: &'a Function<'a>,
+    receiver: Option<&Type<'a>>,
+    declared: &Type<'a>,
+) -> PartialType<'a> {
+    let (circuit, receiver) =
+        match receiver.and_then(|receiver| Some((intrinsic_circuit(function, receiver)?, receiver))) {
+            Some(intrinsic) => intrinsic,
+            None => return declared.clone().partial(),
+        };
+
+    match (rule_key(circuit, function), receiver) {
+        // takes a value of the element type
+        ((mapping, name), Type::Array(element, _)) if mapping == "array" && name == "contains" => {
+            (**element).clone().partial()
+        }
+        // takes an array of the element type and any length
+        ((mapping, name), Type::Array(element, _)) if mapping == "array" && name == "concat" => {
+            PartialType::Array(Some(Box::new((**element).clone().partial())), None)
+        }
+        _ => resolve_self(circuit, declared, receiver).partial(),
+    }
+}
+
+/// Returns the type of the result of a call of `function` on a value of type `receiver` with the
+/// already converted `arguments`, where the context of the call expects `expected`.
+pub fn resolve_call_output_type<'a>(
+    function: &'a Function<'a>,
+    receiver: Option<&Type<'a>>,
+    arguments: &[Cell<&'a Expression<'a>>],
+    expected: Option<&PartialType<'a>>,
+    span: &Span,
+) -> Result<Type<'a>, AsgConvertError> {
+    let (circuit, receiver) =
+        match receiver.and_then(|receiver| Some((intrinsic_circuit(function, receiver)?, receiver))) {
+            Some(intrinsic) => intrinsic,
+            None => return Ok(function.output.clone()),
+        };
+    let (core_mapping, name) = rule_key(circuit, function);
+
+    Ok(match (&*core_mapping, &*name, receiver) {
+        // converts an integer into whichever `fixed` type is expected
+        ("integer", "to_fixed", _) => match expected {
+            Some(PartialType::Type(expected @ Type::Fixed(_))) => expected.clone(),
+            _ => return Err(AsgConvertError::unresolved_call_output(&name, "fixed", span)),
+        },
+        // the length of the result is the sum of the lengths of both arrays
+        ("array", "concat", Type::Array(element, len)) => match arguments.first().and_then(|x| x.get().get_type()) {
+            Some(Type::Array(_, other_len)) => Type::Array(element.clone(), len + other_len),
+            type_ => {
+                return Err(AsgConvertError::unexpected_type(
+                    "array",
+                    type_.map(|x| x.to_string()).as_deref(),
+                    span,
+                ));
+            }
+        },
+        _ => resolve_self(circuit, &function.output, receiver),
+    })
+}
@@ -0,0 +1,95 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Leo core library, imported with `import core.<module>`.
+//!
+//! Modules are embedded Leo sources. Circuits implemented natively by the compiler declare
+//! their functions as `extern` and are bound to their implementation by a core mapping, such as
+//! the `Bits` conversions of `core.bits` that would need a divider per bit if written in Leo.
+
+// TODO (protryon): We should merge this with core
+
+pub mod intrinsic;
+pub use intrinsic::*;
+
+use crate::{AsgContext, AsgConvertError, Circuit, Program};
+
+use std::path::Path;
+
+/// The modules that can be imported with `import core.<module>`.
+pub const CORE_MODULES: &[&str] = &["array", "bits", "math", "unstable.blake2s"];
+
+/// Version of the stable core library, that is every module outside of `core.unstable`.
+///
+/// Stable modules keep their function signatures within a major version, while `core.unstable`
+/// modules may change with any release.
+pub const CORE_LIBRARY_VERSION: &str = "1.0.0";
+
+// TODO (protryon): Make asg deep copy so we can cache resolved core modules
+
+pub fn resolve_core_module<'a>(context: AsgContext<'a>, module: &str) -> Result<Option<Program<'a>>, AsgConvertError> {
+    match module {
+        "array" => Ok(Some(load_core_module(context, module, include_str!("array.leo"))?)),
+        "bits" => {
+            let asg = load_core_module(context, module, include_str!("bits.leo"))?;
+            asg.set_core_mapping("bits");
+            Ok(Some(asg))
+        }
+        "math" => Ok(Some(load_core_module(context, module, include_str!("math.leo"))?)),
+        "unstable.blake2s" => {
+            let asg = load_core_module(context, module, include_str!("unstable/blake2s.leo"))?;
+            asg.set_core_mapping("blake2s");
+            Ok(Some(asg))
+        }
+        _ => Ok(None),
+    }
+}
+
+/// Returns the core circuit whose functions are the intrinsic methods of values of the kind `name`,
+/// one of `field`, `integer`, `fixed` or `array`, such as `a.inv()` or `a.len()`.
+pub fn resolve_intrinsics<'a>(context: AsgContext<'a>, name: &str) -> Result<&'a Circuit<'a>, AsgConvertError> {
+    if let Some(circuit) = context.intrinsics.borrow().get(name) {
+        return Ok(*circuit);
+    }
+    let (content, circuit_name) = match name {
+        "field" => (include_str!("intrinsic/field.leo"), "Field"),
+        "integer" => (include_str!("intrinsic/integer.leo"), "Integer"),
+        "fixed" => (include_str!("intrinsic/fixed.leo"), "Fixed"),
+        "array" => (include_str!("intrinsic/array.leo"), "Array"),
+        _ => return Err(AsgConvertError::InternalError(format!("unknown intrinsics `{}`", name))),
+    };
+    let asg = load_core_module(context, &format!("intrinsic.{}", name), content)?;
+    asg.set_core_mapping(name);
+    let circuit = *asg
+        .circuits
+        .get(circuit_name)
+        .ok_or_else(|| AsgConvertError::InternalError(format!("missing {} intrinsics circuit", name)))?;
+    context.intrinsics.borrow_mut().insert(name.to_string(), circuit);
+    Ok(circuit)
+}
+
+/// Returns the ASG of the embedded Leo source of a core module.
+fn load_core_module<'a>(context: AsgContext<'a>, module: &str, content: &str) -> Result<Program<'a>, AsgConvertError> {
+    let path = format!("core/{}.leo", module.replace('.', "/"));
+    let ast = leo_grammar::Grammar::new(Path::new(&path), content)
+        .map_err(|e| AsgConvertError::InternalError(format!("core.{}: {:?}", module, e)))?;
+
+    Program::new_core(
+        context,
+        leo_ast::Ast::new(&format!("core.{}", module), &ast)?.as_repr(),
+        &mut crate::NullImportResolver,
+    )
+}
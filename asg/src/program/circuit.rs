@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{AsgConvertError, Function, Identifier, Node, Scope, Span, Type};
+use crate::{AsgConvertError, Function, Identifier, Interface, Node, Scope, Span, Type};
 
 use indexmap::IndexMap;
 use std::cell::RefCell;
@@ -33,6 +33,7 @@ pub struct Circuit<'a> {
     pub scope: &'a Scope<'a>,
     pub span: Option<Span>,
     pub members: RefCell<IndexMap<String, CircuitMember<'a>>>,
+    pub implements: RefCell<Vec<&'a Interface<'a>>>,
 }
 
 impl<'a> PartialEq for Circuit<'a> {
@@ -56,10 +57,21 @@ impl<'a> Circuit<'a> {
     pub(super) fn init(scope: &'a Scope<'a>, value: &leo_ast::Circuit) -> Result<&'a Circuit<'a>, AsgConvertError> {
         let new_scope = scope.make_subscope();
 
+        let implements = value
+            .implements
+            .iter()
+            .map(|name| {
+                scope
+                    .resolve_interface(&name.name)
+                    .ok_or_else(|| AsgConvertError::unresolved_interface(&name.name, &name.span))
+            })
+            .collect::<Result<Vec<_>, AsgConvertError>>()?;
+
         let circuit = scope.alloc_circuit(Circuit {
             id: scope.context.get_id(),
             name: RefCell::new(value.circuit_name.clone()),
             members: RefCell::new(IndexMap::new()),
+            implements: RefCell::new(implements),
             core_mapping: RefCell::new(None),
             span: Some(value.circuit_name.span.clone()),
             scope: new_scope,
@@ -79,7 +91,7 @@ impl<'a> Circuit<'a> {
                     }
                     members.insert(
                         name.name.clone(),
-                        CircuitMember::Variable(new_scope.resolve_ast_type(type_)?),
+                        CircuitMember::Variable(new_scope.resolve_ast_type(type_, &name.span)?),
                     );
                 }
                 leo_ast::CircuitMember::CircuitFunction(function) => {
@@ -100,7 +112,9 @@ impl<'a> Circuit<'a> {
         Ok(circuit)
     }
 
+    /// Resolves the bodies of the member functions, reporting the errors of every function.
     pub(super) fn fill_from_ast(self: &'a Circuit<'a>, value: &leo_ast::Circuit) -> Result<(), AsgConvertError> {
+        let mut errors = vec![];
         for member in value.members.iter() {
             match member {
                 leo_ast::CircuitMember::CircuitVariable(..) => {}
@@ -114,12 +128,65 @@ impl<'a> Circuit<'a> {
                         CircuitMember::Function(f) => f,
                         _ => unimplemented!(),
                     };
-                    Function::fill_from_ast(asg_function, function)?;
+                    if let Err(error) = Function::fill_from_ast(asg_function, function) {
+                        errors.push(error);
+                    }
+                }
+            }
+        }
+        if let Err(error) = self.check_interfaces() {
+            errors.push(error);
+        }
+        AsgConvertError::from_errors(errors)
+    }
+
+    /// Checks that the circuit defines every function of the interfaces it implements, with the
+    /// same `self` qualifier, parameter types and output type.
+    fn check_interfaces(&self) -> Result<(), AsgConvertError> {
+        let circuit_name = &self.name.borrow().name;
+        let span = self.span.clone().unwrap_or_default();
+        let members = self.members.borrow();
+        for interface in self.implements.borrow().iter() {
+            let interface_name = &interface.name.borrow().name;
+            for (name, signature) in interface.functions.borrow().iter() {
+                let function = match members.get(name) {
+                    Some(CircuitMember::Function(function)) => *function,
+                    _ => {
+                        return Err(AsgConvertError::missing_interface_function(
+                            circuit_name,
+                            interface_name,
+                            name,
+                            &span,
+                        ));
+                    }
+                };
+                let arguments = function
+                    .arguments
+                    .values()
+                    .map(|argument| argument.get().borrow().type_.clone());
+                if function.qualifier != signature.qualifier
+                    || function.output != signature.output
+                    || function.arguments.len() != signature.arguments.len()
+                    || !arguments
+                        .zip(signature.arguments.iter())
+                        .all(|(type_, (_, expected))| type_ == *expected)
+                {
+                    return Err(AsgConvertError::mismatched_interface_function(
+                        circuit_name,
+                        interface_name,
+                        name,
+                        function.span.as_ref().unwrap_or(&span),
+                    ));
                 }
             }
         }
         Ok(())
     }
+
+    /// Returns `true` if the circuit is declared to implement `interface`.
+    pub fn implements(&self, interface: &Interface<'a>) -> bool {
+        self.implements.borrow().contains(&interface)
+    }
 }
 
 impl<'a> Into<leo_ast::Circuit> for &Circuit<'a> {
@@ -137,6 +204,12 @@ impl<'a> Into<leo_ast::Circuit> for &Circuit<'a> {
             .collect();
         leo_ast::Circuit {
             circuit_name: self.name.borrow().clone(),
+            implements: self
+                .implements
+                .borrow()
+                .iter()
+                .map(|x| x.name.borrow().clone())
+                .collect(),
             members,
         }
     }
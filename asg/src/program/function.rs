@@ -29,7 +29,7 @@ use crate::{
     Variable,
 };
 use indexmap::IndexMap;
-use leo_ast::FunctionInput;
+use leo_ast::{FunctionInput, InlineHint};
 
 use std::cell::{Cell, RefCell};
 
@@ -52,6 +52,13 @@ pub struct Function<'a> {
     pub body: Cell<Option<&'a Statement<'a>>>,
     pub scope: &'a Scope<'a>,
     pub qualifier: FunctionQualifier,
+    /// Set by `@inline` or `@noinline`.
+    pub inline: Option<InlineHint>,
+    /// The declaration of a function with parameters of interface types, which has no body itself
+    /// but is instantiated for the circuits of each call.
+    pub template: Option<leo_ast::Function>,
+    /// Maps the ids of the circuits bound to the interface parameters => instance.
+    pub instances: RefCell<IndexMap<Vec<u32>, &'a Function<'a>>>,
 }
 
 impl<'a> PartialEq for Function<'a> {
@@ -67,10 +74,19 @@ impl<'a> Eq for Function<'a> {}
 
 impl<'a> Function<'a> {
     pub(crate) fn init(scope: &'a Scope<'a>, value: &leo_ast::Function) -> Result<&'a Function<'a>, AsgConvertError> {
+        Self::init_instance(scope, value, &IndexMap::new())
+    }
+
+    /// Declares `value`, with the parameters named in `bindings` typed as the given circuits.
+    fn init_instance(
+        scope: &'a Scope<'a>,
+        value: &leo_ast::Function,
+        bindings: &IndexMap<String, &'a Circuit<'a>>,
+    ) -> Result<&'a Function<'a>, AsgConvertError> {
         let output: Type<'a> = value
             .output
             .as_ref()
-            .map(|t| scope.resolve_ast_type(t))
+            .map(|t| scope.resolve_ast_type(t, &value.span))
             .transpose()?
             .unwrap_or_else(|| Type::Tuple(vec![]));
         let mut qualifier = FunctionQualifier::Static;
@@ -95,12 +111,16 @@ impl<'a> Function<'a> {
                         identifier,
                         const_,
                         mutable,
-                        ..
+                        span,
                     }) => {
+                        let type_ = match bindings.get(&identifier.name) {
+                            Some(circuit) => Type::Circuit(circuit),
+                            None => scope.resolve_parameter_type(&type_, span)?,
+                        };
                         let variable = scope.alloc_variable(RefCell::new(crate::InnerVariable {
                             id: scope.context.get_id(),
                             name: identifier.clone(),
-                            type_: scope.resolve_ast_type(&type_)?,
+                            type_,
                             mutable: *mutable,
                             const_: *const_,
                             declaration: crate::VariableDeclaration::Parameter,
@@ -115,6 +135,9 @@ impl<'a> Function<'a> {
         if qualifier != FunctionQualifier::Static && scope.circuit_self.get().is_none() {
             return Err(AsgConvertError::invalid_self_in_global(&value.span));
         }
+        let is_template = arguments
+            .values()
+            .any(|argument| matches!(argument.get().borrow().type_, Type::Interface(_)));
         let function = scope.alloc_function(Function {
             id: scope.context.get_id(),
             name: RefCell::new(value.identifier.clone()),
@@ -124,15 +147,56 @@ impl<'a> Function<'a> {
             circuit: Cell::new(None),
             body: Cell::new(None),
             qualifier,
+            inline: value.inline,
             scope: new_scope,
             span: Some(value.span.clone()),
+            template: if is_template { Some(value.clone()) } else { None },
+            instances: RefCell::new(IndexMap::new()),
         });
         function.scope.function.replace(Some(function));
 
         Ok(function)
     }
 
+    /// Returns the instance of this template for a call binding its interface parameters, in order,
+    /// to `circuits`. The instance is converted on first use.
+    pub(crate) fn instantiate(
+        self: &'a Function<'a>,
+        circuits: &[&'a Circuit<'a>],
+    ) -> Result<&'a Function<'a>, AsgConvertError> {
+        let template = self
+            .template
+            .as_ref()
+            .expect("instantiated a function without interface parameters");
+        let key = circuits.iter().map(|circuit| circuit.id).collect::<Vec<_>>();
+        if let Some(instance) = self.instances.borrow().get(&key) {
+            return Ok(instance);
+        }
+
+        let bindings = self
+            .arguments
+            .iter()
+            .filter(|(_, argument)| matches!(argument.get().borrow().type_, Type::Interface(_)))
+            .map(|(name, _)| name.clone())
+            .zip(circuits.iter().copied())
+            .collect();
+        let scope = self.scope.parent_scope.get().expect("function scope without parent");
+        let instance = Self::init_instance(scope, template, &bindings)?;
+        instance.circuit.replace(self.circuit.get());
+
+        // registered before the body is converted, so that recursive calls resolve to it
+        self.instances.borrow_mut().insert(key, instance);
+        instance.fill_from_ast(template)?;
+
+        Ok(instance)
+    }
+
     pub(super) fn fill_from_ast(self: &'a Function<'a>, value: &leo_ast::Function) -> Result<(), AsgConvertError> {
+        // Templates are only converted as instances, once the circuits of their parameters are known.
+        if self.template.is_some() {
+            return Ok(());
+        }
+
         if self.qualifier != FunctionQualifier::Static {
             let circuit = self.circuit.get();
             let self_variable = self.scope.alloc_variable(RefCell::new(crate::InnerVariable {
@@ -154,7 +218,13 @@ impl<'a> Function<'a> {
             self.scope.variables.borrow_mut().insert(name.clone(), argument.get());
         }
 
-        let main_block = BlockStatement::from_ast(self.scope, &value.block, None)?;
+        // Extern functions are implemented natively and keep an empty body.
+        let block = match value.block.as_ref() {
+            Some(block) => block,
+            None => return Ok(()),
+        };
+
+        let main_block = BlockStatement::from_ast(self.scope, block, None)?;
         let mut director = MonoidalDirector::new(ReturnPathReducer::new());
         if !director.reduce_block(&main_block).0 && !self.output.is_unit() {
             return Err(AsgConvertError::function_missing_return(
@@ -163,8 +233,13 @@ impl<'a> Function<'a> {
             ));
         }
 
+        let reducer = director.reducer();
+        for warning in reducer.warnings {
+            self.scope.context.warn(warning);
+        }
+
         #[allow(clippy::never_loop)] // TODO @Protryon: How should we return multiple errors?
-        for (span, error) in director.reducer().errors {
+        for (span, error) in reducer.errors {
             return Err(AsgConvertError::function_return_validation(
                 &self.name.borrow().name,
                 &error,
@@ -196,15 +271,9 @@ impl<'a> Into<leo_ast::Function> for &Function<'a> {
             })
             .collect();
         let (body, span) = match self.body.get() {
-            Some(Statement::Block(block)) => (block.into(), block.span.clone().unwrap_or_default()),
+            Some(Statement::Block(block)) => (Some(block.into()), block.span.clone().unwrap_or_default()),
             Some(_) => unimplemented!(),
-            None => (
-                leo_ast::Block {
-                    statements: vec![],
-                    span: Default::default(),
-                },
-                Default::default(),
-            ),
+            None => (None, Default::default()),
         };
         let output: Type = self.output.clone();
         leo_ast::Function {
@@ -212,6 +281,7 @@ impl<'a> Into<leo_ast::Function> for &Function<'a> {
             input,
             block: body,
             output: Some((&output).into()),
+            inline: self.inline,
             span,
         }
     }
@@ -0,0 +1,161 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{AsgConvertError, FunctionQualifier, Identifier, Node, Scope, Span, Type};
+
+use indexmap::IndexMap;
+use std::cell::RefCell;
+
+/// The signature of a function of an interface.
+#[derive(Clone)]
+pub struct InterfaceFunction<'a> {
+    pub name: Identifier,
+    pub qualifier: FunctionQualifier,
+    pub arguments: Vec<(Identifier, Type<'a>)>,
+    pub output: Type<'a>,
+    pub span: Span,
+}
+
+/// A set of function signatures that circuits declared with `implements` must define.
+///
+/// A function parameter of an interface type accepts any circuit implementing it. Such a function
+/// is instantiated for each combination of circuits it is called with, see [`crate::Function`].
+#[derive(Clone)]
+pub struct Interface<'a> {
+    pub id: u32,
+    pub name: RefCell<Identifier>,
+    pub span: Option<Span>,
+    pub functions: RefCell<IndexMap<String, InterfaceFunction<'a>>>,
+}
+
+impl<'a> PartialEq for Interface<'a> {
+    fn eq(&self, other: &Interface) -> bool {
+        if self.name != other.name {
+            return false;
+        }
+        self.id == other.id
+    }
+}
+
+impl<'a> Eq for Interface<'a> {}
+
+impl<'a> Node for Interface<'a> {
+    fn span(&self) -> Option<&Span> {
+        self.span.as_ref()
+    }
+}
+
+impl<'a> Interface<'a> {
+    pub(super) fn init(scope: &'a Scope<'a>, value: &leo_ast::Interface) -> &'a Interface<'a> {
+        scope.alloc_interface(Interface {
+            id: scope.context.get_id(),
+            name: RefCell::new(value.interface_name.clone()),
+            span: Some(value.interface_name.span.clone()),
+            functions: RefCell::new(IndexMap::new()),
+        })
+    }
+
+    /// Resolves the signatures, which may refer to circuits declared after the interface.
+    pub(super) fn fill_from_ast(
+        &self,
+        scope: &'a Scope<'a>,
+        value: &leo_ast::Interface,
+    ) -> Result<(), AsgConvertError> {
+        let mut functions = self.functions.borrow_mut();
+        for function in value.functions.iter() {
+            if functions.contains_key(&function.identifier.name) {
+                return Err(AsgConvertError::redefined_circuit_member(
+                    &value.interface_name.name,
+                    &function.identifier.name,
+                    &function.identifier.span,
+                ));
+            }
+
+            let mut qualifier = FunctionQualifier::Static;
+            let mut arguments = vec![];
+            for input in function.input.iter() {
+                match input {
+                    leo_ast::FunctionInput::InputKeyword(_) => {}
+                    leo_ast::FunctionInput::SelfKeyword(_) => qualifier = FunctionQualifier::SelfRef,
+                    leo_ast::FunctionInput::MutSelfKeyword(_) => qualifier = FunctionQualifier::MutSelfRef,
+                    leo_ast::FunctionInput::Variable(variable) => {
+                        let type_ = scope.resolve_ast_type(&variable.type_, &variable.span)?;
+                        arguments.push((variable.identifier.clone(), type_));
+                    }
+                }
+            }
+            let output = function
+                .output
+                .as_ref()
+                .map(|type_| scope.resolve_ast_type(type_, &function.span))
+                .transpose()?
+                .unwrap_or_else(|| Type::Tuple(vec![]));
+
+            functions.insert(function.identifier.name.clone(), InterfaceFunction {
+                name: function.identifier.clone(),
+                qualifier,
+                arguments,
+                output,
+                span: function.span.clone(),
+            });
+        }
+        Ok(())
+    }
+}
+
+impl<'a> Into<leo_ast::Interface> for &Interface<'a> {
+    fn into(self) -> leo_ast::Interface {
+        let functions = self
+            .functions
+            .borrow()
+            .values()
+            .map(|function| {
+                let receiver = match function.qualifier {
+                    FunctionQualifier::SelfRef => Some(leo_ast::FunctionInput::SelfKeyword(leo_ast::SelfKeyword {
+                        span: Span::default(),
+                    })),
+                    FunctionQualifier::MutSelfRef => {
+                        Some(leo_ast::FunctionInput::MutSelfKeyword(leo_ast::MutSelfKeyword {
+                            span: Span::default(),
+                        }))
+                    }
+                    FunctionQualifier::Static => None,
+                };
+                let arguments = function.arguments.iter().map(|(identifier, type_)| {
+                    leo_ast::FunctionInput::Variable(leo_ast::FunctionInputVariable {
+                        identifier: identifier.clone(),
+                        mutable: false,
+                        const_: false,
+                        type_: type_.into(),
+                        span: Span::default(),
+                    })
+                });
+                leo_ast::Function {
+                    identifier: function.name.clone(),
+                    input: receiver.into_iter().chain(arguments).collect(),
+                    output: Some((&function.output).into()),
+                    block: None,
+                    inline: None,
+                    span: function.span.clone(),
+                }
+            })
+            .collect();
+        leo_ast::Interface {
+            interface_name: self.name.borrow().clone(),
+            functions,
+        }
+    }
+}
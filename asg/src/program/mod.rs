@@ -24,6 +24,9 @@ pub use circuit::*;
 mod function;
 pub use function::*;
 
+mod interface;
+pub use interface::*;
+
 use crate::{ArenaNode, AsgContext, AsgConvertError, ImportResolver, Input, Scope};
 use leo_ast::{Identifier, PackageAccess, PackageOrPackages, Span};
 
@@ -54,6 +57,9 @@ pub struct InternalProgram<'a> {
     /// Maps circuit name => circuit code block.
     pub circuits: IndexMap<String, &'a Circuit<'a>>,
 
+    /// Maps interface name => interface.
+    pub interfaces: IndexMap<String, &'a Interface<'a>>,
+
     /// Bindings for names and additional program context.
     pub scope: &'a Scope<'a>,
 }
@@ -62,7 +68,7 @@ pub type Program<'a> = InternalProgram<'a>;
 
 /// Enumerates what names are imported from a package.
 #[derive(Clone)]
-enum ImportSymbol {
+pub enum ImportSymbol {
     /// Import the symbol by name.
     Direct(String),
 
@@ -73,7 +79,8 @@ enum ImportSymbol {
     All,
 }
 
-fn resolve_import_package(
+/// Flattens an import statement into its imported symbols, each with the path of its package.
+pub fn resolve_import_package(
     output: &mut Vec<(Vec<String>, ImportSymbol, Span)>,
     mut package_segments: Vec<String>,
     package_or_packages: &PackageOrPackages,
@@ -139,6 +146,36 @@ impl<'a> InternalProgram<'a> {
         context: AsgContext<'a>,
         program: &leo_ast::Program,
         import_resolver: &mut T,
+    ) -> Result<Program<'a>, AsgConvertError> {
+        // Extern functions are only implemented for the core library.
+        let circuit_functions = program.circuits.values().flat_map(|circuit| {
+            circuit.members.iter().filter_map(|member| match member {
+                leo_ast::CircuitMember::CircuitFunction(function) => Some(function),
+                leo_ast::CircuitMember::CircuitVariable(..) => None,
+            })
+        });
+        if let Some(function) = program
+            .functions
+            .values()
+            .chain(circuit_functions)
+            .find(|function| function.is_extern())
+        {
+            return Err(AsgConvertError::extern_function_outside_core(
+                function.get_name(),
+                &function.span,
+            ));
+        }
+
+        Self::new_core(context, program, import_resolver)
+    }
+
+    /// Returns a new Leo program ASG for a module of the core library.
+    ///
+    /// Unlike [`InternalProgram::new`], the module may declare `extern` functions.
+    pub(crate) fn new_core<T: ImportResolver<'a>>(
+        context: AsgContext<'a>,
+        program: &leo_ast::Program,
+        import_resolver: &mut T,
     ) -> Result<Program<'a>, AsgConvertError> {
         // Recursively extract imported symbols.
         let mut imported_symbols: Vec<(Vec<String>, ImportSymbol, Span)> = vec![];
@@ -173,6 +210,7 @@ impl<'a> InternalProgram<'a> {
 
         let mut imported_functions: IndexMap<String, &'a Function<'a>> = IndexMap::new();
         let mut imported_circuits: IndexMap<String, &'a Circuit<'a>> = IndexMap::new();
+        let mut imported_interfaces: IndexMap<String, &'a Interface<'a>> = IndexMap::new();
 
         // Prepare locally relevant scope of imports.
         for (package, symbol, span) in imported_symbols.into_iter() {
@@ -185,12 +223,15 @@ impl<'a> InternalProgram<'a> {
                 ImportSymbol::All => {
                     imported_functions.extend(resolved_package.functions.clone().into_iter());
                     imported_circuits.extend(resolved_package.circuits.clone().into_iter());
+                    imported_interfaces.extend(resolved_package.interfaces.clone().into_iter());
                 }
                 ImportSymbol::Direct(name) => {
                     if let Some(function) = resolved_package.functions.get(&name) {
                         imported_functions.insert(name.clone(), *function);
                     } else if let Some(circuit) = resolved_package.circuits.get(&name) {
                         imported_circuits.insert(name.clone(), *circuit);
+                    } else if let Some(interface) = resolved_package.interfaces.get(&name) {
+                        imported_interfaces.insert(name.clone(), *interface);
                     } else {
                         return Err(AsgConvertError::unresolved_import(
                             &*format!("{}.{}", pretty_package, name),
@@ -203,6 +244,8 @@ impl<'a> InternalProgram<'a> {
                         imported_functions.insert(alias.clone(), *function);
                     } else if let Some(circuit) = resolved_package.circuits.get(&name) {
                         imported_circuits.insert(alias.clone(), *circuit);
+                    } else if let Some(interface) = resolved_package.interfaces.get(&name) {
+                        imported_interfaces.insert(alias.clone(), *interface);
                     } else {
                         return Err(AsgConvertError::unresolved_import(
                             &*format!("{}.{}", pretty_package, name),
@@ -221,6 +264,7 @@ impl<'a> InternalProgram<'a> {
             variables: RefCell::new(IndexMap::new()),
             functions: RefCell::new(imported_functions),
             circuits: RefCell::new(imported_circuits),
+            interfaces: RefCell::new(imported_interfaces),
             function: Cell::new(None),
             input: Cell::new(None),
         })) {
@@ -237,10 +281,18 @@ impl<'a> InternalProgram<'a> {
             variables: RefCell::new(IndexMap::new()),
             functions: RefCell::new(IndexMap::new()),
             circuits: RefCell::new(IndexMap::new()),
+            interfaces: RefCell::new(IndexMap::new()),
             function: Cell::new(None),
         });
 
         // Prepare header-like scope entries.
+        for (name, interface) in program.interfaces.iter() {
+            assert_eq!(name.name, interface.interface_name.name);
+            let asg_interface = Interface::init(scope, interface);
+
+            scope.interfaces.borrow_mut().insert(name.name.clone(), asg_interface);
+        }
+
         for (name, circuit) in program.circuits.iter() {
             assert_eq!(name.name, circuit.circuit_name.name);
             let asg_circuit = Circuit::init(scope, circuit)?;
@@ -263,13 +315,36 @@ impl<'a> InternalProgram<'a> {
             scope.functions.borrow_mut().insert(name.name.clone(), function);
         }
 
-        // Load concrete definitions.
+        // Entry points are never called, so they cannot be instantiated for circuits.
+        let main_function = scope.functions.borrow().get("main").copied();
+        for function in proto_test_functions.values().chain(main_function.iter()) {
+            if function.template.is_some() {
+                return Err(AsgConvertError::interface_parameter_in_entry_point(
+                    &function.name.borrow().name,
+                    function.span.as_ref().unwrap(),
+                ));
+            }
+        }
+
+        let mut interfaces = IndexMap::new();
+        for (name, interface) in program.interfaces.iter() {
+            let asg_interface = *scope.interfaces.borrow().get(&name.name).unwrap();
+
+            asg_interface.fill_from_ast(scope, interface)?;
+
+            interfaces.insert(name.name.clone(), asg_interface);
+        }
+
+        // Load concrete definitions, reporting the errors of every definition.
+        let mut errors = vec![];
         let mut test_functions = IndexMap::new();
         for (name, test_function) in program.tests.iter() {
             assert_eq!(name.name, test_function.function.identifier.name);
             let function = proto_test_functions.get(&name.name).unwrap();
 
-            function.fill_from_ast(&test_function.function)?;
+            if let Err(error) = function.fill_from_ast(&test_function.function) {
+                errors.push(error);
+            }
 
             test_functions.insert(name.name.clone(), (*function, test_function.input_file.clone()));
         }
@@ -279,7 +354,9 @@ impl<'a> InternalProgram<'a> {
             assert_eq!(name.name, function.identifier.name);
             let asg_function = *scope.functions.borrow().get(&name.name).unwrap();
 
-            asg_function.fill_from_ast(function)?;
+            if let Err(error) = asg_function.fill_from_ast(function) {
+                errors.push(error);
+            }
 
             functions.insert(name.name.clone(), asg_function);
         }
@@ -289,11 +366,15 @@ impl<'a> InternalProgram<'a> {
             assert_eq!(name.name, circuit.circuit_name.name);
             let asg_circuit = *scope.circuits.borrow().get(&name.name).unwrap();
 
-            asg_circuit.fill_from_ast(circuit)?;
+            if let Err(error) = asg_circuit.fill_from_ast(circuit) {
+                errors.push(error);
+            }
 
             circuits.insert(name.name.clone(), asg_circuit);
         }
 
+        AsgConvertError::from_errors(errors)?;
+
         Ok(InternalProgram {
             context,
             id: context.get_id(),
@@ -301,6 +382,7 @@ impl<'a> InternalProgram<'a> {
             test_functions,
             functions,
             circuits,
+            interfaces,
             imported_modules: resolved_packages
                 .into_iter()
                 .map(|(package, program)| (package.join("."), program))
@@ -349,6 +431,7 @@ pub fn reform_ast<'a>(program: &Program<'a>) -> leo_ast::Program {
     all_programs.retain(|module, _| !module.starts_with("core."));
 
     let mut all_circuits: IndexMap<String, &'a Circuit<'a>> = IndexMap::new();
+    let mut all_interfaces: IndexMap<String, &'a Interface<'a>> = IndexMap::new();
     let mut all_functions: IndexMap<String, &'a Function<'a>> = IndexMap::new();
     let mut all_test_functions: IndexMap<String, (&'a Function<'a>, Option<Identifier>)> = IndexMap::new();
     let mut identifiers = InternalIdentifierGenerator { next: 0 };
@@ -358,6 +441,11 @@ pub fn reform_ast<'a>(program: &Program<'a>) -> leo_ast::Program {
             circuit.name.borrow_mut().name = identifier.clone();
             all_circuits.insert(identifier, *circuit);
         }
+        for (name, interface) in program.interfaces.iter() {
+            let identifier = format!("{}{}", identifiers.next().unwrap(), name);
+            interface.name.borrow_mut().name = identifier.clone();
+            all_interfaces.insert(identifier, *interface);
+        }
         for (name, function) in program.functions.iter() {
             let identifier = if name == "main" {
                 "main".to_string()
@@ -405,6 +493,10 @@ pub fn reform_ast<'a>(program: &Program<'a>) -> leo_ast::Program {
             .into_iter()
             .map(|(_, circuit)| (circuit.name.borrow().clone(), circuit.into()))
             .collect(),
+        interfaces: all_interfaces
+            .into_iter()
+            .map(|(_, interface)| (interface.name.borrow().clone(), interface.into()))
+            .collect(),
     }
 }
 
@@ -419,6 +511,11 @@ impl<'a> Into<leo_ast::Program> for &InternalProgram<'a> {
                 .iter()
                 .map(|(_, circuit)| (circuit.name.borrow().clone(), (*circuit).into()))
                 .collect(),
+            interfaces: self
+                .interfaces
+                .iter()
+                .map(|(_, interface)| (interface.name.borrow().clone(), (*interface).into()))
+                .collect(),
             functions: self
                 .functions
                 .iter()
@@ -253,9 +253,10 @@ impl<'a, T: Monoid, R: MonoidalReducerStatement<'a, T>> MonoidalDirector<'a, T,
     pub fn reduce_iteration(&mut self, input: &IterationStatement<'a>) -> T {
         let start = self.reduce_expression(input.start.get());
         let stop = self.reduce_expression(input.stop.get());
+        let array = input.array.get().map(|array| self.reduce_expression(array));
         let body = self.reduce_statement(input.body.get());
 
-        self.reducer.reduce_iteration(input, start, stop, body)
+        self.reducer.reduce_iteration(input, start, stop, array, body)
     }
 
     pub fn reduce_return(&mut self, input: &ReturnStatement<'a>) -> T {
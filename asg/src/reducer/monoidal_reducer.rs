@@ -130,8 +130,8 @@ pub trait MonoidalReducerStatement<'a, T: Monoid>: MonoidalReducerExpression<'a,
         expression
     }
 
-    fn reduce_iteration(&mut self, input: &IterationStatement<'a>, start: T, stop: T, body: T) -> T {
-        start.append(stop).append(body)
+    fn reduce_iteration(&mut self, input: &IterationStatement<'a>, start: T, stop: T, array: Option<T>, body: T) -> T {
+        start.append(stop).append_option(array).append(body)
     }
 
     fn reduce_return(&mut self, input: &ReturnStatement<'a>, value: T) -> T {
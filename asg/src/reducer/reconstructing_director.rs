@@ -0,0 +1,389 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::{expression::*, program::*, statement::*, AsgContext, Variable};
+
+use std::cell::Cell;
+
+/// A reducer that rebuilds every node unchanged, to copy a subtree of an asg.
+pub struct IdentityReducer;
+
+impl<'a> ReconstructingReducerExpression<'a> for IdentityReducer {}
+impl<'a> ReconstructingReducerStatement<'a> for IdentityReducer {}
+impl<'a> ReconstructingReducerProgram<'a> for IdentityReducer {}
+
+/// Rebuilds the nodes of an asg with a [`ReconstructingReducerExpression`], bottom up.
+///
+/// Every rebuilt node is allocated in the given context and becomes the parent of its children.
+/// After a function body is rebuilt, the references and assignments recorded on its variables
+/// point at the new nodes instead of the replaced ones.
+pub struct ReconstructingDirector<'a, R: ReconstructingReducerExpression<'a>> {
+    context: AsgContext<'a>,
+    reducer: R,
+}
+
+impl<'a, R: ReconstructingReducerExpression<'a>> ReconstructingDirector<'a, R> {
+    pub fn new(context: AsgContext<'a>, reducer: R) -> Self {
+        Self { context, reducer }
+    }
+
+    pub fn reducer(self) -> R {
+        self.reducer
+    }
+
+    pub fn reduce_expression(&mut self, input: &'a Expression<'a>) -> &'a Expression<'a> {
+        let value = match input {
+            Expression::ArrayAccess(e) => self.reduce_array_access(e),
+            Expression::ArrayInit(e) => self.reduce_array_init(e),
+            Expression::ArrayInline(e) => self.reduce_array_inline(e),
+            Expression::ArrayRangeAccess(e) => self.reduce_array_range_access(e),
+            Expression::Binary(e) => self.reduce_binary(e),
+            Expression::Call(e) => self.reduce_call(e),
+            Expression::CircuitAccess(e) => self.reduce_circuit_access(e),
+            Expression::CircuitInit(e) => self.reduce_circuit_init(e),
+            Expression::Ternary(e) => self.reduce_ternary_expression(e),
+            Expression::Constant(e) => self.reduce_constant(e),
+            Expression::TupleAccess(e) => self.reduce_tuple_access(e),
+            Expression::TupleInit(e) => self.reduce_tuple_init(e),
+            Expression::Unary(e) => self.reduce_unary(e),
+            Expression::VariableRef(e) => self.reduce_variable_ref(e),
+        };
+
+        let expression = self
+            .context
+            .alloc_expression(self.reducer.reduce_expression(input, value));
+        expression.enforce_parents(expression);
+        expression
+    }
+
+    pub fn reduce_array_access(&mut self, input: &ArrayAccessExpression<'a>) -> Expression<'a> {
+        let array = self.reduce_expression(input.array.get());
+        let index = self.reduce_expression(input.index.get());
+
+        self.reducer.reduce_array_access(input, array, index)
+    }
+
+    pub fn reduce_array_init(&mut self, input: &ArrayInitExpression<'a>) -> Expression<'a> {
+        let element = self.reduce_expression(input.element.get());
+
+        self.reducer.reduce_array_init(input, element)
+    }
+
+    pub fn reduce_array_inline(&mut self, input: &ArrayInlineExpression<'a>) -> Expression<'a> {
+        let elements = input
+            .elements
+            .iter()
+            .map(|(element, spread)| (self.reduce_expression(element.get()), *spread))
+            .collect();
+
+        self.reducer.reduce_array_inline(input, elements)
+    }
+
+    pub fn reduce_array_range_access(&mut self, input: &ArrayRangeAccessExpression<'a>) -> Expression<'a> {
+        let array = self.reduce_expression(input.array.get());
+        let left = input.left.get().map(|e| self.reduce_expression(e));
+        let right = input.right.get().map(|e| self.reduce_expression(e));
+
+        self.reducer.reduce_array_range_access(input, array, left, right)
+    }
+
+    pub fn reduce_binary(&mut self, input: &BinaryExpression<'a>) -> Expression<'a> {
+        let left = self.reduce_expression(input.left.get());
+        let right = self.reduce_expression(input.right.get());
+
+        self.reducer.reduce_binary(input, left, right)
+    }
+
+    pub fn reduce_call(&mut self, input: &CallExpression<'a>) -> Expression<'a> {
+        let target = input.target.get().map(|e| self.reduce_expression(e));
+        let arguments = input
+            .arguments
+            .iter()
+            .map(|e| self.reduce_expression(e.get()))
+            .collect();
+
+        self.reducer.reduce_call(input, target, arguments)
+    }
+
+    pub fn reduce_circuit_access(&mut self, input: &CircuitAccessExpression<'a>) -> Expression<'a> {
+        let target = input.target.get().map(|e| self.reduce_expression(e));
+
+        self.reducer.reduce_circuit_access(input, target)
+    }
+
+    pub fn reduce_circuit_init(&mut self, input: &CircuitInitExpression<'a>) -> Expression<'a> {
+        let values = input
+            .values
+            .iter()
+            .map(|(_, e)| self.reduce_expression(e.get()))
+            .collect();
+
+        self.reducer.reduce_circuit_init(input, values)
+    }
+
+    pub fn reduce_ternary_expression(&mut self, input: &TernaryExpression<'a>) -> Expression<'a> {
+        let condition = self.reduce_expression(input.condition.get());
+        let if_true = self.reduce_expression(input.if_true.get());
+        let if_false = self.reduce_expression(input.if_false.get());
+
+        self.reducer
+            .reduce_ternary_expression(input, condition, if_true, if_false)
+    }
+
+    pub fn reduce_constant(&mut self, input: &Constant<'a>) -> Expression<'a> {
+        self.reducer.reduce_constant(input)
+    }
+
+    pub fn reduce_tuple_access(&mut self, input: &TupleAccessExpression<'a>) -> Expression<'a> {
+        let tuple_ref = self.reduce_expression(input.tuple_ref.get());
+
+        self.reducer.reduce_tuple_access(input, tuple_ref)
+    }
+
+    pub fn reduce_tuple_init(&mut self, input: &TupleInitExpression<'a>) -> Expression<'a> {
+        let values = input.elements.iter().map(|e| self.reduce_expression(e.get())).collect();
+
+        self.reducer.reduce_tuple_init(input, values)
+    }
+
+    pub fn reduce_unary(&mut self, input: &UnaryExpression<'a>) -> Expression<'a> {
+        let inner = self.reduce_expression(input.inner.get());
+
+        self.reducer.reduce_unary(input, inner)
+    }
+
+    pub fn reduce_variable_ref(&mut self, input: &VariableRef<'a>) -> Expression<'a> {
+        self.reducer.reduce_variable_ref(input)
+    }
+}
+
+impl<'a, R: ReconstructingReducerStatement<'a>> ReconstructingDirector<'a, R> {
+    pub fn reduce_statement(&mut self, input: &'a Statement<'a>) -> &'a Statement<'a> {
+        let value = match input {
+            Statement::Assign(s) => self.reduce_assign(s),
+            Statement::Block(s) => self.reduce_block(s),
+            Statement::Conditional(s) => self.reduce_conditional_statement(s),
+            Statement::Console(s) => self.reduce_console(s),
+            Statement::Definition(s) => self.reduce_definition(s),
+            Statement::Expression(s) => self.reduce_expression_statement(s),
+            Statement::Iteration(s) => self.reduce_iteration(s),
+            Statement::Return(s) => self.reduce_return(s),
+        };
+
+        let statement = self
+            .context
+            .alloc_statement(self.reducer.reduce_statement(input, value));
+        enforce_statement_parents(statement);
+        statement
+    }
+
+    pub fn reduce_assign_access(&mut self, input: &AssignAccess<'a>) -> AssignAccess<'a> {
+        let (left, right) = match input {
+            AssignAccess::ArrayRange(left, right) => (
+                left.get().map(|e| self.reduce_expression(e)),
+                right.get().map(|e| self.reduce_expression(e)),
+            ),
+            AssignAccess::ArrayIndex(index) => (Some(self.reduce_expression(index.get())), None),
+            _ => (None, None),
+        };
+
+        self.reducer.reduce_assign_access(input, left, right)
+    }
+
+    pub fn reduce_assign(&mut self, input: &AssignStatement<'a>) -> Statement<'a> {
+        let accesses = input
+            .target_accesses
+            .iter()
+            .map(|access| self.reduce_assign_access(access))
+            .collect();
+        let value = self.reduce_expression(input.value.get());
+
+        self.reducer.reduce_assign(input, accesses, value)
+    }
+
+    pub fn reduce_block(&mut self, input: &BlockStatement<'a>) -> Statement<'a> {
+        let statements = input
+            .statements
+            .iter()
+            .map(|s| self.reduce_statement(s.get()))
+            .collect();
+
+        self.reducer.reduce_block(input, statements)
+    }
+
+    pub fn reduce_conditional_statement(&mut self, input: &ConditionalStatement<'a>) -> Statement<'a> {
+        let condition = self.reduce_expression(input.condition.get());
+        let if_true = self.reduce_statement(input.result.get());
+        let if_false = input.next.get().map(|s| self.reduce_statement(s));
+
+        self.reducer
+            .reduce_conditional_statement(input, condition, if_true, if_false)
+    }
+
+    pub fn reduce_formatted_string(&mut self, input: &FormattedString<'a>) -> FormattedString<'a> {
+        let parameters = input
+            .parameters
+            .iter()
+            .map(|e| self.reduce_expression(e.get()))
+            .collect();
+
+        self.reducer.reduce_formatted_string(input, parameters)
+    }
+
+    pub fn reduce_console(&mut self, input: &ConsoleStatement<'a>) -> Statement<'a> {
+        let argument = match &input.function {
+            ConsoleFunction::Assert(e) => ConsoleFunction::Assert(Cell::new(self.reduce_expression(e.get()))),
+            ConsoleFunction::Debug(f) => ConsoleFunction::Debug(self.reduce_formatted_string(f)),
+            ConsoleFunction::Error(f) => ConsoleFunction::Error(self.reduce_formatted_string(f)),
+            ConsoleFunction::Log(f) => ConsoleFunction::Log(self.reduce_formatted_string(f)),
+        };
+
+        self.reducer.reduce_console(input, argument)
+    }
+
+    pub fn reduce_definition(&mut self, input: &DefinitionStatement<'a>) -> Statement<'a> {
+        let value = self.reduce_expression(input.value.get());
+
+        self.reducer.reduce_definition(input, value)
+    }
+
+    pub fn reduce_expression_statement(&mut self, input: &ExpressionStatement<'a>) -> Statement<'a> {
+        let value = self.reduce_expression(input.expression.get());
+
+        self.reducer.reduce_expression_statement(input, value)
+    }
+
+    pub fn reduce_iteration(&mut self, input: &IterationStatement<'a>) -> Statement<'a> {
+        let start = self.reduce_expression(input.start.get());
+        let stop = self.reduce_expression(input.stop.get());
+        let array = input.array.get().map(|e| self.reduce_expression(e));
+        let body = self.reduce_statement(input.body.get());
+
+        self.reducer.reduce_iteration(input, start, stop, array, body)
+    }
+
+    pub fn reduce_return(&mut self, input: &ReturnStatement<'a>) -> Statement<'a> {
+        let value = self.reduce_expression(input.expression.get());
+
+        self.reducer.reduce_return(input, value)
+    }
+}
+
+impl<'a, R: ReconstructingReducerProgram<'a>> ReconstructingDirector<'a, R> {
+    pub fn reduce_function(&mut self, input: &'a Function<'a>) {
+        let old_body = input.body.get();
+        let body = old_body.map(|s| self.reduce_statement(s));
+        let body = self.reducer.reduce_function(input, body);
+
+        if let Some(old_body) = old_body {
+            relink_variables(old_body, false);
+        }
+        if let Some(body) = body {
+            body.set_parent(None);
+            relink_variables(body, true);
+        }
+        input.body.set(body);
+    }
+
+    pub fn reduce_circuit(&mut self, input: &'a Circuit<'a>) {
+        for (_, member) in input.members.borrow().iter() {
+            if let CircuitMember::Function(function) = member {
+                self.reduce_function(function);
+            }
+        }
+    }
+
+    pub fn reduce_program(&mut self, input: &Program<'a>) {
+        for (_, import) in input.imported_modules.iter() {
+            self.reduce_program(import);
+        }
+        for (_, (function, _)) in input.test_functions.iter() {
+            self.reduce_function(function);
+        }
+        for (_, function) in input.functions.iter() {
+            self.reduce_function(function);
+        }
+        for (_, circuit) in input.circuits.iter() {
+            self.reduce_circuit(circuit);
+        }
+    }
+}
+
+/// Makes a statement the parent of the statements nested in it.
+fn enforce_statement_parents<'a>(statement: &'a Statement<'a>) {
+    match statement {
+        Statement::Block(block) => {
+            for child in block.statements.iter() {
+                child.get().set_parent(Some(statement));
+            }
+        }
+        Statement::Conditional(conditional) => {
+            conditional.result.get().set_parent(Some(statement));
+            if let Some(next) = conditional.next.get() {
+                next.set_parent(Some(statement));
+            }
+        }
+        Statement::Iteration(iteration) => iteration.body.get().set_parent(Some(statement)),
+        _ => (),
+    }
+}
+
+/// Removes the variable references and assignments in a statement, or records them again if `attach` is set.
+pub(crate) fn relink_variables<'a>(statement: &'a Statement<'a>, attach: bool) {
+    VisitorDirector::new(VariableLinker { attach })
+        .visit_statement(&Cell::new(statement))
+        .ok();
+}
+
+struct VariableLinker {
+    attach: bool,
+}
+
+impl VariableLinker {
+    fn link<'a, T: ?Sized>(&self, nodes: &mut Vec<&'a T>, node: &'a T) {
+        nodes.retain(|other| !std::ptr::eq(*other, node));
+        if self.attach {
+            nodes.push(node);
+        }
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for VariableLinker {
+    fn visit_expression(&mut self, input: &Cell<&'a Expression<'a>>) -> VisitResult {
+        let expression = input.get();
+        if let Expression::VariableRef(variable_ref) = expression {
+            self.link(&mut variable_ref.variable.borrow_mut().references, expression);
+        }
+        VisitResult::VisitChildren
+    }
+}
+
+impl<'a> StatementVisitor<'a> for VariableLinker {
+    fn visit_statement(&mut self, input: &Cell<&'a Statement<'a>>) -> VisitResult {
+        let statement = input.get();
+        let variables: Vec<&'a Variable<'a>> = match statement {
+            Statement::Definition(definition) => definition.variables.clone(),
+            Statement::Assign(assign) => vec![assign.target_variable.get()],
+            Statement::Iteration(iteration) => vec![iteration.variable],
+            _ => vec![],
+        };
+        for variable in variables {
+            self.link(&mut variable.borrow_mut().assignments, statement);
+        }
+        VisitResult::VisitChildren
+    }
+}
@@ -0,0 +1,365 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{expression::*, program::*, statement::*};
+
+use std::cell::Cell;
+
+/// Rebuilds expressions from their reduced children.
+///
+/// Each method receives the original node and the already reduced children, and returns the node
+/// that replaces it. The defaults rebuild the original node unchanged. Parent links are set by the
+/// [`ReconstructingDirector`](crate::ReconstructingDirector) once the returned node is allocated.
+#[allow(unused_variables)]
+pub trait ReconstructingReducerExpression<'a> {
+    fn reduce_expression(&mut self, input: &'a Expression<'a>, value: Expression<'a>) -> Expression<'a> {
+        value
+    }
+
+    fn reduce_array_access(
+        &mut self,
+        input: &ArrayAccessExpression<'a>,
+        array: &'a Expression<'a>,
+        index: &'a Expression<'a>,
+    ) -> Expression<'a> {
+        Expression::ArrayAccess(ArrayAccessExpression {
+            parent: Cell::new(None),
+            span: input.span.clone(),
+            array: Cell::new(array),
+            index: Cell::new(index),
+        })
+    }
+
+    fn reduce_array_init(&mut self, input: &ArrayInitExpression<'a>, element: &'a Expression<'a>) -> Expression<'a> {
+        Expression::ArrayInit(ArrayInitExpression {
+            parent: Cell::new(None),
+            span: input.span.clone(),
+            element: Cell::new(element),
+            len: input.len,
+        })
+    }
+
+    fn reduce_array_inline(
+        &mut self,
+        input: &ArrayInlineExpression<'a>,
+        elements: Vec<(&'a Expression<'a>, bool)>,
+    ) -> Expression<'a> {
+        Expression::ArrayInline(ArrayInlineExpression {
+            parent: Cell::new(None),
+            span: input.span.clone(),
+            elements: elements
+                .into_iter()
+                .map(|(element, spread)| (Cell::new(element), spread))
+                .collect(),
+        })
+    }
+
+    fn reduce_array_range_access(
+        &mut self,
+        input: &ArrayRangeAccessExpression<'a>,
+        array: &'a Expression<'a>,
+        left: Option<&'a Expression<'a>>,
+        right: Option<&'a Expression<'a>>,
+    ) -> Expression<'a> {
+        Expression::ArrayRangeAccess(ArrayRangeAccessExpression {
+            parent: Cell::new(None),
+            span: input.span.clone(),
+            array: Cell::new(array),
+            left: Cell::new(left),
+            right: Cell::new(right),
+        })
+    }
+
+    fn reduce_binary(
+        &mut self,
+        input: &BinaryExpression<'a>,
+        left: &'a Expression<'a>,
+        right: &'a Expression<'a>,
+    ) -> Expression<'a> {
+        Expression::Binary(BinaryExpression {
+            parent: Cell::new(None),
+            span: input.span.clone(),
+            operation: input.operation.clone(),
+            left: Cell::new(left),
+            right: Cell::new(right),
+        })
+    }
+
+    fn reduce_call(
+        &mut self,
+        input: &CallExpression<'a>,
+        target: Option<&'a Expression<'a>>,
+        arguments: Vec<&'a Expression<'a>>,
+    ) -> Expression<'a> {
+        Expression::Call(CallExpression {
+            parent: Cell::new(None),
+            span: input.span.clone(),
+            function: Cell::new(input.function.get()),
+            target: Cell::new(target),
+            arguments: arguments.into_iter().map(Cell::new).collect(),
+            output: input.output.clone(),
+        })
+    }
+
+    fn reduce_circuit_access(
+        &mut self,
+        input: &CircuitAccessExpression<'a>,
+        target: Option<&'a Expression<'a>>,
+    ) -> Expression<'a> {
+        Expression::CircuitAccess(CircuitAccessExpression {
+            parent: Cell::new(None),
+            span: input.span.clone(),
+            circuit: Cell::new(input.circuit.get()),
+            target: Cell::new(target),
+            member: input.member.clone(),
+        })
+    }
+
+    fn reduce_circuit_init(
+        &mut self,
+        input: &CircuitInitExpression<'a>,
+        values: Vec<&'a Expression<'a>>,
+    ) -> Expression<'a> {
+        Expression::CircuitInit(CircuitInitExpression {
+            parent: Cell::new(None),
+            span: input.span.clone(),
+            circuit: Cell::new(input.circuit.get()),
+            values: input
+                .values
+                .iter()
+                .zip(values)
+                .map(|((name, _), value)| (name.clone(), Cell::new(value)))
+                .collect(),
+        })
+    }
+
+    fn reduce_ternary_expression(
+        &mut self,
+        input: &TernaryExpression<'a>,
+        condition: &'a Expression<'a>,
+        if_true: &'a Expression<'a>,
+        if_false: &'a Expression<'a>,
+    ) -> Expression<'a> {
+        Expression::Ternary(TernaryExpression {
+            parent: Cell::new(None),
+            span: input.span.clone(),
+            condition: Cell::new(condition),
+            if_true: Cell::new(if_true),
+            if_false: Cell::new(if_false),
+        })
+    }
+
+    fn reduce_constant(&mut self, input: &Constant<'a>) -> Expression<'a> {
+        Expression::Constant(Constant {
+            parent: Cell::new(None),
+            span: input.span.clone(),
+            value: input.value.clone(),
+        })
+    }
+
+    fn reduce_tuple_access(
+        &mut self,
+        input: &TupleAccessExpression<'a>,
+        tuple_ref: &'a Expression<'a>,
+    ) -> Expression<'a> {
+        Expression::TupleAccess(TupleAccessExpression {
+            parent: Cell::new(None),
+            span: input.span.clone(),
+            tuple_ref: Cell::new(tuple_ref),
+            index: input.index,
+        })
+    }
+
+    fn reduce_tuple_init(
+        &mut self,
+        input: &TupleInitExpression<'a>,
+        values: Vec<&'a Expression<'a>>,
+    ) -> Expression<'a> {
+        Expression::TupleInit(TupleInitExpression {
+            parent: Cell::new(None),
+            span: input.span.clone(),
+            elements: values.into_iter().map(Cell::new).collect(),
+        })
+    }
+
+    fn reduce_unary(&mut self, input: &UnaryExpression<'a>, inner: &'a Expression<'a>) -> Expression<'a> {
+        Expression::Unary(UnaryExpression {
+            parent: Cell::new(None),
+            span: input.span.clone(),
+            operation: input.operation.clone(),
+            inner: Cell::new(inner),
+        })
+    }
+
+    fn reduce_variable_ref(&mut self, input: &VariableRef<'a>) -> Expression<'a> {
+        Expression::VariableRef(VariableRef {
+            parent: Cell::new(None),
+            span: input.span.clone(),
+            variable: input.variable,
+        })
+    }
+}
+
+/// Rebuilds statements from their reduced children.
+///
+/// Variable references and assignments are relinked by the
+/// [`ReconstructingDirector`](crate::ReconstructingDirector) once a function body is rebuilt.
+#[allow(unused_variables)]
+pub trait ReconstructingReducerStatement<'a>: ReconstructingReducerExpression<'a> {
+    fn reduce_statement(&mut self, input: &'a Statement<'a>, value: Statement<'a>) -> Statement<'a> {
+        value
+    }
+
+    // left = Some(ArrayIndex.0) always if AssignAccess::ArrayIndex. if member/tuple, always None
+    fn reduce_assign_access(
+        &mut self,
+        input: &AssignAccess<'a>,
+        left: Option<&'a Expression<'a>>,
+        right: Option<&'a Expression<'a>>,
+    ) -> AssignAccess<'a> {
+        match input {
+            AssignAccess::ArrayRange(_, _) => AssignAccess::ArrayRange(Cell::new(left), Cell::new(right)),
+            AssignAccess::ArrayIndex(_) => AssignAccess::ArrayIndex(Cell::new(left.unwrap())),
+            AssignAccess::Tuple(index) => AssignAccess::Tuple(*index),
+            AssignAccess::Member(name) => AssignAccess::Member(name.clone()),
+        }
+    }
+
+    fn reduce_assign(
+        &mut self,
+        input: &AssignStatement<'a>,
+        accesses: Vec<AssignAccess<'a>>,
+        value: &'a Expression<'a>,
+    ) -> Statement<'a> {
+        Statement::Assign(AssignStatement {
+            parent: Cell::new(None),
+            span: input.span.clone(),
+            operation: input.operation.clone(),
+            target_variable: Cell::new(input.target_variable.get()),
+            target_accesses: accesses,
+            value: Cell::new(value),
+        })
+    }
+
+    fn reduce_block(&mut self, input: &BlockStatement<'a>, statements: Vec<&'a Statement<'a>>) -> Statement<'a> {
+        Statement::Block(BlockStatement {
+            parent: Cell::new(None),
+            span: input.span.clone(),
+            statements: statements.into_iter().map(Cell::new).collect(),
+            scope: input.scope,
+            iteration: input.iteration,
+        })
+    }
+
+    fn reduce_conditional_statement(
+        &mut self,
+        input: &ConditionalStatement<'a>,
+        condition: &'a Expression<'a>,
+        if_true: &'a Statement<'a>,
+        if_false: Option<&'a Statement<'a>>,
+    ) -> Statement<'a> {
+        Statement::Conditional(ConditionalStatement {
+            parent: Cell::new(None),
+            span: input.span.clone(),
+            condition: Cell::new(condition),
+            result: Cell::new(if_true),
+            next: Cell::new(if_false),
+        })
+    }
+
+    fn reduce_formatted_string(
+        &mut self,
+        input: &FormattedString<'a>,
+        parameters: Vec<&'a Expression<'a>>,
+    ) -> FormattedString<'a> {
+        FormattedString {
+            string: input.string.clone(),
+            containers: input.containers.clone(),
+            parameters: parameters.into_iter().map(Cell::new).collect(),
+            span: input.span.clone(),
+        }
+    }
+
+    fn reduce_console(&mut self, input: &ConsoleStatement<'a>, argument: ConsoleFunction<'a>) -> Statement<'a> {
+        Statement::Console(ConsoleStatement {
+            parent: Cell::new(None),
+            span: input.span.clone(),
+            function: argument,
+        })
+    }
+
+    fn reduce_definition(&mut self, input: &DefinitionStatement<'a>, value: &'a Expression<'a>) -> Statement<'a> {
+        Statement::Definition(DefinitionStatement {
+            parent: Cell::new(None),
+            span: input.span.clone(),
+            variables: input.variables.clone(),
+            value: Cell::new(value),
+        })
+    }
+
+    fn reduce_expression_statement(
+        &mut self,
+        input: &ExpressionStatement<'a>,
+        expression: &'a Expression<'a>,
+    ) -> Statement<'a> {
+        Statement::Expression(ExpressionStatement {
+            parent: Cell::new(None),
+            span: input.span.clone(),
+            expression: Cell::new(expression),
+        })
+    }
+
+    fn reduce_iteration(
+        &mut self,
+        input: &IterationStatement<'a>,
+        start: &'a Expression<'a>,
+        stop: &'a Expression<'a>,
+        array: Option<&'a Expression<'a>>,
+        body: &'a Statement<'a>,
+    ) -> Statement<'a> {
+        Statement::Iteration(IterationStatement {
+            parent: Cell::new(None),
+            span: input.span.clone(),
+            variable: input.variable,
+            start: Cell::new(start),
+            stop: Cell::new(stop),
+            array: Cell::new(array),
+            body: Cell::new(body),
+        })
+    }
+
+    fn reduce_return(&mut self, input: &ReturnStatement<'a>, value: &'a Expression<'a>) -> Statement<'a> {
+        Statement::Return(ReturnStatement {
+            parent: Cell::new(None),
+            span: input.span.clone(),
+            expression: Cell::new(value),
+        })
+    }
+}
+
+/// Rebuilds function bodies in place.
+///
+/// Functions and circuits keep their identity, since calls and types refer to them directly.
+#[allow(unused_variables)]
+pub trait ReconstructingReducerProgram<'a>: ReconstructingReducerStatement<'a> {
+    fn reduce_function(
+        &mut self,
+        input: &'a Function<'a>,
+        body: Option<&'a Statement<'a>>,
+    ) -> Option<&'a Statement<'a>> {
+        body
+    }
+}
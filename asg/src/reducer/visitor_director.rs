@@ -24,14 +24,18 @@ pub struct VisitorDirector<'a, R: ExpressionVisitor<'a>> {
     lifetime: PhantomData<&'a ()>,
 }
 
-pub type ConcreteVisitResult = Result<(), ()>;
+/// Returned by the director once a visitor stops the traversal with [`VisitResult::Exit`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct VisitExit;
+
+pub type ConcreteVisitResult = Result<(), VisitExit>;
 
 impl Into<ConcreteVisitResult> for VisitResult {
     fn into(self) -> ConcreteVisitResult {
         match self {
             VisitResult::VisitChildren => Ok(()),
             VisitResult::SkipChildren => Ok(()),
-            VisitResult::Exit => Err(()),
+            VisitResult::Exit => Err(VisitExit),
         }
     }
 }
@@ -359,6 +363,7 @@ impl<'a, R: StatementVisitor<'a>> VisitorDirector<'a, R> {
             VisitResult::VisitChildren => {
                 self.visit_expression(&input.start)?;
                 self.visit_expression(&input.stop)?;
+                self.visit_opt_expression(&input.array)?;
                 self.visit_statement(&input.body)?;
                 Ok(())
             }
@@ -377,9 +382,8 @@ impl<'a, R: StatementVisitor<'a>> VisitorDirector<'a, R> {
     }
 }
 
-#[allow(dead_code)]
 impl<'a, R: ProgramVisitor<'a>> VisitorDirector<'a, R> {
-    fn visit_function(&mut self, input: &'a Function<'a>) -> ConcreteVisitResult {
+    pub fn visit_function(&mut self, input: &'a Function<'a>) -> ConcreteVisitResult {
         match self.visitor.visit_function(input) {
             VisitResult::VisitChildren => {
                 self.visit_opt_statement(&input.body)?;
@@ -389,7 +393,7 @@ impl<'a, R: ProgramVisitor<'a>> VisitorDirector<'a, R> {
         }
     }
 
-    fn visit_circuit_member(&mut self, input: &CircuitMember<'a>) -> ConcreteVisitResult {
+    pub fn visit_circuit_member(&mut self, input: &CircuitMember<'a>) -> ConcreteVisitResult {
         match self.visitor.visit_circuit_member(input) {
             VisitResult::VisitChildren => {
                 if let CircuitMember::Function(f) = input {
@@ -401,7 +405,7 @@ impl<'a, R: ProgramVisitor<'a>> VisitorDirector<'a, R> {
         }
     }
 
-    fn visit_circuit(&mut self, input: &'a Circuit<'a>) -> ConcreteVisitResult {
+    pub fn visit_circuit(&mut self, input: &'a Circuit<'a>) -> ConcreteVisitResult {
         match self.visitor.visit_circuit(input) {
             VisitResult::VisitChildren => {
                 for (_, member) in input.members.borrow().iter() {
@@ -413,7 +417,7 @@ impl<'a, R: ProgramVisitor<'a>> VisitorDirector<'a, R> {
         }
     }
 
-    fn visit_program(&mut self, input: &Program<'a>) -> ConcreteVisitResult {
+    pub fn visit_program(&mut self, input: &Program<'a>) -> ConcreteVisitResult {
         match self.visitor.visit_program(input) {
             VisitResult::VisitChildren => {
                 for (_, import) in input.imported_modules.iter() {
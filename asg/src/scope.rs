@@ -14,7 +14,20 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{ArenaNode, AsgContext, AsgConvertError, Circuit, Expression, Function, Input, Statement, Type, Variable};
+use crate::{
+    ArenaNode,
+    AsgContext,
+    AsgConvertError,
+    Circuit,
+    Expression,
+    Function,
+    Input,
+    Interface,
+    Span,
+    Statement,
+    Type,
+    Variable,
+};
 
 use indexmap::IndexMap;
 use std::cell::{Cell, RefCell};
@@ -45,6 +58,9 @@ pub struct Scope<'a> {
     /// Maps circuit name => circuit.
     pub circuits: RefCell<IndexMap<String, &'a Circuit<'a>>>,
 
+    /// Maps interface name => interface.
+    pub interfaces: RefCell<IndexMap<String, &'a Interface<'a>>>,
+
     /// The main input to the program.
     pub input: Cell<Option<Input<'a>>>,
 }
@@ -93,6 +109,13 @@ impl<'a> Scope<'a> {
         }
     }
 
+    pub fn alloc_interface(&'a self, interface: Interface<'a>) -> &'a mut Interface<'a> {
+        match self.context.arena.alloc(ArenaNode::Interface(interface)) {
+            ArenaNode::Interface(e) => e,
+            _ => unimplemented!(),
+        }
+    }
+
     ///
     /// Returns a reference to the variable corresponding to the name.
     ///
@@ -175,6 +198,22 @@ impl<'a> Scope<'a> {
         }
     }
 
+    ///
+    /// Returns a reference to the interface corresponding to the name.
+    ///
+    /// If the current scope did not have this name present, then the parent scope is checked.
+    /// If there is no parent scope, then `None` is returned.
+    ///
+    pub fn resolve_interface(&self, name: &str) -> Option<&'a Interface<'a>> {
+        if let Some(resolved) = self.interfaces.borrow().get(name) {
+            Some(*resolved)
+        } else if let Some(resolved) = self.parent_scope.get() {
+            resolved.resolve_interface(name)
+        } else {
+            None
+        }
+    }
+
     ///
     /// Returns a reference to the current circuit.
     ///
@@ -203,6 +242,7 @@ impl<'a> Scope<'a> {
             variables: RefCell::new(IndexMap::new()),
             functions: RefCell::new(IndexMap::new()),
             circuits: RefCell::new(IndexMap::new()),
+            interfaces: RefCell::new(IndexMap::new()),
             function: Cell::new(None),
             input: Cell::new(None),
         })
@@ -211,16 +251,28 @@ impl<'a> Scope<'a> {
     ///
     /// Returns the type returned by the current scope.
     ///
-    pub fn resolve_ast_type(&self, type_: &leo_ast::Type) -> Result<Type<'a>, AsgConvertError> {
+    /// Errors in the type are reported at `span`, the span of its declaration.
+    ///
+    pub fn resolve_ast_type(&self, type_: &leo_ast::Type, span: &Span) -> Result<Type<'a>, AsgConvertError> {
         use leo_ast::Type::*;
         Ok(match type_ {
             Address => Type::Address,
             Boolean => Type::Boolean,
             Field => Type::Field,
+            Fixed(fixed_type) => {
+                // the scale must leave room for whole numbers, i.e. `1.0` must be representable
+                let one = 10u128.checked_pow(fixed_type.scale);
+                if fixed_type.integer_type().is_none()
+                    || one.map(|one| one >> (fixed_type.width - 1) != 0).unwrap_or(true)
+                {
+                    return Err(AsgConvertError::invalid_fixed_type(&fixed_type.to_string(), span));
+                }
+                Type::Fixed(fixed_type.clone())
+            }
             Group => Type::Group,
             IntegerType(int_type) => Type::Integer(int_type.clone()),
             Array(sub_type, dimensions) => {
-                let mut item = Box::new(self.resolve_ast_type(&*sub_type)?);
+                let mut item = Box::new(self.resolve_ast_type(&*sub_type, span)?);
                 for dimension in dimensions.0.iter().rev() {
                     let dimension = dimension
                         .value
@@ -233,7 +285,7 @@ impl<'a> Scope<'a> {
             Tuple(sub_types) => Type::Tuple(
                 sub_types
                     .iter()
-                    .map(|x| self.resolve_ast_type(x))
+                    .map(|x| self.resolve_ast_type(x, span))
                     .collect::<Result<Vec<_>, AsgConvertError>>()?,
             ),
             Circuit(name) if name.name == "Self" => Type::Circuit(
@@ -244,10 +296,28 @@ impl<'a> Scope<'a> {
                 self.resolve_circuit_self()
                     .ok_or_else(AsgConvertError::reference_self_outside_circuit)?,
             ),
+            Circuit(name) if self.resolve_interface(&name.name).is_some() => {
+                return Err(AsgConvertError::unexpected_interface_type(&name.name, &name.span));
+            }
             Circuit(name) => Type::Circuit(
                 self.resolve_circuit(&name.name)
                     .ok_or_else(|| AsgConvertError::unresolved_circuit(&name.name, &name.span))?,
             ),
         })
     }
+
+    ///
+    /// Returns the type of a function parameter, which unlike other types may be an interface.
+    ///
+    pub fn resolve_parameter_type(&self, type_: &leo_ast::Type, span: &Span) -> Result<Type<'a>, AsgConvertError> {
+        match type_ {
+            leo_ast::Type::Circuit(name) if self.resolve_circuit(&name.name).is_none() => {
+                match self.resolve_interface(&name.name) {
+                    Some(interface) => Ok(Type::Interface(interface)),
+                    None => self.resolve_ast_type(type_, span),
+                }
+            }
+            type_ => self.resolve_ast_type(type_, span),
+        }
+    }
 }
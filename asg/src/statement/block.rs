@@ -24,6 +24,8 @@ pub struct BlockStatement<'a> {
     pub span: Option<Span>,
     pub statements: Vec<Cell<&'a Statement<'a>>>,
     pub scope: &'a Scope<'a>,
+    /// The iteration of an unrolled loop that the block was materialized for.
+    pub iteration: Option<usize>,
 }
 
 impl<'a> Node for BlockStatement<'a> {
@@ -49,6 +51,7 @@ impl<'a> FromAst<'a, leo_ast::Block> for BlockStatement<'a> {
             span: Some(statement.span.clone()),
             statements: output,
             scope: new_scope,
+            iteration: None,
         })
     }
 }
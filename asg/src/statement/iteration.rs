@@ -18,6 +18,9 @@ use leo_ast::IntegerType;
 
 use crate::{
     AsgConvertError,
+    ConstInt,
+    ConstValue,
+    Constant,
     Expression,
     ExpressionNode,
     FromAst,
@@ -27,6 +30,7 @@ use crate::{
     Scope,
     Span,
     Statement,
+    Type,
     Variable,
 };
 
@@ -39,6 +43,8 @@ pub struct IterationStatement<'a> {
     pub variable: &'a Variable<'a>,
     pub start: Cell<&'a Expression<'a>>,
     pub stop: Cell<&'a Expression<'a>>,
+    /// The array of `for x in a`, whose element at each index from `start` to `stop` is bound to `variable`.
+    pub array: Cell<Option<&'a Expression<'a>>>,
     pub body: Cell<&'a Statement<'a>>,
 }
 
@@ -80,6 +86,66 @@ impl<'a> FromAst<'a, leo_ast::IterationStatement> for &'a Statement<'a> {
             variable,
             stop: Cell::new(stop),
             start: Cell::new(start),
+            array: Cell::new(None),
+            body: Cell::new(scope.alloc_statement(Statement::Block(crate::BlockStatement::from_ast(
+                scope,
+                &statement.block,
+                None,
+            )?))),
+        }));
+        variable.borrow_mut().assignments.push(statement);
+        Ok(statement)
+    }
+}
+
+impl<'a> FromAst<'a, leo_ast::ArrayIterationStatement> for &'a Statement<'a> {
+    fn from_ast(
+        scope: &'a Scope<'a>,
+        statement: &leo_ast::ArrayIterationStatement,
+        _expected_type: Option<PartialType<'a>>,
+    ) -> Result<Self, AsgConvertError> {
+        let array = <&Expression<'a>>::from_ast(scope, &statement.array, None)?;
+        let (element_type, len) = match array.get_type() {
+            Some(Type::Array(element_type, len)) => (*element_type, len),
+            type_ => {
+                return Err(AsgConvertError::unexpected_type(
+                    "array",
+                    type_.map(|x| x.to_string()).as_deref(),
+                    &statement.span,
+                ));
+            }
+        };
+
+        // the loop is unrolled over the indices of the array, like `for i in 0..len`
+        let index = |value: usize| {
+            &*scope.alloc_expression(Expression::Constant(Constant {
+                parent: Cell::new(None),
+                span: Some(statement.span.clone()),
+                value: ConstValue::Int(ConstInt::U32(value as u32)),
+            }))
+        };
+        let variable = scope.alloc_variable(RefCell::new(InnerVariable {
+            id: scope.context.get_id(),
+            name: statement.variable.clone(),
+            type_: element_type,
+            mutable: false,
+            const_: array.is_consty(),
+            declaration: crate::VariableDeclaration::IterationDefinition,
+            references: vec![],
+            assignments: vec![],
+        }));
+        scope
+            .variables
+            .borrow_mut()
+            .insert(statement.variable.name.clone(), variable);
+
+        let statement = scope.alloc_statement(Statement::Iteration(IterationStatement {
+            parent: Cell::new(None),
+            span: Some(statement.span.clone()),
+            variable,
+            start: Cell::new(index(0)),
+            stop: Cell::new(index(len)),
+            array: Cell::new(Some(array)),
             body: Cell::new(scope.alloc_statement(Statement::Block(crate::BlockStatement::from_ast(
                 scope,
                 &statement.block,
@@ -105,3 +171,17 @@ impl<'a> Into<leo_ast::IterationStatement> for &IterationStatement<'a> {
         }
     }
 }
+
+impl<'a> Into<leo_ast::ArrayIterationStatement> for &IterationStatement<'a> {
+    fn into(self) -> leo_ast::ArrayIterationStatement {
+        leo_ast::ArrayIterationStatement {
+            variable: self.variable.borrow().name.clone(),
+            array: self.array.get().expect("missing iterated array").into(),
+            block: match self.body.get() {
+                Statement::Block(block) => block.into(),
+                _ => unimplemented!(),
+            },
+            span: self.span.clone().unwrap_or_default(),
+        }
+    }
+}
@@ -72,6 +72,38 @@ impl<'a> Node for Statement<'a> {
     }
 }
 
+impl<'a> Statement<'a> {
+    /// Returns the statement this statement is nested in, if any.
+    pub fn get_parent(&self) -> Option<&'a Statement<'a>> {
+        use Statement::*;
+        match self {
+            Return(s) => s.parent.get(),
+            Definition(s) => s.parent.get(),
+            Assign(s) => s.parent.get(),
+            Conditional(s) => s.parent.get(),
+            Iteration(s) => s.parent.get(),
+            Console(s) => s.parent.get(),
+            Expression(s) => s.parent.get(),
+            Block(s) => s.parent.get(),
+        }
+    }
+
+    /// Sets the statement this statement is nested in.
+    pub fn set_parent(&self, parent: Option<&'a Statement<'a>>) {
+        use Statement::*;
+        match self {
+            Return(s) => s.parent.replace(parent),
+            Definition(s) => s.parent.replace(parent),
+            Assign(s) => s.parent.replace(parent),
+            Conditional(s) => s.parent.replace(parent),
+            Iteration(s) => s.parent.replace(parent),
+            Console(s) => s.parent.replace(parent),
+            Expression(s) => s.parent.replace(parent),
+            Block(s) => s.parent.replace(parent),
+        };
+    }
+}
+
 impl<'a> FromAst<'a, leo_ast::Statement> for &'a Statement<'a> {
     fn from_ast(
         scope: &'a Scope<'a>,
@@ -89,6 +121,7 @@ impl<'a> FromAst<'a, leo_ast::Statement> for &'a Statement<'a> {
                 scope, statement, None,
             )?)),
             Iteration(statement) => Self::from_ast(scope, statement, None)?,
+            ArrayIteration(statement) => Self::from_ast(scope, statement, None)?,
             Console(statement) => {
                 scope.alloc_statement(Statement::Console(ConsoleStatement::from_ast(scope, statement, None)?))
             }
@@ -110,7 +143,10 @@ impl<'a> Into<leo_ast::Statement> for &Statement<'a> {
             Definition(statement) => leo_ast::Statement::Definition(statement.into()),
             Assign(statement) => leo_ast::Statement::Assign(statement.into()),
             Conditional(statement) => leo_ast::Statement::Conditional(statement.into()),
-            Iteration(statement) => leo_ast::Statement::Iteration(statement.into()),
+            Iteration(statement) => match statement.array.get() {
+                Some(_) => leo_ast::Statement::ArrayIteration(statement.into()),
+                None => leo_ast::Statement::Iteration(statement.into()),
+            },
             Console(statement) => leo_ast::Statement::Console(statement.into()),
             Expression(statement) => leo_ast::Statement::Expression(statement.into()),
             Block(statement) => leo_ast::Statement::Block(statement.into()),
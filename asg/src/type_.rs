@@ -14,8 +14,8 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::Circuit;
-pub use leo_ast::IntegerType;
+use crate::{Circuit, Interface};
+pub use leo_ast::{FixedType, IntegerType};
 
 use std::fmt;
 
@@ -26,6 +26,7 @@ pub enum Type<'a> {
     Address,
     Boolean,
     Field,
+    Fixed(FixedType),
     Group,
     Integer(IntegerType),
 
@@ -33,6 +34,8 @@ pub enum Type<'a> {
     Array(Box<Type<'a>>, usize),
     Tuple(Vec<Type<'a>>),
     Circuit(&'a Circuit<'a>),
+    /// Any circuit implementing the interface, only allowed as the type of a function parameter.
+    Interface(&'a Interface<'a>),
 }
 
 #[derive(Clone, PartialEq)]
@@ -131,6 +134,7 @@ impl<'a> fmt::Display for Type<'a> {
             Type::Address => write!(f, "address"),
             Type::Boolean => write!(f, "bool"),
             Type::Field => write!(f, "field"),
+            Type::Fixed(fixed_type) => fixed_type.fmt(f),
             Type::Group => write!(f, "group"),
             Type::Integer(sub_type) => sub_type.fmt(f),
             Type::Array(sub_type, len) => write!(f, "[{}; {}]", sub_type, len),
@@ -145,6 +149,7 @@ impl<'a> fmt::Display for Type<'a> {
                 write!(f, ")")
             }
             Type::Circuit(circuit) => write!(f, "{}", &circuit.name.borrow().name),
+            Type::Interface(interface) => write!(f, "{}", &interface.name.borrow().name),
         }
     }
 }
@@ -196,6 +201,7 @@ impl<'a> Into<leo_ast::Type> for &Type<'a> {
             Address => leo_ast::Type::Address,
             Boolean => leo_ast::Type::Boolean,
             Field => leo_ast::Type::Field,
+            Fixed(fixed_type) => leo_ast::Type::Fixed(fixed_type.clone()),
             Group => leo_ast::Type::Group,
             Integer(int_type) => leo_ast::Type::IntegerType(int_type.clone()),
             Array(type_, len) => leo_ast::Type::Array(
@@ -204,6 +210,7 @@ impl<'a> Into<leo_ast::Type> for &Type<'a> {
             ),
             Tuple(subtypes) => leo_ast::Type::Tuple(subtypes.iter().map(Into::into).collect()),
             Circuit(circuit) => leo_ast::Type::Circuit(circuit.name.borrow().clone()),
+            Interface(interface) => leo_ast::Type::Circuit(interface.name.borrow().clone()),
         }
     }
 }
@@ -16,6 +16,12 @@
 
 use crate::load_asg;
 
+#[test]
+fn test_inline_annotation_fail() {
+    let program_string = include_str!("inline_annotation_fail.leo");
+    load_asg(program_string).err().unwrap();
+}
+
 // Expressions
 
 #[test]
@@ -39,3 +39,15 @@ fn test_core_unstable_package_invalid() {
     let program_string = include_str!("core_unstable_package_invalid.leo");
     load_asg(program_string).err().unwrap();
 }
+
+#[test]
+fn test_extern_function_fail() {
+    let program_string = include_str!("extern_function_fail.leo");
+    load_asg(program_string).err().unwrap();
+}
+
+#[test]
+fn test_extern_circuit_function_fail() {
+    let program_string = include_str!("extern_circuit_function_fail.leo");
+    load_asg(program_string).err().unwrap();
+}
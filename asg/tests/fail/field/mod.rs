@@ -13,3 +13,17 @@
 
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::load_asg;
+
+#[test]
+fn test_unknown_intrinsic() {
+    let program_string = include_str!("unknown_intrinsic.leo");
+    load_asg(program_string).err().unwrap();
+}
+
+#[test]
+fn test_pow_u32() {
+    let program_string = include_str!("pow_u32.leo");
+    load_asg(program_string).err().unwrap();
+}
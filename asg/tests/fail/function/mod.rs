@@ -15,6 +15,7 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::load_asg;
+use leo_asg::AsgConvertError;
 
 #[test]
 fn test_multiple_returns_fail() {
@@ -65,3 +66,18 @@ fn test_return_array_tuple_fail() {
     let program_string = include_str!("return_array_tuple_fail.leo");
     load_asg(program_string).err().unwrap();
 }
+
+#[test]
+fn test_multiple_errors() {
+    let program_string = include_str!("multiple_errors.leo");
+    match load_asg(program_string).err().unwrap() {
+        AsgConvertError::Errors(errors) => {
+            let lines = errors
+                .iter()
+                .map(|error| error.position().unwrap().0)
+                .collect::<Vec<_>>();
+            assert_eq!(lines, vec![3, 8, 12]);
+        }
+        error => panic!("expected several errors, found {}", error),
+    }
+}
@@ -0,0 +1,86 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::load_asg;
+use leo_asg::*;
+
+fn fold(program: &str) -> (Program<'static>, Vec<String>) {
+    let mut program = load_asg(program).unwrap();
+    let changes = ConstantFolding::do_pass(&mut program).unwrap();
+    (program, changes)
+}
+
+#[test]
+fn test_fold_expression() {
+    let (program, changes) = fold(
+        r#"
+        function main(a: u8) -> u8 {
+            return a + (1u8 + 2u8)
+        }
+        "#,
+    );
+    let expected = load_asg(
+        r#"
+        function main(a: u8) -> u8 {
+            return a + 3u8
+        }
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(reform_ast(&expected).to_string(), reform_ast(&program).to_string());
+    assert_eq!(changes, vec!["folded expression on line 3 into a constant"]);
+}
+
+#[test]
+fn test_fold_conditionals() {
+    let (program, changes) = fold(
+        r#"
+        function main(a: u8) -> u8 {
+            let mut b = a;
+            if true {
+                b += 1u8;
+            }
+            return if false ? a : b
+        }
+        "#,
+    );
+    let expected = load_asg(
+        r#"
+        function main(a: u8) -> u8 {
+            let mut b = a;
+            {
+                b += 1u8;
+            }
+            return b
+        }
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(reform_ast(&expected).to_string(), reform_ast(&program).to_string());
+    assert_eq!(changes, vec![
+        "folded conditional statement on line 4 into its taken branch",
+        "folded conditional expression on line 7 into its taken branch",
+    ]);
+}
+
+#[test]
+fn test_fold_nothing() {
+    let (_, changes) = fold("function main(a: u8) -> u8 { return a + 1u8 }");
+
+    assert!(changes.is_empty());
+}
@@ -33,3 +33,9 @@ fn test_blake2s_random() {
     let program_string = include_str!("blake2s_random.leo");
     load_asg(program_string).unwrap();
 }
+
+#[test]
+fn test_stable_library() {
+    let program_string = include_str!("stable_library.leo");
+    load_asg(program_string).unwrap();
+}
@@ -0,0 +1,127 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::load_asg;
+use leo_asg::*;
+
+fn eliminate(program: &str) -> (Program<'static>, Vec<String>) {
+    let mut program = load_asg(program).unwrap();
+    let changes = DeadCodeElimination::do_pass(&mut program).unwrap();
+    (program, changes)
+}
+
+fn assert_eliminated(program: &str, expected: &str) {
+    let (program, _) = eliminate(program);
+    let expected = load_asg(expected).unwrap();
+
+    assert_eq!(reform_ast(&expected).to_string(), reform_ast(&program).to_string());
+}
+
+#[test]
+fn test_unreachable_definitions() {
+    let (program, changes) = eliminate(
+        r#"
+        circuit Point { x: u32 }
+        circuit Line { start: Point }
+        circuit Unused { x: u32 }
+
+        function length(line: Line) -> u32 {
+            return line.start.x
+        }
+
+        function unused() {}
+
+        function main() {
+            let line = Line { start: Point { x: 0u32 } };
+            console.assert(length(line) == 0u32);
+        }
+        "#,
+    );
+
+    assert_eq!(program.functions.keys().collect::<Vec<_>>(), vec!["length", "main"]);
+    assert_eq!(program.circuits.keys().collect::<Vec<_>>(), vec!["Point", "Line"]);
+    assert_eq!(changes, vec![
+        "removed unused function `unused`",
+        "removed unused circuit `Unused`",
+    ]);
+}
+
+#[test]
+fn test_test_functions_are_roots() {
+    let (program, changes) = eliminate(
+        r#"
+        function helper() {}
+
+        @test
+        function test_helper() {
+            helper();
+        }
+        "#,
+    );
+
+    assert!(program.functions.contains_key("helper"));
+    assert!(changes.is_empty());
+}
+
+#[test]
+fn test_library_keeps_definitions() {
+    let (program, changes) = eliminate(
+        r#"
+        circuit Point { x: u32 }
+
+        function helper() {}
+        "#,
+    );
+
+    assert_eq!(program.functions.len(), 1);
+    assert_eq!(program.circuits.len(), 1);
+    assert!(changes.is_empty());
+}
+
+#[test]
+fn test_unused_statements() {
+    assert_eliminated(
+        r#"
+        function main(a: u32, b: bool) {
+            let c = b;
+            let d = c && b;
+            let e = a * a;
+            let f = a;
+            console.assert(f == a);
+        }
+        "#,
+        r#"
+        function main(a: u32, b: bool) {
+            let e = a * a;
+            let f = a;
+            console.assert(f == a);
+        }
+        "#,
+    );
+}
+
+#[test]
+fn test_used_mutable_definition() {
+    let program = r#"
+        function main(a: u32) -> u32 {
+            let mut b = 0u32;
+            b = a;
+            return b
+        }
+        "#;
+
+    assert_eliminated(program, program);
+}
@@ -73,3 +73,54 @@ fn test_ternary() {
     let program_string = include_str!("ternary.leo");
     load_asg(program_string).unwrap();
 }
+
+#[test]
+fn test_intrinsics() {
+    let program_string = include_str!("intrinsics.leo");
+    load_asg(program_string).unwrap();
+}
+
+#[test]
+fn test_intrinsics_const_value() {
+    use leo_asg::{ConstValue, DefinitionStatement, ExpressionNode, Statement};
+    use num_bigint::BigInt;
+
+    let program_string = r#"
+    function main() {
+        let three = 3field;
+        let zero = 0field;
+        let cube = three.pow(3field);
+        let inverse = three.inv();
+        let root = 16field;
+        let sqrt = root.sqrt();
+        let is_zero = zero.is_zero();
+    }
+    "#;
+    let asg = load_asg(program_string).unwrap();
+    let main = asg.functions.get("main").unwrap();
+    let values = match main.body.get() {
+        Some(Statement::Block(block)) => block
+            .statements
+            .iter()
+            .map(|statement| match statement.get() {
+                Statement::Definition(DefinitionStatement { value, .. }) => value.get().const_value(),
+                _ => panic!("expected definition"),
+            })
+            .collect::<Vec<_>>(),
+        _ => panic!("expected block"),
+    };
+
+    let field = |value: &str| ConstValue::Field(value.parse::<BigInt>().unwrap());
+    assert_eq!(values[2], Some(field("27")));
+    assert_eq!(
+        values[3],
+        Some(field(
+            "5629641166285580282832549959187697687583932890102709218623488970611606159361"
+        ))
+    );
+    assert_eq!(
+        values[5],
+        Some(ConstValue::Tuple(vec![field("4"), ConstValue::Boolean(true)]))
+    );
+    assert_eq!(values[6], Some(ConstValue::Boolean(true)));
+}
@@ -14,7 +14,8 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::load_asg;
+use crate::{load_asg, load_asg_imports, make_test_context};
+use leo_asg::{NullImportResolver, DEAD_CODE};
 
 #[test]
 fn test_empty() {
@@ -22,6 +23,18 @@ fn test_empty() {
     load_asg(program_string).unwrap();
 }
 
+#[test]
+fn test_dead_code() {
+    let program_string = include_str!("dead_code.leo");
+    let context = make_test_context();
+    load_asg_imports(context, program_string, &mut NullImportResolver).unwrap();
+
+    let warnings = context.warnings.borrow();
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].name, DEAD_CODE);
+    assert_eq!(warnings[0].error.line, 3);
+}
+
 #[test]
 fn test_iteration() {
     let program_string = include_str!("iteration.leo");
@@ -0,0 +1,264 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{load_asg, load_asg_imports, make_test_context};
+use leo_asg::*;
+
+fn inline(program: &str) -> (Program<'static>, Vec<String>) {
+    let mut program = load_asg(program).unwrap();
+    let changes = Inlining::do_pass(&mut program).unwrap();
+    (program, changes)
+}
+
+fn assert_inlined(program: &str, expected: &str) {
+    let (program, _) = inline(program);
+    let expected = load_asg(expected).unwrap();
+
+    assert_eq!(reform_ast(&expected).to_string(), reform_ast(&program).to_string());
+}
+
+#[test]
+fn test_inline_single_return() {
+    let (program, changes) = inline(
+        r#"
+        function double(x: u32) -> u32 {
+            return x + x
+        }
+
+        function main(a: u32) -> u32 {
+            return double(a)
+        }
+        "#,
+    );
+    let expected = load_asg(
+        r#"
+        function double(x: u32) -> u32 {
+            return x + x
+        }
+
+        function main(a: u32) -> u32 {
+            return a + a
+        }
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(reform_ast(&expected).to_string(), reform_ast(&program).to_string());
+    assert_eq!(changes, vec!["inlined call to `double` on line 7"]);
+}
+
+#[test]
+fn test_repeated_argument_is_not_inlined() {
+    let program = r#"
+        function double(x: u32) -> u32 {
+            return x + x
+        }
+
+        function main(a: u32, b: u32) -> u32 {
+            return double(a * b)
+        }
+        "#;
+
+    assert_inlined(program, program);
+}
+
+#[test]
+fn test_large_function_is_not_inlined() {
+    let program = r#"
+        function poly(x: u32) -> u32 {
+            return x * x * x * x + x * x * x + x * x + x + 1u32
+        }
+
+        function main(a: u32) -> u32 {
+            return poly(a)
+        }
+        "#;
+
+    assert_inlined(program, program);
+}
+
+#[test]
+fn test_inline_annotation() {
+    assert_inlined(
+        r#"
+        @inline
+        function poly(x: u32) -> u32 {
+            return x * x * x * x + x * x * x + x * x + x + 1u32
+        }
+
+        function main(a: u32) -> u32 {
+            return poly(a)
+        }
+        "#,
+        r#"
+        @inline
+        function poly(x: u32) -> u32 {
+            return x * x * x * x + x * x * x + x * x + x + 1u32
+        }
+
+        function main(a: u32) -> u32 {
+            return a * a * a * a + a * a * a + a * a + a + 1u32
+        }
+        "#,
+    );
+}
+
+#[test]
+fn test_noinline_annotation() {
+    let program = r#"
+        @noinline
+        function id(x: u32) -> u32 {
+            return x
+        }
+
+        function main(a: u32) -> u32 {
+            return id(a)
+        }
+        "#;
+
+    assert_inlined(program, program);
+}
+
+#[test]
+fn test_stacked_annotations() {
+    let program = r#"
+        @allow(unused_variables)
+        @noinline
+        function id(x: u32) -> u32 {
+            return x
+        }
+
+        function main(a: u32) -> u32 {
+            return id(a)
+        }
+        "#;
+
+    assert_inlined(program, program);
+}
+
+#[test]
+fn test_nested_calls() {
+    let (program, changes) = inline(
+        r#"
+        function add_one(x: u32) -> u32 {
+            return x + 1u32
+        }
+
+        function add_two(x: u32) -> u32 {
+            return add_one(x) + 1u32
+        }
+
+        function main(a: u32) -> u32 {
+            return add_two(a)
+        }
+        "#,
+    );
+    let expected = load_asg(
+        r#"
+        function add_one(x: u32) -> u32 {
+            return x + 1u32
+        }
+
+        function add_two(x: u32) -> u32 {
+            return x + 1u32 + 1u32
+        }
+
+        function main(a: u32) -> u32 {
+            return a + 1u32 + 1u32
+        }
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(reform_ast(&expected).to_string(), reform_ast(&program).to_string());
+    assert_eq!(changes, vec![
+        "inlined call to `add_one` on line 7",
+        "inlined call to `add_two` on line 11",
+    ]);
+}
+
+#[test]
+fn test_repeated_call_in_block() {
+    let (_, changes) = inline(
+        r#"
+        function add_one(x: u32) -> u32 {
+            return x + 1u32
+        }
+
+        function main(a: u32) -> u32 {
+            let b = add_one(a);
+            let mut c = add_one(b);
+            if a == 0u32 {
+                c = add_one(c);
+            }
+            return c
+        }
+        "#,
+    );
+
+    assert_eq!(changes, vec![
+        "inlined call to `add_one` on line 7",
+        "inlined call to `add_one` on line 8",
+        "inlined call to `add_one` on line 10",
+    ]);
+}
+
+#[test]
+fn test_unused_argument_may_fail() {
+    // dropping `a + 1u8` would drop its overflow check
+    let (_, changes) = inline(
+        r#"
+        function ignore(x: u8) -> u8 {
+            return 1u8
+        }
+
+        function main(a: u8) -> u8 {
+            let b = ignore(a);
+            return ignore(a + 1u8)
+        }
+        "#,
+    );
+
+    assert_eq!(changes, vec!["inlined call to `ignore` on line 7"]);
+}
+
+#[test]
+fn test_ineffective_inline() {
+    let context = make_test_context();
+    let mut program = load_asg_imports(
+        context,
+        r#"
+        @inline
+        function id(x: u32) -> u32 {
+            let y = x;
+            return y
+        }
+
+        function main(a: u32) -> u32 {
+            return id(a)
+        }
+        "#,
+        &mut NullImportResolver,
+    )
+    .unwrap();
+    let changes = Inlining::do_pass(&mut program).unwrap();
+
+    let warnings = context.warnings.borrow();
+    assert!(changes.is_empty());
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].name, INEFFECTIVE_INLINE);
+    assert_eq!(warnings[0].error.line, 3);
+}
@@ -0,0 +1,192 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::load_asg;
+use leo_asg::*;
+
+fn unroll(program: &str) -> (Program<'static>, Vec<String>) {
+    let mut program = load_asg(program).unwrap();
+    let changes = LoopUnrolling::do_pass(&mut program).unwrap();
+    (program, changes)
+}
+
+#[test]
+fn test_unroll_range() {
+    let (program, changes) = unroll(
+        r#"
+        function main(a: u8) -> u8 {
+            let mut b = a;
+            for i in 0u8..2u8 {
+                b += i;
+            }
+            return b
+        }
+        "#,
+    );
+    let expected = load_asg(
+        r#"
+        function main(a: u8) -> u8 {
+            let mut b = a;
+            {
+                {
+                    let i = 0u8;
+                    {
+                        b += i;
+                    }
+                }
+                {
+                    let i = 1u8;
+                    {
+                        b += i;
+                    }
+                }
+            }
+            return b
+        }
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(reform_ast(&expected).to_string(), reform_ast(&program).to_string());
+    assert_eq!(changes, vec!["unrolled loop on line 4 into 2 iterations"]);
+}
+
+#[test]
+fn test_unroll_array() {
+    let (program, changes) = unroll(
+        r#"
+        function main(a: [u8; 2]) -> u8 {
+            let mut b = 0u8;
+            for x in a {
+                b += x;
+            }
+            return b
+        }
+        "#,
+    );
+    let expected = load_asg(
+        r#"
+        function main(a: [u8; 2]) -> u8 {
+            let mut b = 0u8;
+            {
+                {
+                    let x = a[0u32];
+                    {
+                        b += x;
+                    }
+                }
+                {
+                    let x = a[1u32];
+                    {
+                        b += x;
+                    }
+                }
+            }
+            return b
+        }
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(reform_ast(&expected).to_string(), reform_ast(&program).to_string());
+    assert_eq!(changes, vec!["unrolled loop on line 4 into 2 iterations"]);
+}
+
+#[test]
+fn test_mutable_array_is_not_unrolled() {
+    let (_, changes) = unroll(
+        r#"
+        function main() -> u8 {
+            let mut a = [1u8; 2];
+            let mut b = 0u8;
+            for x in a {
+                a[1] = x;
+                b += x;
+            }
+            return b
+        }
+        "#,
+    );
+
+    assert!(changes.is_empty());
+}
+
+#[test]
+fn test_nested_loops() {
+    let (_, changes) = unroll(
+        r#"
+        function main() -> u32 {
+            let mut a = 0u32;
+            for i in 0..2 {
+                for j in 0..i {
+                    a += j;
+                }
+            }
+            return a
+        }
+        "#,
+    );
+
+    assert_eq!(changes, vec![
+        "unrolled loop on line 4 into 2 iterations",
+        "unrolled loop on line 5 into 0 iterations",
+        "unrolled loop on line 5 into 1 iteration",
+    ]);
+}
+
+#[test]
+fn test_unroll_definitions_fold() {
+    let (mut program, _) = unroll(
+        r#"
+        function main(a: u8) -> u8 {
+            let mut b = a;
+            for i in 0u8..2u8 {
+                let y = i * 2u8;
+                b += y;
+            }
+            return b
+        }
+        "#,
+    );
+    ConstantFolding::do_pass(&mut program).unwrap();
+    let expected = load_asg(
+        r#"
+        function main(a: u8) -> u8 {
+            let mut b = a;
+            {
+                {
+                    let i = 0u8;
+                    {
+                        let y = 0u8;
+                        b += 0u8;
+                    }
+                }
+                {
+                    let i = 1u8;
+                    {
+                        let y = 2u8;
+                        b += 2u8;
+                    }
+                }
+            }
+            return b
+        }
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(reform_ast(&expected).to_string(), reform_ast(&program).to_string());
+}
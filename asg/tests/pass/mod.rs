@@ -19,15 +19,21 @@ pub mod array;
 pub mod boolean;
 pub mod circuits;
 pub mod console;
+pub mod constant_folding;
 pub mod core;
+pub mod dead_code_elimination;
 pub mod definition;
 pub mod field;
 pub mod form_ast;
 pub mod function;
 pub mod group;
 pub mod import;
+pub mod inlining;
 pub mod input_files;
 pub mod integers;
+pub mod loop_unrolling;
 pub mod mutability;
+pub mod pass_manager;
+pub mod reconstructing_reducer;
 pub mod statements;
 pub mod tuples;
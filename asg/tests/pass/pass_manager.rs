@@ -0,0 +1,143 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::load_asg;
+use leo_asg::*;
+
+struct Analysis;
+
+impl AsgPass for Analysis {
+    const KIND: PassKind = PassKind::Analysis;
+    const NAME: &'static str = "analysis";
+
+    fn do_pass(_asg: &mut Program) -> Result<Vec<String>, FormattedError> {
+        Ok(vec![])
+    }
+}
+
+struct Transform;
+
+impl AsgPass for Transform {
+    const DEPENDENCIES: &'static [&'static str] = &["analysis"];
+    const KIND: PassKind = PassKind::Transform;
+    const NAME: &'static str = "transform";
+
+    fn do_pass(_asg: &mut Program) -> Result<Vec<String>, FormattedError> {
+        Ok(vec![])
+    }
+}
+
+struct Cleanup;
+
+impl AsgPass for Cleanup {
+    const DEPENDENCIES: &'static [&'static str] = &["transform"];
+    const KIND: PassKind = PassKind::Analysis;
+    const NAME: &'static str = "cleanup";
+
+    fn do_pass(_asg: &mut Program) -> Result<Vec<String>, FormattedError> {
+        Ok(vec![])
+    }
+}
+
+fn manager() -> PassManager {
+    PassManager::new()
+        .register::<Analysis>()
+        .register::<Transform>()
+        .register::<Cleanup>()
+}
+
+fn ran(reports: &[PassReport]) -> Vec<&'static str> {
+    reports
+        .iter()
+        .filter(|report| report.duration.is_some())
+        .map(|report| report.name)
+        .collect()
+}
+
+const PROGRAM: &str = "function main() {}";
+
+#[test]
+fn test_passes_run_in_order() {
+    let mut program = load_asg(PROGRAM).unwrap();
+
+    let reports = manager().run(&mut program).unwrap();
+
+    assert_eq!(manager().names(), vec!["analysis", "transform", "cleanup"]);
+    assert_eq!(ran(&reports), vec!["analysis", "transform", "cleanup"]);
+}
+
+#[test]
+fn test_disabled_pass_skips_dependents() {
+    let mut program = load_asg(PROGRAM).unwrap();
+
+    let mut passes = manager();
+    assert!(passes.set_enabled("analysis", false));
+    let reports = passes.run(&mut program).unwrap();
+
+    assert_eq!(reports.len(), 3);
+    assert!(ran(&reports).is_empty());
+}
+
+#[test]
+fn test_disable_transforms() {
+    let mut program = load_asg(PROGRAM).unwrap();
+
+    let mut passes = manager();
+    passes.disable_transforms();
+    let reports = passes.run(&mut program).unwrap();
+
+    assert_eq!(ran(&reports), vec!["analysis"]);
+}
+
+#[test]
+fn test_unknown_pass() {
+    assert!(!manager().set_enabled("unknown", false));
+    assert_eq!(manager().kind("unknown"), None);
+}
+
+#[test]
+fn test_pass_kind() {
+    assert_eq!(manager().kind("analysis"), Some(PassKind::Analysis));
+    assert_eq!(manager().kind("transform"), Some(PassKind::Transform));
+}
+
+#[test]
+#[should_panic]
+fn test_missing_dependency() {
+    PassManager::new().register::<Transform>();
+}
+
+#[test]
+#[should_panic]
+fn test_duplicate_pass() {
+    PassManager::new().register::<Analysis>().register::<Analysis>();
+}
+
+#[test]
+fn test_default_passes() {
+    let mut program = load_asg("function main() -> u8 { let a = 1u8 + 2u8; return a }").unwrap();
+    let expected = load_asg("function main() -> u8 { return 3u8 }").unwrap();
+
+    let reports = default_passes().run(&mut program).unwrap();
+
+    assert_eq!(ran(&reports), vec![
+        "loop_unrolling",
+        "inlining",
+        "constant_folding",
+        "dead_code_elimination"
+    ]);
+    assert_eq!(reform_ast(&expected).to_string(), reform_ast(&program).to_string());
+}
@@ -0,0 +1,176 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::load_asg;
+use leo_asg::*;
+use leo_grammar::Grammar;
+
+use std::{cell::Cell, collections::HashSet, fs, path::Path};
+
+/// Replaces the references to the variable `x` with `0u32`.
+struct ZeroX;
+
+impl<'a> ReconstructingReducerExpression<'a> for ZeroX {
+    fn reduce_variable_ref(&mut self, input: &VariableRef<'a>) -> Expression<'a> {
+        if input.variable.borrow().name.name == "x" {
+            Expression::Constant(Constant {
+                parent: Cell::new(None),
+                span: input.span.clone(),
+                value: ConstValue::Int(ConstInt::U32(0)),
+            })
+        } else {
+            Expression::VariableRef(VariableRef {
+                parent: Cell::new(None),
+                span: input.span.clone(),
+                variable: input.variable,
+            })
+        }
+    }
+}
+impl<'a> ReconstructingReducerStatement<'a> for ZeroX {}
+impl<'a> ReconstructingReducerProgram<'a> for ZeroX {}
+
+/// Collects the nodes of an asg and the variables they refer to.
+#[derive(Default)]
+struct Nodes<'a> {
+    expressions: Vec<&'a Expression<'a>>,
+    statements: Vec<&'a Statement<'a>>,
+    variables: Vec<&'a Variable<'a>>,
+}
+
+impl<'a> ExpressionVisitor<'a> for Nodes<'a> {
+    fn visit_expression(&mut self, input: &Cell<&'a Expression<'a>>) -> VisitResult {
+        self.expressions.push(input.get());
+        if let Expression::VariableRef(variable_ref) = input.get() {
+            self.variables.push(variable_ref.variable);
+        }
+        VisitResult::VisitChildren
+    }
+}
+
+impl<'a> StatementVisitor<'a> for Nodes<'a> {
+    fn visit_statement(&mut self, input: &Cell<&'a Statement<'a>>) -> VisitResult {
+        self.statements.push(input.get());
+        VisitResult::VisitChildren
+    }
+}
+
+impl<'a> ProgramVisitor<'a> for Nodes<'a> {}
+
+fn nodes<'a>(program: &Program<'a>) -> Nodes<'a> {
+    let mut director = VisitorDirector::new(Nodes::default());
+    director.visit_program(program).unwrap();
+    director.visitor()
+}
+
+fn address<T>(node: &T) -> usize {
+    node as *const T as usize
+}
+
+/// Asserts that parent links, variable references, and assignments only point at nodes in the program.
+fn assert_consistent(program: &Program) {
+    let nodes = nodes(program);
+    let expressions: HashSet<usize> = nodes.expressions.iter().map(|e| address(*e)).collect();
+    let statements: HashSet<usize> = nodes.statements.iter().map(|s| address(*s)).collect();
+
+    for expression in nodes.expressions.iter() {
+        if let Some(parent) = expression.get_parent() {
+            assert!(expressions.contains(&address(parent)));
+        }
+        if let Expression::VariableRef(variable_ref) = expression {
+            let variable = variable_ref.variable.borrow();
+            assert!(variable.references.iter().any(|r| std::ptr::eq(*r, *expression)));
+        }
+    }
+    for statement in nodes.statements.iter() {
+        if let Some(parent) = statement.get_parent() {
+            assert!(statements.contains(&address(parent)));
+        }
+    }
+    for variable in nodes.variables.iter() {
+        let variable = variable.borrow();
+        if variable.declaration == VariableDeclaration::Input {
+            continue;
+        }
+        assert!(variable.references.iter().all(|r| expressions.contains(&address(*r))));
+        assert!(variable.assignments.iter().all(|s| statements.contains(&address(*s))));
+    }
+}
+
+#[test]
+fn test_noop_reconstruction() {
+    let files = Grammar::find_files(&Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/pass"), "leo").unwrap();
+
+    let mut rebuilt = 0;
+    for path in files {
+        let program_string = fs::read_to_string(path).unwrap();
+        // programs with imports need a resolver and are skipped
+        let asg = match load_asg(&program_string) {
+            Ok(asg) => asg,
+            Err(_) => continue,
+        };
+        let before = serde_json::to_string(&reform_ast(&asg)).unwrap();
+        let old_nodes = nodes(&asg);
+
+        let mut director = ReconstructingDirector::new(asg.context, IdentityReducer);
+        director.reduce_program(&asg);
+
+        assert_eq!(before, serde_json::to_string(&reform_ast(&asg)).unwrap());
+        let new_nodes = nodes(&asg);
+        assert_eq!(old_nodes.expressions.len(), new_nodes.expressions.len());
+        assert_eq!(old_nodes.statements.len(), new_nodes.statements.len());
+        assert_consistent(&asg);
+        rebuilt += 1;
+    }
+
+    assert!(rebuilt > 0);
+}
+
+#[test]
+fn test_replace_variable_refs() {
+    let program_string = r#"
+    function main() {
+        let x = 1u32;
+        let mut y = x + 2u32;
+        y += x;
+        console.assert(y == 2u32);
+    }
+    "#;
+    let asg = load_asg(program_string).unwrap();
+    let x = nodes(&asg)
+        .variables
+        .into_iter()
+        .find(|variable| variable.borrow().name.name == "x")
+        .unwrap();
+
+    let mut director = ReconstructingDirector::new(asg.context, ZeroX);
+    director.reduce_program(&asg);
+
+    assert_consistent(&asg);
+    assert!(x.borrow().references.is_empty());
+    let expected = r#"
+    function main() {
+        let x = 1u32;
+        let mut y = 0u32 + 2u32;
+        y += 0u32;
+        console.assert(y == 2u32);
+    }
+    "#;
+    assert_eq!(
+        reform_ast(&load_asg(expected).unwrap()).to_string(),
+        reform_ast(&asg).to_string()
+    );
+}
@@ -14,7 +14,18 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Circuit, DeprecatedError, Function, FunctionInput, Identifier, ImportStatement, TestFunction};
+use crate::{
+    program::MAIN_FUNCTION_NAME,
+    Circuit,
+    DeprecatedError,
+    Function,
+    FunctionInput,
+    Identifier,
+    ImportStatement,
+    InlineHint,
+    Span,
+    TestFunction,
+};
 use leo_grammar::{
     annotations::{Annotation, AnnotationArguments, AnnotationName},
     definitions::{AnnotatedDefinition, Definition},
@@ -26,42 +37,95 @@ use indexmap::IndexMap;
 
 pub fn load_annotation(
     annotated_definition: AnnotatedDefinition,
-    _imports: &mut Vec<ImportStatement>,
-    _circuits: &mut IndexMap<Identifier, Circuit>,
-    _functions: &mut IndexMap<Identifier, Function>,
+    imports: &mut Vec<ImportStatement>,
+    circuits: &mut IndexMap<Identifier, Circuit>,
+    functions: &mut IndexMap<Identifier, Function>,
     tests: &mut IndexMap<Identifier, TestFunction>,
-    _expected: &mut Vec<FunctionInput>,
+    expected: &mut Vec<FunctionInput>,
 ) -> Result<(), DeprecatedError> {
-    let ast_annotation = annotated_definition.annotation;
-    let ast_definition = *annotated_definition.definition;
+    // Stacked annotations all apply to the innermost definition, outermost first.
+    let mut ast_annotations = vec![annotated_definition.annotation];
+    let mut ast_definition = *annotated_definition.definition;
+    while let Definition::Annotated(annotated_definition) = ast_definition {
+        ast_annotations.push(annotated_definition.annotation);
+        ast_definition = *annotated_definition.definition;
+    }
 
     match ast_definition {
-        Definition::Import(_) => {
-            unimplemented!("annotated imports are not supported yet");
+        // `@allow(...)` only concerns the linter, the definition itself is loaded as usual.
+        Definition::Import(import) => {
+            allow_only(&ast_annotations, "imports")?;
+            imports.push(ImportStatement::from(import));
+            Ok(())
         }
-        Definition::Circuit(_) => {
-            unimplemented!("annotated circuits are not supported yet");
+        Definition::Circuit(circuit) => {
+            allow_only(&ast_annotations, "circuits")?;
+            circuits.insert(Identifier::from(circuit.identifier.clone()), Circuit::from(circuit));
+            Ok(())
         }
-        Definition::Function(function) => match ast_annotation.name {
-            // If it's deprecated for more than one type of syntax,
-            // we could just call it before the match on ast_definition.
-            AnnotationName::Context(_) => Err(DeprecatedError::try_from(ast_annotation.name).unwrap()),
-            AnnotationName::Test(_) => {
-                let ident = Identifier::from(function.identifier.clone());
-                _functions.remove(&ident);
-
-                let test_function = leo_grammar::functions::TestFunction::from(function);
-                let test = TestFunction::from(test_function);
-                tests.insert(ident, test.clone());
-
-                load_annotated_test(test, ast_annotation, tests);
-                Ok(())
+        Definition::Function(function) => {
+            let mut test_annotation = None;
+            let mut inline = None;
+            for ast_annotation in ast_annotations {
+                match ast_annotation.name {
+                    AnnotationName::Allow(_) => {}
+                    AnnotationName::Inline(_) => inline = Some(InlineHint::Inline),
+                    AnnotationName::NoInline(_) => inline = Some(InlineHint::NoInline),
+                    // If it's deprecated for more than one type of syntax,
+                    // we could just call it before the match on ast_definition.
+                    AnnotationName::Context(_) => {
+                        return Err(DeprecatedError::try_from(ast_annotation.name).unwrap());
+                    }
+                    AnnotationName::Test(_) => test_annotation = Some(ast_annotation),
+                }
             }
-        },
-        Definition::Deprecated(_) => Ok(()),
-        Definition::Annotated(_) => {
-            unimplemented!("nested annotations are not supported yet");
+
+            match test_annotation {
+                Some(ast_annotation) => {
+                    let ident = Identifier::from(function.identifier.clone());
+                    functions.remove(&ident);
+
+                    let test_function = leo_grammar::functions::TestFunction::from(function);
+                    let mut test = TestFunction::from(test_function);
+                    test.function.inline = inline;
+                    tests.insert(ident, test.clone());
+
+                    load_annotated_test(test, ast_annotation, tests);
+                }
+                None => {
+                    let mut function = Function::from(function);
+                    function.inline = inline;
+                    if function.identifier.name.eq(MAIN_FUNCTION_NAME) {
+                        *expected = function.input.clone();
+                    }
+                    functions.insert(function.identifier.clone(), function);
+                }
+            }
+            Ok(())
+        }
+        Definition::Interface(_) => {
+            unreachable!("annotated interfaces are rejected by the grammar");
         }
+        Definition::ExternFunction(_) => {
+            unreachable!("annotated extern functions are rejected by the grammar");
+        }
+        Definition::Deprecated(_) => Ok(()),
+        Definition::Annotated(_) => unreachable!("nested annotations are unwrapped above"),
+    }
+}
+
+/// Rejects any annotation other than `@allow(...)` on definitions of the given kind.
+fn allow_only(annotations: &[Annotation], definitions: &str) -> Result<(), DeprecatedError> {
+    match annotations
+        .iter()
+        .find(|annotation| !matches!(annotation.name, AnnotationName::Allow(_)))
+    {
+        Some(annotation) => Err(DeprecatedError::unsupported_annotation(
+            annotation.span.as_str(),
+            definitions,
+            &Span::from(annotation.span.clone()),
+        )),
+        None => Ok(()),
     }
 }
 
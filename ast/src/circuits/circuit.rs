@@ -23,21 +23,38 @@ use std::fmt;
 #[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Circuit {
     pub circuit_name: Identifier,
+    /// The interfaces named after `implements`.
+    pub implements: Vec<Identifier>,
     pub members: Vec<CircuitMember>,
 }
 
 impl<'ast> From<GrammarCircuit<'ast>> for Circuit {
     fn from(circuit: GrammarCircuit<'ast>) -> Self {
         let circuit_name = Identifier::from(circuit.identifier);
+        let implements = circuit.implements.into_iter().map(Identifier::from).collect();
         let members = circuit.members.into_iter().map(CircuitMember::from).collect();
 
-        Self { circuit_name, members }
+        Self {
+            circuit_name,
+            implements,
+            members,
+        }
     }
 }
 
 impl Circuit {
     fn format(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        writeln!(f, "circuit {} {{ ", self.circuit_name)?;
+        write!(f, "circuit {}", self.circuit_name)?;
+        if !self.implements.is_empty() {
+            let implements = self
+                .implements
+                .iter()
+                .map(|x| x.to_string())
+                .collect::<Vec<_>>()
+                .join(", ");
+            write!(f, " implements {}", implements)?;
+        }
+        writeln!(f, " {{ ")?;
         for field in self.members.iter() {
             writeln!(f, "    {}", field)?;
         }
@@ -17,7 +17,7 @@
 use crate::{Function, Identifier, Type};
 use leo_grammar::{
     circuits::{CircuitMember as GrammarCircuitMember, CircuitVariableDefinition as GrammarCircuitVariableDefinition},
-    functions::Function as GrammarFunction,
+    functions::{ExternFunction as GrammarExternFunction, Function as GrammarFunction},
 };
 
 use serde::{Deserialize, Serialize};
@@ -46,11 +46,18 @@ impl<'ast> From<GrammarFunction<'ast>> for CircuitMember {
     }
 }
 
+impl<'ast> From<GrammarExternFunction<'ast>> for CircuitMember {
+    fn from(circuit_function: GrammarExternFunction<'ast>) -> Self {
+        CircuitMember::CircuitFunction(Function::from(circuit_function))
+    }
+}
+
 impl<'ast> From<GrammarCircuitMember<'ast>> for CircuitMember {
     fn from(object: GrammarCircuitMember<'ast>) -> Self {
         match object {
             GrammarCircuitMember::CircuitVariableDefinition(circuit_value) => CircuitMember::from(circuit_value),
             GrammarCircuitMember::CircuitFunction(circuit_function) => CircuitMember::from(circuit_function),
+            GrammarCircuitMember::CircuitExternFunction(circuit_function) => CircuitMember::from(circuit_function),
         }
     }
 }
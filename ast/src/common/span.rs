@@ -14,25 +14,44 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
+use leo_grammar::{FileId, SourceSpan};
+
 use pest::Span as GrammarSpan;
 use serde::{Deserialize, Serialize};
-use std::hash::{Hash, Hasher};
+use std::{
+    hash::{Hash, Hasher},
+    path::Path,
+};
 
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct Span {
-    /// text of input string
+    /// text of the program lines the span covers, each prefixed by a space
     pub text: String,
-    /// program line
+    /// program line the span starts on
     pub line: usize,
     /// start column
     pub start: usize,
-    /// end column
+    /// end column, on the line the span ends on
     pub end: usize,
+    /// program line the span ends on
+    pub line_end: usize,
+    /// start byte offset in the program file
+    pub byte_start: usize,
+    /// end byte offset in the program file
+    pub byte_end: usize,
+    /// program file the span points into
+    #[serde(default, skip_serializing_if = "FileId::is_unknown")]
+    pub file: FileId,
 }
 
 impl PartialEq for Span {
     fn eq(&self, other: &Self) -> bool {
-        self.line == other.line && self.start == other.start && self.end == other.end
+        self.line == other.line
+            && self.start == other.start
+            && self.end == other.end
+            && self.line_end == other.line_end
+            && self.byte_start == other.byte_start
+            && self.byte_end == other.byte_end
     }
 }
 
@@ -43,6 +62,9 @@ impl Hash for Span {
         self.line.hash(state);
         self.start.hash(state);
         self.end.hash(state);
+        self.line_end.hash(state);
+        self.byte_start.hash(state);
+        self.byte_end.hash(state);
     }
 }
 
@@ -50,26 +72,40 @@ impl Span {
     pub fn from_internal_string(value: &str) -> Span {
         Span {
             text: value.to_string(),
-            line: 0,
-            start: 0,
-            end: 0,
+            ..Default::default()
         }
     }
-}
 
-impl<'ast> From<GrammarSpan<'ast>> for Span {
-    fn from(span: GrammarSpan<'ast>) -> Self {
-        let mut text = " ".to_string();
-        let line_col = span.start_pos().line_col();
-        let end = span.end_pos().line_col().1;
+    /// Returns the path of the program file the span points into, if it is known.
+    pub fn path(&self) -> Option<&Path> {
+        self.file.path()
+    }
 
-        text.push_str(span.start_pos().line_of().trim_end());
+    /// Returns the lines the span covers, each prefixed by a space.
+    pub fn lines(&self) -> impl Iterator<Item = &str> {
+        self.text.lines()
+    }
+}
+
+impl<'a> From<SourceSpan<'a>> for Span {
+    fn from(span: SourceSpan<'a>) -> Self {
+        let text = span.lines.iter().map(|line| format!(" {}", line)).collect::<Vec<_>>();
 
         Self {
-            text,
-            line: line_col.0,
-            start: line_col.1,
-            end,
+            text: text.join("\n"),
+            line: span.line_start,
+            start: span.col_start,
+            end: span.col_stop,
+            line_end: span.line_stop,
+            byte_start: span.byte_start,
+            byte_end: span.byte_stop,
+            file: span.file,
         }
     }
 }
+
+impl<'ast> From<GrammarSpan<'ast>> for Span {
+    fn from(span: GrammarSpan<'ast>) -> Self {
+        Self::from(SourceSpan::from(&span))
+    }
+}
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Error as FormattedError, Span};
+use crate::{Diagnostic, Error as FormattedError, Report, Span};
 use leo_grammar::{annotations::AnnotationName, definitions::Deprecated};
 
 use std::{convert::TryFrom, path::Path};
@@ -32,8 +32,22 @@ impl DeprecatedError {
         }
     }
 
-    fn new_from_span(message: String, span: Span) -> Self {
-        DeprecatedError::Error(FormattedError::new_from_span(message, span))
+    fn new_from_span(code: &'static str, message: String, span: Span) -> Self {
+        DeprecatedError::Error(FormattedError::new_from_span(message, span).with_code(code))
+    }
+}
+
+impl Diagnostic for DeprecatedError {
+    fn error_code(&self) -> &'static str {
+        match self {
+            DeprecatedError::Error(error) => error.code.unwrap_or("E0210"),
+        }
+    }
+
+    fn reports(&self) -> Vec<Report> {
+        match self {
+            DeprecatedError::Error(error) => vec![Report::from_error(self.error_code(), error)],
+        }
     }
 }
 
@@ -41,6 +55,7 @@ impl<'ast> From<Deprecated<'ast>> for DeprecatedError {
     fn from(deprecated: Deprecated<'ast>) -> Self {
         match deprecated {
             Deprecated::TestFunction(test_function) => DeprecatedError::new_from_span(
+                "E0211",
                 "\"test function...\" is deprecated. Did you mean @test annotation?".to_string(),
                 Span::from(test_function.span.clone()),
             ),
@@ -54,6 +69,7 @@ impl<'ast> TryFrom<AnnotationName<'ast>> for DeprecatedError {
     fn try_from(annotation_name: AnnotationName<'ast>) -> Result<Self, bool> {
         match annotation_name {
             AnnotationName::Context(context) => Ok(DeprecatedError::new_from_span(
+                "E0212",
                 "\"@context(...)\" is deprecated. Did you mean @test annotation?".to_string(),
                 Span::from(context.span.clone()),
             )),
@@ -65,6 +81,11 @@ impl<'ast> TryFrom<AnnotationName<'ast>> for DeprecatedError {
 impl DeprecatedError {
     pub fn const_statement(span: &Span) -> Self {
         let message = "const _ = ... is deprecated. Did you mean let?".to_string();
-        Self::new_from_span(message, span.clone())
+        Self::new_from_span("E0213", message, span.clone())
+    }
+
+    pub fn unsupported_annotation(annotation: &str, definitions: &str, span: &Span) -> Self {
+        let message = format!("\"{}\" is not supported on {}", annotation, definitions);
+        Self::new_from_span("E0214", message, span.clone())
     }
 }
@@ -0,0 +1,183 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Error as FormattedError, Span};
+
+use std::{collections::HashMap, fmt, path::Path};
+
+/// The name that applies a level to every warning, as in `-D warnings`.
+pub const ALL_WARNINGS: &str = "warnings";
+
+/// How a warning is reported.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum WarningLevel {
+    /// The warning is dropped.
+    Allow,
+    /// The warning is reported without failing the build.
+    Warn,
+    /// The warning is reported and fails the build.
+    Deny,
+}
+
+/// Formatted compiler warning type
+///     --> file.leo 3:5
+///      |
+///    3 | return 2u8
+///      | ^^^^^^^^^^
+///      |
+///      = dead code due to unconditional early return [dead_code]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Warning {
+    /// Name of the warning, used to set its level
+    pub name: String,
+    /// Level the warning is reported at
+    pub level: WarningLevel,
+    /// Location and explanation of the warning
+    pub error: FormattedError,
+}
+
+impl Warning {
+    pub fn new_from_span(name: &str, message: String, span: Span) -> Self {
+        Self {
+            name: name.to_string(),
+            level: WarningLevel::Warn,
+            error: FormattedError::new_from_span(format!("{} [{}]", message, name), span),
+        }
+    }
+
+    pub fn set_path(&mut self, path: &Path) {
+        self.error.set_path(path);
+    }
+
+    pub fn is_denied(&self) -> bool {
+        self.level == WarningLevel::Deny
+    }
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let kind = match self.level {
+            WarningLevel::Deny => "error",
+            _ => "warning",
+        };
+
+        write!(f, "{}:\n{}", kind, self.error.format())
+    }
+}
+
+/// Collects the warnings reported while compiling a program.
+#[derive(Clone, Debug, Default)]
+pub struct Diagnostics {
+    levels: HashMap<String, WarningLevel>,
+    warnings: Vec<Warning>,
+}
+
+impl Diagnostics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    ///
+    /// Sets the level of the warnings called `name`, or of all warnings if `name` is [`ALL_WARNINGS`].
+    ///
+    /// A level set for a single warning takes precedence over the level of all warnings.
+    ///
+    pub fn set_level(&mut self, name: &str, level: WarningLevel) {
+        self.levels.insert(name.to_string(), level);
+    }
+
+    /// Returns the level warnings called `name` are reported at.
+    pub fn level(&self, name: &str) -> WarningLevel {
+        self.levels
+            .get(name)
+            .or_else(|| self.levels.get(ALL_WARNINGS))
+            .copied()
+            .unwrap_or(WarningLevel::Warn)
+    }
+
+    ///
+    /// Records the given warning at its configured level.
+    ///
+    /// Allowed warnings and warnings that were already recorded are dropped.
+    ///
+    pub fn warn(&mut self, mut warning: Warning) {
+        warning.level = self.level(&warning.name);
+
+        if warning.level != WarningLevel::Allow && !self.warnings.contains(&warning) {
+            self.warnings.push(warning);
+        }
+    }
+
+    pub fn extend<I: IntoIterator<Item = Warning>>(&mut self, warnings: I) {
+        for warning in warnings {
+            self.warn(warning);
+        }
+    }
+
+    pub fn set_path(&mut self, path: &Path) {
+        for warning in self.warnings.iter_mut() {
+            warning.set_path(path);
+        }
+    }
+
+    pub fn warnings(&self) -> &[Warning] {
+        &self.warnings
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.warnings.is_empty()
+    }
+
+    /// Returns the number of recorded warnings that fail the build.
+    pub fn num_denied(&self) -> usize {
+        self.warnings.iter().filter(|warning| warning.is_denied()).count()
+    }
+}
+
+impl fmt::Display for Diagnostics {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let warnings = self.warnings.iter().map(Warning::to_string).collect::<Vec<_>>();
+
+        write!(f, "{}", warnings.join("\n\n"))
+    }
+}
+
+#[test]
+fn test_diagnostics() {
+    let span = Span {
+        text: " return 2u8".to_string(),
+        line: 3,
+        start: 2,
+        end: 12,
+        ..Default::default()
+    };
+    let warning = Warning::new_from_span("dead_code", "unreachable".to_string(), span);
+
+    let mut diagnostics = Diagnostics::new();
+    diagnostics.set_level(ALL_WARNINGS, WarningLevel::Deny);
+    diagnostics.warn(warning.clone());
+    diagnostics.warn(warning.clone());
+
+    assert_eq!(diagnostics.warnings().len(), 1);
+    assert_eq!(diagnostics.num_denied(), 1);
+
+    let mut diagnostics = Diagnostics::new();
+    diagnostics.set_level(ALL_WARNINGS, WarningLevel::Deny);
+    diagnostics.set_level("dead_code", WarningLevel::Allow);
+    diagnostics.warn(warning);
+
+    assert!(diagnostics.is_empty());
+}
@@ -16,7 +16,10 @@
 
 use crate::Span;
 
-use std::{fmt, path::Path};
+use std::{
+    fmt,
+    path::{Path, PathBuf},
+};
 
 pub const INDENT: &str = "    ";
 
@@ -30,67 +33,126 @@ pub const INDENT: &str = "    ";
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub struct Error {
     /// File path where error occurred
-    pub path: Option<String>,
-    /// Line number
+    pub path: Option<PathBuf>,
+    /// Starting line number
     pub line: usize,
+    /// Ending line number
+    pub line_end: usize,
     /// Starting column
     pub start: usize,
-    /// Ending column
+    /// Ending column, on the ending line
     pub end: usize,
-    /// Text of errored line
+    /// Text of errored lines, each prefixed by a space
     pub text: String,
     /// Error explanation
     pub message: String,
+    /// Stable code of the constructor that created the error, if it has one
+    pub code: Option<&'static str>,
 }
 
 impl Error {
     pub fn new_from_span(message: String, span: Span) -> Self {
         Self {
-            path: None,
+            path: span.path().map(Path::to_owned),
             line: span.line,
+            line_end: span.line_end.max(span.line),
             start: span.start,
             end: span.end,
             text: span.text,
             message,
+            code: None,
         }
     }
 
     pub fn new_from_span_with_path(message: String, span: Span, path: &Path) -> Self {
         Self {
-            path: Some(format!("{:?}", path)),
-            line: span.line,
-            start: span.start,
-            end: span.end,
-            text: span.text,
-            message,
+            path: Some(path.to_owned()),
+            ..Self::new_from_span(message, span)
+        }
+    }
+
+    pub fn with_code(self, code: &'static str) -> Self {
+        Self {
+            code: Some(code),
+            ..self
         }
     }
 
     pub fn set_path(&mut self, path: &Path) {
-        self.path = Some(format!("{:?}", path));
+        self.path = Some(path.to_owned());
     }
 
     pub fn format(&self) -> String {
-        let path = self.path.as_ref().map(|path| format!("{}:", path)).unwrap_or_default();
-        let underline = underline(self.start, self.end);
+        let path = self
+            .path
+            .as_ref()
+            .map(|path| format!("{}:", path.display()))
+            .unwrap_or_default();
 
         format!(
             "{indent     }--> {path} {line}:{start}\n\
              {indent     } |\n\
-             {line:width$} | {text}\n\
-             {indent     } | {underline}\n\
+             {snippet}\n\
              {indent     } |\n\
              {indent     } = {message}",
             indent = INDENT,
-            width = INDENT.len(),
             path = path,
             line = self.line,
             start = self.start,
-            text = self.text,
-            underline = underline,
+            snippet = self.snippet(),
             message = self.message,
         )
     }
+
+    ///
+    /// Returns the errored lines, each followed by an underline of the part the error covers.
+    ///
+    /// Only the first and last lines of errors covering more than [`MAX_SNIPPET_LINES`] lines are shown.
+    ///
+    fn snippet(&self) -> String {
+        let lines = self.text.lines().collect::<Vec<_>>();
+        let last = lines.len().saturating_sub(1);
+
+        let mut snippet = Vec::new();
+        for (index, text) in lines.iter().enumerate() {
+            if lines.len() > MAX_SNIPPET_LINES && index > 0 && index < last {
+                if index == 1 {
+                    snippet.push("...".to_string());
+                }
+                continue;
+            }
+
+            // Underline from the start column on the first line, and up to the end column on the last line
+            let start = match index {
+                0 => self.start,
+                _ => indentation(text),
+            };
+            let end = match index == last {
+                true => self.end,
+                false => text.len(),
+            };
+
+            snippet.push(format!(
+                "{line:width$} | {text}\n\
+                 {indent     } | {underline}",
+                indent = INDENT,
+                width = INDENT.len(),
+                line = self.line + index,
+                text = text,
+                underline = underline(start, end),
+            ));
+        }
+
+        snippet.join("\n")
+    }
+}
+
+/// The number of lines shown in full when formatting an error.
+pub const MAX_SNIPPET_LINES: usize = 4;
+
+/// Returns the column of the first character that is not a space.
+fn indentation(text: &str) -> usize {
+    text.len() - text.trim_start().len()
 }
 
 fn underline(mut start: usize, mut end: usize) -> String {
@@ -127,12 +189,14 @@ impl std::error::Error for Error {
 #[test]
 fn test_error() {
     let err = Error {
-        path: Some("file.leo".to_string()),
+        path: Some(PathBuf::from("file.leo")),
         line: 2,
+        line_end: 2,
         start: 8,
         end: 9,
         text: "let a = x;".to_string(),
         message: "undefined value `x`".to_string(),
+        code: None,
     };
 
     assert_eq!(
@@ -148,3 +212,34 @@ fn test_error() {
         .join("\n")
     );
 }
+
+#[test]
+fn test_multi_line_error() {
+    let err = Error {
+        path: None,
+        line: 2,
+        line_end: 4,
+        start: 13,
+        end: 13,
+        text: ["     let a = (1u8", "         + 2u8", "         + x);"].join("\n"),
+        message: "undefined value `x`".to_string(),
+        code: None,
+    };
+
+    assert_eq!(
+        err.to_string(),
+        [
+            "    -->  2:13",
+            "     |",
+            "   2 |      let a = (1u8",
+            "     |              ^^^^",
+            "   3 |          + 2u8",
+            "     |          ^^^^^",
+            "   4 |          + x);",
+            "     |          ^^^^",
+            "     |",
+            "     = undefined value `x`",
+        ]
+        .join("\n")
+    );
+}
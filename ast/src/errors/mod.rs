@@ -17,9 +17,15 @@
 pub mod deprecated;
 pub use deprecated::*;
 
+pub mod diagnostics;
+pub use diagnostics::*;
+
 pub mod error;
 pub use error::*;
 
+pub mod report;
+pub use report::*;
+
 use error::Error as FormattedError;
 
 use leo_grammar::ParserError;
@@ -41,3 +47,24 @@ pub enum AstError {
     #[error("{}", _0)]
     JsonError(#[from] serde_json::error::Error),
 }
+
+impl Diagnostic for AstError {
+    fn error_code(&self) -> &'static str {
+        match self {
+            AstError::DeprecatedError(error) => error.error_code(),
+            AstError::Error(_) => "E0200",
+            AstError::IoError(_) => "E0201",
+            AstError::ParserError(error) => error.error_code(),
+            AstError::JsonError(_) => "E0202",
+        }
+    }
+
+    fn reports(&self) -> Vec<Report> {
+        match self {
+            AstError::DeprecatedError(error) => error.reports(),
+            AstError::Error(error) => vec![Report::from_error(self.error_code(), error)],
+            AstError::ParserError(error) => error.reports(),
+            _ => vec![Report::new(self.error_code(), self.to_string())],
+        }
+    }
+}
@@ -0,0 +1,246 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Error as FormattedError, Warning};
+use leo_grammar::{ParserError, SyntaxError};
+use leo_input::{InputParserError, SyntaxError as InputSyntaxError};
+
+use pest::{
+    error::{Error as PestError, ErrorVariant, LineColLocation},
+    RuleType,
+};
+use serde::Serialize;
+use std::{fmt, path::Path};
+
+/// How severe a reported error or warning is.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+/// Source range of a report, with one-based lines and columns and an exclusive end column.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct ReportSpan {
+    pub line_start: usize,
+    pub column_start: usize,
+    pub line_end: usize,
+    pub column_end: usize,
+}
+
+/// Machine-readable error or warning, as printed by `--message-format json`.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct Report {
+    /// Stable code identifying the kind of error or warning
+    pub code: String,
+    pub severity: Severity,
+    /// File path where the error occurred
+    pub path: Option<String>,
+    pub span: Option<ReportSpan>,
+    pub message: String,
+    /// Related explanations that do not point at source code
+    pub notes: Vec<String>,
+}
+
+impl Report {
+    pub fn new(code: &str, message: String) -> Self {
+        Self {
+            code: code.to_string(),
+            severity: Severity::Error,
+            path: None,
+            span: None,
+            message,
+            notes: vec![],
+        }
+    }
+
+    pub fn from_error(code: &str, error: &FormattedError) -> Self {
+        Self {
+            path: error.path.as_ref().map(|path| path.display().to_string()),
+            span: Some(ReportSpan {
+                line_start: error.line,
+                column_start: error.start,
+                line_end: error.line_end,
+                column_end: error.end,
+            }),
+            ..Self::new(code, error.message.clone())
+        }
+    }
+
+    pub fn from_warning(warning: &Warning) -> Self {
+        let mut report = Self::from_error(&warning.name, &warning.error);
+
+        if warning.is_denied() {
+            report
+                .notes
+                .push(format!("`{}` is denied, so this warning fails the build", warning.name));
+        } else {
+            report.severity = Severity::Warning;
+        }
+
+        report
+    }
+
+    pub fn from_syntax_error<R: RuleType>(code: &str, error: &PestError<R>, path: Option<&Path>) -> Self {
+        let message = match &error.variant {
+            ErrorVariant::CustomError { message } => message.clone(),
+            ErrorVariant::ParsingError { positives, negatives } => parsing_error_message(positives, negatives),
+        };
+        let (line_start, column_start, line_end, column_end) = match error.line_col {
+            LineColLocation::Pos((line, column)) => (line, column, line, column + 1),
+            LineColLocation::Span((line_start, column_start), (line_end, column_end)) => {
+                (line_start, column_start, line_end, column_end)
+            }
+        };
+
+        Self {
+            path: path.map(|path| path.display().to_string()),
+            span: Some(ReportSpan {
+                line_start,
+                column_start,
+                line_end,
+                column_end,
+            }),
+            ..Self::new(code, message)
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("reports are always serializable")
+    }
+}
+
+/// An error that can be reported with a stable error code.
+pub trait Diagnostic: fmt::Display {
+    /// Returns the code of the innermost error.
+    fn error_code(&self) -> &'static str;
+
+    /// Returns the reports of every error this error is made of.
+    fn reports(&self) -> Vec<Report> {
+        vec![Report::new(self.error_code(), self.to_string())]
+    }
+}
+
+/// Returns the message of a syntax error listing the rules that were expected and unexpected,
+/// worded the same way as pest.
+fn parsing_error_message<R: RuleType>(positives: &[R], negatives: &[R]) -> String {
+    fn enumerate<R: RuleType>(rules: &[R]) -> String {
+        let rules = rules.iter().map(|rule| format!("{:?}", rule)).collect::<Vec<_>>();
+        match rules.len() {
+            1 => rules[0].clone(),
+            2 => format!("{} or {}", rules[0], rules[1]),
+            len => format!("{}, or {}", rules[..len - 1].join(", "), rules[len - 1]),
+        }
+    }
+
+    match (negatives.is_empty(), positives.is_empty()) {
+        (false, false) => format!("unexpected {}; expected {}", enumerate(negatives), enumerate(positives)),
+        (false, true) => format!("unexpected {}", enumerate(negatives)),
+        (true, false) => format!("expected {}", enumerate(positives)),
+        (true, true) => "unknown parsing error".to_string(),
+    }
+}
+
+impl Diagnostic for ParserError {
+    fn error_code(&self) -> &'static str {
+        match self {
+            ParserError::Crate(..) => "E0100",
+            ParserError::FileReadError(_) => "E0101",
+            ParserError::IoError(_) => "E0102",
+            ParserError::JsonError(_) => "E0103",
+            ParserError::SyntaxError(_) => "E0104",
+            ParserError::SyntaxErrors(_) => "E0105",
+            ParserError::SyntaxTreeError => "E0106",
+        }
+    }
+
+    fn reports(&self) -> Vec<Report> {
+        match self {
+            ParserError::SyntaxError(syntax_error @ SyntaxError::Error(error, _)) => {
+                vec![Report::from_syntax_error(self.error_code(), error, syntax_error.path())]
+            }
+            ParserError::SyntaxErrors(errors) => errors
+                .iter()
+                .map(|syntax_error @ SyntaxError::Error(error, _)| {
+                    Report::from_syntax_error("E0104", error, syntax_error.path())
+                })
+                .collect(),
+            _ => vec![Report::new(self.error_code(), self.to_string())],
+        }
+    }
+}
+
+impl Diagnostic for InputParserError {
+    fn error_code(&self) -> &'static str {
+        match self {
+            InputParserError::InputNotFound(_) => "E0500",
+            InputParserError::FileReadError(_) => "E0501",
+            InputParserError::ParseIntError(_) => "E0502",
+            InputParserError::ParseBoolError(_) => "E0503",
+            InputParserError::SyntaxError(_) => "E0504",
+            InputParserError::SyntaxTreeError => "E0505",
+        }
+    }
+
+    fn reports(&self) -> Vec<Report> {
+        match self {
+            InputParserError::SyntaxError(syntax_error @ InputSyntaxError::Error(error, _)) => {
+                vec![Report::from_syntax_error(self.error_code(), error, syntax_error.path())]
+            }
+            _ => vec![Report::new(self.error_code(), self.to_string())],
+        }
+    }
+}
+
+#[test]
+fn test_report() {
+    let error = FormattedError {
+        path: Some("file.leo".into()),
+        line: 2,
+        line_end: 2,
+        start: 9,
+        end: 10,
+        text: " let a = x;".to_string(),
+        message: "undefined value `x`".to_string(),
+        code: None,
+    };
+
+    assert_eq!(
+        Report::from_error("E0300", &error).to_json(),
+        concat!(
+            r#"{"code":"E0300","severity":"error","path":"file.leo","#,
+            r#""span":{"line_start":2,"column_start":9,"line_end":2,"column_end":10},"#,
+            r#""message":"undefined value `x`","notes":[]}"#
+        )
+    );
+}
+
+#[test]
+fn test_syntax_error_report() {
+    let path = std::path::Path::new("file.leo");
+    let mut error = leo_grammar::Grammar::new(path, "function main() {\n    let a = ;\n}\n")
+        .err()
+        .unwrap();
+    error.set_path(path);
+
+    let reports = error.reports();
+
+    assert_eq!(reports.len(), 1);
+    assert_eq!(reports[0].code, "E0104");
+    assert_eq!(reports[0].path.as_deref(), Some("file.leo"));
+    assert_eq!(reports[0].span.as_ref().map(|span| span.line_start), Some(2));
+}
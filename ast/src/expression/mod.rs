@@ -42,6 +42,7 @@ use leo_grammar::{
     values::{
         AddressValue,
         BooleanValue,
+        DecimalValue,
         FieldValue,
         GroupValue as GrammarGroupValue,
         IntegerValue,
@@ -467,6 +468,7 @@ impl<'ast> From<Value<'ast>> for Expression {
         match value {
             Value::Address(address) => Expression::from(address),
             Value::Boolean(boolean) => Expression::from(boolean),
+            Value::Decimal(decimal) => Expression::from(decimal),
             Value::Field(field) => Expression::from(field),
             Value::Group(group) => Expression::from(group),
             Value::Implicit(number) => Expression::from(number),
@@ -505,6 +507,12 @@ impl<'ast> From<BooleanValue<'ast>> for Expression {
     }
 }
 
+impl<'ast> From<DecimalValue<'ast>> for Expression {
+    fn from(decimal: DecimalValue<'ast>) -> Self {
+        Expression::Value(ValueExpression::Decimal(decimal.value, Span::from(decimal.span)))
+    }
+}
+
 impl<'ast> From<FieldValue<'ast>> for Expression {
     fn from(field: FieldValue<'ast>) -> Self {
         Expression::Value(ValueExpression::Field(field.number.to_string(), Span::from(field.span)))
@@ -22,6 +22,7 @@ pub enum ValueExpression {
     // todo: deserialize values here
     Address(String, Span),
     Boolean(String, Span),
+    Decimal(String, Span),
     Field(String, Span),
     Group(Box<GroupValue>),
     Implicit(String, Span),
@@ -34,6 +35,7 @@ impl fmt::Display for ValueExpression {
         match &self {
             Address(address, _) => write!(f, "{}", address),
             Boolean(boolean, _) => write!(f, "{}", boolean),
+            Decimal(decimal, _) => write!(f, "{}", decimal),
             Field(field, _) => write!(f, "{}", field),
             Implicit(implicit, _) => write!(f, "{}", implicit),
             Integer(value, type_, _) => write!(f, "{}{}", value, type_),
@@ -46,7 +48,12 @@ impl Node for ValueExpression {
     fn span(&self) -> &Span {
         use ValueExpression::*;
         match &self {
-            Address(_, span) | Boolean(_, span) | Field(_, span) | Implicit(_, span) | Integer(_, _, span) => span,
+            Address(_, span)
+            | Boolean(_, span)
+            | Decimal(_, span)
+            | Field(_, span)
+            | Implicit(_, span)
+            | Integer(_, _, span) => span,
             Group(group) => match &**group {
                 GroupValue::Single(_, span) | GroupValue::Tuple(GroupTuple { span, .. }) => span,
             },
@@ -56,9 +63,12 @@ impl Node for ValueExpression {
     fn set_span(&mut self, new_span: Span) {
         use ValueExpression::*;
         match self {
-            Address(_, span) | Boolean(_, span) | Field(_, span) | Implicit(_, span) | Integer(_, _, span) => {
-                *span = new_span
-            }
+            Address(_, span)
+            | Boolean(_, span)
+            | Decimal(_, span)
+            | Field(_, span)
+            | Implicit(_, span)
+            | Integer(_, _, span) => *span = new_span,
             Group(group) => match &mut **group {
                 GroupValue::Single(_, span) | GroupValue::Tuple(GroupTuple { span, .. }) => *span = new_span,
             },
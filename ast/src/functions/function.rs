@@ -15,17 +15,33 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{Block, FunctionInput, Identifier, Node, Span, Type};
-use leo_grammar::functions::Function as GrammarFunction;
+use leo_grammar::{
+    functions::{ExternFunction as GrammarExternFunction, Function as GrammarFunction},
+    interfaces::InterfaceFunction as GrammarInterfaceFunction,
+};
 
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
+/// Whether calls to a function should be inlined, as requested by an annotation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum InlineHint {
+    /// `@inline`
+    Inline,
+    /// `@noinline`
+    NoInline,
+}
+
 #[derive(Clone, Serialize, Deserialize)]
 pub struct Function {
     pub identifier: Identifier,
     pub input: Vec<FunctionInput>,
     pub output: Option<Type>,
-    pub block: Block,
+    /// The function body, or `None` for an `extern` declaration.
+    pub block: Option<Block>,
+    /// Set by `@inline` or `@noinline`, otherwise the inlining pass decides.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inline: Option<InlineHint>,
     pub span: Span,
 }
 
@@ -49,7 +65,44 @@ impl<'ast> From<GrammarFunction<'ast>> for Function {
             identifier: function_name,
             input: parameters,
             output: returns,
-            block,
+            block: Some(block),
+            inline: None,
+            span: Span::from(function.span),
+        }
+    }
+}
+
+impl<'ast> From<GrammarExternFunction<'ast>> for Function {
+    fn from(function: GrammarExternFunction<'ast>) -> Self {
+        let function_name = Identifier::from(function.identifier);
+
+        let parameters = function.parameters.into_iter().map(FunctionInput::from).collect();
+        let returns = function.returns.map(Type::from);
+
+        Function {
+            identifier: function_name,
+            input: parameters,
+            output: returns,
+            block: None,
+            inline: None,
+            span: Span::from(function.span),
+        }
+    }
+}
+
+impl<'ast> From<GrammarInterfaceFunction<'ast>> for Function {
+    fn from(function: GrammarInterfaceFunction<'ast>) -> Self {
+        let function_name = Identifier::from(function.identifier);
+
+        let parameters = function.parameters.into_iter().map(FunctionInput::from).collect();
+        let returns = function.returns.map(Type::from);
+
+        Function {
+            identifier: function_name,
+            input: parameters,
+            output: returns,
+            block: None,
+            inline: None,
             span: Span::from(function.span),
         }
     }
@@ -76,6 +129,14 @@ impl Function {
         self.input.iter().any(|param| param.is_mut_self())
     }
 
+    ///
+    /// Returns `true` if the function is an `extern` declaration without a body.
+    /// Returns `false` otherwise.
+    ///
+    pub fn is_extern(&self) -> bool {
+        self.block.is_none()
+    }
+
     ///
     /// Returns an iterator of [&FunctionInput] removing `self` and `mut self` inputs.
     ///
@@ -84,14 +145,24 @@ impl Function {
     }
 
     fn format(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.inline {
+            Some(InlineHint::Inline) => write!(f, "@inline ")?,
+            Some(InlineHint::NoInline) => write!(f, "@noinline ")?,
+            None => {}
+        }
+        if self.is_extern() {
+            write!(f, "extern ")?;
+        }
         write!(f, "function {}", self.identifier)?;
 
         let parameters = self.input.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(",");
-        let returns = self.output.as_ref().map(|type_| type_.to_string());
-        if returns.is_none() {
-            write!(f, "({}) {}", parameters, self.block)
-        } else {
-            write!(f, "({}) -> {} {}", parameters, returns.unwrap(), self.block)
+        write!(f, "({})", parameters)?;
+        if let Some(returns) = self.output.as_ref() {
+            write!(f, " -> {}", returns)?;
+        }
+        match self.block.as_ref() {
+            Some(block) => write!(f, " {}", block),
+            None => write!(f, ";"),
         }
     }
 }
@@ -14,11 +14,17 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{ArrayDimensions, GroupValue};
+use crate::{ArrayDimensions, FixedType, GroupValue, Identifier};
 use leo_input::{
     errors::InputParserError,
-    expressions::{ArrayInitializerExpression, ArrayInlineExpression, Expression, TupleExpression},
-    types::{ArrayType, DataType, IntegerType, TupleType, Type},
+    expressions::{
+        ArrayInitializerExpression,
+        ArrayInlineExpression,
+        CircuitInlineExpression,
+        Expression,
+        TupleExpression,
+    },
+    types::{ArrayType, CircuitType, DataType, IntegerType, TupleType, Type},
     values::{Address, AddressValue, BooleanValue, FieldValue, GroupValue as InputGroupValue, NumberValue, Value},
 };
 use pest::Span;
@@ -30,10 +36,14 @@ pub enum InputValue {
     Address(String),
     Boolean(bool),
     Field(String),
+    Fixed(FixedType, String),
     Group(GroupValue),
     Integer(IntegerType, String),
     Array(Vec<InputValue>),
     Tuple(Vec<InputValue>),
+    Circuit(Identifier, Vec<(Identifier, InputValue)>),
+    /// A number without a type inside a circuit, typed by the circuit member it initializes.
+    Implicit(String),
 }
 
 impl InputValue {
@@ -72,6 +82,7 @@ impl InputValue {
             DataType::Integer(integer_type) => Ok(InputValue::from_number(integer_type, implicit.to_string())),
             DataType::Group(_) => Err(InputParserError::implicit_group(implicit)),
             DataType::Field(_) => Ok(InputValue::Field(implicit.to_string())),
+            DataType::Fixed(fixed_type) => Ok(InputValue::Fixed(FixedType::from(fixed_type), implicit.to_string())),
         }
     }
 
@@ -84,6 +95,9 @@ impl InputValue {
             }
             (DataType::Group(_), Value::Group(group)) => Ok(InputValue::from_group(group)),
             (DataType::Field(_), Value::Field(field)) => Ok(InputValue::from_field(field)),
+            (DataType::Fixed(fixed_type), Value::Decimal(decimal)) => {
+                Ok(InputValue::Fixed(FixedType::from(fixed_type), decimal.value))
+            }
             (data_type, Value::Implicit(implicit)) => InputValue::from_implicit(data_type, implicit),
             (data_type, value) => Err(InputParserError::data_type_mismatch(data_type, value)),
         }
@@ -99,10 +113,87 @@ impl InputValue {
                 InputValue::from_array_initializer(array_type, initializer)
             }
             (Type::Tuple(tuple_type), Expression::Tuple(tuple)) => InputValue::from_tuple(tuple_type, tuple),
+            (Type::Circuit(circuit_type), Expression::CircuitInline(circuit)) => {
+                InputValue::from_circuit_inline(circuit_type, circuit)
+            }
             (type_, expression) => Err(InputParserError::expression_type_mismatch(type_, expression)),
         }
     }
 
+    ///
+    /// Returns a new `InputValue` from an expression without a declared type.
+    ///
+    /// Circuit members are declared in the program rather than in the input file, so their values
+    /// are typed by their literals. Numbers without a type become `InputValue::Implicit`.
+    ///
+    pub(crate) fn from_untyped_expression(expression: Expression) -> Result<Self, InputParserError> {
+        match expression {
+            Expression::Value(value) => match value {
+                Value::Address(address) => Ok(InputValue::from_address_value(address)),
+                Value::Boolean(boolean) => InputValue::from_boolean(boolean),
+                Value::Field(field) => Ok(InputValue::from_field(field)),
+                Value::Group(group) => Ok(InputValue::from_group(group)),
+                Value::Integer(integer) => Ok(InputValue::from_number(integer.type_(), integer.to_string())),
+                Value::Decimal(decimal) => Ok(InputValue::Implicit(decimal.value)),
+                Value::Implicit(implicit) => Ok(InputValue::Implicit(implicit.to_string())),
+            },
+            Expression::ArrayInline(inline) => Ok(InputValue::Array(
+                inline
+                    .expressions
+                    .into_iter()
+                    .map(InputValue::from_untyped_expression)
+                    .collect::<Result<Vec<_>, _>>()?,
+            )),
+            Expression::ArrayInitializer(initializer) => {
+                let array_dimensions_type = ArrayDimensions::from(initializer.dimensions.clone());
+                let array_dimensions = parse_array_dimensions(array_dimensions_type, initializer.span.clone())?;
+
+                let mut value = InputValue::from_untyped_expression(*initializer.expression)?;
+                for dimension in array_dimensions.into_iter().rev() {
+                    value = InputValue::Array(vec![value; dimension]);
+                }
+
+                Ok(value)
+            }
+            Expression::Tuple(tuple) => Ok(InputValue::Tuple(
+                tuple
+                    .expressions
+                    .into_iter()
+                    .map(InputValue::from_untyped_expression)
+                    .collect::<Result<Vec<_>, _>>()?,
+            )),
+            Expression::CircuitInline(circuit) => InputValue::from_circuit_members(circuit),
+        }
+    }
+
+    ///
+    /// Returns a new `InputValue` from the given `CircuitType` and `CircuitInlineExpression`.
+    ///
+    pub(crate) fn from_circuit_inline(
+        circuit_type: CircuitType,
+        circuit: CircuitInlineExpression,
+    ) -> Result<Self, InputParserError> {
+        if circuit_type.identifier.value != circuit.name.value {
+            return Err(InputParserError::expression_type_mismatch(
+                Type::Circuit(circuit_type),
+                Expression::CircuitInline(circuit),
+            ));
+        }
+
+        InputValue::from_circuit_members(circuit)
+    }
+
+    fn from_circuit_members(circuit: CircuitInlineExpression) -> Result<Self, InputParserError> {
+        let mut members = Vec::with_capacity(circuit.members.len());
+        for member in circuit.members.into_iter() {
+            let value = InputValue::from_untyped_expression(member.expression)?;
+
+            members.push((Identifier::from(member.identifier), value));
+        }
+
+        Ok(InputValue::Circuit(Identifier::from(circuit.name), members))
+    }
+
     ///
     /// Returns a new `InputValue` from the given `ArrayType` and `ArrayInlineExpression`.
     ///
@@ -309,6 +400,7 @@ impl fmt::Display for InputValue {
             InputValue::Boolean(ref boolean) => write!(f, "{}", boolean),
             InputValue::Group(ref group) => write!(f, "{}", group),
             InputValue::Field(ref field) => write!(f, "{}", field),
+            InputValue::Fixed(_, ref value) => write!(f, "{}", value),
             InputValue::Integer(ref type_, ref number) => write!(f, "{}{:?}", number, type_),
             InputValue::Array(ref array) => {
                 let values = array.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(", ");
@@ -320,6 +412,16 @@ impl fmt::Display for InputValue {
 
                 write!(f, "({})", values)
             }
+            InputValue::Circuit(ref name, ref members) => {
+                let members = members
+                    .iter()
+                    .map(|(name, value)| format!("{}: {}", name, value))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                write!(f, "{} {{ {} }}", name, members)
+            }
+            InputValue::Implicit(ref number) => write!(f, "{}", number),
         }
     }
 }
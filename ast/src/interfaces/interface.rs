@@ -0,0 +1,74 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Function, Identifier};
+use leo_grammar::interfaces::Interface as GrammarInterface;
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A set of function signatures that circuits declared with `implements` must define.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Interface {
+    pub interface_name: Identifier,
+    /// The signatures, as functions without a body.
+    pub functions: Vec<Function>,
+}
+
+impl<'ast> From<GrammarInterface<'ast>> for Interface {
+    fn from(interface: GrammarInterface<'ast>) -> Self {
+        let interface_name = Identifier::from(interface.identifier);
+        let functions = interface.functions.into_iter().map(Function::from).collect();
+
+        Self {
+            interface_name,
+            functions,
+        }
+    }
+}
+
+impl Interface {
+    fn format(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "interface {} {{ ", self.interface_name)?;
+        for function in self.functions.iter() {
+            write!(f, "    function {}", function.identifier)?;
+            let parameters = function
+                .input
+                .iter()
+                .map(|x| x.to_string())
+                .collect::<Vec<_>>()
+                .join(",");
+            write!(f, "({})", parameters)?;
+            if let Some(returns) = function.output.as_ref() {
+                write!(f, " -> {}", returns)?;
+            }
+            writeln!(f, ";")?;
+        }
+        write!(f, "}}")
+    }
+}
+
+impl fmt::Debug for Interface {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.format(f)
+    }
+}
+
+impl fmt::Display for Interface {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.format(f)
+    }
+}
@@ -14,6 +14,5 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-fn main() {
-    println!("Hello, world!");
-}
+pub mod interface;
+pub use interface::*;
@@ -49,6 +49,9 @@ pub use self::imports::*;
 pub mod input;
 pub use self::input::*;
 
+pub mod interfaces;
+pub use self::interfaces::*;
+
 pub mod program;
 pub use self::program::*;
 
@@ -77,9 +80,12 @@ pub struct Ast {
 impl Ast {
     /// Creates a new AST from a given program name and grammar tree.
     pub fn new<'ast>(program_name: &str, grammar: &Grammar<'ast>) -> Result<Self, AstError> {
-        Ok(Self {
-            ast: Program::from(program_name, grammar.as_repr())?,
-        })
+        // Spans converted from the grammar point into the file it was parsed from.
+        let ast = grammar
+            .file_id()
+            .enter(|| Program::from(program_name, grammar.as_repr()))?;
+
+        Ok(Self { ast })
     }
 
     /// Returns a reference to the inner program AST representation.
@@ -25,6 +25,7 @@ use crate::{
     FunctionInput,
     Identifier,
     ImportStatement,
+    Interface,
     TestFunction,
 };
 use leo_grammar::{definitions::Definition, files::File};
@@ -40,6 +41,7 @@ pub struct Program {
     pub expected_input: Vec<FunctionInput>,
     pub imports: Vec<ImportStatement>,
     pub circuits: IndexMap<Identifier, Circuit>,
+    pub interfaces: IndexMap<Identifier, Interface>,
     pub functions: IndexMap<Identifier, Function>,
     pub tests: IndexMap<Identifier, TestFunction>,
 }
@@ -56,6 +58,11 @@ impl fmt::Display for Program {
             writeln!(f,)?;
         }
         writeln!(f,)?;
+        for (_, interface) in self.interfaces.iter() {
+            interface.fmt(f)?;
+            writeln!(f,)?;
+        }
+        writeln!(f,)?;
         for (_, function) in self.functions.iter() {
             function.fmt(f)?;
             writeln!(f,)?;
@@ -69,13 +76,14 @@ impl fmt::Display for Program {
     }
 }
 
-const MAIN_FUNCTION_NAME: &str = "main";
+pub(crate) const MAIN_FUNCTION_NAME: &str = "main";
 
 impl<'ast> Program {
     //! Logic to convert from an abstract syntax tree (ast) representation to a Leo program.
     pub fn from(program_name: &str, program_ast: &File<'ast>) -> Result<Self, DeprecatedError> {
         let mut imports = vec![];
         let mut circuits = IndexMap::new();
+        let mut interfaces = IndexMap::new();
         let mut functions = IndexMap::new();
         let mut tests = IndexMap::new();
         let mut expected_input = vec![];
@@ -94,6 +102,10 @@ impl<'ast> Program {
                     circuits.insert(Identifier::from(circuit.identifier.clone()), Circuit::from(circuit));
                     None
                 }
+                Definition::Interface(interface) => {
+                    interfaces.insert(Identifier::from(interface.identifier.clone()), Interface::from(interface));
+                    None
+                }
                 Definition::Function(function_def) => {
                     let function = Function::from(function_def);
                     if function.identifier.name.eq(MAIN_FUNCTION_NAME) {
@@ -102,6 +114,11 @@ impl<'ast> Program {
                     functions.insert(function.identifier.clone(), function);
                     None
                 }
+                Definition::ExternFunction(function_def) => {
+                    let function = Function::from(function_def);
+                    functions.insert(function.identifier.clone(), function);
+                    None
+                }
                 Definition::Deprecated(deprecated) => {
                     Some(Err(DeprecatedError::from(deprecated)))
                 }
@@ -128,6 +145,7 @@ impl<'ast> Program {
             expected_input,
             imports,
             circuits,
+            interfaces,
             functions,
             tests,
         })
@@ -141,6 +159,7 @@ impl Program {
             expected_input: vec![],
             imports: vec![],
             circuits: IndexMap::new(),
+            interfaces: IndexMap::new(),
             functions: IndexMap::new(),
             tests: IndexMap::new(),
         }
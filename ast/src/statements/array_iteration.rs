@@ -0,0 +1,57 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Block, Expression, Identifier, Node, Span};
+
+use leo_grammar::statements::ForEachStatement;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A loop over the elements of an array, `for x in a { ... }`.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
+pub struct ArrayIterationStatement {
+    pub variable: Identifier,
+    pub array: Expression,
+    pub block: Block,
+    pub span: Span,
+}
+
+impl fmt::Display for ArrayIterationStatement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "for {} in {} {}", self.variable, self.array, self.block)
+    }
+}
+
+impl<'ast> From<ForEachStatement<'ast>> for ArrayIterationStatement {
+    fn from(statement: ForEachStatement<'ast>) -> Self {
+        ArrayIterationStatement {
+            variable: Identifier::from(statement.element),
+            array: Expression::from(statement.array),
+            block: Block::from(statement.block),
+            span: Span::from(statement.span),
+        }
+    }
+}
+
+impl Node for ArrayIterationStatement {
+    fn span(&self) -> &Span {
+        &self.span
+    }
+
+    fn set_span(&mut self, span: Span) {
+        self.span = span;
+    }
+}
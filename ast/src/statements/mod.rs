@@ -29,6 +29,9 @@ pub use return_statement::*;
 pub mod iteration;
 pub use iteration::*;
 
+pub mod array_iteration;
+pub use array_iteration::*;
+
 pub mod expression;
 pub use expression::*;
 
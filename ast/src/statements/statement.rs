@@ -30,6 +30,7 @@ pub enum Statement {
     Assign(AssignStatement),
     Conditional(ConditionalStatement),
     Iteration(IterationStatement),
+    ArrayIteration(ArrayIterationStatement),
     Console(ConsoleStatement),
     Expression(ExpressionStatement),
     Block(Block),
@@ -43,6 +44,9 @@ impl<'ast> From<GrammarStatement<'ast>> for Statement {
             GrammarStatement::Assign(statement) => Statement::Assign(AssignStatement::from(statement)),
             GrammarStatement::Conditional(statement) => Statement::Conditional(ConditionalStatement::from(statement)),
             GrammarStatement::Iteration(statement) => Statement::Iteration(IterationStatement::from(statement)),
+            GrammarStatement::ArrayIteration(statement) => {
+                Statement::ArrayIteration(ArrayIterationStatement::from(statement))
+            }
             GrammarStatement::Console(statement) => Statement::Console(ConsoleStatement::from(statement)),
             GrammarStatement::Expression(statement) => Statement::Expression(ExpressionStatement::from(statement)),
             GrammarStatement::Block(statement) => Statement::Block(Block::from(statement)),
@@ -58,6 +62,7 @@ impl fmt::Display for Statement {
             Statement::Assign(x) => x.fmt(f),
             Statement::Conditional(x) => x.fmt(f),
             Statement::Iteration(x) => x.fmt(f),
+            Statement::ArrayIteration(x) => x.fmt(f),
             Statement::Console(x) => x.fmt(f),
             Statement::Expression(x) => x.fmt(f),
             Statement::Block(x) => x.fmt(f),
@@ -74,6 +79,7 @@ impl Node for Statement {
             Assign(n) => n.span(),
             Conditional(n) => n.span(),
             Iteration(n) => n.span(),
+            ArrayIteration(n) => n.span(),
             Console(n) => n.span(),
             Expression(n) => n.span(),
             Block(n) => n.span(),
@@ -88,6 +94,7 @@ impl Node for Statement {
             Assign(n) => n.set_span(span),
             Conditional(n) => n.set_span(span),
             Iteration(n) => n.set_span(span),
+            ArrayIteration(n) => n.set_span(span),
             Console(n) => n.set_span(span),
             Expression(n) => n.set_span(span),
             Block(n) => n.set_span(span),
@@ -0,0 +1,72 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::IntegerType;
+use leo_grammar::types::FixedType as GrammarFixedType;
+use leo_input::types::FixedType as InputFixedType;
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Explicit fixed-point decimal type `fixed<width, scale>`.
+///
+/// Values are stored as `width`-bit signed integers counting multiples of `10^-scale`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct FixedType {
+    pub width: u32,
+    pub scale: u32,
+}
+
+impl FixedType {
+    /// Returns the signed integer type storing values of this type, or `None` if the width is
+    /// not the width of an integer type.
+    pub fn integer_type(&self) -> Option<IntegerType> {
+        Some(match self.width {
+            8 => IntegerType::I8,
+            16 => IntegerType::I16,
+            32 => IntegerType::I32,
+            64 => IntegerType::I64,
+            128 => IntegerType::I128,
+            _ => return None,
+        })
+    }
+}
+
+impl From<GrammarFixedType> for FixedType {
+    fn from(fixed_type: GrammarFixedType) -> Self {
+        // the grammar only admits integer widths and scales of up to two digits
+        FixedType {
+            width: fixed_type.width.value.parse().unwrap(),
+            scale: fixed_type.scale.value.parse().unwrap(),
+        }
+    }
+}
+
+impl From<InputFixedType> for FixedType {
+    fn from(fixed_type: InputFixedType) -> Self {
+        // the input grammar only admits integer widths and scales of up to two digits
+        FixedType {
+            width: fixed_type.width.value.parse().unwrap(),
+            scale: fixed_type.scale.value.parse().unwrap(),
+        }
+    }
+}
+
+impl fmt::Display for FixedType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "fixed<{}, {}>", self.width, self.scale)
+    }
+}
@@ -36,12 +36,41 @@ pub enum IntegerType {
     U32,
     U64,
     U128,
+    U256,
 
     I8,
     I16,
     I32,
     I64,
     I128,
+    I256,
+}
+
+impl IntegerType {
+    /// Returns `true` if the type is a signed integer type.
+    pub fn is_signed(&self) -> bool {
+        matches!(
+            self,
+            IntegerType::I8
+                | IntegerType::I16
+                | IntegerType::I32
+                | IntegerType::I64
+                | IntegerType::I128
+                | IntegerType::I256
+        )
+    }
+
+    /// Returns the number of bits in the type.
+    pub fn bit_width(&self) -> usize {
+        match self {
+            IntegerType::U8 | IntegerType::I8 => 8,
+            IntegerType::U16 | IntegerType::I16 => 16,
+            IntegerType::U32 | IntegerType::I32 => 32,
+            IntegerType::U64 | IntegerType::I64 => 64,
+            IntegerType::U128 | IntegerType::I128 => 128,
+            IntegerType::U256 | IntegerType::I256 => 256,
+        }
+    }
 }
 
 impl From<GrammarIntegerType> for IntegerType {
@@ -61,6 +90,7 @@ impl From<GrammarUnsignedIntegerType> for IntegerType {
             GrammarUnsignedIntegerType::U32Type(_type) => IntegerType::U32,
             GrammarUnsignedIntegerType::U64Type(_type) => IntegerType::U64,
             GrammarUnsignedIntegerType::U128Type(_type) => IntegerType::U128,
+            GrammarUnsignedIntegerType::U256Type(_type) => IntegerType::U256,
         }
     }
 }
@@ -73,6 +103,7 @@ impl From<GrammarSignedIntegerType> for IntegerType {
             GrammarSignedIntegerType::I32Type(_type) => IntegerType::I32,
             GrammarSignedIntegerType::I64Type(_type) => IntegerType::I64,
             GrammarSignedIntegerType::I128Type(_type) => IntegerType::I128,
+            GrammarSignedIntegerType::I256Type(_type) => IntegerType::I256,
         }
     }
 }
@@ -94,6 +125,7 @@ impl From<InputUnsignedIntegerType> for IntegerType {
             InputUnsignedIntegerType::U32Type(_type) => IntegerType::U32,
             InputUnsignedIntegerType::U64Type(_type) => IntegerType::U64,
             InputUnsignedIntegerType::U128Type(_type) => IntegerType::U128,
+            InputUnsignedIntegerType::U256Type(_type) => IntegerType::U256,
         }
     }
 }
@@ -106,6 +138,7 @@ impl From<InputSignedIntegerType> for IntegerType {
             InputSignedIntegerType::I32Type(_type) => IntegerType::I32,
             InputSignedIntegerType::I64Type(_type) => IntegerType::I64,
             InputSignedIntegerType::I128Type(_type) => IntegerType::I128,
+            InputSignedIntegerType::I256Type(_type) => IntegerType::I256,
         }
     }
 }
@@ -118,12 +151,14 @@ impl fmt::Display for IntegerType {
             IntegerType::U32 => write!(f, "u32"),
             IntegerType::U64 => write!(f, "u64"),
             IntegerType::U128 => write!(f, "u128"),
+            IntegerType::U256 => write!(f, "u256"),
 
             IntegerType::I8 => write!(f, "i8"),
             IntegerType::I16 => write!(f, "i16"),
             IntegerType::I32 => write!(f, "i32"),
             IntegerType::I64 => write!(f, "i64"),
             IntegerType::I128 => write!(f, "i128"),
+            IntegerType::I256 => write!(f, "i256"),
         }
     }
 }
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{ArrayDimensions, Identifier, IntegerType};
+use crate::{ArrayDimensions, FixedType, Identifier, IntegerType};
 use leo_grammar::types::{ArrayType, CircuitType, DataType, TupleType, Type as GrammarType};
 use leo_input::types::{
     ArrayType as InputArrayType,
@@ -33,6 +33,7 @@ pub enum Type {
     Address,
     Boolean,
     Field,
+    Fixed(FixedType),
     Group,
     IntegerType(IntegerType),
 
@@ -68,6 +69,7 @@ impl Type {
             (Type::Address, Type::Address) => true,
             (Type::Boolean, Type::Boolean) => true,
             (Type::Field, Type::Field) => true,
+            (Type::Fixed(left), Type::Fixed(right)) => left == right,
             (Type::Group, Type::Group) => true,
             (Type::IntegerType(left), Type::IntegerType(right)) => left.eq(&right),
             (Type::Circuit(left), Type::Circuit(right)) => left.eq(&right),
@@ -110,6 +112,7 @@ impl From<DataType> for Type {
             DataType::Address(_type) => Type::Address,
             DataType::Boolean(_type) => Type::Boolean,
             DataType::Field(_type) => Type::Field,
+            DataType::Fixed(type_) => Type::Fixed(FixedType::from(type_)),
             DataType::Group(_type) => Type::Group,
             DataType::Integer(_type) => Type::IntegerType(IntegerType::from(_type)),
         }
@@ -159,6 +162,7 @@ impl From<InputDataType> for Type {
             InputDataType::Address(_type) => Type::Address,
             InputDataType::Boolean(_type) => Type::Boolean,
             InputDataType::Field(_type) => Type::Field,
+            InputDataType::Fixed(type_) => Type::Fixed(FixedType::from(type_)),
             InputDataType::Group(_type) => Type::Group,
             InputDataType::Integer(type_) => Type::IntegerType(IntegerType::from(type_)),
         }
@@ -188,6 +192,7 @@ impl<'ast> From<InputType<'ast>> for Type {
             InputType::Basic(type_) => Type::from(type_),
             InputType::Array(type_) => Type::from(type_),
             InputType::Tuple(type_) => Type::from(type_),
+            InputType::Circuit(type_) => Type::Circuit(Identifier::from(type_.identifier)),
         }
     }
 }
@@ -198,6 +203,7 @@ impl fmt::Display for Type {
             Type::Address => write!(f, "address"),
             Type::Boolean => write!(f, "bool"),
             Type::Field => write!(f, "field"),
+            Type::Fixed(ref fixed_type) => write!(f, "{}", fixed_type),
             Type::Group => write!(f, "group"),
             Type::IntegerType(ref integer_type) => write!(f, "{}", integer_type),
             Type::Circuit(ref variable) => write!(f, "circuit {}", variable),
@@ -17,23 +17,27 @@
 //! Compiles a Leo program from a file path.
 
 use crate::{
-    constraints::{generate_constraints, generate_test_constraints},
+    constraints::{generate_constraints, generate_ir_constraints, generate_test_constraints},
     errors::CompilerError,
+    ir,
+    option::CompilerOptions,
+    soundness::{check_constraints, check_output, SoundnessWarning},
     GroupType,
     OutputBytes,
     OutputFile,
 };
-use leo_asg::Asg;
-use leo_ast::{Ast, Input, MainInput, Program};
+use leo_asg::{default_passes, Asg, AsgConvertError, PassKind, PassReport};
+use leo_ast::{Ast, Diagnostics, Input, MainInput, Program};
 use leo_grammar::Grammar;
 use leo_input::LeoInputParser;
 use leo_package::inputs::InputPairs;
 use leo_state::verify_local_data_commitment;
+use leo_synthesizer::NamespacedSynthesizer;
 
 use snarkvm_dpc::{base_dpc::instantiated::Components, SystemParameters};
 use snarkvm_errors::gadgets::SynthesisError;
 use snarkvm_models::{
-    curves::PrimeField,
+    curves::{PairingEngine, PrimeField},
     gadgets::r1cs::{ConstraintSynthesizer, ConstraintSystem},
 };
 
@@ -63,9 +67,14 @@ pub fn thread_leaked_context() -> AsgContext<'static> {
 pub struct Compiler<'a, F: PrimeField, G: GroupType<F>> {
     program_name: String,
     main_file_path: PathBuf,
+    input_path: Option<PathBuf>,
     output_directory: PathBuf,
     program: Program,
+    program_string: String,
     program_input: Input,
+    diagnostics: Diagnostics,
+    options: CompilerOptions,
+    pass_reports: Vec<PassReport>,
     context: AsgContext<'a>,
     asg: Option<Asg<'a>>,
     _engine: PhantomData<F>,
@@ -85,9 +94,14 @@ impl<'a, F: PrimeField, G: GroupType<F>> Compiler<'a, F, G> {
         Self {
             program_name: package_name.clone(),
             main_file_path,
+            input_path: None,
             output_directory,
             program: Program::new(package_name),
+            program_string: String::new(),
             program_input: Input::new(),
+            diagnostics: Diagnostics::new(),
+            options: CompilerOptions::default(),
+            pass_reports: vec![],
             asg: None,
             context,
             _engine: PhantomData,
@@ -101,14 +115,20 @@ impl<'a, F: PrimeField, G: GroupType<F>> Compiler<'a, F, G> {
     /// Parses and stores a program from the main file path.
     /// Parses and stores all imported programs.
     /// Performs type inference checking on the program and imported programs.
+    /// Records warnings at the levels configured in the given diagnostics.
+    /// Runs the asg passes configured in the given options.
     ///
     pub fn parse_program_without_input(
         package_name: String,
         main_file_path: PathBuf,
         output_directory: PathBuf,
         context: AsgContext<'a>,
+        diagnostics: Diagnostics,
+        options: CompilerOptions,
     ) -> Result<Self, CompilerError> {
         let mut compiler = Self::new(package_name, main_file_path, output_directory, context);
+        compiler.set_diagnostics(diagnostics);
+        compiler.set_options(options);
 
         compiler.parse_program()?;
 
@@ -122,6 +142,8 @@ impl<'a, F: PrimeField, G: GroupType<F>> Compiler<'a, F, G> {
     /// Parses and stores a program from the main file path.
     /// Parses and stores all imported programs.
     /// Performs type inference checking on the program, imported programs, and program input.
+    /// Records warnings at the levels configured in the given diagnostics.
+    /// Runs the asg passes configured in the given options.
     ///
     #[allow(clippy::too_many_arguments)]
     pub fn parse_program_with_input(
@@ -133,8 +155,12 @@ impl<'a, F: PrimeField, G: GroupType<F>> Compiler<'a, F, G> {
         state_string: &str,
         state_path: &Path,
         context: AsgContext<'a>,
+        diagnostics: Diagnostics,
+        options: CompilerOptions,
     ) -> Result<Self, CompilerError> {
         let mut compiler = Self::new(package_name, main_file_path, output_directory, context);
+        compiler.set_diagnostics(diagnostics);
+        compiler.set_options(options);
 
         compiler.parse_input(input_string, input_path, state_string, state_path)?;
 
@@ -171,6 +197,7 @@ impl<'a, F: PrimeField, G: GroupType<F>> Compiler<'a, F, G> {
 
             e
         })?;
+        self.input_path = Some(input_path.to_owned());
         self.program_input.parse_state(state_syntax_tree).map_err(|mut e| {
             e.set_path(state_path);
 
@@ -209,14 +236,50 @@ impl<'a, F: PrimeField, G: GroupType<F>> Compiler<'a, F, G> {
 
         // Store the main program file.
         self.program = core_ast.as_repr().clone();
+        self.program_string = program_string.to_string();
 
         tracing::debug!("Program parsing complete\n{:#?}", self.program);
 
         // Create a new symbol table from the program, imported_programs, and program_input.
-        let asg = Asg::new(self.context, &core_ast, &mut leo_imports::ImportParser::default())?;
+        let mut asg = Asg::new(
+            self.context,
+            &core_ast,
+            &mut leo_imports::ImportParser::default(),
+            &mut self.diagnostics,
+        )
+        .map_err(|mut e| {
+            e.set_path(&self.main_file_path);
+
+            e
+        })?;
 
         tracing::debug!("ASG generation complete");
 
+        let mut passes = default_passes();
+        for (name, enabled) in self.options.passes.iter() {
+            match passes.kind(name) {
+                None => return Err(CompilerError::UnknownPass(name.clone())),
+                // Later stages expect the asg in the form lowering passes leave it in.
+                Some(PassKind::Lowering) if !enabled => return Err(CompilerError::RequiredPass(name.clone())),
+                Some(_) => {
+                    passes.set_enabled(name, *enabled);
+                }
+            }
+        }
+        if !self.options.optimize {
+            passes.disable_transforms();
+        }
+        self.pass_reports = passes.run(asg.as_repr_mut()).map_err(AsgConvertError::from)?;
+        self.diagnostics.extend(self.context.warnings.borrow_mut().drain(..));
+
+        tracing::debug!("ASG passes complete");
+
+        // Fail if any warning was escalated to an error.
+        self.diagnostics.set_path(&self.main_file_path);
+        if self.diagnostics.num_denied() > 0 {
+            return Err(CompilerError::WarningsDenied(self.diagnostics.clone()));
+        }
+
         // Store the ASG.
         self.asg = Some(asg);
 
@@ -229,10 +292,53 @@ impl<'a, F: PrimeField, G: GroupType<F>> Compiler<'a, F, G> {
     pub fn compile_constraints<CS: ConstraintSystem<F>>(&self, cs: &mut CS) -> Result<OutputBytes, CompilerError> {
         generate_constraints::<F, G, CS>(cs, &self.asg.as_ref().unwrap(), &self.program_input).map_err(|mut error| {
             error.set_path(&self.main_file_path);
+            if let Some(input_path) = &self.input_path {
+                error.set_input_path(input_path);
+            }
             error
         })
     }
 
+    ///
+    /// Synthesizes the circuit from the program lowered to the ir with program input.
+    ///
+    pub fn compile_ir_constraints<CS: ConstraintSystem<F>>(&self, cs: &mut CS) -> Result<OutputBytes, CompilerError> {
+        let ir = self.lower_to_ir()?;
+        generate_ir_constraints::<F, G, CS>(cs, self.asg.as_ref().unwrap(), &ir, &self.program_input).map_err(
+            |mut error| {
+                error.set_path(&self.main_file_path);
+                if let Some(input_path) = &self.input_path {
+                    error.set_input_path(input_path);
+                }
+                error
+            },
+        )
+    }
+
+    ///
+    /// Synthesizes the circuit with program input and returns warnings for witnesses it leaves
+    /// under-constrained.
+    ///
+    pub fn check_soundness<E: PairingEngine<Fr = F>>(&self) -> Result<Vec<SoundnessWarning>, CompilerError> {
+        let mut cs = NamespacedSynthesizer::<E>::new();
+        cs.alloc_input(|| "one", || Ok(F::one()))
+            .expect("failed to allocate the constant one");
+        self.compile_constraints(&mut cs)?;
+
+        let asg = self.asg.as_ref().unwrap().as_repr();
+        let main = asg.functions.get("main").ok_or(CompilerError::NoMainFunction)?;
+
+        let mut warnings = check_constraints(&cs, &self.program_string);
+        warnings.extend(check_output(main));
+        warnings.sort_by_key(|warning| (warning.error.line, warning.error.start));
+
+        for warning in warnings.iter_mut() {
+            warning.set_path(&self.main_file_path);
+        }
+
+        Ok(warnings)
+    }
+
     ///
     /// Synthesizes the circuit for test functions with program input.
     ///
@@ -245,6 +351,16 @@ impl<'a, F: PrimeField, G: GroupType<F>> Compiler<'a, F, G> {
         )
     }
 
+    ///
+    /// Returns the program lowered to the ir, after the asg passes.
+    ///
+    pub fn lower_to_ir(&self) -> Result<ir::Program<'a>, CompilerError> {
+        ir::lower_program(&self.program_name, &self.asg.as_ref().unwrap().as_repr()).map_err(|mut error| {
+            error.set_path(&self.main_file_path);
+            CompilerError::from(error)
+        })
+    }
+
     ///
     /// Returns a SHA256 checksum of the program file.
     ///
@@ -283,6 +399,38 @@ impl<'a, F: PrimeField, G: GroupType<F>> Compiler<'a, F, G> {
     pub fn set_main_input(&mut self, input: MainInput) {
         self.program_input.set_main_input(input);
     }
+
+    ///
+    /// Sets the diagnostics that warnings are recorded in.
+    ///
+    /// Must be called before parsing the program for the configured warning levels to apply.
+    ///
+    pub fn set_diagnostics(&mut self, diagnostics: Diagnostics) {
+        self.diagnostics = diagnostics;
+    }
+
+    ///
+    /// Sets the optimizations run on the program.
+    ///
+    /// Must be called before parsing the program for the options to apply.
+    ///
+    pub fn set_options(&mut self, options: CompilerOptions) {
+        self.options = options;
+    }
+
+    ///
+    /// Returns the warnings recorded while parsing the program.
+    ///
+    pub fn diagnostics(&self) -> &Diagnostics {
+        &self.diagnostics
+    }
+
+    ///
+    /// Returns what each asg pass did while parsing the program.
+    ///
+    pub fn pass_reports(&self) -> &[PassReport] {
+        &self.pass_reports
+    }
 }
 
 impl<'a, F: PrimeField, G: GroupType<F>> ConstraintSynthesizer<F> for Compiler<'a, F, G> {
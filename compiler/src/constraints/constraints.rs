@@ -16,7 +16,7 @@
 
 //! Generates R1CS constraints for a compiled Leo program.
 
-use crate::{errors::CompilerError, ConstrainedProgram, GroupType, OutputBytes, OutputFile};
+use crate::{errors::CompilerError, ir, ConstrainedProgram, GroupType, OutputBytes, OutputFile};
 use leo_asg::Asg;
 use leo_ast::Input;
 use leo_input::LeoInputParser;
@@ -50,6 +50,21 @@ pub fn generate_constraints<'a, F: PrimeField, G: GroupType<F>, CS: ConstraintSy
     }
 }
 
+pub fn generate_ir_constraints<'a, F: PrimeField, G: GroupType<F>, CS: ConstraintSystem<F>>(
+    cs: &mut CS,
+    asg: &Asg<'a>,
+    ir: &ir::Program<'a>,
+    input: &Input,
+) -> Result<OutputBytes, CompilerError> {
+    let program = asg.as_repr();
+    let mut resolved_program = ConstrainedProgram::<F, G>::new(program.clone());
+
+    match program.functions.get("main") {
+        Some(function) => Ok(resolved_program.enforce_ir_main_function(cs, ir, function, input)?),
+        _ => Err(CompilerError::NoMainFunction),
+    }
+}
+
 pub fn generate_test_constraints<'a, F: PrimeField, G: GroupType<F>>(
     asg: &Asg<'a>,
     input: InputPairs,
@@ -14,9 +14,9 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::errors::{FunctionError, ImportError, OutputBytesError, OutputFileError};
+use crate::errors::{FunctionError, ImportError, IrError, OutputBytesError, OutputFileError};
 use leo_asg::AsgConvertError;
-use leo_ast::AstError;
+use leo_ast::{AstError, Diagnostic, Diagnostics, Report};
 use leo_grammar::ParserError;
 use leo_imports::ImportParserError;
 use leo_input::InputParserError;
@@ -51,6 +51,9 @@ pub enum CompilerError {
     #[error("{}", _0)]
     LocalDataVerificationError(#[from] LocalDataVerificationError),
 
+    #[error("{}", _0)]
+    IrError(#[from] IrError),
+
     #[error("`main` function not found")]
     NoMain,
 
@@ -74,6 +77,69 @@ pub enum CompilerError {
 
     #[error("{}", _0)]
     AsgConvertError(#[from] AsgConvertError),
+
+    #[error("Unknown optimization pass `{}`", _0)]
+    UnknownPass(String),
+
+    #[error("Optimization pass `{}` lowers the program and cannot be disabled", _0)]
+    RequiredPass(String),
+
+    #[error("{}\n\naborting due to {} denied warning(s)", _0, _0.num_denied())]
+    WarningsDenied(Diagnostics),
+}
+
+impl Diagnostic for CompilerError {
+    fn error_code(&self) -> &'static str {
+        match self {
+            CompilerError::AstError(error) => error.error_code(),
+            CompilerError::ImportError(error) => error.error_code(),
+            CompilerError::ImportParserError(error) => error.error_code(),
+            CompilerError::InputParserError(error) => error.error_code(),
+            CompilerError::InvalidTestContext(_) => "E0600",
+            CompilerError::FunctionError(error) => error.error_code(),
+            CompilerError::FileReadError(_) => "E0601",
+            CompilerError::LocalDataVerificationError(_) => "E0602",
+            CompilerError::IrError(error) => error.error_code(),
+            CompilerError::NoMain => "E0603",
+            CompilerError::NoMainFunction => "E0604",
+            CompilerError::NoTestInput => "E0605",
+            CompilerError::OutputError(error) => error.error_code(),
+            CompilerError::OutputStringError(error) => error.error_code(),
+            CompilerError::ParserError(error) => error.error_code(),
+            CompilerError::SerdeError(_) => "E0606",
+            CompilerError::AsgConvertError(error) => error.error_code(),
+            CompilerError::WarningsDenied(_) => "E0607",
+            CompilerError::UnknownPass(_) => "E0608",
+            CompilerError::RequiredPass(_) => "E0609",
+        }
+    }
+
+    fn reports(&self) -> Vec<Report> {
+        match self {
+            CompilerError::AstError(error) => error.reports(),
+            CompilerError::ImportError(error) => error.reports(),
+            CompilerError::ImportParserError(error) => error.reports(),
+            CompilerError::InputParserError(error) => error.reports(),
+            CompilerError::FunctionError(error) => error.reports(),
+            CompilerError::IrError(error) => error.reports(),
+            CompilerError::OutputError(error) => error.reports(),
+            CompilerError::OutputStringError(error) => error.reports(),
+            CompilerError::ParserError(error) => error.reports(),
+            CompilerError::AsgConvertError(error) => error.reports(),
+            CompilerError::WarningsDenied(diagnostics) => {
+                let mut reports = diagnostics
+                    .warnings()
+                    .iter()
+                    .map(Report::from_warning)
+                    .collect::<Vec<_>>();
+                let message = format!("aborting due to {} denied warning(s)", diagnostics.num_denied());
+
+                reports.push(Report::new(self.error_code(), message));
+                reports
+            }
+            _ => vec![Report::new(self.error_code(), self.to_string())],
+        }
+    }
 }
 
 impl CompilerError {
@@ -81,8 +147,16 @@ impl CompilerError {
         match self {
             CompilerError::InputParserError(error) => error.set_path(path),
             CompilerError::FunctionError(error) => error.set_path(path),
+            CompilerError::IrError(error) => error.set_path(path),
             CompilerError::OutputStringError(error) => error.set_path(path),
             _ => {}
         }
     }
+
+    /// Sets the path of errors pointing at the input file rather than the program.
+    pub fn set_input_path(&mut self, path: &Path) {
+        if let CompilerError::FunctionError(error) = self {
+            error.set_input_path(path);
+        }
+    }
 }
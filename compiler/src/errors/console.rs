@@ -15,7 +15,7 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::errors::ExpressionError;
-use leo_ast::{Error as FormattedError, Span};
+use leo_ast::{Diagnostic, Error as FormattedError, Report, Span};
 
 use std::path::Path;
 
@@ -28,6 +28,22 @@ pub enum ConsoleError {
     Expression(#[from] ExpressionError),
 }
 
+impl Diagnostic for ConsoleError {
+    fn error_code(&self) -> &'static str {
+        match self {
+            ConsoleError::Error(error) => error.code.unwrap_or("E1100"),
+            ConsoleError::Expression(error) => error.error_code(),
+        }
+    }
+
+    fn reports(&self) -> Vec<Report> {
+        match self {
+            ConsoleError::Error(error) => vec![Report::from_error(self.error_code(), error)],
+            ConsoleError::Expression(error) => error.reports(),
+        }
+    }
+}
+
 impl ConsoleError {
     pub fn set_path(&mut self, path: &Path) {
         match self {
@@ -36,8 +52,8 @@ impl ConsoleError {
         }
     }
 
-    fn new_from_span(message: String, span: Span) -> Self {
-        ConsoleError::Error(FormattedError::new_from_span(message, span))
+    fn new_from_span(code: &'static str, message: String, span: Span) -> Self {
+        ConsoleError::Error(FormattedError::new_from_span(message, span).with_code(code))
     }
 
     pub fn length(containers: usize, parameters: usize, span: Span) -> Self {
@@ -46,25 +62,25 @@ impl ConsoleError {
             containers, parameters
         );
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E1101", message, span)
     }
 
     pub fn assertion_depends_on_input(span: Span) -> Self {
         let message =
             "console.assert() failed to evaluate. This error is caused by empty input file values".to_string();
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E1102", message, span)
     }
 
     pub fn assertion_failed(expression: String, span: Span) -> Self {
         let message = format!("Assertion `{}` failed", expression);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E1103", message, span)
     }
 
     pub fn assertion_must_be_boolean(expression: String, span: Span) -> Self {
         let message = format!("Assertion expression `{}` must evaluate to a boolean value", expression);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E1104", message, span)
     }
 }
@@ -14,8 +14,17 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::errors::{AddressError, BooleanError, FieldError, FunctionError, GroupError, IntegerError, ValueError};
-use leo_ast::{ArrayDimensions, Error as FormattedError, Identifier, PositiveNumber, Span};
+use crate::errors::{
+    AddressError,
+    BooleanError,
+    FieldError,
+    FixedError,
+    FunctionError,
+    GroupError,
+    IntegerError,
+    ValueError,
+};
+use leo_ast::{ArrayDimensions, Diagnostic, Error as FormattedError, Identifier, PositiveNumber, Report, Span};
 
 use snarkvm_errors::gadgets::SynthesisError;
 use std::path::Path;
@@ -34,6 +43,9 @@ pub enum ExpressionError {
     #[error("{}", _0)]
     FieldError(#[from] FieldError),
 
+    #[error("{}", _0)]
+    FixedError(#[from] FixedError),
+
     #[error("{}", _0)]
     FunctionError(#[from] Box<FunctionError>),
 
@@ -47,6 +59,36 @@ pub enum ExpressionError {
     ValueError(#[from] ValueError),
 }
 
+impl Diagnostic for ExpressionError {
+    fn error_code(&self) -> &'static str {
+        match self {
+            ExpressionError::AddressError(error) => error.error_code(),
+            ExpressionError::BooleanError(error) => error.error_code(),
+            ExpressionError::Error(error) => error.code.unwrap_or("E0800"),
+            ExpressionError::FieldError(error) => error.error_code(),
+            ExpressionError::FixedError(error) => error.error_code(),
+            ExpressionError::FunctionError(error) => error.error_code(),
+            ExpressionError::GroupError(error) => error.error_code(),
+            ExpressionError::IntegerError(error) => error.error_code(),
+            ExpressionError::ValueError(error) => error.error_code(),
+        }
+    }
+
+    fn reports(&self) -> Vec<Report> {
+        match self {
+            ExpressionError::AddressError(error) => error.reports(),
+            ExpressionError::BooleanError(error) => error.reports(),
+            ExpressionError::Error(error) => vec![Report::from_error(self.error_code(), error)],
+            ExpressionError::FieldError(error) => error.reports(),
+            ExpressionError::FixedError(error) => error.reports(),
+            ExpressionError::FunctionError(error) => error.reports(),
+            ExpressionError::GroupError(error) => error.reports(),
+            ExpressionError::IntegerError(error) => error.reports(),
+            ExpressionError::ValueError(error) => error.reports(),
+        }
+    }
+}
+
 impl ExpressionError {
     pub fn set_path(&mut self, path: &Path) {
         match self {
@@ -54,6 +96,7 @@ impl ExpressionError {
             ExpressionError::BooleanError(error) => error.set_path(path),
             ExpressionError::Error(error) => error.set_path(path),
             ExpressionError::FieldError(error) => error.set_path(path),
+            ExpressionError::FixedError(error) => error.set_path(path),
             ExpressionError::FunctionError(error) => error.set_path(path),
             ExpressionError::GroupError(error) => error.set_path(path),
             ExpressionError::IntegerError(error) => error.set_path(path),
@@ -61,8 +104,8 @@ impl ExpressionError {
         }
     }
 
-    fn new_from_span(message: String, span: Span) -> Self {
-        ExpressionError::Error(FormattedError::new_from_span(message, span))
+    fn new_from_span(code: &'static str, message: String, span: Span) -> Self {
+        ExpressionError::Error(FormattedError::new_from_span(message, span).with_code(code))
     }
 
     pub fn cannot_enforce(operation: String, error: SynthesisError, span: Span) -> Self {
@@ -71,37 +114,37 @@ impl ExpressionError {
             operation, error,
         );
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0801", message, span)
     }
 
     pub fn cannot_evaluate(operation: String, span: Span) -> Self {
         let message = format!("Mismatched types found for operation `{}`", operation);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0802", message, span)
     }
 
     pub fn conditional_boolean(actual: String, span: Span) -> Self {
         let message = format!("if, else conditional must resolve to a boolean, found `{}`", actual);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0803", message, span)
     }
 
     pub fn expected_circuit_member(expected: String, span: Span) -> Self {
         let message = format!("expected circuit member `{}`, not found", expected);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0804", message, span)
     }
 
     pub fn incompatible_types(operation: String, span: Span) -> Self {
         let message = format!("no implementation for `{}`", operation);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0805", message, span)
     }
 
     pub fn index_out_of_bounds(index: usize, span: Span) -> Self {
         let message = format!("cannot access index {} of tuple out of bounds", index);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0806", message, span)
     }
 
     pub fn invalid_dimensions(expected: &ArrayDimensions, actual: &ArrayDimensions, span: Span) -> Self {
@@ -110,7 +153,7 @@ impl ExpressionError {
             expected, actual
         );
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0807", message, span)
     }
 
     pub fn invalid_first_dimension(expected: &PositiveNumber, actual: &PositiveNumber, span: Span) -> Self {
@@ -119,61 +162,61 @@ impl ExpressionError {
             expected, actual
         );
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0808", message, span)
     }
 
     pub fn invalid_index(actual: String, span: &Span) -> Self {
         let message = format!("index must resolve to an integer, found `{}`", actual);
 
-        Self::new_from_span(message, span.to_owned())
+        Self::new_from_span("E0809", message, span.to_owned())
     }
 
     pub fn invalid_length(expected: usize, actual: usize, span: Span) -> Self {
         let message = format!("expected array length {}, found one with length {}", expected, actual);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0810", message, span)
     }
 
     pub fn invalid_spread(actual: String, span: Span) -> Self {
         let message = format!("spread should contain an array, found `{}`", actual);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0811", message, span)
     }
 
     pub fn invalid_member_access(member: String, span: Span) -> Self {
         let message = format!("non-static member `{}` must be accessed using `.` syntax", member);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0812", message, span)
     }
 
     pub fn invalid_static_access(member: String, span: Span) -> Self {
         let message = format!("static member `{}` must be accessed using `::` syntax", member);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0813", message, span)
     }
 
     pub fn function_no_return(function: String, span: Span) -> Self {
         let message = format!("inline function call to `{}` did not return", function);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0814", message, span)
     }
 
     pub fn self_keyword(span: Span) -> Self {
         let message = "cannot call keyword `Self` outside of a circuit function".to_string();
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0815", message, span)
     }
 
     pub fn undefined_array(actual: String, span: Span) -> Self {
         let message = format!("array `{}` must be declared before it is used in an expression", actual);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0816", message, span)
     }
 
     pub fn undefined_tuple(actual: String, span: Span) -> Self {
         let message = format!("tuple `{}` must be declared before it is used in an expression", actual);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0817", message, span)
     }
 
     pub fn undefined_circuit(actual: String, span: Span) -> Self {
@@ -182,13 +225,13 @@ impl ExpressionError {
             actual
         );
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0818", message, span)
     }
 
     pub fn undefined_first_dimension(span: Span) -> Self {
         let message = "the first dimension of the array must be a number".to_string();
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0819", message, span)
     }
 
     pub fn undefined_function(function: String, span: Span) -> Self {
@@ -197,36 +240,36 @@ impl ExpressionError {
             function
         );
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0820", message, span)
     }
 
     pub fn undefined_identifier(identifier: Identifier) -> Self {
         let message = format!("Cannot find value `{}` in this scope", identifier.name);
 
-        Self::new_from_span(message, identifier.span)
+        Self::new_from_span("E0821", message, identifier.span)
     }
 
     pub fn undefined_member_access(circuit: String, member: String, span: Span) -> Self {
         let message = format!("Circuit `{}` has no member `{}`", circuit, member);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0822", message, span)
     }
 
     pub fn undefined_static_access(circuit: String, member: String, span: Span) -> Self {
         let message = format!("Circuit `{}` has no static member `{}`", circuit, member);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0823", message, span)
     }
 
     pub fn unexpected_array(expected: String, span: Span) -> Self {
         let message = format!("expected type `{}`, found array with elements", expected);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0824", message, span)
     }
 
     pub fn unexpected_tuple(expected: String, actual: String, span: Span) -> Self {
         let message = format!("expected type `{}`, found tuple with values `{}`", expected, actual);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0825", message, span)
     }
 }
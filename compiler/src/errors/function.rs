@@ -19,6 +19,7 @@ use crate::errors::{
     BooleanError,
     ExpressionError,
     FieldError,
+    FixedError,
     GroupError,
     IntegerError,
     OutputBytesError,
@@ -26,7 +27,7 @@ use crate::errors::{
     ValueError,
 };
 use leo_asg::AsgConvertError;
-use leo_ast::{Error as FormattedError, Span};
+use leo_ast::{Diagnostic, Error as FormattedError, Report, Span};
 
 use std::path::Path;
 
@@ -47,6 +48,9 @@ pub enum FunctionError {
     #[error("{}", _0)]
     FieldError(#[from] FieldError),
 
+    #[error("{}", _0)]
+    FixedError(#[from] FixedError),
+
     #[error("{}", _0)]
     GroupError(#[from] GroupError),
 
@@ -64,6 +68,47 @@ pub enum FunctionError {
 
     #[error("{}", _0)]
     ImportASGError(#[from] AsgConvertError),
+
+    #[error("{}", _0)]
+    InputError(FormattedError),
+}
+
+impl Diagnostic for FunctionError {
+    fn error_code(&self) -> &'static str {
+        match self {
+            FunctionError::AddressError(error) => error.error_code(),
+            FunctionError::BooleanError(error) => error.error_code(),
+            FunctionError::ExpressionError(error) => error.error_code(),
+            FunctionError::Error(error) => error.code.unwrap_or("E0700"),
+            FunctionError::FieldError(error) => error.error_code(),
+            FunctionError::FixedError(error) => error.error_code(),
+            FunctionError::GroupError(error) => error.error_code(),
+            FunctionError::IntegerError(error) => error.error_code(),
+            FunctionError::OutputStringError(error) => error.error_code(),
+            FunctionError::StatementError(error) => error.error_code(),
+            FunctionError::ValueError(error) => error.error_code(),
+            FunctionError::ImportASGError(error) => error.error_code(),
+            FunctionError::InputError(error) => error.code.unwrap_or("E0701"),
+        }
+    }
+
+    fn reports(&self) -> Vec<Report> {
+        match self {
+            FunctionError::AddressError(error) => error.reports(),
+            FunctionError::BooleanError(error) => error.reports(),
+            FunctionError::ExpressionError(error) => error.reports(),
+            FunctionError::Error(error) => vec![Report::from_error(self.error_code(), error)],
+            FunctionError::FieldError(error) => error.reports(),
+            FunctionError::FixedError(error) => error.reports(),
+            FunctionError::GroupError(error) => error.reports(),
+            FunctionError::IntegerError(error) => error.reports(),
+            FunctionError::OutputStringError(error) => error.reports(),
+            FunctionError::StatementError(error) => error.reports(),
+            FunctionError::ValueError(error) => error.reports(),
+            FunctionError::ImportASGError(error) => error.reports(),
+            FunctionError::InputError(error) => vec![Report::from_error(self.error_code(), error)],
+        }
+    }
 }
 
 impl FunctionError {
@@ -74,23 +119,36 @@ impl FunctionError {
             FunctionError::ExpressionError(error) => error.set_path(path),
             FunctionError::Error(error) => error.set_path(path),
             FunctionError::FieldError(error) => error.set_path(path),
+            FunctionError::FixedError(error) => error.set_path(path),
             FunctionError::GroupError(error) => error.set_path(path),
             FunctionError::IntegerError(error) => error.set_path(path),
             FunctionError::OutputStringError(error) => error.set_path(path),
             FunctionError::StatementError(error) => error.set_path(path),
             FunctionError::ValueError(error) => error.set_path(path),
             FunctionError::ImportASGError(_error) => (),
+            FunctionError::InputError(_error) => (),
         }
     }
 
-    fn new_from_span(message: String, span: Span) -> Self {
-        FunctionError::Error(FormattedError::new_from_span(message, span))
+    /// Sets the path of errors pointing at the input file rather than the program.
+    pub fn set_input_path(&mut self, path: &Path) {
+        if let FunctionError::InputError(error) = self {
+            error.set_path(path);
+        }
+    }
+
+    fn new_from_span(code: &'static str, message: String, span: Span) -> Self {
+        FunctionError::Error(FormattedError::new_from_span(message, span).with_code(code))
+    }
+
+    fn new_from_input_span(code: &'static str, message: String, span: Span) -> Self {
+        FunctionError::InputError(FormattedError::new_from_span(message, span).with_code(code))
     }
 
     pub fn invalid_array(actual: String, span: Span) -> Self {
         let message = format!("Expected function input array, found `{}`", actual);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0702", message, span)
     }
 
     pub fn invalid_input_array_dimensions(expected: usize, actual: usize, span: Span) -> Self {
@@ -99,30 +157,63 @@ impl FunctionError {
             expected, actual
         );
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0703", message, span)
     }
 
     pub fn invalid_tuple(actual: String, span: Span) -> Self {
         let message = format!("Expected function input tuple, found `{}`", actual);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0704", message, span)
+    }
+
+    pub fn invalid_circuit(actual: String, span: Span) -> Self {
+        let message = format!("Expected function input circuit, found `{}`", actual);
+
+        Self::new_from_span("E0705", message, span)
+    }
+
+    pub fn mismatched_input_circuit(expected: String, actual: String, span: Span) -> Self {
+        let message = format!("Expected input circuit `{}`, found circuit `{}`", expected, actual);
+
+        Self::new_from_input_span("E0706", message, span)
+    }
+
+    pub fn missing_input_circuit_member(circuit: String, member: String, span: Span) -> Self {
+        let message = format!("Input circuit `{}` is missing member `{}`", circuit, member);
+
+        Self::new_from_input_span("E0707", message, span)
+    }
+
+    pub fn undefined_input_circuit_member(circuit: String, member: String, span: Span) -> Self {
+        let message = format!("Circuit `{}` has no member `{}`", circuit, member);
+
+        Self::new_from_input_span("E0708", message, span)
+    }
+
+    pub fn invalid_input_circuit_member(member: String, expected: String, actual: String, span: Span) -> Self {
+        let message = format!(
+            "Expected input circuit member `{}` of type `{}`, found `{}`",
+            member, expected, actual
+        );
+
+        Self::new_from_input_span("E0709", message, span)
     }
 
     pub fn return_arguments_length(expected: usize, actual: usize, span: Span) -> Self {
         let message = format!("function expected {} returns, found {} returns", expected, actual);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0710", message, span)
     }
 
     pub fn return_argument_type(expected: String, actual: String, span: Span) -> Self {
         let message = format!("Expected function return type `{}`, found `{}`", expected, actual);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0711", message, span)
     }
 
     pub fn input_not_found(expected: String, span: Span) -> Self {
         let message = format!("main function input {} not found", expected);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0712", message, span)
     }
 }
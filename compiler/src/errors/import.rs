@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use leo_ast::{Error as FormattedError, Identifier, ImportSymbol, Span};
+use leo_ast::{Diagnostic, Error as FormattedError, Identifier, ImportSymbol, Report, Span};
 
 #[derive(Debug, Error)]
 pub enum ImportError {
@@ -22,9 +22,23 @@ pub enum ImportError {
     Error(#[from] FormattedError),
 }
 
+impl Diagnostic for ImportError {
+    fn error_code(&self) -> &'static str {
+        match self {
+            ImportError::Error(error) => error.code.unwrap_or("E1200"),
+        }
+    }
+
+    fn reports(&self) -> Vec<Report> {
+        match self {
+            ImportError::Error(error) => vec![Report::from_error(self.error_code(), error)],
+        }
+    }
+}
+
 impl ImportError {
-    fn new_from_span(message: String, span: Span) -> Self {
-        ImportError::Error(FormattedError::new_from_span(message, span))
+    fn new_from_span(code: &'static str, message: String, span: Span) -> Self {
+        ImportError::Error(FormattedError::new_from_span(message, span).with_code(code))
     }
 
     pub fn unknown_package(identifier: Identifier) -> Self {
@@ -33,7 +47,7 @@ impl ImportError {
             identifier.name
         );
 
-        Self::new_from_span(message, identifier.span)
+        Self::new_from_span("E1201", message, identifier.span)
     }
 
     pub fn unknown_symbol(symbol: ImportSymbol, file: String) -> Self {
@@ -0,0 +1,68 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::{Diagnostic, Error as FormattedError, Report, Span};
+
+use std::path::Path;
+
+#[derive(Debug, Error)]
+pub enum IrError {
+    #[error("{}", _0)]
+    Error(#[from] FormattedError),
+}
+
+impl Diagnostic for IrError {
+    fn error_code(&self) -> &'static str {
+        match self {
+            IrError::Error(error) => error.code.unwrap_or("E2200"),
+        }
+    }
+
+    fn reports(&self) -> Vec<Report> {
+        match self {
+            IrError::Error(error) => vec![Report::from_error(self.error_code(), error)],
+        }
+    }
+}
+
+impl IrError {
+    pub fn set_path(&mut self, path: &Path) {
+        match self {
+            IrError::Error(error) => error.set_path(path),
+        }
+    }
+
+    fn new_from_span(code: &'static str, message: String, span: Span) -> Self {
+        IrError::Error(FormattedError::new_from_span(message, span).with_code(code))
+    }
+
+    pub fn loop_not_unrolled(span: Span) -> Self {
+        let message =
+            "loops must be unrolled before lowering to the ir, but the bounds of this loop are not known at compile time"
+                .to_string();
+
+        Self::new_from_span("E2201", message, span)
+    }
+
+    pub fn mut_self_call(function: &str, span: Span) -> Self {
+        let message = format!(
+            "calls to `{}` cannot be lowered to the ir, as functions taking `mut self` are not supported yet",
+            function
+        );
+
+        Self::new_from_span("E2202", message, span)
+    }
+}
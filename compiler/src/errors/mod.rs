@@ -26,6 +26,9 @@ pub use self::function::*;
 pub mod import;
 pub use self::import::*;
 
+pub mod ir;
+pub use self::ir::*;
+
 pub mod console;
 pub use self::console::*;
 
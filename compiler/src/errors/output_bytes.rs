@@ -16,7 +16,7 @@
 
 use crate::errors::ValueError;
 use leo_asg::{AsgConvertError, Type};
-use leo_ast::{Error as FormattedError, Span};
+use leo_ast::{Diagnostic, Error as FormattedError, Report, Span};
 
 use std::path::Path;
 
@@ -32,6 +32,24 @@ pub enum OutputBytesError {
     AsgConvertError(#[from] AsgConvertError),
 }
 
+impl Diagnostic for OutputBytesError {
+    fn error_code(&self) -> &'static str {
+        match self {
+            OutputBytesError::Error(error) => error.code.unwrap_or("E1300"),
+            OutputBytesError::ValueError(error) => error.error_code(),
+            OutputBytesError::AsgConvertError(error) => error.error_code(),
+        }
+    }
+
+    fn reports(&self) -> Vec<Report> {
+        match self {
+            OutputBytesError::Error(error) => vec![Report::from_error(self.error_code(), error)],
+            OutputBytesError::ValueError(error) => error.reports(),
+            OutputBytesError::AsgConvertError(error) => error.reports(),
+        }
+    }
+}
+
 impl OutputBytesError {
     pub fn set_path(&mut self, path: &Path) {
         match self {
@@ -41,14 +59,14 @@ impl OutputBytesError {
         }
     }
 
-    fn new_from_span(message: String, span: Span) -> Self {
-        OutputBytesError::Error(FormattedError::new_from_span(message, span))
+    fn new_from_span(code: &'static str, message: String, span: Span) -> Self {
+        OutputBytesError::Error(FormattedError::new_from_span(message, span).with_code(code))
     }
 
     pub fn not_enough_registers(span: Span) -> Self {
         let message = "number of input registers must be greater than or equal to output registers".to_string();
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E1301", message, span)
     }
 
     pub fn mismatched_output_types(left: &Type, right: &Type, span: Span) -> Self {
@@ -57,6 +75,6 @@ impl OutputBytesError {
             left, right
         );
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E1302", message, span)
     }
 }
@@ -14,6 +14,8 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
+use leo_ast::Diagnostic;
+
 use std::{io, path::PathBuf};
 
 #[derive(Debug, Error)]
@@ -34,6 +36,18 @@ pub enum OutputFileError {
     Writing(io::Error),
 }
 
+impl Diagnostic for OutputFileError {
+    fn error_code(&self) -> &'static str {
+        match self {
+            OutputFileError::Crate(..) => "E1400",
+            OutputFileError::Creating(_) => "E1401",
+            OutputFileError::FileReadError(_) => "E1402",
+            OutputFileError::FileRemovalError(_) => "E1403",
+            OutputFileError::Writing(_) => "E1404",
+        }
+    }
+}
+
 impl From<std::io::Error> for OutputFileError {
     fn from(error: std::io::Error) -> Self {
         OutputFileError::Crate("std::io", error.to_string())
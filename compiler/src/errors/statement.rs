@@ -16,7 +16,7 @@
 
 use crate::errors::{AddressError, BooleanError, ConsoleError, ExpressionError, IntegerError, ValueError};
 use leo_asg::Type;
-use leo_ast::{Error as FormattedError, Span};
+use leo_ast::{Diagnostic, Error as FormattedError, Report, Span};
 
 use std::path::Path;
 
@@ -44,6 +44,32 @@ pub enum StatementError {
     ValueError(#[from] ValueError),
 }
 
+impl Diagnostic for StatementError {
+    fn error_code(&self) -> &'static str {
+        match self {
+            StatementError::AddressError(error) => error.error_code(),
+            StatementError::BooleanError(error) => error.error_code(),
+            StatementError::Error(error) => error.code.unwrap_or("E0900"),
+            StatementError::ExpressionError(error) => error.error_code(),
+            StatementError::IntegerError(error) => error.error_code(),
+            StatementError::MacroError(error) => error.error_code(),
+            StatementError::ValueError(error) => error.error_code(),
+        }
+    }
+
+    fn reports(&self) -> Vec<Report> {
+        match self {
+            StatementError::AddressError(error) => error.reports(),
+            StatementError::BooleanError(error) => error.reports(),
+            StatementError::Error(error) => vec![Report::from_error(self.error_code(), error)],
+            StatementError::ExpressionError(error) => error.reports(),
+            StatementError::IntegerError(error) => error.reports(),
+            StatementError::MacroError(error) => error.reports(),
+            StatementError::ValueError(error) => error.reports(),
+        }
+    }
+}
+
 impl StatementError {
     pub fn set_path(&mut self, path: &Path) {
         match self {
@@ -57,32 +83,32 @@ impl StatementError {
         }
     }
 
-    fn new_from_span(message: String, span: Span) -> Self {
-        StatementError::Error(FormattedError::new_from_span(message, span))
+    fn new_from_span(code: &'static str, message: String, span: Span) -> Self {
+        StatementError::Error(FormattedError::new_from_span(message, span).with_code(code))
     }
 
     pub fn arguments_type(expected: &Type, actual: &Type, span: Span) -> Self {
         let message = format!("expected return argument type `{}`, found type `{}`", expected, actual);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0901", message, span)
     }
 
     pub fn array_assign_index(span: Span) -> Self {
         let message = "Cannot assign single index to array of values".to_string();
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0902", message, span)
     }
 
     pub fn array_assign_interior_index(span: Span) -> Self {
         let message = "Cannot assign single index to interior of array of values".to_string();
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0903", message, span)
     }
 
     pub fn array_assign_range(span: Span) -> Self {
         let message = "Cannot assign range of array values to single value".to_string();
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0904", message, span)
     }
 
     pub fn array_assign_index_bounds(index: usize, length: usize, span: Span) -> Self {
@@ -91,7 +117,7 @@ impl StatementError {
             index, length
         );
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0905", message, span)
     }
 
     pub fn array_assign_range_order(start: usize, stop: usize, length: usize, span: Span) -> Self {
@@ -100,31 +126,31 @@ impl StatementError {
             start, stop, length
         );
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0906", message, span)
     }
 
     pub fn conditional_boolean(actual: String, span: Span) -> Self {
         let message = format!("If, else conditional must resolve to a boolean, found `{}`", actual);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0907", message, span)
     }
 
     pub fn immutable_assign(name: String, span: Span) -> Self {
         let message = format!("Cannot assign to immutable variable `{}`", name);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0908", message, span)
     }
 
     pub fn immutable_circuit_function(name: String, span: Span) -> Self {
         let message = format!("Cannot mutate circuit function, `{}`", name);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0909", message, span)
     }
 
     pub fn immutable_circuit_variable(name: String, span: Span) -> Self {
         let message = format!("Circuit member variable `{}` is immutable", name);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0910", message, span)
     }
 
     pub fn indicator_calculation(name: String, span: Span) -> Self {
@@ -133,7 +159,7 @@ impl StatementError {
             name
         );
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0911", message, span)
     }
 
     pub fn invalid_number_of_definitions(expected: usize, actual: usize, span: Span) -> Self {
@@ -142,7 +168,7 @@ impl StatementError {
             expected, actual
         );
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0912", message, span)
     }
 
     pub fn invalid_number_of_returns(expected: usize, actual: usize, span: Span) -> Self {
@@ -151,20 +177,20 @@ impl StatementError {
             expected, actual
         );
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0913", message, span)
     }
 
     pub fn multiple_definition(value: String, span: Span) -> Self {
         let message = format!("cannot assign multiple variables to a single value: {}", value,);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0914", message, span)
     }
 
     pub fn multiple_returns(span: Span) -> Self {
         let message = "This function returns multiple times and produces unreachable circuits with undefined behavior."
             .to_string();
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0915", message, span)
     }
 
     pub fn no_returns(expected: &Type, span: Span) -> Self {
@@ -173,7 +199,7 @@ impl StatementError {
             expected
         );
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0916", message, span)
     }
 
     pub fn select_fail(first: String, second: String, span: Span) -> Self {
@@ -182,13 +208,13 @@ impl StatementError {
             first, second
         );
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0917", message, span)
     }
 
     pub fn tuple_assign_index(span: Span) -> Self {
         let message = "Cannot assign single index to tuple of values".to_string();
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0918", message, span)
     }
 
     pub fn tuple_assign_index_bounds(index: usize, length: usize, span: Span) -> Self {
@@ -197,36 +223,36 @@ impl StatementError {
             index, length
         );
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0919", message, span)
     }
 
     pub fn tuple_type(type_: String, span: Span) -> Self {
         let message = format!("Expected tuple type, found type `{}`", type_);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0920", message, span)
     }
 
     pub fn unassigned(name: String, span: Span) -> Self {
         let message = format!("Expected assignment of return values for expression `{}`", name);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0921", message, span)
     }
 
     pub fn undefined_variable(name: String, span: Span) -> Self {
         let message = format!("Attempted to assign to unknown variable `{}`", name);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0922", message, span)
     }
 
     pub fn undefined_circuit(name: String, span: Span) -> Self {
         let message = format!("Attempted to assign to unknown circuit `{}`", name);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0923", message, span)
     }
 
     pub fn undefined_circuit_variable(name: String, span: Span) -> Self {
         let message = format!("Attempted to assign to unknown circuit member variable `{}`", name);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0924", message, span)
     }
 }
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use leo_ast::{Error as FormattedError, Span};
+use leo_ast::{Diagnostic, Error as FormattedError, Report, Span};
 
 use snarkvm_errors::{gadgets::SynthesisError, objects::account::AccountError};
 use std::path::Path;
@@ -25,6 +25,20 @@ pub enum AddressError {
     Error(#[from] FormattedError),
 }
 
+impl Diagnostic for AddressError {
+    fn error_code(&self) -> &'static str {
+        match self {
+            AddressError::Error(error) => error.code.unwrap_or("E1500"),
+        }
+    }
+
+    fn reports(&self) -> Vec<Report> {
+        match self {
+            AddressError::Error(error) => vec![Report::from_error(self.error_code(), error)],
+        }
+    }
+}
+
 impl AddressError {
     pub fn set_path(&mut self, path: &Path) {
         match self {
@@ -32,14 +46,14 @@ impl AddressError {
         }
     }
 
-    fn new_from_span(message: String, span: Span) -> Self {
-        AddressError::Error(FormattedError::new_from_span(message, span))
+    fn new_from_span(code: &'static str, message: String, span: Span) -> Self {
+        AddressError::Error(FormattedError::new_from_span(message, span).with_code(code))
     }
 
     pub fn account_error(error: AccountError, span: Span) -> Self {
         let message = format!("account creation failed due to `{}`", error);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E1501", message, span)
     }
 
     pub fn cannot_enforce(operation: String, error: SynthesisError, span: Span) -> Self {
@@ -48,24 +62,24 @@ impl AddressError {
             operation, error,
         );
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E1502", message, span)
     }
 
     pub fn cannot_evaluate(operation: String, span: Span) -> Self {
         let message = format!("no implementation found for `{}`", operation);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E1503", message, span)
     }
 
     pub fn invalid_address(actual: String, span: Span) -> Self {
         let message = format!("expected address input type, found `{}`", actual);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E1504", message, span)
     }
 
     pub fn missing_address(span: Span) -> Self {
         let message = "expected address input not found".to_string();
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E1505", message, span)
     }
 }
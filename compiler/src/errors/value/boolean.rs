@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use leo_ast::{Error as FormattedError, Span};
+use leo_ast::{Diagnostic, Error as FormattedError, Report, Span};
 
 use snarkvm_errors::gadgets::SynthesisError;
 use std::path::Path;
@@ -25,6 +25,20 @@ pub enum BooleanError {
     Error(#[from] FormattedError),
 }
 
+impl Diagnostic for BooleanError {
+    fn error_code(&self) -> &'static str {
+        match self {
+            BooleanError::Error(error) => error.code.unwrap_or("E1600"),
+        }
+    }
+
+    fn reports(&self) -> Vec<Report> {
+        match self {
+            BooleanError::Error(error) => vec![Report::from_error(self.error_code(), error)],
+        }
+    }
+}
+
 impl BooleanError {
     pub fn set_path(&mut self, path: &Path) {
         match self {
@@ -32,8 +46,8 @@ impl BooleanError {
         }
     }
 
-    fn new_from_span(message: String, span: Span) -> Self {
-        BooleanError::Error(FormattedError::new_from_span(message, span))
+    fn new_from_span(code: &'static str, message: String, span: Span) -> Self {
+        BooleanError::Error(FormattedError::new_from_span(message, span).with_code(code))
     }
 
     pub fn cannot_enforce(operation: String, error: SynthesisError, span: Span) -> Self {
@@ -42,24 +56,24 @@ impl BooleanError {
             operation, error,
         );
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E1601", message, span)
     }
 
     pub fn cannot_evaluate(operation: String, span: Span) -> Self {
         let message = format!("no implementation found for `{}`", operation);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E1602", message, span)
     }
 
     pub fn invalid_boolean(actual: String, span: Span) -> Self {
         let message = format!("expected boolean input type, found `{}`", actual);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E1603", message, span)
     }
 
     pub fn missing_boolean(expected: String, span: Span) -> Self {
         let message = format!("expected boolean input `{}` not found", expected);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E1604", message, span)
     }
 }
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use leo_ast::{Error as FormattedError, Span};
+use leo_ast::{Diagnostic, Error as FormattedError, Report, Span};
 
 use snarkvm_errors::gadgets::SynthesisError;
 use std::path::Path;
@@ -25,6 +25,20 @@ pub enum FieldError {
     Error(#[from] FormattedError),
 }
 
+impl Diagnostic for FieldError {
+    fn error_code(&self) -> &'static str {
+        match self {
+            FieldError::Error(error) => error.code.unwrap_or("E1700"),
+        }
+    }
+
+    fn reports(&self) -> Vec<Report> {
+        match self {
+            FieldError::Error(error) => vec![Report::from_error(self.error_code(), error)],
+        }
+    }
+}
+
 impl FieldError {
     pub fn set_path(&mut self, path: &Path) {
         match self {
@@ -32,14 +46,14 @@ impl FieldError {
         }
     }
 
-    fn new_from_span(message: String, span: Span) -> Self {
-        FieldError::Error(FormattedError::new_from_span(message, span))
+    fn new_from_span(code: &'static str, message: String, span: Span) -> Self {
+        FieldError::Error(FormattedError::new_from_span(message, span).with_code(code))
     }
 
     pub fn negate_operation(error: SynthesisError, span: Span) -> Self {
         let message = format!("field negation failed due to synthesis error `{:?}`", error,);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E1701", message, span)
     }
 
     pub fn binary_operation(operation: String, error: SynthesisError, span: Span) -> Self {
@@ -48,30 +62,39 @@ impl FieldError {
             operation, error,
         );
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E1702", message, span)
+    }
+
+    pub fn intrinsic_operation(operation: String, error: SynthesisError, span: Span) -> Self {
+        let message = format!(
+            "the field operation `{}` failed due to synthesis error `{:?}`",
+            operation, error,
+        );
+
+        Self::new_from_span("E1703", message, span)
     }
 
     pub fn invalid_field(actual: String, span: Span) -> Self {
         let message = format!("expected field element input type, found `{}`", actual);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E1704", message, span)
     }
 
     pub fn missing_field(expected: String, span: Span) -> Self {
         let message = format!("expected field input `{}` not found", expected);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E1705", message, span)
     }
 
     pub fn no_inverse(field: String, span: Span) -> Self {
         let message = format!("no multiplicative inverse found for field `{}`", field);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E1706", message, span)
     }
 
     pub fn synthesis_error(error: SynthesisError, span: Span) -> Self {
         let message = format!("compilation failed due to field synthesis error `{:?}`", error);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E1707", message, span)
     }
 }
@@ -0,0 +1,95 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::errors::IntegerError;
+use leo_ast::{Diagnostic, Error as FormattedError, FixedType, Report, Span};
+
+use snarkvm_errors::gadgets::SynthesisError;
+use std::path::Path;
+
+#[derive(Debug, Error)]
+pub enum FixedError {
+    #[error("{}", _0)]
+    Error(#[from] FormattedError),
+
+    #[error("{}", _0)]
+    IntegerError(#[from] IntegerError),
+}
+
+impl Diagnostic for FixedError {
+    fn error_code(&self) -> &'static str {
+        match self {
+            FixedError::Error(error) => error.code.unwrap_or("E1800"),
+            FixedError::IntegerError(error) => error.error_code(),
+        }
+    }
+
+    fn reports(&self) -> Vec<Report> {
+        match self {
+            FixedError::Error(error) => vec![Report::from_error(self.error_code(), error)],
+            FixedError::IntegerError(error) => error.reports(),
+        }
+    }
+}
+
+impl FixedError {
+    pub fn set_path(&mut self, path: &Path) {
+        match self {
+            FixedError::Error(error) => error.set_path(path),
+            FixedError::IntegerError(error) => error.set_path(path),
+        }
+    }
+
+    fn new_from_span(code: &'static str, message: String, span: Span) -> Self {
+        FixedError::Error(FormattedError::new_from_span(message, span).with_code(code))
+    }
+
+    pub fn cannot_enforce(operation: String, error: SynthesisError, span: Span) -> Self {
+        let message = format!(
+            "the fixed point operation `{}` failed due to the synthesis error `{:?}`",
+            operation, error,
+        );
+
+        Self::new_from_span("E1801", message, span)
+    }
+
+    pub fn binary_operation(operation: String, left: &FixedType, right: &FixedType, span: Span) -> Self {
+        let message = format!(
+            "the fixed point operation `{}` expected operands of the same type, found `{}` and `{}`",
+            operation, left, right
+        );
+
+        Self::new_from_span("E1802", message, span)
+    }
+
+    pub fn invalid_fixed(actual: String, span: Span) -> Self {
+        let message = format!("failed to parse `{}` as expected fixed point type", actual);
+
+        Self::new_from_span("E1803", message, span)
+    }
+
+    pub fn missing_fixed(expected: String, span: Span) -> Self {
+        let message = format!("expected fixed point input `{}` not found", expected);
+
+        Self::new_from_span("E1804", message, span)
+    }
+
+    pub fn division_by_zero(span: Span) -> Self {
+        let message = "fixed point division by zero".to_string();
+
+        Self::new_from_span("E1805", message, span)
+    }
+}
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use leo_ast::{Error as FormattedError, Span};
+use leo_ast::{Diagnostic, Error as FormattedError, Report, Span};
 
 use snarkvm_errors::gadgets::SynthesisError;
 use std::path::Path;
@@ -25,6 +25,20 @@ pub enum GroupError {
     Error(#[from] FormattedError),
 }
 
+impl Diagnostic for GroupError {
+    fn error_code(&self) -> &'static str {
+        match self {
+            GroupError::Error(error) => error.code.unwrap_or("E1900"),
+        }
+    }
+
+    fn reports(&self) -> Vec<Report> {
+        match self {
+            GroupError::Error(error) => vec![Report::from_error(self.error_code(), error)],
+        }
+    }
+}
+
 impl GroupError {
     pub fn set_path(&mut self, path: &Path) {
         match self {
@@ -32,14 +46,14 @@ impl GroupError {
         }
     }
 
-    fn new_from_span(message: String, span: Span) -> Self {
-        GroupError::Error(FormattedError::new_from_span(message, span))
+    fn new_from_span(code: &'static str, message: String, span: Span) -> Self {
+        GroupError::Error(FormattedError::new_from_span(message, span).with_code(code))
     }
 
     pub fn negate_operation(error: SynthesisError, span: Span) -> Self {
         let message = format!("group negation failed due to the synthesis error `{:?}`", error,);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E1901", message, span)
     }
 
     pub fn binary_operation(operation: String, error: SynthesisError, span: Span) -> Self {
@@ -48,60 +62,60 @@ impl GroupError {
             operation, error,
         );
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E1902", message, span)
     }
 
     pub fn invalid_group(actual: String, span: Span) -> Self {
         let message = format!("expected group affine point input type, found `{}`", actual);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E1903", message, span)
     }
 
     pub fn missing_group(expected: String, span: Span) -> Self {
         let message = format!("expected group input `{}` not found", expected);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E1904", message, span)
     }
 
     pub fn synthesis_error(error: SynthesisError, span: Span) -> Self {
         let message = format!("compilation failed due to group synthesis error `{:?}`", error);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E1905", message, span)
     }
 
     pub fn x_invalid(x: String, span: Span) -> Self {
         let message = format!("invalid x coordinate `{}`", x);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E1906", message, span)
     }
 
     pub fn y_invalid(y: String, span: Span) -> Self {
         let message = format!("invalid y coordinate `{}`", y);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E1907", message, span)
     }
 
     pub fn not_on_curve(element: String, span: Span) -> Self {
         let message = format!("group element `{}` is not on the supported curve", element);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E1908", message, span)
     }
 
     pub fn x_recover(span: Span) -> Self {
         let message = "could not recover group element from x coordinate".to_string();
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E1909", message, span)
     }
 
     pub fn y_recover(span: Span) -> Self {
         let message = "could not recover group element from y coordinate".to_string();
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E1910", message, span)
     }
 
     pub fn n_group(number: String, span: Span) -> Self {
         let message = format!("cannot multiply group generator by \"{}\"", number);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E1911", message, span)
     }
 }
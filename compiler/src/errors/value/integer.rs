@@ -14,8 +14,8 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use leo_ast::{error::Error as FormattedError, IntegerType, Span, Type};
-use leo_gadgets::errors::SignedIntegerError;
+use leo_ast::{error::Error as FormattedError, Diagnostic, IntegerType, Report, Span, Type};
+use leo_gadgets::errors::{SignedIntegerError, UnsignedIntegerError};
 
 use snarkvm_errors::gadgets::SynthesisError;
 use std::path::Path;
@@ -26,6 +26,20 @@ pub enum IntegerError {
     Error(#[from] FormattedError),
 }
 
+impl Diagnostic for IntegerError {
+    fn error_code(&self) -> &'static str {
+        match self {
+            IntegerError::Error(error) => error.code.unwrap_or("E2000"),
+        }
+    }
+
+    fn reports(&self) -> Vec<Report> {
+        match self {
+            IntegerError::Error(error) => vec![Report::from_error(self.error_code(), error)],
+        }
+    }
+}
+
 impl IntegerError {
     pub fn set_path(&mut self, path: &Path) {
         match self {
@@ -33,8 +47,8 @@ impl IntegerError {
         }
     }
 
-    fn new_from_span(message: String, span: Span) -> Self {
-        IntegerError::Error(FormattedError::new_from_span(message, span))
+    fn new_from_span(code: &'static str, message: String, span: Span) -> Self {
+        IntegerError::Error(FormattedError::new_from_span(message, span).with_code(code))
     }
 
     pub fn cannot_enforce(operation: String, error: SynthesisError, span: Span) -> Self {
@@ -43,19 +57,28 @@ impl IntegerError {
             operation, error,
         );
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E2001", message, span)
     }
 
     pub fn signed(error: SignedIntegerError, span: Span) -> Self {
         let message = format!("integer operation failed due to the signed integer error `{:?}`", error,);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E2002", message, span)
+    }
+
+    pub fn unsigned(error: UnsignedIntegerError, span: Span) -> Self {
+        let message = format!(
+            "integer operation failed due to the unsigned integer error `{:?}`",
+            error,
+        );
+
+        Self::new_from_span("E2003", message, span)
     }
 
     pub fn synthesis(error: SynthesisError, span: Span) -> Self {
         let message = format!("integer operation failed due to the synthesis error `{}`", error,);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E2004", message, span)
     }
 
     pub fn signed_error(operation: String, error: SignedIntegerError, span: Span) -> Self {
@@ -64,13 +87,13 @@ impl IntegerError {
             operation, error
         );
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E2005", message, span)
     }
 
     pub fn negate_operation(span: Span) -> Self {
         let message = "integer negation can only be enforced on signed integers".to_string();
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E2006", message, span)
     }
 
     pub fn binary_operation(operation: String, span: Span) -> Self {
@@ -79,7 +102,7 @@ impl IntegerError {
             operation
         );
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E2007", message, span)
     }
 
     pub fn invalid_index(span: Span) -> Self {
@@ -87,30 +110,42 @@ impl IntegerError {
             "index must be a constant value unsigned integer. allocated indices produce a circuit of unknown size"
                 .to_string();
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E2008", message, span)
     }
 
     pub fn invalid_integer(actual: String, span: Span) -> Self {
         let message = format!("failed to parse `{}` as expected integer type", actual);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E2009", message, span)
     }
 
     pub fn invalid_integer_type(expected: &IntegerType, actual: &IntegerType, span: Span) -> Self {
         let message = format!("expected integer type {} found integer type {}", expected, actual);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E2010", message, span)
     }
 
     pub fn invalid_type(actual: &Type, span: Span) -> Self {
         let message = format!("expected type {}, found type IntegerType", actual);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E2011", message, span)
+    }
+
+    pub fn field_overflow(value: String, span: Span) -> Self {
+        let message = format!("the integer `{}` does not fit in a field element", value);
+
+        Self::new_from_span("E2012", message, span)
+    }
+
+    pub fn integer_overflow(value: String, integer_type: &IntegerType, span: Span) -> Self {
+        let message = format!("the integer `{}` does not fit in `{}`", value, integer_type);
+
+        Self::new_from_span("E2013", message, span)
     }
 
     pub fn missing_integer(expected: String, span: Span) -> Self {
         let message = format!("expected integer input `{}` not found", expected);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E2014", message, span)
     }
 }
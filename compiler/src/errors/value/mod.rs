@@ -23,6 +23,9 @@ pub use self::boolean::*;
 pub mod field;
 pub use self::field::*;
 
+pub mod fixed;
+pub use self::fixed::*;
+
 pub mod group;
 pub use self::group::*;
 
@@ -15,7 +15,7 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::errors::{AddressError, BooleanError, FieldError, GroupError, IntegerError};
-use leo_ast::{Error as FormattedError, Span};
+use leo_ast::{Diagnostic, Error as FormattedError, Report, Span};
 
 use std::path::Path;
 
@@ -40,6 +40,30 @@ pub enum ValueError {
     IntegerError(#[from] IntegerError),
 }
 
+impl Diagnostic for ValueError {
+    fn error_code(&self) -> &'static str {
+        match self {
+            ValueError::AddressError(error) => error.error_code(),
+            ValueError::BooleanError(error) => error.error_code(),
+            ValueError::Error(error) => error.code.unwrap_or("E1000"),
+            ValueError::FieldError(error) => error.error_code(),
+            ValueError::GroupError(error) => error.error_code(),
+            ValueError::IntegerError(error) => error.error_code(),
+        }
+    }
+
+    fn reports(&self) -> Vec<Report> {
+        match self {
+            ValueError::AddressError(error) => error.reports(),
+            ValueError::BooleanError(error) => error.reports(),
+            ValueError::Error(error) => vec![Report::from_error(self.error_code(), error)],
+            ValueError::FieldError(error) => error.reports(),
+            ValueError::GroupError(error) => error.reports(),
+            ValueError::IntegerError(error) => error.reports(),
+        }
+    }
+}
+
 impl ValueError {
     pub fn set_path(&mut self, path: &Path) {
         match self {
@@ -52,19 +76,19 @@ impl ValueError {
         }
     }
 
-    fn new_from_span(message: String, span: Span) -> Self {
-        ValueError::Error(FormattedError::new_from_span(message, span))
+    fn new_from_span(code: &'static str, message: String, span: Span) -> Self {
+        ValueError::Error(FormattedError::new_from_span(message, span).with_code(code))
     }
 
     pub fn implicit(value: String, span: Span) -> Self {
         let message = format!("explicit type needed for `{}`", value);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E1001", message, span)
     }
 
     pub fn implicit_group(span: Span) -> Self {
         let message = "group coordinates should be in (x, y)group format".to_string();
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E1002", message, span)
     }
 }
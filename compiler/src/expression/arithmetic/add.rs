@@ -31,6 +31,9 @@ pub fn enforce_add<'a, F: PrimeField, G: GroupType<F>, CS: ConstraintSystem<F>>(
         (ConstrainedValue::Integer(num_1), ConstrainedValue::Integer(num_2)) => {
             Ok(ConstrainedValue::Integer(num_1.add(cs, num_2, span)?))
         }
+        (ConstrainedValue::Fixed(fixed_1), ConstrainedValue::Fixed(fixed_2)) => {
+            Ok(ConstrainedValue::Fixed(fixed_1.add(cs, fixed_2, span)?))
+        }
         (ConstrainedValue::Field(field_1), ConstrainedValue::Field(field_2)) => {
             Ok(ConstrainedValue::Field(field_1.add(cs, &field_2, span)?))
         }
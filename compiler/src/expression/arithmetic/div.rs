@@ -17,7 +17,7 @@
 //! Enforces an arithmetic `/` operator in a resolved Leo program.
 
 use crate::{errors::ExpressionError, value::ConstrainedValue, GroupType};
-use leo_ast::Span;
+use leo_asg::{Rounding, Span};
 
 use snarkvm_models::{curves::PrimeField, gadgets::r1cs::ConstraintSystem};
 
@@ -31,6 +31,9 @@ pub fn enforce_div<'a, F: PrimeField, G: GroupType<F>, CS: ConstraintSystem<F>>(
         (ConstrainedValue::Integer(num_1), ConstrainedValue::Integer(num_2)) => {
             Ok(ConstrainedValue::Integer(num_1.div(cs, num_2, span)?))
         }
+        (ConstrainedValue::Fixed(fixed_1), ConstrainedValue::Fixed(fixed_2)) => Ok(ConstrainedValue::Fixed(
+            fixed_1.div(cs, fixed_2, Rounding::Trunc, span)?,
+        )),
         (ConstrainedValue::Field(field_1), ConstrainedValue::Field(field_2)) => {
             Ok(ConstrainedValue::Field(field_1.div(cs, &field_2, span)?))
         }
@@ -23,7 +23,7 @@ use crate::{
     program::ConstrainedProgram,
     relational::*,
     resolve_core_circuit,
-    value::{Address, ConstrainedValue, Integer},
+    value::{Address, ConstrainedValue, Fixed, Integer},
     FieldType,
     GroupType,
 };
@@ -39,6 +39,29 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
         &mut self,
         cs: &mut CS,
         expression: &'a Expression<'a>,
+    ) -> Result<ConstrainedValue<'a, F, G>, ExpressionError> {
+        // Constraints are named after the position of the node enforcing them. Nodes copied by the
+        // optimizer share a position, so every node evaluated at a position after the first gets a
+        // namespace of its own.
+        let span = match expression {
+            Expression::VariableRef(_) | Expression::Constant(_) => None,
+            _ => expression.span(),
+        };
+        if let Some(span) = span {
+            let evaluation = self.count_evaluation(span);
+            if evaluation > 1 {
+                let namespace = format!("evaluation {} {}:{}", evaluation, span.line, span.start);
+                return self.enforce_expression_node(&mut cs.ns(|| namespace), expression);
+            }
+        }
+
+        self.enforce_expression_node(cs, expression)
+    }
+
+    fn enforce_expression_node<CS: ConstraintSystem<F>>(
+        &mut self,
+        cs: &mut CS,
+        expression: &'a Expression<'a>,
     ) -> Result<ConstrainedValue<'a, F, G>, ExpressionError> {
         let span = expression.span().cloned().unwrap_or_default();
         match expression {
@@ -51,6 +74,7 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
                     ConstValue::Address(value) => ConstrainedValue::Address(Address::constant(value.clone(), &span)?),
                     ConstValue::Boolean(value) => ConstrainedValue::Boolean(Boolean::Constant(*value)),
                     ConstValue::Field(value) => ConstrainedValue::Field(FieldType::constant(value.to_string(), &span)?),
+                    ConstValue::Fixed(value) => ConstrainedValue::Fixed(Fixed::constant(value)),
                     ConstValue::Group(value) => ConstrainedValue::Group(G::constant(value, &span)?),
                     ConstValue::Int(value) => ConstrainedValue::Integer(Integer::new(value)),
                     ConstValue::Tuple(_) | ConstValue::Array(_) => unimplemented!(), // shouldnt be in the asg here
@@ -130,16 +154,18 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
                 function,
                 target,
                 arguments,
+                output,
                 ..
             }) => {
                 if let Some(circuit) = function.get().circuit.get() {
                     let core_mapping = circuit.core_mapping.borrow();
                     if let Some(core_mapping) = core_mapping.as_deref() {
-                        let core_circuit = resolve_core_circuit::<F, G>(core_mapping);
+                        let core_circuit = resolve_core_circuit(core_mapping);
                         return self.enforce_core_circuit_call_expression(
                             cs,
                             &core_circuit,
                             function.get(),
+                            output,
                             target.get(),
                             &arguments[..],
                             &span,
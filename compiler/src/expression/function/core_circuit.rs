@@ -19,7 +19,7 @@ use std::cell::Cell;
 use crate::{program::ConstrainedProgram, value::ConstrainedValue, CoreCircuit, GroupType};
 
 use crate::errors::ExpressionError;
-use leo_asg::{Expression, Function, Span};
+use leo_asg::{Expression, Function, Span, Type};
 use snarkvm_models::{curves::PrimeField, gadgets::r1cs::ConstraintSystem};
 
 impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
@@ -30,6 +30,7 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
         cs: &mut CS,
         core_circuit: &C,
         function: &'a Function<'a>,
+        output: &Type<'a>,
         target: Option<&'a Expression<'a>>,
         arguments: &[Cell<&'a Expression<'a>>],
         span: &Span,
@@ -43,7 +44,7 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
             .collect::<Result<Vec<_>, _>>()?;
 
         // Call the core function
-        let return_value = core_circuit.call_function(cs, function, span, target_value, arguments)?;
+        let return_value = core_circuit.call_function(cs, function, output, span, target_value, arguments)?;
 
         Ok(return_value)
     }
@@ -16,7 +16,12 @@
 
 //! Enforces a relational `==` operator in a resolved Leo program.
 
-use crate::{enforce_and, errors::ExpressionError, value::ConstrainedValue, GroupType};
+use crate::{
+    enforce_and,
+    errors::ExpressionError,
+    value::{ConstrainedCircuitMember, ConstrainedValue},
+    GroupType,
+};
 use leo_asg::Span;
 
 use snarkvm_models::{
@@ -51,6 +56,10 @@ pub fn evaluate_eq<'a, F: PrimeField, G: GroupType<F>, CS: ConstraintSystem<F>>(
             let unique_namespace = cs.ns(|| namespace_string);
             field_1.evaluate_equal(unique_namespace, &field_2)
         }
+        (ConstrainedValue::Fixed(fixed_1), ConstrainedValue::Fixed(fixed_2)) => {
+            let unique_namespace = cs.ns(|| namespace_string);
+            fixed_1.evaluate_equal(unique_namespace, &fixed_2)
+        }
         (ConstrainedValue::Group(point_1), ConstrainedValue::Group(point_2)) => {
             let unique_namespace = cs.ns(|| namespace_string);
             point_1.evaluate_equal(unique_namespace, &point_2)
@@ -74,6 +83,26 @@ pub fn evaluate_eq<'a, F: PrimeField, G: GroupType<F>, CS: ConstraintSystem<F>>(
             }
             return Ok(current);
         }
+        (
+            ConstrainedValue::CircuitExpression(circuit_1, members_1),
+            ConstrainedValue::CircuitExpression(circuit_2, mut members_2),
+        ) if circuit_1 == circuit_2 => {
+            let mut cs = cs.ns(|| namespace_string);
+            let mut current = ConstrainedValue::Boolean(Boolean::constant(true));
+
+            // members are compared by name, since they are stored in the order of their initialization
+            for (i, ConstrainedCircuitMember(name, left)) in members_1.into_iter().enumerate() {
+                let position = members_2
+                    .iter()
+                    .position(|member| member.0 == name)
+                    .expect("missing member in asg circuit init expression"); // asg enforced
+                let ConstrainedCircuitMember(_, right) = members_2.swap_remove(position);
+                let next = evaluate_eq(&mut cs.ns(|| format!("circuit member {}", name)), left, right, span)?;
+
+                current = enforce_and(&mut cs.ns(|| format!("circuit result {}", i)), current, next, span)?;
+            }
+            return Ok(current);
+        }
         (val_1, val_2) => {
             return Err(ExpressionError::incompatible_types(
                 format!("{} == {}", val_1, val_2,),
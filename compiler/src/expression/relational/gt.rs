@@ -33,6 +33,9 @@ pub fn evaluate_gt<'a, F: PrimeField, G: GroupType<F>, CS: ConstraintSystem<F>>(
         (ConstrainedValue::Integer(num_1), ConstrainedValue::Integer(num_2)) => {
             num_1.greater_than(unique_namespace, &num_2)
         }
+        (ConstrainedValue::Fixed(fixed_1), ConstrainedValue::Fixed(fixed_2)) => {
+            fixed_1.greater_than(unique_namespace, &fixed_2)
+        }
         (val_1, val_2) => {
             return Err(ExpressionError::incompatible_types(
                 format!("{} > {}", val_1, val_2),
@@ -0,0 +1,144 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Allocates a circuit as a main function input parameter in a compiled Leo program.
+
+use crate::{
+    errors::FunctionError,
+    program::ConstrainedProgram,
+    value::{ConstrainedCircuitMember, ConstrainedValue},
+    GroupType,
+};
+
+use leo_asg::{Circuit, CircuitMember, Type};
+use leo_ast::{Identifier, InputValue, IntegerType, Span};
+
+use snarkvm_models::{curves::PrimeField, gadgets::r1cs::ConstraintSystem};
+
+impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
+    pub fn allocate_circuit<CS: ConstraintSystem<F>>(
+        &mut self,
+        cs: &mut CS,
+        name: &str,
+        circuit: &'a Circuit<'a>,
+        input_value: Option<InputValue>,
+        span: &Span,
+    ) -> Result<ConstrainedValue<'a, F, G>, FunctionError> {
+        let circuit_name = circuit.name.borrow().name.clone();
+
+        let mut input_members = match input_value {
+            Some(InputValue::Circuit(identifier, members)) => {
+                if identifier.name != circuit_name {
+                    return Err(FunctionError::mismatched_input_circuit(
+                        circuit_name,
+                        identifier.name,
+                        identifier.span,
+                    ));
+                }
+                Some((identifier, members))
+            }
+            None => None,
+            Some(input) => return Err(FunctionError::invalid_circuit(input.to_string(), span.to_owned())),
+        };
+
+        // Allocate each member variable declared by the circuit
+        let mut members = vec![];
+        for (member_name, member) in circuit.members.borrow().iter() {
+            let type_ = match member {
+                CircuitMember::Variable(type_) => type_,
+                CircuitMember::Function(_) => continue,
+            };
+            let value_name = format!("{}_{}", name, member_name);
+
+            let (value, member_span) = match &mut input_members {
+                Some((identifier, values)) => {
+                    let index = values
+                        .iter()
+                        .position(|(name, _)| &name.name == member_name)
+                        .ok_or_else(|| {
+                            FunctionError::missing_input_circuit_member(
+                                circuit_name.clone(),
+                                member_name.clone(),
+                                identifier.span.clone(),
+                            )
+                        })?;
+                    let (member_identifier, value) = values.remove(index);
+                    if !Self::input_matches_type(type_, &value) {
+                        return Err(FunctionError::invalid_input_circuit_member(
+                            member_name.clone(),
+                            type_.to_string(),
+                            value.to_string(),
+                            member_identifier.span,
+                        ));
+                    }
+                    (Some(value), member_identifier.span)
+                }
+                None => (None, span.clone()),
+            };
+
+            let value = self.allocate_main_function_input(cs, type_, &value_name, value, &member_span)?;
+            members.push(ConstrainedCircuitMember(
+                Identifier::new_with_span(member_name, &member_span),
+                value,
+            ));
+        }
+
+        // Reject members the circuit does not declare
+        if let Some((_, values)) = input_members {
+            if let Some((identifier, _)) = values.into_iter().next() {
+                return Err(FunctionError::undefined_input_circuit_member(
+                    circuit_name,
+                    identifier.name,
+                    identifier.span,
+                ));
+            }
+        }
+
+        Ok(ConstrainedValue::CircuitExpression(circuit, members))
+    }
+
+    ///
+    /// Returns `true` if the circuit member `value`, typed by its literal in the input file, can
+    /// initialize a member of type `type_`.
+    ///
+    fn input_matches_type(type_: &Type, value: &InputValue) -> bool {
+        match (type_, value) {
+            (Type::Address, InputValue::Address(_))
+            | (Type::Boolean, InputValue::Boolean(_))
+            | (Type::Field, InputValue::Field(_))
+            | (Type::Group, InputValue::Group(_)) => true,
+            (Type::Integer(integer_type), InputValue::Integer(input_type, _)) => {
+                IntegerType::from(input_type.clone()) == *integer_type
+            }
+            (Type::Fixed(fixed_type), InputValue::Fixed(input_type, _)) => input_type == fixed_type,
+            (Type::Field, InputValue::Implicit(_))
+            | (Type::Fixed(_), InputValue::Implicit(_))
+            | (Type::Integer(_), InputValue::Implicit(_)) => true,
+            (Type::Array(type_, len), InputValue::Array(values)) => {
+                values.len() == *len && values.iter().all(|value| Self::input_matches_type(type_, value))
+            }
+            (Type::Tuple(types), InputValue::Tuple(values)) => {
+                types.len() == values.len()
+                    && types
+                        .iter()
+                        .zip(values.iter())
+                        .all(|(t, v)| Self::input_matches_type(t, v))
+            }
+            (Type::Circuit(circuit), InputValue::Circuit(name, _)) => circuit.name.borrow().name == name.name,
+            _ => false,
+        }
+    }
+}
@@ -39,7 +39,7 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
                 Some(CircuitMember::Variable(inner)) => inner,
                 _ => continue, // present, but unused
             };
-            let declared_type = self.asg.scope.resolve_ast_type(&parameter.type_)?;
+            let declared_type = self.asg.scope.resolve_ast_type(&parameter.type_, &parameter.span)?;
             if !expected_type.is_assignable_from(&declared_type) {
                 return Err(AsgConvertError::unexpected_type(
                     &expected_type.to_string(),
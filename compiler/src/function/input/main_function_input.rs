@@ -26,6 +26,7 @@ use crate::{
         group::input::group_from_input,
         ConstrainedValue,
     },
+    Fixed,
     GroupType,
     Integer,
 };
@@ -38,7 +39,7 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
     pub fn allocate_main_function_input<CS: ConstraintSystem<F>>(
         &mut self,
         cs: &mut CS,
-        type_: &Type,
+        type_: &Type<'a>,
         name: &str,
         input_option: Option<InputValue>,
         span: &Span,
@@ -47,6 +48,13 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
             Type::Address => Ok(Address::from_input(cs, name, input_option, span)?),
             Type::Boolean => Ok(bool_from_input(cs, name, input_option, span)?),
             Type::Field => Ok(field_from_input(cs, name, input_option, span)?),
+            Type::Fixed(fixed_type) => Ok(ConstrainedValue::Fixed(Fixed::from_input(
+                cs,
+                fixed_type,
+                name,
+                input_option,
+                span,
+            )?)),
             Type::Group => Ok(group_from_input(cs, name, input_option, span)?),
             Type::Integer(integer_type) => Ok(ConstrainedValue::Integer(Integer::from_input(
                 cs,
@@ -57,6 +65,7 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
             )?)),
             Type::Array(type_, len) => self.allocate_array(cs, name, &*type_, *len, input_option, span),
             Type::Tuple(types) => self.allocate_tuple(cs, &name, types, input_option, span),
+            Type::Circuit(circuit) => self.allocate_circuit(cs, name, circuit, input_option, span),
             _ => unimplemented!("main function input not implemented for type"),
         }
     }
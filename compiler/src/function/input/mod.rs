@@ -19,6 +19,8 @@
 pub mod array;
 pub use self::array::*;
 
+pub mod circuit;
+
 pub mod main_function_input;
 pub use self::main_function_input::*;
 
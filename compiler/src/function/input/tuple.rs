@@ -28,7 +28,7 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
         &mut self,
         cs: &mut CS,
         name: &str,
-        types: &[Type],
+        types: &[Type<'a>],
         input_value: Option<InputValue>,
         span: &Span,
     ) -> Result<ConstrainedValue<'a, F, G>, FunctionError> {
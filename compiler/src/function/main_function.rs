@@ -16,7 +16,13 @@
 
 //! Enforces constraints on the main function of a compiled Leo program.
 
-use crate::{errors::FunctionError, program::ConstrainedProgram, GroupType, OutputBytes};
+use crate::{
+    errors::FunctionError,
+    program::ConstrainedProgram,
+    soundness::main_input_namespace,
+    GroupType,
+    OutputBytes,
+};
 
 use leo_asg::{Expression, Function, FunctionQualifier};
 use leo_ast::Input;
@@ -41,9 +47,10 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
                 .resolve_input()
                 .expect("no input variable in scope when function is qualified");
 
+            let span = function.name.borrow().span.clone();
             let value = self.allocate_input_keyword(
-                cs,
-                function.name.borrow().span.clone(),
+                &mut cs.ns(|| main_input_namespace("input", &span)),
+                span.clone(),
                 &asg_input.container_circuit,
                 input,
             )?;
@@ -68,7 +75,7 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
                     FunctionError::input_not_found(name.clone(), function.span.clone().unwrap_or_default())
                 })?;
                 let input_value = self.allocate_main_function_input(
-                    cs,
+                    &mut cs.ns(|| main_input_namespace(&name, &input_variable.name.span)),
                     &input_variable.type_.clone(),
                     &name,
                     input_option,
@@ -0,0 +1,538 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Enforces constraints on a program lowered to the ir.
+//!
+//! Blocks are enforced after all of their predecessors. Each block is reached under a condition: the
+//! condition of the edge into it if it has one predecessor, and otherwise the condition of its immediate
+//! dominator, which is where the conditional that the block joins branched. Phi nodes select between
+//! their incoming values by the condition of each incoming edge, and the values of `return`s are selected
+//! by the conditions of their blocks.
+
+use crate::{
+    arithmetic::*,
+    errors::{ConsoleError, ExpressionError, FunctionError, StatementError},
+    get_indicator_value,
+    ir::{self, BlockId, LogLevel, Operation, Terminator, Value},
+    logical::*,
+    program::ConstrainedProgram,
+    relational::*,
+    resolve_core_circuit,
+    soundness::main_input_namespace,
+    value::{Address, ConstrainedCircuitMember, ConstrainedValue, Fixed, Integer},
+    CoreCircuit,
+    FieldType,
+    GroupType,
+    OutputBytes,
+};
+use leo_asg::{BinaryOperation, ConstValue, FunctionQualifier, Span, UnaryOperation};
+use leo_ast::Input;
+
+use snarkvm_models::{
+    curves::PrimeField,
+    gadgets::{
+        r1cs::ConstraintSystem,
+        utilities::{boolean::Boolean, select::CondSelectGadget},
+    },
+};
+use std::collections::HashMap;
+
+type Registers<'a, F, G> = HashMap<Value, ConstrainedValue<'a, F, G>>;
+
+impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
+    ///
+    /// Enforces the main function of a program lowered to the ir.
+    ///
+    /// The input of the main function is allocated like [`ConstrainedProgram::enforce_main_function`] does.
+    ///
+    pub fn enforce_ir_main_function<CS: ConstraintSystem<F>>(
+        &mut self,
+        cs: &mut CS,
+        program: &ir::Program<'a>,
+        function: &'a leo_asg::Function<'a>,
+        input: &Input,
+    ) -> Result<OutputBytes, FunctionError> {
+        let registers = input.get_registers();
+
+        if function.has_input {
+            let asg_input = function
+                .scope
+                .resolve_input()
+                .expect("no input variable in scope when function is qualified");
+
+            let span = function.name.borrow().span.clone();
+            let value = self.allocate_input_keyword(
+                &mut cs.ns(|| main_input_namespace("input", &span)),
+                span.clone(),
+                asg_input.container_circuit,
+                input,
+            )?;
+
+            self.store(asg_input.container.borrow().id, value);
+        }
+
+        let span = function.span.clone().unwrap_or_default();
+        let mut arguments = vec![];
+        for (_, input_variable) in function.arguments.iter() {
+            let input_variable = input_variable.get().borrow();
+            let name = input_variable.name.name.clone();
+            let input_option = input
+                .get(&name)
+                .ok_or_else(|| FunctionError::input_not_found(name.clone(), span.clone()))?;
+            arguments.push(self.allocate_main_function_input(
+                &mut cs.ns(|| main_input_namespace(&name, &input_variable.name.span)),
+                &input_variable.type_,
+                &name,
+                input_option,
+                &span,
+            )?);
+        }
+
+        let main = program
+            .function(function)
+            .expect("the main function was not lowered to the ir");
+        let result_value = self.enforce_ir_function(cs, program, main, arguments)?;
+        let output_bytes = OutputBytes::new_from_constrained_value(&self.asg, registers, result_value, span)?;
+
+        Ok(output_bytes)
+    }
+
+    fn enforce_ir_function<CS: ConstraintSystem<F>>(
+        &mut self,
+        cs: &mut CS,
+        program: &ir::Program<'a>,
+        function: &ir::Function<'a>,
+        arguments: Vec<ConstrainedValue<'a, F, G>>,
+    ) -> Result<ConstrainedValue<'a, F, G>, FunctionError> {
+        let span = function.definition.span.clone().unwrap_or_default();
+        if function.parameters.len() != arguments.len() {
+            return Err(FunctionError::input_not_found(
+                "arguments length invalid".to_string(),
+                span,
+            ));
+        }
+
+        let mut registers = Registers::new();
+        for (parameter, argument) in function.parameters.iter().zip(arguments) {
+            registers.insert(parameter.value, argument);
+        }
+
+        let order = reverse_postorder(function);
+        let dominators = immediate_dominators(function, &order);
+
+        // The condition under which control reaches each block, and the edges into each block with
+        // the condition under which control takes them.
+        let mut conditions = HashMap::new();
+        let mut edges: HashMap<BlockId, Vec<(BlockId, Boolean)>> = HashMap::new();
+        let mut results = vec![];
+
+        for id in order {
+            let block = &function.blocks[id.0];
+            let incoming = edges.remove(&id).unwrap_or_default();
+            let condition = match incoming.as_slice() {
+                [] => Boolean::constant(true),
+                [(_, condition)] => *condition,
+                _ => conditions[&dominators[&id]],
+            };
+            conditions.insert(id, condition);
+
+            for (i, phi) in block.phis.iter().enumerate() {
+                let mut value: Option<ConstrainedValue<'a, F, G>> = None;
+                for (j, (from, incoming_value)) in phi.incoming.iter().enumerate() {
+                    let edge = match incoming.iter().find(|(predecessor, _)| predecessor == from) {
+                        Some((_, edge)) => edge,
+                        None => continue,
+                    };
+                    let incoming_value = &registers[incoming_value];
+                    value = Some(match value {
+                        None => incoming_value.clone(),
+                        Some(value) => ConstrainedValue::conditionally_select(
+                            cs.ns(|| format!("{} phi {} incoming {}", id, i, j)),
+                            edge,
+                            incoming_value,
+                            &value,
+                        )
+                        .map_err(|_| {
+                            StatementError::select_fail(incoming_value.to_string(), value.to_string(), span.clone())
+                        })?,
+                    });
+                }
+                registers.insert(phi.dest, value.expect("phi node without incoming values"));
+            }
+
+            for (i, instruction) in block.instructions.iter().enumerate() {
+                let span = instruction.span.clone().unwrap_or_default();
+                let value = self.enforce_ir_operation(
+                    &mut cs.ns(|| format!("{} instruction {} {}:{}", id, i, span.line, span.start)),
+                    program,
+                    &registers,
+                    &condition,
+                    &instruction.operation,
+                    &span,
+                )?;
+                if let Some(dest) = instruction.dest {
+                    registers.insert(dest, value);
+                }
+            }
+
+            match &block.terminator {
+                Terminator::Jump(to) => edges.entry(*to).or_default().push((id, condition)),
+                Terminator::Branch(value, if_true, if_false) => {
+                    let inner = match &registers[value] {
+                        ConstrainedValue::Boolean(inner) => *inner,
+                        value => return Err(StatementError::conditional_boolean(value.to_string(), span).into()),
+                    };
+                    let true_condition = Boolean::and(cs.ns(|| format!("{} branch 1", id)), &condition, &inner)
+                        .map_err(|_| StatementError::indicator_calculation(format!("{} branch 1", id), span.clone()))?;
+                    let false_condition = Boolean::and(cs.ns(|| format!("{} branch 2", id)), &condition, &inner.not())
+                        .map_err(|_| StatementError::indicator_calculation(format!("{} branch 2", id), span.clone()))?;
+                    edges.entry(*if_true).or_default().push((id, true_condition));
+                    edges.entry(*if_false).or_default().push((id, false_condition));
+                }
+                Terminator::Return(Some(value)) => results.push((condition, registers[value].clone())),
+                Terminator::Return(None) | Terminator::Unreachable => {}
+            }
+        }
+
+        Self::conditionally_select_result(cs, &function.output, results, &span).map_err(FunctionError::StatementError)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn enforce_ir_operation<CS: ConstraintSystem<F>>(
+        &mut self,
+        cs: &mut CS,
+        program: &ir::Program<'a>,
+        registers: &Registers<'a, F, G>,
+        condition: &Boolean,
+        operation: &Operation<'a>,
+        span: &Span,
+    ) -> Result<ConstrainedValue<'a, F, G>, FunctionError> {
+        let value = |value: &Value| registers[value].clone();
+        let index = |value: &Value| match &registers[value] {
+            ConstrainedValue::Integer(number) => Ok(number.to_usize(span)?),
+            value => Err(ExpressionError::invalid_index(value.to_string(), span)),
+        };
+        let array = |value: &Value| match &registers[value] {
+            ConstrainedValue::Array(array) => Ok(array.clone()),
+            value => Err(ExpressionError::undefined_array(value.to_string(), span.clone())),
+        };
+        let tuple = |value: &Value| match &registers[value] {
+            ConstrainedValue::Tuple(tuple) => Ok(tuple.clone()),
+            value => Err(ExpressionError::undefined_array(value.to_string(), span.clone())),
+        };
+
+        Ok(match operation {
+            Operation::Constant(value) => constant(value, span)?,
+            Operation::Load(variable) => {
+                let variable = variable.borrow();
+                self.get(variable.id)
+                    .cloned()
+                    .ok_or_else(|| StatementError::undefined_variable(variable.name.to_string(), span.clone()))?
+            }
+            Operation::Binary(operation, left, right) => {
+                let (left, right) = (value(left), value(right));
+                match operation {
+                    BinaryOperation::Add => enforce_add(cs, left, right, span)?,
+                    BinaryOperation::Sub => enforce_sub(cs, left, right, span)?,
+                    BinaryOperation::Mul => enforce_mul(cs, left, right, span)?,
+                    BinaryOperation::Div => enforce_div(cs, left, right, span)?,
+                    BinaryOperation::Pow => enforce_pow(cs, left, right, span)?,
+                    BinaryOperation::Or => enforce_or(cs, left, right, span)?,
+                    BinaryOperation::And => enforce_and(cs, left, right, span)?,
+                    BinaryOperation::Eq => evaluate_eq(cs, left, right, span)?,
+                    BinaryOperation::Ne => evaluate_not(evaluate_eq(cs, left, right, span)?, span)?,
+                    BinaryOperation::Ge => evaluate_ge(cs, left, right, span)?,
+                    BinaryOperation::Gt => evaluate_gt(cs, left, right, span)?,
+                    BinaryOperation::Le => evaluate_le(cs, left, right, span)?,
+                    BinaryOperation::Lt => evaluate_lt(cs, left, right, span)?,
+                }
+            }
+            Operation::Unary(UnaryOperation::Negate, inner) => enforce_negate(cs, value(inner), span)?,
+            Operation::Unary(UnaryOperation::Not, inner) => evaluate_not(value(inner), span)?,
+            Operation::Select(select_condition, if_true, if_false) => {
+                let select_condition = match &registers[select_condition] {
+                    ConstrainedValue::Boolean(resolved) => *resolved,
+                    value => return Err(ExpressionError::conditional_boolean(value.to_string(), span.clone()).into()),
+                };
+                let (if_true, if_false) = (&registers[if_true], &registers[if_false]);
+                ConstrainedValue::conditionally_select(cs.ns(|| "select"), &select_condition, if_true, if_false)
+                    .map_err(|e| ExpressionError::cannot_enforce("conditional select".to_string(), e, span.clone()))?
+            }
+            Operation::Array(elements) => {
+                let mut result = vec![];
+                for (element, spread) in elements.iter() {
+                    if *spread {
+                        result.extend(array(element)?);
+                    } else {
+                        result.push(value(element));
+                    }
+                }
+                ConstrainedValue::Array(result)
+            }
+            Operation::ArrayRepeat(element, len) => ConstrainedValue::Array(vec![value(element); *len]),
+            Operation::ArrayGet(target, index_value) => {
+                let index = index(index_value)?;
+                array(target)?
+                    .get(index)
+                    .cloned()
+                    .ok_or_else(|| ExpressionError::index_out_of_bounds(index, span.clone()))?
+            }
+            Operation::ArraySlice(target, left, right) => {
+                let array = array(target)?;
+                let left = left.as_ref().map(index).transpose()?.unwrap_or(0);
+                let right = right.as_ref().map(index).transpose()?.unwrap_or(array.len());
+                Self::check_range_index(left, right, array.len(), span)?;
+                ConstrainedValue::Array(array[left..right].to_vec())
+            }
+            Operation::ArraySet(target, index_value, element) => {
+                let mut array = array(target)?;
+                let index = index(index_value)?;
+                match array.get_mut(index) {
+                    Some(old) => *old = value(element),
+                    None => {
+                        return Err(StatementError::array_assign_index_bounds(index, array.len(), span.clone()).into())
+                    }
+                }
+                ConstrainedValue::Array(array)
+            }
+            Operation::ArraySliceSet(target, left, right, elements) => {
+                let mut array = array(target)?;
+                let left = left.as_ref().map(index).transpose()?.unwrap_or(0);
+                let right = right.as_ref().map(index).transpose()?.unwrap_or(array.len());
+                Self::check_range_index(left, right, array.len(), span)?;
+                let elements = array_of(value(elements), span)?;
+                if elements.len() != right - left {
+                    return Err(ExpressionError::invalid_length(right - left, elements.len(), span.clone()).into());
+                }
+                array.splice(left..right, elements);
+                ConstrainedValue::Array(array)
+            }
+            Operation::Tuple(elements) => ConstrainedValue::Tuple(elements.iter().map(value).collect()),
+            Operation::TupleGet(target, index) => tuple(target)?
+                .get(*index)
+                .cloned()
+                .ok_or_else(|| ExpressionError::index_out_of_bounds(*index, span.clone()))?,
+            Operation::TupleSet(target, index, element) => {
+                let mut tuple = tuple(target)?;
+                match tuple.get_mut(*index) {
+                    Some(old) => *old = value(element),
+                    None => {
+                        return Err(StatementError::tuple_assign_index_bounds(*index, tuple.len(), span.clone()).into())
+                    }
+                }
+                ConstrainedValue::Tuple(tuple)
+            }
+            Operation::Circuit(circuit, members) => ConstrainedValue::CircuitExpression(
+                circuit,
+                members
+                    .iter()
+                    .map(|(name, member)| ConstrainedCircuitMember(name.clone(), value(member)))
+                    .collect(),
+            ),
+            Operation::MemberGet(target, name) => match value(target) {
+                ConstrainedValue::CircuitExpression(circuit, members) => {
+                    match members.into_iter().find(|member| &member.0.name == name) {
+                        Some(member) => member.1,
+                        None => {
+                            return Err(ExpressionError::undefined_member_access(
+                                circuit.name.borrow().to_string(),
+                                name.clone(),
+                                span.clone(),
+                            )
+                            .into());
+                        }
+                    }
+                }
+                value => return Err(ExpressionError::undefined_circuit(value.to_string(), span.clone()).into()),
+            },
+            Operation::StaticMember(_, member) => {
+                return Err(ExpressionError::invalid_static_access(member.clone(), span.clone()).into());
+            }
+            Operation::MemberSet(target, name, member_value) => match value(target) {
+                ConstrainedValue::CircuitExpression(circuit, mut members) => {
+                    match members.iter_mut().find(|member| &member.0.name == name) {
+                        Some(member) => member.1 = value(member_value),
+                        None => {
+                            return Err(StatementError::undefined_circuit_variable(name.clone(), span.clone()).into());
+                        }
+                    }
+                    ConstrainedValue::CircuitExpression(circuit, members)
+                }
+                value => return Err(StatementError::undefined_circuit(value.to_string(), span.clone()).into()),
+            },
+            Operation::Call(function, output, arguments) => {
+                let mut arguments = arguments.iter().map(value).collect::<Vec<_>>();
+                if let Some(circuit) = function.circuit.get() {
+                    let core_mapping = circuit.core_mapping.borrow();
+                    if let Some(core_mapping) = core_mapping.as_deref() {
+                        let target = match function.qualifier {
+                            FunctionQualifier::Static => None,
+                            _ => Some(arguments.remove(0)),
+                        };
+                        let core_circuit = resolve_core_circuit(core_mapping);
+                        return Ok(core_circuit.call_function(cs, function, output, span, target, arguments)?);
+                    }
+                }
+                let function = program
+                    .function(function)
+                    .expect("called a function that was not lowered to the ir");
+                self.enforce_ir_function(cs, program, function, arguments)?
+            }
+            Operation::Assert(assertion) => {
+                // If the block is not reached, do not evaluate the assertion.
+                // This is okay since we are not enforcing any constraints.
+                if get_indicator_value(condition) {
+                    let result_option = match &registers[assertion] {
+                        ConstrainedValue::Boolean(boolean) => boolean.get_value(),
+                        _ => {
+                            return Err(StatementError::from(ConsoleError::assertion_must_be_boolean(
+                                span.text.clone(),
+                                span.clone(),
+                            ))
+                            .into());
+                        }
+                    };
+                    let result_bool = result_option
+                        .ok_or_else(|| StatementError::from(ConsoleError::assertion_depends_on_input(span.clone())))?;
+                    if !result_bool {
+                        return Err(StatementError::from(ConsoleError::assertion_failed(
+                            span.text.clone(),
+                            span.clone(),
+                        ))
+                        .into());
+                    }
+                }
+                ConstrainedValue::Tuple(vec![])
+            }
+            Operation::Print(level, string, parameters) => {
+                if get_indicator_value(condition) {
+                    // Trim the double quotes around the string, and insert the parameter for each container `{}`
+                    let string = string.trim_start_matches('\"');
+                    let mut result = string.split('\"').next().unwrap().to_string();
+                    for parameter in parameters.iter() {
+                        result = result.replacen("{}", &registers[parameter].to_string(), 1);
+                    }
+                    match level {
+                        LogLevel::Debug => tracing::debug!("{}", result),
+                        LogLevel::Error => tracing::error!("{}", result),
+                        LogLevel::Log => tracing::info!("{}", result),
+                    }
+                }
+                ConstrainedValue::Tuple(vec![])
+            }
+        })
+    }
+}
+
+/// Returns the value of a constant.
+fn constant<'a, F: PrimeField, G: GroupType<F>>(
+    value: &ConstValue,
+    span: &Span,
+) -> Result<ConstrainedValue<'a, F, G>, ExpressionError> {
+    Ok(match value {
+        ConstValue::Address(value) => ConstrainedValue::Address(Address::constant(value.clone(), span)?),
+        ConstValue::Boolean(value) => ConstrainedValue::Boolean(Boolean::Constant(*value)),
+        ConstValue::Field(value) => ConstrainedValue::Field(FieldType::constant(value.to_string(), span)?),
+        ConstValue::Fixed(value) => ConstrainedValue::Fixed(Fixed::constant(value)),
+        ConstValue::Group(value) => ConstrainedValue::Group(G::constant(value, span)?),
+        ConstValue::Int(value) => ConstrainedValue::Integer(Integer::new(value)),
+        ConstValue::Tuple(values) => ConstrainedValue::Tuple(
+            values
+                .iter()
+                .map(|value| constant(value, span))
+                .collect::<Result<_, _>>()?,
+        ),
+        ConstValue::Array(values) => ConstrainedValue::Array(
+            values
+                .iter()
+                .map(|value| constant(value, span))
+                .collect::<Result<_, _>>()?,
+        ),
+    })
+}
+
+fn array_of<'a, F: PrimeField, G: GroupType<F>>(
+    value: ConstrainedValue<'a, F, G>,
+    span: &Span,
+) -> Result<Vec<ConstrainedValue<'a, F, G>>, ExpressionError> {
+    match value {
+        ConstrainedValue::Array(array) => Ok(array),
+        value => Err(ExpressionError::undefined_array(value.to_string(), span.clone())),
+    }
+}
+
+fn successors(terminator: &Terminator) -> Vec<BlockId> {
+    match terminator {
+        Terminator::Jump(to) => vec![*to],
+        Terminator::Branch(_, if_true, if_false) => vec![*if_true, *if_false],
+        Terminator::Return(_) | Terminator::Unreachable => vec![],
+    }
+}
+
+/// Returns the blocks reachable from the entry of a function, each after all of its predecessors.
+fn reverse_postorder(function: &ir::Function) -> Vec<BlockId> {
+    fn visit(function: &ir::Function, id: BlockId, visited: &mut Vec<bool>, postorder: &mut Vec<BlockId>) {
+        if visited[id.0] {
+            return;
+        }
+        visited[id.0] = true;
+        // successors are visited in reverse, so that the first successor comes first in the order
+        for successor in successors(&function.blocks[id.0].terminator).into_iter().rev() {
+            visit(function, successor, visited, postorder);
+        }
+        postorder.push(id);
+    }
+
+    let mut visited = vec![false; function.blocks.len()];
+    let mut postorder = vec![];
+    visit(function, BlockId(0), &mut visited, &mut postorder);
+    postorder.reverse();
+    postorder
+}
+
+/// Returns the immediate dominator of each block but the entry, given the blocks in reverse postorder.
+fn immediate_dominators(function: &ir::Function, order: &[BlockId]) -> HashMap<BlockId, BlockId> {
+    let position = order
+        .iter()
+        .enumerate()
+        .map(|(position, id)| (*id, position))
+        .collect::<HashMap<_, _>>();
+    let mut predecessors: HashMap<BlockId, Vec<BlockId>> = HashMap::new();
+    for id in order.iter() {
+        for successor in successors(&function.blocks[id.0].terminator) {
+            predecessors.entry(successor).or_default().push(*id);
+        }
+    }
+
+    // The cfg is acyclic, so the predecessors of a block are all visited before it.
+    let mut dominators = HashMap::new();
+    for id in order.iter().skip(1) {
+        let mut predecessors = predecessors[id].iter().copied();
+        let mut dominator = predecessors.next().expect("reachable block without predecessors");
+        for mut predecessor in predecessors {
+            while dominator != predecessor {
+                while position[&dominator] > position[&predecessor] {
+                    dominator = dominators[&dominator];
+                }
+                while position[&predecessor] > position[&dominator] {
+                    predecessor = dominators[&predecessor];
+                }
+            }
+        }
+        dominators.insert(*id, dominator);
+    }
+    dominators
+}
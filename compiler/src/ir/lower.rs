@@ -0,0 +1,492 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Lowers an asg to the ir.
+
+use crate::{
+    errors::IrError,
+    ir::{
+        qualified_name,
+        BasicBlock,
+        BlockId,
+        Function,
+        Instruction,
+        LogLevel,
+        Operation,
+        Parameter,
+        Phi,
+        Program,
+        Terminator,
+        Value,
+    },
+};
+use leo_asg::{
+    AssignAccess,
+    AssignOperation,
+    CircuitMember,
+    ConsoleFunction,
+    Expression,
+    FunctionQualifier,
+    Node,
+    Span,
+    Statement,
+    Variable,
+};
+use leo_ast::BinaryOperation;
+
+use std::collections::BTreeMap;
+
+/// Lowers the functions of a program, including methods and the functions of imported modules.
+///
+/// Functions without a body, like core functions, are left out, and functions with interface parameters are
+/// lowered once for each instance.
+pub fn lower_program<'a>(name: &str, program: &leo_asg::Program<'a>) -> Result<Program<'a>, IrError> {
+    let mut functions = vec![];
+    lower_module(program, &mut functions)?;
+
+    Ok(Program {
+        name: name.to_string(),
+        functions,
+    })
+}
+
+fn lower_module<'a>(program: &leo_asg::Program<'a>, functions: &mut Vec<Function<'a>>) -> Result<(), IrError> {
+    for (_, import) in program.imported_modules.iter() {
+        lower_module(import, functions)?;
+    }
+    for (_, circuit) in program.circuits.iter() {
+        for (_, member) in circuit.members.borrow().iter() {
+            if let CircuitMember::Function(function) = member {
+                lower_instances(function, functions)?;
+            }
+        }
+    }
+    for (_, function) in program.functions.iter() {
+        lower_instances(function, functions)?;
+    }
+    for (_, (function, _)) in program.test_functions.iter() {
+        lower_instances(function, functions)?;
+    }
+    Ok(())
+}
+
+/// Lowers a function, or each instance of a function with interface parameters.
+fn lower_instances<'a>(function: &'a leo_asg::Function<'a>, functions: &mut Vec<Function<'a>>) -> Result<(), IrError> {
+    if function.template.is_none() {
+        functions.extend(lower_function(function)?);
+        return Ok(());
+    }
+    for instance in function.instances.borrow().values() {
+        functions.extend(lower_function(instance)?);
+    }
+    Ok(())
+}
+
+fn lower_function<'a>(function: &'a leo_asg::Function<'a>) -> Result<Option<Function<'a>>, IrError> {
+    let body = match function.body.get() {
+        Some(body) => body,
+        _ => return Ok(None),
+    };
+
+    let mut lowerer = Lowerer::default();
+    let mut parameters = vec![];
+    let self_variable = match function.qualifier {
+        FunctionQualifier::Static => None,
+        FunctionQualifier::SelfRef | FunctionQualifier::MutSelfRef => function.scope.resolve_variable("self"),
+    };
+    for variable in self_variable
+        .into_iter()
+        .chain(function.arguments.values().map(|variable| variable.get()))
+    {
+        let value = lowerer.define(variable);
+        let variable = variable.borrow();
+        parameters.push(Parameter {
+            name: variable.name.name.clone(),
+            value,
+            type_: variable.type_.clone(),
+        });
+    }
+
+    lowerer.statement(body)?;
+    if lowerer.blocks[lowerer.current].terminator.is_none() {
+        lowerer.terminate(Terminator::Return(None));
+    }
+
+    Ok(Some(Function {
+        name: qualified_name(function),
+        definition: function,
+        parameters,
+        output: function.output.clone(),
+        blocks: lowerer
+            .blocks
+            .into_iter()
+            .enumerate()
+            .map(|(id, block)| BasicBlock {
+                id: BlockId(id),
+                phis: block.phis,
+                instructions: block.instructions,
+                terminator: block.terminator.unwrap_or(Terminator::Unreachable),
+            })
+            .collect(),
+    }))
+}
+
+/// A basic block that is still being lowered.
+#[derive(Default)]
+struct PartialBlock<'a> {
+    phis: Vec<Phi>,
+    instructions: Vec<Instruction<'a>>,
+    terminator: Option<Terminator>,
+}
+
+/// Lowers the body of a function, keeping track of the register that holds each variable.
+struct Lowerer<'a> {
+    blocks: Vec<PartialBlock<'a>>,
+    current: usize,
+    next_value: usize,
+    /// The register of each variable in scope, by variable id.
+    variables: BTreeMap<u32, Value>,
+    /// The span of the expression or statement being lowered.
+    span: Option<Span>,
+}
+
+impl<'a> Default for Lowerer<'a> {
+    fn default() -> Self {
+        Self {
+            blocks: vec![PartialBlock::default()],
+            current: 0,
+            next_value: 0,
+            variables: BTreeMap::new(),
+            span: None,
+        }
+    }
+}
+
+impl<'a> Lowerer<'a> {
+    fn value(&mut self) -> Value {
+        self.next_value += 1;
+        Value(self.next_value - 1)
+    }
+
+    fn define(&mut self, variable: &Variable) -> Value {
+        let value = self.value();
+        self.variables.insert(variable.borrow().id, value);
+        value
+    }
+
+    fn block(&mut self) -> usize {
+        self.blocks.push(PartialBlock::default());
+        self.blocks.len() - 1
+    }
+
+    fn emit(&mut self, operation: Operation<'a>) -> Value {
+        let dest = self.value();
+        self.blocks[self.current].instructions.push(Instruction {
+            dest: Some(dest),
+            operation,
+            span: self.span.clone(),
+        });
+        dest
+    }
+
+    fn emit_effect(&mut self, operation: Operation<'a>) {
+        self.blocks[self.current].instructions.push(Instruction {
+            dest: None,
+            operation,
+            span: self.span.clone(),
+        });
+    }
+
+    fn terminate(&mut self, terminator: Terminator) {
+        self.blocks[self.current].terminator = Some(terminator);
+    }
+
+    fn terminated(&self) -> bool {
+        self.blocks[self.current].terminator.is_some()
+    }
+
+    fn variable(&mut self, variable: &'a Variable<'a>) -> Value {
+        let id = variable.borrow().id;
+        match self.variables.get(&id) {
+            Some(value) => *value,
+            None => self.emit(Operation::Load(variable)),
+        }
+    }
+
+    fn expressions(
+        &mut self,
+        expressions: impl IntoIterator<Item = &'a Expression<'a>>,
+    ) -> Result<Vec<Value>, IrError> {
+        expressions
+            .into_iter()
+            .map(|expression| self.expression(expression))
+            .collect()
+    }
+
+    fn optional(&mut self, expression: Option<&'a Expression<'a>>) -> Result<Option<Value>, IrError> {
+        expression.map(|expression| self.expression(expression)).transpose()
+    }
+
+    fn expression(&mut self, expression: &'a Expression<'a>) -> Result<Value, IrError> {
+        let span = std::mem::replace(&mut self.span, expression.span().cloned());
+        let value = self.expression_node(expression);
+        self.span = span;
+        value
+    }
+
+    fn expression_node(&mut self, expression: &'a Expression<'a>) -> Result<Value, IrError> {
+        let operation = match expression {
+            Expression::VariableRef(reference) => return Ok(self.variable(reference.variable)),
+            Expression::Constant(constant) => Operation::Constant(constant.value.clone()),
+            Expression::Binary(binary) => {
+                let left = self.expression(binary.left.get())?;
+                let right = self.expression(binary.right.get())?;
+                Operation::Binary(binary.operation.clone(), left, right)
+            }
+            Expression::Unary(unary) => Operation::Unary(unary.operation.clone(), self.expression(unary.inner.get())?),
+            Expression::Ternary(ternary) => {
+                let condition = self.expression(ternary.condition.get())?;
+                let if_true = self.expression(ternary.if_true.get())?;
+                let if_false = self.expression(ternary.if_false.get())?;
+                Operation::Select(condition, if_true, if_false)
+            }
+            Expression::ArrayInline(array) => Operation::Array(
+                array
+                    .elements
+                    .iter()
+                    .map(|(element, spread)| Ok((self.expression(element.get())?, *spread)))
+                    .collect::<Result<_, IrError>>()?,
+            ),
+            Expression::ArrayInit(array) => Operation::ArrayRepeat(self.expression(array.element.get())?, array.len),
+            Expression::ArrayAccess(access) => {
+                let array = self.expression(access.array.get())?;
+                let index = self.expression(access.index.get())?;
+                Operation::ArrayGet(array, index)
+            }
+            Expression::ArrayRangeAccess(access) => {
+                let array = self.expression(access.array.get())?;
+                let left = self.optional(access.left.get())?;
+                let right = self.optional(access.right.get())?;
+                Operation::ArraySlice(array, left, right)
+            }
+            Expression::TupleInit(tuple) => {
+                Operation::Tuple(self.expressions(tuple.elements.iter().map(|element| element.get()))?)
+            }
+            Expression::TupleAccess(access) => {
+                Operation::TupleGet(self.expression(access.tuple_ref.get())?, access.index)
+            }
+            Expression::CircuitInit(init) => Operation::Circuit(
+                init.circuit.get(),
+                init.values
+                    .iter()
+                    .map(|(member, value)| Ok((member.clone(), self.expression(value.get())?)))
+                    .collect::<Result<_, IrError>>()?,
+            ),
+            Expression::CircuitAccess(access) => match access.target.get() {
+                Some(target) => Operation::MemberGet(self.expression(target)?, access.member.name.clone()),
+                None => Operation::StaticMember(access.circuit.get(), access.member.name.clone()),
+            },
+            Expression::Call(call) => {
+                let function = call.function.get();
+                if function.qualifier == FunctionQualifier::MutSelfRef {
+                    return Err(IrError::mut_self_call(
+                        &qualified_name(function),
+                        call.span.clone().unwrap_or_default(),
+                    ));
+                }
+                let mut arguments = self.optional(call.target.get())?.into_iter().collect::<Vec<_>>();
+                arguments.extend(self.expressions(call.arguments.iter().map(|argument| argument.get()))?);
+                Operation::Call(function, call.output.clone(), arguments)
+            }
+        };
+        Ok(self.emit(operation))
+    }
+
+    /// Returns the value of an assignment target after the value is assigned to it through the given accesses.
+    fn assign(
+        &mut self,
+        target: Value,
+        accesses: &[AssignAccess<'a>],
+        operation: &AssignOperation,
+        value: Value,
+    ) -> Result<Value, IrError> {
+        let (access, rest) = match accesses.split_first() {
+            Some(split) => split,
+            None => {
+                let operation = match operation {
+                    AssignOperation::Assign => return Ok(value),
+                    AssignOperation::Add => BinaryOperation::Add,
+                    AssignOperation::Sub => BinaryOperation::Sub,
+                    AssignOperation::Mul => BinaryOperation::Mul,
+                    AssignOperation::Div => BinaryOperation::Div,
+                    AssignOperation::Pow => BinaryOperation::Pow,
+                };
+                return Ok(self.emit(Operation::Binary(operation, target, value)));
+            }
+        };
+        Ok(match access {
+            AssignAccess::ArrayIndex(index) => {
+                let index = self.expression(index.get())?;
+                let element = self.emit(Operation::ArrayGet(target, index));
+                let element = self.assign(element, rest, operation, value)?;
+                self.emit(Operation::ArraySet(target, index, element))
+            }
+            AssignAccess::ArrayRange(left, right) => {
+                let left = self.optional(left.get())?;
+                let right = self.optional(right.get())?;
+                let slice = self.emit(Operation::ArraySlice(target, left, right));
+                let slice = self.assign(slice, rest, operation, value)?;
+                self.emit(Operation::ArraySliceSet(target, left, right, slice))
+            }
+            AssignAccess::Tuple(index) => {
+                let element = self.emit(Operation::TupleGet(target, *index));
+                let element = self.assign(element, rest, operation, value)?;
+                self.emit(Operation::TupleSet(target, *index, element))
+            }
+            AssignAccess::Member(member) => {
+                let field = self.emit(Operation::MemberGet(target, member.name.clone()));
+                let field = self.assign(field, rest, operation, value)?;
+                self.emit(Operation::MemberSet(target, member.name.clone(), field))
+            }
+        })
+    }
+
+    fn statement(&mut self, statement: &'a Statement<'a>) -> Result<(), IrError> {
+        // statements after a `return` are never reached
+        if self.terminated() {
+            return Ok(());
+        }
+        let span = std::mem::replace(&mut self.span, statement.span().cloned());
+        let result = self.statement_node(statement);
+        self.span = span;
+        result
+    }
+
+    fn statement_node(&mut self, statement: &'a Statement<'a>) -> Result<(), IrError> {
+        match statement {
+            Statement::Assign(assign) => {
+                let value = self.expression(assign.value.get())?;
+                let variable = assign.target_variable.get();
+                let target = self.variable(variable);
+                let value = self.assign(target, &assign.target_accesses, &assign.operation, value)?;
+                self.variables.insert(variable.borrow().id, value);
+            }
+            Statement::Block(block) => {
+                for statement in block.statements.iter() {
+                    self.statement(statement.get())?;
+                }
+            }
+            Statement::Conditional(conditional) => {
+                let condition = self.expression(conditional.condition.get())?;
+                let before = self.variables.clone();
+                let if_true = self.block();
+                let if_false = conditional.next.get().map(|_| self.block());
+                let join = self.block();
+                self.terminate(Terminator::Branch(
+                    condition,
+                    BlockId(if_true),
+                    BlockId(if_false.unwrap_or(join)),
+                ));
+
+                // the blocks that fall through to the join block, with the variables at their end
+                let mut incoming = vec![];
+                if if_false.is_none() {
+                    incoming.push((self.current, before.clone()));
+                }
+                let branches = std::iter::once((if_true, conditional.result.get()))
+                    .chain(if_false.zip(conditional.next.get()))
+                    .collect::<Vec<_>>();
+                for (block, statement) in branches {
+                    self.current = block;
+                    self.variables = before.clone();
+                    self.statement(statement)?;
+                    if !self.terminated() {
+                        self.terminate(Terminator::Jump(BlockId(join)));
+                        incoming.push((self.current, self.variables.clone()));
+                    }
+                }
+
+                self.current = join;
+                self.variables = before;
+                if let [(_, variables)] = incoming.as_slice() {
+                    self.variables = variables.clone();
+                } else if !incoming.is_empty() {
+                    self.join(&incoming);
+                }
+            }
+            Statement::Console(console) => match &console.function {
+                ConsoleFunction::Assert(condition) => {
+                    let condition = self.expression(condition.get())?;
+                    self.emit_effect(Operation::Assert(condition));
+                }
+                ConsoleFunction::Debug(string) | ConsoleFunction::Error(string) | ConsoleFunction::Log(string) => {
+                    let level = match &console.function {
+                        ConsoleFunction::Debug(_) => LogLevel::Debug,
+                        ConsoleFunction::Error(_) => LogLevel::Error,
+                        _ => LogLevel::Log,
+                    };
+                    let parameters = self.expressions(string.parameters.iter().map(|parameter| parameter.get()))?;
+                    self.emit_effect(Operation::Print(level, string.string.clone(), parameters));
+                }
+            },
+            Statement::Definition(definition) => {
+                let value = self.expression(definition.value.get())?;
+                match definition.variables.as_slice() {
+                    [variable] => {
+                        self.variables.insert(variable.borrow().id, value);
+                    }
+                    variables => {
+                        for (index, variable) in variables.iter().enumerate() {
+                            let element = self.emit(Operation::TupleGet(value, index));
+                            self.variables.insert(variable.borrow().id, element);
+                        }
+                    }
+                }
+            }
+            Statement::Expression(statement) => {
+                self.expression(statement.expression.get())?;
+            }
+            Statement::Iteration(iteration) => {
+                return Err(IrError::loop_not_unrolled(iteration.span.clone().unwrap_or_default()));
+            }
+            Statement::Return(statement) => {
+                let value = self.expression(statement.expression.get())?;
+                self.terminate(Terminator::Return(Some(value)));
+            }
+        }
+        Ok(())
+    }
+
+    /// Merges the variables at the end of the blocks that jump to the current block, with a phi node
+    /// for each variable that holds a different value in some of them.
+    fn join(&mut self, incoming: &[(usize, BTreeMap<u32, Value>)]) {
+        let ids = self.variables.keys().copied().collect::<Vec<_>>();
+        for id in ids {
+            let values = incoming
+                .iter()
+                .map(|(block, variables)| (BlockId(*block), variables[&id]))
+                .collect::<Vec<_>>();
+            let value = if values.iter().all(|(_, value)| *value == values[0].1) {
+                values[0].1
+            } else {
+                let dest = self.value();
+                self.blocks[self.current].phis.push(Phi { dest, incoming: values });
+                dest
+            };
+            self.variables.insert(id, value);
+        }
+    }
+}
@@ -0,0 +1,33 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! An intermediate representation of a Leo program in static single assignment form.
+//!
+//! Every value is defined once, by an instruction or a phi node. Conditional statements are lowered
+//! to branches between basic blocks, and a phi node at the end of a conditional stands in for the
+//! selection between the values a variable has in each branch. Loops must be unrolled before a
+//! program is lowered.
+//!
+//! The ir is emitted for review with `leo build --emit ir`. Constraints are generated from the asg by
+//! default, and from the ir by [`Compiler::compile_ir_constraints`](crate::Compiler::compile_ir_constraints).
+
+pub mod enforce;
+
+pub mod lower;
+pub use self::lower::*;
+
+pub mod program;
+pub use self::program::*;
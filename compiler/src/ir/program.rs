@@ -0,0 +1,359 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The instructions, basic blocks and functions of the ir.
+
+use leo_asg::{Circuit, ConstValue, GroupValue, Identifier, Span, Type, Variable};
+use leo_ast::{BinaryOperation, UnaryOperation};
+
+use std::fmt;
+
+/// A register that holds the result of one instruction or phi node.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Value(pub usize);
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "%{}", self.0)
+    }
+}
+
+/// The index of a basic block in its function.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BlockId(pub usize);
+
+impl fmt::Display for BlockId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "block{}", self.0)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LogLevel {
+    Debug,
+    Error,
+    Log,
+}
+
+#[derive(Clone)]
+pub enum Operation<'a> {
+    Constant(ConstValue),
+    /// A variable defined outside of the function, like `input`.
+    Load(&'a Variable<'a>),
+    Binary(BinaryOperation, Value, Value),
+    Unary(UnaryOperation, Value),
+    /// Chooses the second value if the first is `true`, and the third otherwise.
+    Select(Value, Value, Value),
+    /// An inline array, where elements marked `true` are spread.
+    Array(Vec<(Value, bool)>),
+    ArrayRepeat(Value, usize),
+    ArrayGet(Value, Value),
+    ArraySlice(Value, Option<Value>, Option<Value>),
+    /// A copy of an array with the element at an index replaced.
+    ArraySet(Value, Value, Value),
+    /// A copy of an array with a range of elements replaced.
+    ArraySliceSet(Value, Option<Value>, Option<Value>, Value),
+    Tuple(Vec<Value>),
+    TupleGet(Value, usize),
+    TupleSet(Value, usize, Value),
+    Circuit(&'a Circuit<'a>, Vec<(Identifier, Value)>),
+    MemberGet(Value, String),
+    /// A member of a circuit that is accessed without an instance.
+    StaticMember(&'a Circuit<'a>, String),
+    MemberSet(Value, String, Value),
+    /// A call to a function with the given output type, taking the target of a method as its first argument.
+    Call(&'a leo_asg::Function<'a>, Type<'a>, Vec<Value>),
+    Assert(Value),
+    Print(LogLevel, String, Vec<Value>),
+}
+
+#[derive(Clone)]
+pub struct Instruction<'a> {
+    /// The register the result is stored in, `None` for instructions without a result.
+    pub dest: Option<Value>,
+    pub operation: Operation<'a>,
+    /// The span of the expression or statement the instruction was lowered from.
+    pub span: Option<Span>,
+}
+
+/// Takes the value from the block that control came from.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Phi {
+    pub dest: Value,
+    pub incoming: Vec<(BlockId, Value)>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Terminator {
+    Jump(BlockId),
+    Branch(Value, BlockId, BlockId),
+    Return(Option<Value>),
+    /// Ends a block that control never reaches, like the end of a conditional whose branches all return.
+    Unreachable,
+}
+
+#[derive(Clone)]
+pub struct BasicBlock<'a> {
+    pub id: BlockId,
+    pub phis: Vec<Phi>,
+    pub instructions: Vec<Instruction<'a>>,
+    pub terminator: Terminator,
+}
+
+#[derive(Clone)]
+pub struct Parameter<'a> {
+    pub name: String,
+    pub value: Value,
+    pub type_: Type<'a>,
+}
+
+#[derive(Clone)]
+pub struct Function<'a> {
+    /// The name of the function, prefixed with its circuit for methods.
+    pub name: String,
+    /// The asg function the function was lowered from.
+    pub definition: &'a leo_asg::Function<'a>,
+    pub parameters: Vec<Parameter<'a>>,
+    pub output: Type<'a>,
+    /// The basic blocks of the function, the first of which is its entry.
+    pub blocks: Vec<BasicBlock<'a>>,
+}
+
+#[derive(Clone)]
+pub struct Program<'a> {
+    pub name: String,
+    pub functions: Vec<Function<'a>>,
+}
+
+impl<'a> Program<'a> {
+    /// Returns the function lowered from the given asg function, if it has a body.
+    pub fn function(&self, definition: &leo_asg::Function<'a>) -> Option<&Function<'a>> {
+        self.functions
+            .iter()
+            .find(|function| std::ptr::eq(function.definition, definition))
+    }
+}
+
+/// Returns the name of a function, prefixed with its circuit for methods.
+pub fn qualified_name(function: &leo_asg::Function) -> String {
+    match function.circuit.get() {
+        Some(circuit) => format!("{}::{}", circuit.name.borrow().name, function.name.borrow().name),
+        None => function.name.borrow().name.clone(),
+    }
+}
+
+/// Writes values separated by commas.
+fn list<T: fmt::Display>(f: &mut fmt::Formatter, values: impl IntoIterator<Item = T>) -> fmt::Result {
+    for (i, value) in values.into_iter().enumerate() {
+        if i > 0 {
+            write!(f, ", ")?;
+        }
+        write!(f, "{}", value)?;
+    }
+    Ok(())
+}
+
+struct Bound(Option<Value>);
+
+impl fmt::Display for Bound {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.0 {
+            Some(value) => write!(f, "{}", value),
+            None => write!(f, "_"),
+        }
+    }
+}
+
+struct Constant<'a>(&'a ConstValue);
+
+impl<'a> fmt::Display for Constant<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.0 {
+            ConstValue::Int(value) => write!(f, "{}{}", value.raw_value(), value.get_int_type()),
+            ConstValue::Group(GroupValue::Single(value)) => write!(f, "{}group", value),
+            ConstValue::Group(GroupValue::Tuple(x, y)) => write!(f, "({}, {})group", x, y),
+            ConstValue::Field(value) => write!(f, "{}field", value),
+            ConstValue::Fixed(value) => write!(f, "{}{}", value, value.type_),
+            ConstValue::Address(value) => write!(f, "{}", value),
+            ConstValue::Boolean(value) => write!(f, "{}", value),
+            ConstValue::Tuple(values) => {
+                write!(f, "(")?;
+                list(f, values.iter().map(Constant))?;
+                write!(f, ")")
+            }
+            ConstValue::Array(values) => {
+                write!(f, "[")?;
+                list(f, values.iter().map(Constant))?;
+                write!(f, "]")
+            }
+        }
+    }
+}
+
+impl<'a> fmt::Display for Operation<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Operation::Constant(value) => write!(f, "const {}", Constant(value)),
+            Operation::Load(variable) => write!(f, "load {}", variable.borrow().name.name),
+            Operation::Binary(operation, left, right) => {
+                write!(f, "{} {}, {}", format!("{:?}", operation).to_lowercase(), left, right)
+            }
+            Operation::Unary(operation, inner) => write!(f, "{} {}", format!("{:?}", operation).to_lowercase(), inner),
+            Operation::Select(condition, if_true, if_false) => {
+                write!(f, "select {}, {}, {}", condition, if_true, if_false)
+            }
+            Operation::Array(elements) => {
+                write!(f, "array [")?;
+                list(
+                    f,
+                    elements.iter().map(|(element, spread)| {
+                        if *spread {
+                            format!("...{}", element)
+                        } else {
+                            element.to_string()
+                        }
+                    }),
+                )?;
+                write!(f, "]")
+            }
+            Operation::ArrayRepeat(element, len) => write!(f, "array [{}; {}]", element, len),
+            Operation::ArrayGet(array, index) => write!(f, "array_get {}, {}", array, index),
+            Operation::ArraySlice(array, left, right) => {
+                write!(f, "array_slice {}, {}..{}", array, Bound(*left), Bound(*right))
+            }
+            Operation::ArraySet(array, index, value) => write!(f, "array_set {}, {}, {}", array, index, value),
+            Operation::ArraySliceSet(array, left, right, value) => write!(
+                f,
+                "array_slice_set {}, {}..{}, {}",
+                array,
+                Bound(*left),
+                Bound(*right),
+                value
+            ),
+            Operation::Tuple(elements) => {
+                write!(f, "tuple (")?;
+                list(f, elements)?;
+                write!(f, ")")
+            }
+            Operation::TupleGet(tuple, index) => write!(f, "tuple_get {}, {}", tuple, index),
+            Operation::TupleSet(tuple, index, value) => write!(f, "tuple_set {}, {}, {}", tuple, index, value),
+            Operation::Circuit(circuit, members) => {
+                write!(f, "circuit {} {{ ", circuit.name.borrow().name)?;
+                list(
+                    f,
+                    members
+                        .iter()
+                        .map(|(member, value)| format!("{}: {}", member.name, value)),
+                )?;
+                write!(f, " }}")
+            }
+            Operation::MemberGet(target, member) => write!(f, "member_get {}, {}", target, member),
+            Operation::StaticMember(circuit, member) => {
+                write!(f, "static_member {}::{}", circuit.name.borrow().name, member)
+            }
+            Operation::MemberSet(target, member, value) => write!(f, "member_set {}, {}, {}", target, member, value),
+            Operation::Call(function, _, arguments) => {
+                write!(f, "call {}(", qualified_name(function))?;
+                list(f, arguments)?;
+                write!(f, ")")
+            }
+            Operation::Assert(condition) => write!(f, "assert {}", condition),
+            Operation::Print(level, string, parameters) => {
+                write!(f, "{} {:?}", format!("{:?}", level).to_lowercase(), string)?;
+                for parameter in parameters {
+                    write!(f, ", {}", parameter)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl<'a> fmt::Display for Instruction<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.dest {
+            Some(dest) => write!(f, "{} = {}", dest, self.operation),
+            None => write!(f, "{}", self.operation),
+        }
+    }
+}
+
+impl fmt::Display for Phi {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} = phi ", self.dest)?;
+        list(
+            f,
+            self.incoming
+                .iter()
+                .map(|(block, value)| format!("[{}, {}]", value, block)),
+        )
+    }
+}
+
+impl fmt::Display for Terminator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Terminator::Jump(block) => write!(f, "jump {}", block),
+            Terminator::Branch(condition, if_true, if_false) => {
+                write!(f, "branch {}, {}, {}", condition, if_true, if_false)
+            }
+            Terminator::Return(Some(value)) => write!(f, "return {}", value),
+            Terminator::Return(None) => write!(f, "return"),
+            Terminator::Unreachable => write!(f, "unreachable"),
+        }
+    }
+}
+
+impl<'a> fmt::Display for BasicBlock<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{}:", self.id)?;
+        for phi in self.phis.iter() {
+            writeln!(f, "    {}", phi)?;
+        }
+        for instruction in self.instructions.iter() {
+            writeln!(f, "    {}", instruction)?;
+        }
+        writeln!(f, "    {}", self.terminator)
+    }
+}
+
+impl<'a> fmt::Display for Function<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "function {}(", self.name)?;
+        list(
+            f,
+            self.parameters
+                .iter()
+                .map(|parameter| format!("{} {}: {}", parameter.value, parameter.name, parameter.type_)),
+        )?;
+        writeln!(f, ") -> {} {{", self.output)?;
+        for block in self.blocks.iter() {
+            write!(f, "{}", block)?;
+        }
+        writeln!(f, "}}")
+    }
+}
+
+impl<'a> fmt::Display for Program<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "program {}", self.name)?;
+        for function in self.functions.iter() {
+            writeln!(f)?;
+            write!(f, "{}", function)?;
+        }
+        Ok(())
+    }
+}
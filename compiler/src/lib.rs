@@ -41,6 +41,11 @@ pub use self::expression::*;
 pub mod function;
 pub use self::function::*;
 
+pub mod ir;
+
+pub mod option;
+pub use self::option::*;
+
 pub mod output;
 pub use self::output::*;
 
@@ -50,6 +55,8 @@ pub use self::program::*;
 pub mod statement;
 pub use self::statement::*;
 
+pub mod soundness;
+
 pub mod prelude;
 pub use self::prelude::*;
 
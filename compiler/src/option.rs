@@ -0,0 +1,35 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use std::collections::BTreeMap;
+
+/// Toggles the optimizations run on the asg before constraint generation.
+#[derive(Clone, Debug)]
+pub struct CompilerOptions {
+    /// Runs the transform passes, `false` at `-O0`.
+    pub optimize: bool,
+    /// Switches individual passes on or off by name, applied before `optimize`.
+    pub passes: BTreeMap<String, bool>,
+}
+
+impl Default for CompilerOptions {
+    fn default() -> Self {
+        CompilerOptions {
+            optimize: true,
+            passes: BTreeMap::new(),
+        }
+    }
+}
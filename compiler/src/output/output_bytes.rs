@@ -67,7 +67,7 @@ impl OutputBytes {
             let name = parameter.variable.name;
 
             // Check register type == return value type.
-            let register_type = program.scope.resolve_ast_type(&parameter.type_)?;
+            let register_type = program.scope.resolve_ast_type(&parameter.type_, &parameter.span)?;
             let return_value_type = value.to_type(&span)?;
 
             if !register_type.is_assignable_from(&return_value_type) {
@@ -0,0 +1,86 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::CoreCircuit;
+use crate::{enforce_or, errors::ExpressionError, evaluate_eq, ConstrainedValue, GroupType, Integer};
+use leo_asg::{Function, Span, Type};
+use snarkvm_models::{
+    curves::PrimeField,
+    gadgets::{
+        r1cs::ConstraintSystem,
+        utilities::{boolean::Boolean, uint::UInt32},
+    },
+};
+
+/// The intrinsic methods of array values.
+pub struct ArrayIntrinsics;
+
+fn unwrap_array<'a, F: PrimeField, G: GroupType<F>>(
+    value: ConstrainedValue<'a, F, G>,
+) -> Vec<ConstrainedValue<'a, F, G>> {
+    if let ConstrainedValue::Array(array) = value {
+        array
+    } else {
+        panic!("illegal non-array type in array intrinsic call");
+    }
+}
+
+impl<'a, F: PrimeField, G: GroupType<F>> CoreCircuit<'a, F, G> for ArrayIntrinsics {
+    fn call_function<CS: ConstraintSystem<F>>(
+        &self,
+        cs: &mut CS,
+        function: &'a Function<'a>,
+        _output: &Type<'a>,
+        span: &Span,
+        target: Option<ConstrainedValue<'a, F, G>>,
+        mut arguments: Vec<ConstrainedValue<'a, F, G>>,
+    ) -> Result<ConstrainedValue<'a, F, G>, ExpressionError> {
+        let name = function.name.borrow().name.clone();
+        let mut array = unwrap_array(target.expect("missing array intrinsic target")); // asg enforced
+        let mut cs = cs.ns(|| format!("array {} {}:{}", name, span.line, span.start));
+
+        Ok(match &*name {
+            "len" => ConstrainedValue::Integer(Integer::U32(UInt32::constant(array.len() as u32))),
+            "reverse" => {
+                array.reverse();
+                ConstrainedValue::Array(array)
+            }
+            "contains" => {
+                assert_eq!(arguments.len(), 1); // asg enforced
+                let element = arguments.remove(0);
+
+                let mut found = ConstrainedValue::Boolean(Boolean::constant(false));
+                for (i, value) in array.into_iter().enumerate() {
+                    let equal = evaluate_eq(&mut cs.ns(|| format!("element {}", i)), value, element.clone(), span)?;
+                    found = enforce_or(&mut cs.ns(|| format!("found {}", i)), found, equal, span)?;
+                }
+                found
+            }
+            "concat" => {
+                assert_eq!(arguments.len(), 1); // asg enforced
+                array.extend(unwrap_array(arguments.remove(0)));
+                ConstrainedValue::Array(array)
+            }
+            _ => {
+                return Err(ExpressionError::undefined_member_access(
+                    "array".to_string(),
+                    name,
+                    span.to_owned(),
+                ));
+            }
+        })
+    }
+}
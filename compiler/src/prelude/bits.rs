@@ -0,0 +1,62 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::CoreCircuit;
+use crate::{errors::ExpressionError, ConstrainedValue, GroupType, Integer};
+use leo_asg::{Function, Span, Type};
+use snarkvm_models::{curves::PrimeField, gadgets::r1cs::ConstraintSystem};
+
+/// The functions of `core.bits`, converting between integers and their little-endian bits.
+pub struct Bits;
+
+impl<'a, F: PrimeField, G: GroupType<F>> CoreCircuit<'a, F, G> for Bits {
+    fn call_function<CS: ConstraintSystem<F>>(
+        &self,
+        _cs: &mut CS,
+        function: &'a Function<'a>,
+        output: &Type<'a>,
+        span: &Span,
+        target: Option<ConstrainedValue<'a, F, G>>,
+        mut arguments: Vec<ConstrainedValue<'a, F, G>>,
+    ) -> Result<ConstrainedValue<'a, F, G>, ExpressionError> {
+        assert_eq!(arguments.len(), 1); // asg enforced
+        assert!(target.is_none()); // asg enforced
+        let name = function.name.borrow().name.clone();
+
+        Ok(match (arguments.remove(0), output) {
+            (ConstrainedValue::Integer(integer), _) if name.ends_with("_to_bits") => {
+                ConstrainedValue::Array(integer.get_bits().into_iter().map(ConstrainedValue::Boolean).collect())
+            }
+            (ConstrainedValue::Array(bits), Type::Integer(integer_type)) if name.ends_with("_from_bits") => {
+                let bits = bits
+                    .into_iter()
+                    .map(|bit| match bit {
+                        ConstrainedValue::Boolean(bit) => bit,
+                        _ => panic!("illegal non-bool type in bits call"),
+                    })
+                    .collect();
+                ConstrainedValue::Integer(Integer::from_bits_le(integer_type, bits))
+            }
+            _ => {
+                return Err(ExpressionError::undefined_static_access(
+                    "Bits".to_string(),
+                    name,
+                    span.to_owned(),
+                ));
+            }
+        })
+    }
+}
@@ -16,7 +16,7 @@
 
 use super::CoreCircuit;
 use crate::{errors::ExpressionError, ConstrainedValue, GroupType, Integer};
-use leo_asg::{Function, Span};
+use leo_asg::{Function, Span, Type};
 use snarkvm_gadgets::algorithms::prf::Blake2sGadget;
 use snarkvm_models::{
     curves::PrimeField,
@@ -51,6 +51,7 @@ impl<'a, F: PrimeField, G: GroupType<F>> CoreCircuit<'a, F, G> for Blake2s {
         &self,
         cs: &mut CS,
         function: &'a Function<'a>,
+        _output: &Type<'a>,
         span: &Span,
         target: Option<ConstrainedValue<'a, F, G>>,
         mut arguments: Vec<ConstrainedValue<'a, F, G>>,
@@ -0,0 +1,72 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::CoreCircuit;
+use crate::{errors::ExpressionError, ConstrainedValue, FieldType, GroupType, Integer};
+use leo_asg::{Function, Span, Type};
+use snarkvm_models::{curves::PrimeField, gadgets::r1cs::ConstraintSystem};
+
+/// The intrinsic methods of `field` values.
+pub struct FieldIntrinsics;
+
+fn unwrap_field<F: PrimeField, G: GroupType<F>>(value: ConstrainedValue<F, G>) -> FieldType<F> {
+    if let ConstrainedValue::Field(field) = value {
+        field
+    } else {
+        panic!("illegal non-field type in field intrinsic call");
+    }
+}
+
+impl<'a, F: PrimeField, G: GroupType<F>> CoreCircuit<'a, F, G> for FieldIntrinsics {
+    fn call_function<CS: ConstraintSystem<F>>(
+        &self,
+        cs: &mut CS,
+        function: &'a Function<'a>,
+        _output: &Type<'a>,
+        span: &Span,
+        target: Option<ConstrainedValue<'a, F, G>>,
+        mut arguments: Vec<ConstrainedValue<'a, F, G>>,
+    ) -> Result<ConstrainedValue<'a, F, G>, ExpressionError> {
+        let name = function.name.borrow().name.clone();
+        let value = unwrap_field(target.expect("missing field intrinsic target")); // asg enforced
+        let mut cs = cs.ns(|| format!("field {} {}:{}", name, span.line, span.start));
+
+        Ok(match &*name {
+            "inv" => ConstrainedValue::Field(value.inv(cs, span)?),
+            "square" => ConstrainedValue::Field(value.square(cs, span)?),
+            "double" => ConstrainedValue::Field(value.double(cs, span)?),
+            "pow" => {
+                assert_eq!(arguments.len(), 1); // asg enforced
+                let exponent = unwrap_field(arguments.remove(0));
+                ConstrainedValue::Field(value.pow(cs, &exponent, span)?)
+            }
+            "sqrt" => {
+                let (root, is_valid) = value.sqrt(&mut cs, span)?;
+                ConstrainedValue::Tuple(vec![ConstrainedValue::Field(root), ConstrainedValue::Boolean(is_valid)])
+            }
+            "is_zero" => ConstrainedValue::Boolean(value.is_zero(&mut cs, span)?),
+            "to_u256" => ConstrainedValue::Integer(Integer::U256(value.to_u256(cs, span)?)),
+            "to_i256" => ConstrainedValue::Integer(Integer::I256(value.to_i256(cs, span)?)),
+            _ => {
+                return Err(ExpressionError::undefined_member_access(
+                    "field".to_string(),
+                    name,
+                    span.to_owned(),
+                ));
+            }
+        })
+    }
+}
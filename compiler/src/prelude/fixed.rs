@@ -0,0 +1,74 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::CoreCircuit;
+use crate::{errors::ExpressionError, ConstrainedValue, Fixed, GroupType};
+use leo_asg::{Function, Rounding, Span, Type};
+use snarkvm_models::{curves::PrimeField, gadgets::r1cs::ConstraintSystem};
+
+/// The intrinsic methods of `fixed` values.
+pub struct FixedIntrinsics;
+
+fn unwrap_fixed<F: PrimeField, G: GroupType<F>>(value: ConstrainedValue<F, G>) -> Fixed {
+    if let ConstrainedValue::Fixed(fixed) = value {
+        fixed
+    } else {
+        panic!("illegal non-fixed type in fixed intrinsic call");
+    }
+}
+
+impl<'a, F: PrimeField, G: GroupType<F>> CoreCircuit<'a, F, G> for FixedIntrinsics {
+    fn call_function<CS: ConstraintSystem<F>>(
+        &self,
+        cs: &mut CS,
+        function: &'a Function<'a>,
+        output: &Type<'a>,
+        span: &Span,
+        target: Option<ConstrainedValue<'a, F, G>>,
+        mut arguments: Vec<ConstrainedValue<'a, F, G>>,
+    ) -> Result<ConstrainedValue<'a, F, G>, ExpressionError> {
+        let name = function.name.borrow().name.clone();
+        let value = unwrap_fixed(target.expect("missing fixed intrinsic target")); // asg enforced
+        let mut cs = cs.ns(|| format!("fixed {} {}:{}", name, span.line, span.start));
+
+        let undefined = || ExpressionError::undefined_member_access("fixed".to_string(), name.clone(), span.to_owned());
+        let rounding = |prefix: &str| {
+            name.strip_prefix(prefix)
+                .and_then(Rounding::from_name)
+                .ok_or_else(undefined)
+        };
+
+        Ok(match (&*name, output) {
+            ("mul_floor", _) | ("mul_ceil", _) | ("mul_round", _) => {
+                assert_eq!(arguments.len(), 1); // asg enforced
+                let other = unwrap_fixed(arguments.remove(0));
+                ConstrainedValue::Fixed(value.mul(&mut cs, other, rounding("mul_")?, span)?)
+            }
+            ("div_floor", _) | ("div_ceil", _) | ("div_round", _) => {
+                assert_eq!(arguments.len(), 1); // asg enforced
+                let other = unwrap_fixed(arguments.remove(0));
+                ConstrainedValue::Fixed(value.div(&mut cs, other, rounding("div_")?, span)?)
+            }
+            ("floor", _) | ("ceil", _) | ("round", _) | ("trunc", _) => {
+                ConstrainedValue::Fixed(value.round(&mut cs, rounding("")?, span)?)
+            }
+            (_, Type::Integer(integer_type)) if name.starts_with("to_") => {
+                ConstrainedValue::Integer(value.to_integer(&mut cs, integer_type, span)?)
+            }
+            _ => return Err(undefined()),
+        })
+    }
+}
@@ -0,0 +1,60 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::CoreCircuit;
+use crate::{errors::ExpressionError, ConstrainedValue, Fixed, GroupType, Integer};
+use leo_asg::{Function, Span, Type};
+use snarkvm_models::{curves::PrimeField, gadgets::r1cs::ConstraintSystem};
+
+/// The intrinsic methods of integer values.
+pub struct IntegerIntrinsics;
+
+fn unwrap_integer<F: PrimeField, G: GroupType<F>>(value: ConstrainedValue<F, G>) -> Integer {
+    if let ConstrainedValue::Integer(integer) = value {
+        integer
+    } else {
+        panic!("illegal non-integer type in integer intrinsic call");
+    }
+}
+
+impl<'a, F: PrimeField, G: GroupType<F>> CoreCircuit<'a, F, G> for IntegerIntrinsics {
+    fn call_function<CS: ConstraintSystem<F>>(
+        &self,
+        cs: &mut CS,
+        function: &'a Function<'a>,
+        output: &Type<'a>,
+        span: &Span,
+        target: Option<ConstrainedValue<'a, F, G>>,
+        _arguments: Vec<ConstrainedValue<'a, F, G>>,
+    ) -> Result<ConstrainedValue<'a, F, G>, ExpressionError> {
+        let name = function.name.borrow().name.clone();
+        let value = unwrap_integer(target.expect("missing integer intrinsic target")); // asg enforced
+
+        Ok(match (&*name, output) {
+            ("to_field", _) => ConstrainedValue::Field(value.to_field(cs, span)?),
+            ("to_fixed", Type::Fixed(fixed_type)) => {
+                ConstrainedValue::Fixed(Fixed::from_integer(cs, fixed_type, &value, span)?)
+            }
+            _ => {
+                return Err(ExpressionError::undefined_member_access(
+                    "integer".to_string(),
+                    name,
+                    span.to_owned(),
+                ));
+            }
+        })
+    }
+}
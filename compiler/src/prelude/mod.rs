@@ -14,11 +14,26 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
+pub mod array;
+pub use array::*;
+
+pub mod bits;
+pub use bits::*;
+
 pub mod blake2s;
 pub use blake2s::*;
 
+pub mod field;
+pub use field::*;
+
+pub mod fixed;
+pub use fixed::*;
+
+pub mod integer;
+pub use integer::*;
+
 use crate::{errors::ExpressionError, ConstrainedValue, GroupType};
-use leo_asg::{Function, Span};
+use leo_asg::{Function, Span, Type};
 use snarkvm_models::{curves::PrimeField, gadgets::r1cs::ConstraintSystem};
 
 pub trait CoreCircuit<'a, F: PrimeField, G: GroupType<F>>: Send + Sync {
@@ -26,15 +41,56 @@ pub trait CoreCircuit<'a, F: PrimeField, G: GroupType<F>>: Send + Sync {
         &self,
         cs: &mut CS,
         function: &'a Function<'a>,
+        output: &Type<'a>,
         span: &Span,
         target: Option<ConstrainedValue<'a, F, G>>,
         arguments: Vec<ConstrainedValue<'a, F, G>>,
     ) -> Result<ConstrainedValue<'a, F, G>, ExpressionError>;
 }
 
-pub fn resolve_core_circuit<'a, F: PrimeField, G: GroupType<F>>(name: &str) -> impl CoreCircuit<'a, F, G> {
+/// A core circuit implementation, selected by the `core_mapping` of an asg circuit.
+pub enum ResolvedCoreCircuit {
+    Array(ArrayIntrinsics),
+    Bits(Bits),
+    Blake2s(Blake2s),
+    Field(FieldIntrinsics),
+    Fixed(FixedIntrinsics),
+    Integer(IntegerIntrinsics),
+}
+
+impl<'a, F: PrimeField, G: GroupType<F>> CoreCircuit<'a, F, G> for ResolvedCoreCircuit {
+    fn call_function<CS: ConstraintSystem<F>>(
+        &self,
+        cs: &mut CS,
+        function: &'a Function<'a>,
+        output: &Type<'a>,
+        span: &Span,
+        target: Option<ConstrainedValue<'a, F, G>>,
+        arguments: Vec<ConstrainedValue<'a, F, G>>,
+    ) -> Result<ConstrainedValue<'a, F, G>, ExpressionError> {
+        match self {
+            ResolvedCoreCircuit::Array(circuit) => circuit.call_function(cs, function, output, span, target, arguments),
+            ResolvedCoreCircuit::Bits(circuit) => circuit.call_function(cs, function, output, span, target, arguments),
+            ResolvedCoreCircuit::Blake2s(circuit) => {
+                circuit.call_function(cs, function, output, span, target, arguments)
+            }
+            ResolvedCoreCircuit::Field(circuit) => circuit.call_function(cs, function, output, span, target, arguments),
+            ResolvedCoreCircuit::Fixed(circuit) => circuit.call_function(cs, function, output, span, target, arguments),
+            ResolvedCoreCircuit::Integer(circuit) => {
+                circuit.call_function(cs, function, output, span, target, arguments)
+            }
+        }
+    }
+}
+
+pub fn resolve_core_circuit(name: &str) -> ResolvedCoreCircuit {
     match name {
-        "blake2s" => Blake2s,
+        "array" => ResolvedCoreCircuit::Array(ArrayIntrinsics),
+        "bits" => ResolvedCoreCircuit::Bits(Bits),
+        "blake2s" => ResolvedCoreCircuit::Blake2s(Blake2s),
+        "field" => ResolvedCoreCircuit::Field(FieldIntrinsics),
+        "fixed" => ResolvedCoreCircuit::Fixed(FixedIntrinsics),
+        "integer" => ResolvedCoreCircuit::Integer(IntegerIntrinsics),
         _ => unimplemented!("invalid core circuit: {}", name),
     }
 }
@@ -18,14 +18,17 @@
 
 use crate::{value::ConstrainedValue, GroupType};
 
-use leo_asg::Program;
+use leo_asg::{Program, Span};
 use snarkvm_models::curves::PrimeField;
 
 use indexmap::IndexMap;
+use std::collections::HashMap;
 
 pub struct ConstrainedProgram<'a, F: PrimeField, G: GroupType<F>> {
     pub asg: Program<'a>,
     identifiers: IndexMap<u32, ConstrainedValue<'a, F, G>>,
+    /// How many nodes were evaluated at each `line:start` position
+    evaluations: HashMap<(usize, usize), usize>,
 }
 
 impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
@@ -33,6 +36,7 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
         Self {
             asg,
             identifiers: IndexMap::new(),
+            evaluations: HashMap::new(),
         }
     }
 
@@ -47,4 +51,14 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
     pub(crate) fn get_mut(&mut self, id: u32) -> Option<&mut ConstrainedValue<'a, F, G>> {
         self.identifiers.get_mut(&id)
     }
+
+    ///
+    /// Counts an evaluation of a node at the position of `span`, and returns how many nodes were
+    /// evaluated there so far.
+    ///
+    pub(crate) fn count_evaluation(&mut self, span: &Span) -> usize {
+        let count = self.evaluations.entry((span.line, span.start)).or_insert(0);
+        *count += 1;
+        *count
+    }
 }
@@ -0,0 +1,165 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Finds variables that the synthesized constraints leave unconstrained.
+
+use crate::soundness::{SoundnessIssue, SoundnessWarning, MAIN_INPUT_PREFIX};
+use leo_ast::Span;
+use leo_synthesizer::NamespacedSynthesizer;
+
+use indexmap::IndexMap;
+use snarkvm_models::{curves::PairingEngine, gadgets::r1cs::Index};
+
+/// Returns the `line:start` position at the end of a namespace name, if any.
+fn parse_position(name: &str) -> Option<(usize, usize)> {
+    let mut position = name.rsplit(' ').next()?.split(':');
+    let line = position.next()?.parse().ok()?;
+    let start = position.next()?.parse().ok()?;
+
+    match position.next() {
+        None if line > 0 => Some((line, start)),
+        _ => None,
+    }
+}
+
+/// Returns the top-level namespace of `path` if it holds a main function input.
+fn main_input(path: &[String]) -> Option<&str> {
+    path.first()
+        .map(String::as_str)
+        .filter(|name| name.starts_with(MAIN_INPUT_PREFIX))
+}
+
+/// Returns the span at `line:start` with the given length in `program_string`.
+fn span_at(program_string: &str, (line, start): (usize, usize), length: usize) -> Span {
+    let text = line
+        .checked_sub(1)
+        .and_then(|index| program_string.lines().nth(index))
+        .unwrap_or_default();
+
+    Span {
+        text: format!(" {}", text.trim_end()),
+        line,
+        start,
+        end: start + length,
+        line_end: line,
+        ..Default::default()
+    }
+}
+
+///
+/// Returns warnings for the private variables and main function inputs in `synthesizer` that are not
+/// constrained.
+///
+/// Variables are reported at the innermost namespace carrying a source position. Main function inputs
+/// are reported when every constraint on them was enforced while allocating the input itself.
+///
+pub fn check_constraints<E: PairingEngine>(
+    synthesizer: &NamespacedSynthesizer<E>,
+    program_string: &str,
+) -> Vec<SoundnessWarning> {
+    let circuit = &synthesizer.circuit;
+    let mut constrained = vec![false; synthesizer.private_names.len()];
+    let mut influential = IndexMap::new();
+
+    for path in synthesizer.private_names.iter().chain(synthesizer.public_names.iter()) {
+        if let Some(input) = main_input(path) {
+            influential.entry(input).or_insert(false);
+        }
+    }
+
+    for (index, name) in synthesizer.constraint_names.iter().enumerate() {
+        let namespace = name.first().map(String::as_str);
+        let terms = circuit.at[index]
+            .iter()
+            .chain(circuit.bt[index].iter())
+            .chain(circuit.ct[index].iter());
+
+        for (_, variable) in terms {
+            let path = match variable {
+                Index::Private(variable) => {
+                    constrained[*variable] = true;
+                    &synthesizer.private_names[*variable]
+                }
+                Index::Public(variable) => &synthesizer.public_names[*variable],
+            };
+
+            if let Some(input) = main_input(path) {
+                if Some(input) != namespace {
+                    influential.insert(input, true);
+                }
+            }
+        }
+    }
+
+    let mut warnings = vec![];
+
+    for (input, _) in influential.into_iter().filter(|(_, influential)| !influential) {
+        let name = input[MAIN_INPUT_PREFIX.len()..]
+            .trim_start()
+            .split('`')
+            .nth(1)
+            .unwrap_or_default();
+
+        // The `input` keyword bundles all registers, records and state, most of which are typically unused.
+        if name == "input" {
+            continue;
+        }
+
+        if let Some(position) = parse_position(input) {
+            warnings.push(SoundnessWarning::new(
+                SoundnessIssue::UninfluentialInput,
+                format!("main input `{}` does not influence any constraint", name),
+                span_at(program_string, position, name.len()),
+            ));
+        }
+    }
+
+    let mut unconstrained = IndexMap::new();
+
+    for (path, _) in synthesizer
+        .private_names
+        .iter()
+        .zip(constrained)
+        .filter(|(path, constrained)| !constrained && main_input(path).is_none())
+    {
+        let position = path
+            .iter()
+            .rev()
+            .find_map(|name| parse_position(name))
+            .unwrap_or_default();
+
+        *unconstrained.entry(position).or_insert(0usize) += 1;
+    }
+
+    for (position, count) in unconstrained {
+        let message = if count == 1 {
+            "private variable allocated here does not appear in any constraint".to_string()
+        } else {
+            format!(
+                "{} private variables allocated here do not appear in any constraint",
+                count
+            )
+        };
+
+        warnings.push(SoundnessWarning::new(
+            SoundnessIssue::UnconstrainedVariable,
+            message,
+            span_at(program_string, position, 1),
+        ));
+    }
+
+    warnings
+}
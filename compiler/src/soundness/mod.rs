@@ -0,0 +1,40 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Detects under-constrained witnesses in a synthesized Leo program.
+
+pub mod constraints;
+pub use self::constraints::*;
+
+pub mod output;
+pub use self::output::*;
+
+pub mod warning;
+pub use self::warning::*;
+
+use leo_ast::Span;
+
+/// The prefix of the namespace each main function input is allocated in.
+pub const MAIN_INPUT_PREFIX: &str = "main input";
+
+///
+/// Returns the name of the namespace the main function input `name` is allocated in.
+///
+/// The soundness checks recover the input name and position from this namespace.
+///
+pub fn main_input_namespace(name: &str, span: &Span) -> String {
+    format!("{} `{}` {}:{}", MAIN_INPUT_PREFIX, name, span.line, span.start)
+}
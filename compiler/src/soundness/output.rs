@@ -0,0 +1,215 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Finds main function outputs that do not depend on any input.
+
+use crate::soundness::{SoundnessIssue, SoundnessWarning};
+use leo_asg::{
+    AssignAccess,
+    CallExpression,
+    Expression,
+    ExpressionVisitor,
+    Function,
+    FunctionQualifier,
+    Statement,
+    Variable,
+    VariableRef,
+    VisitResult,
+    VisitorDirector,
+};
+use leo_ast::Span;
+
+use std::{cell::Cell, collections::HashSet};
+
+/// Collects the variables an expression reads and the variables it mutates through `mut self` calls.
+#[derive(Default)]
+struct ExpressionDependencies<'a> {
+    read: Vec<u32>,
+    mutated: Vec<&'a Variable<'a>>,
+}
+
+/// Returns the variable holding the value `expression` accesses, if any.
+fn accessed_variable<'a>(expression: &'a Expression<'a>) -> Option<&'a Variable<'a>> {
+    match expression {
+        Expression::VariableRef(variable_ref) => Some(variable_ref.variable),
+        Expression::ArrayAccess(access) => accessed_variable(access.array.get()),
+        Expression::ArrayRangeAccess(access) => accessed_variable(access.array.get()),
+        Expression::CircuitAccess(access) => access.target.get().and_then(accessed_variable),
+        Expression::TupleAccess(access) => accessed_variable(access.tuple_ref.get()),
+        _ => None,
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for ExpressionDependencies<'a> {
+    fn visit_call(&mut self, input: &CallExpression<'a>) -> VisitResult {
+        if input.function.get().qualifier == FunctionQualifier::MutSelfRef {
+            if let Some(variable) = input.target.get().and_then(accessed_variable) {
+                self.mutated.push(variable);
+            }
+        }
+        Default::default()
+    }
+
+    fn visit_variable_ref(&mut self, input: &VariableRef<'a>) -> VisitResult {
+        self.read.push(input.variable.borrow().id);
+        Default::default()
+    }
+}
+
+/// Tracks which variables of the main function depend on its inputs.
+struct InputDependencies {
+    dependent: HashSet<u32>,
+    changed: bool,
+    constant_returns: Vec<Span>,
+}
+
+impl InputDependencies {
+    fn insert(&mut self, variable: &Variable) {
+        self.changed |= self.dependent.insert(variable.borrow().id);
+    }
+
+    ///
+    /// Returns `true` if `expression` depends on an input.
+    ///
+    /// Variables mutated by the expression become dependent if the expression or the control flow
+    /// reaching it does.
+    ///
+    fn expression<'a>(&mut self, expression: &Cell<&'a Expression<'a>>, control: bool) -> bool {
+        let mut director = VisitorDirector::new(ExpressionDependencies::default());
+        director.visit_expression(expression).ok();
+        let dependencies = director.visitor();
+
+        let dependent = dependencies.read.iter().any(|id| self.dependent.contains(id));
+        if dependent || control {
+            for variable in dependencies.mutated {
+                self.insert(variable);
+            }
+        }
+
+        dependent
+    }
+
+    fn statement<'a>(&mut self, statement: &'a Statement<'a>, control: bool) {
+        match statement {
+            Statement::Return(statement) => {
+                if !self.expression(&statement.expression, control) && !control {
+                    self.constant_returns.push(statement.span.clone().unwrap_or_default());
+                }
+            }
+            Statement::Definition(statement) => {
+                if self.expression(&statement.value, control) || control {
+                    for variable in statement.variables.iter() {
+                        self.insert(variable);
+                    }
+                }
+            }
+            Statement::Assign(statement) => {
+                let mut dependent = self.expression(&statement.value, control) || control;
+                for access in statement.target_accesses.iter() {
+                    match access {
+                        AssignAccess::ArrayRange(left, right) => {
+                            for index in [left, right].iter().filter_map(|index| index.get()) {
+                                dependent |= self.expression(&Cell::new(index), control);
+                            }
+                        }
+                        AssignAccess::ArrayIndex(index) => dependent |= self.expression(index, control),
+                        AssignAccess::Tuple(_) | AssignAccess::Member(_) => (),
+                    }
+                }
+
+                if dependent {
+                    self.insert(statement.target_variable.get());
+                }
+            }
+            Statement::Conditional(statement) => {
+                let control = self.expression(&statement.condition, control) || control;
+                self.statement(statement.result.get(), control);
+                if let Some(next) = statement.next.get() {
+                    self.statement(next, control);
+                }
+            }
+            Statement::Iteration(statement) => {
+                let mut control = self.expression(&statement.start, control) || control;
+                control |= self.expression(&statement.stop, control);
+
+                let element = statement
+                    .array
+                    .get()
+                    .map(|array| self.expression(&Cell::new(array), control))
+                    .unwrap_or_default();
+                if element || control {
+                    self.insert(statement.variable);
+                }
+
+                self.statement(statement.body.get(), control);
+            }
+            Statement::Expression(statement) => {
+                self.expression(&statement.expression, control);
+            }
+            Statement::Block(statement) => {
+                for statement in statement.statements.iter() {
+                    self.statement(statement.get(), control);
+                }
+            }
+            Statement::Console(_) => (),
+        }
+    }
+}
+
+///
+/// Returns warnings for the return statements of `main` whose value does not depend on any main
+/// function input, directly or through the control flow reaching the return.
+///
+pub fn check_output<'a>(main: &'a Function<'a>) -> Vec<SoundnessWarning> {
+    let body = match main.body.get() {
+        Some(body) if !main.output.is_unit() => body,
+        _ => return vec![],
+    };
+
+    let mut dependencies = InputDependencies {
+        dependent: main
+            .arguments
+            .values()
+            .map(|argument| argument.get().borrow().id)
+            .collect(),
+        changed: true,
+        constant_returns: vec![],
+    };
+
+    if let Some(input) = main.scope.resolve_input() {
+        dependencies.insert(input.container);
+    }
+
+    // Iterate until no more variables become dependent, so values carried across loop iterations are
+    // taken into account.
+    while dependencies.changed {
+        dependencies.changed = false;
+        dependencies.constant_returns.clear();
+        dependencies.statement(body, false);
+    }
+
+    dependencies
+        .constant_returns
+        .into_iter()
+        .map(|span| {
+            SoundnessWarning::new(
+                SoundnessIssue::ConstantOutput,
+                "main function output does not depend on any input".to_string(),
+                span,
+            )
+        })
+        .collect()
+}
@@ -0,0 +1,73 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::{Error as FormattedError, Report, Severity, Span};
+
+use std::{fmt, path::Path};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SoundnessIssue {
+    /// A private variable that does not appear in any constraint.
+    UnconstrainedVariable,
+    /// A main function input that no constraint outside of its own allocation depends on.
+    UninfluentialInput,
+    /// A main function output that does not depend on any input.
+    ConstantOutput,
+}
+
+impl SoundnessIssue {
+    pub fn name(&self) -> &'static str {
+        match self {
+            SoundnessIssue::UnconstrainedVariable => "unconstrained_variable",
+            SoundnessIssue::UninfluentialInput => "uninfluential_input",
+            SoundnessIssue::ConstantOutput => "constant_output",
+        }
+    }
+}
+
+/// A potential soundness issue, pointing at the code that caused it.
+#[derive(Clone, Debug)]
+pub struct SoundnessWarning {
+    pub issue: SoundnessIssue,
+    pub error: FormattedError,
+}
+
+impl SoundnessWarning {
+    pub fn new(issue: SoundnessIssue, message: String, span: Span) -> Self {
+        Self {
+            issue,
+            error: FormattedError::new_from_span(message, span),
+        }
+    }
+
+    pub fn set_path(&mut self, path: &Path) {
+        self.error.set_path(path);
+    }
+
+    /// Returns the warning as a report, coded by the name of its issue.
+    pub fn report(&self) -> Report {
+        Report {
+            severity: Severity::Warning,
+            ..Report::from_error(self.issue.name(), &self.error)
+        }
+    }
+}
+
+impl fmt::Display for SoundnessWarning {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.error)
+    }
+}
@@ -71,7 +71,12 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
         Ok(result)
     }
 
-    fn check_range_index(start_index: usize, stop_index: usize, len: usize, span: &Span) -> Result<(), StatementError> {
+    pub(crate) fn check_range_index(
+        start_index: usize,
+        stop_index: usize,
+        len: usize,
+        span: &Span,
+    ) -> Result<(), StatementError> {
         if stop_index < start_index {
             Err(StatementError::array_assign_range_order(
                 start_index,
@@ -17,7 +17,7 @@
 //! Enforces a branch of a conditional or iteration statement in a compiled Leo program.
 
 use crate::{program::ConstrainedProgram, GroupType, IndicatorAndConstrainedValue, StatementResult};
-use leo_asg::BlockStatement;
+use leo_asg::{BlockStatement, MonoidalDirector, ReturnPathReducer};
 
 use snarkvm_models::{
     curves::PrimeField,
@@ -40,6 +40,14 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
             let value = self.enforce_statement(cs, indicator, statement.get())?;
 
             results.extend(value);
+
+            // Statements after an unconditional return are dead code, so they are not enforced.
+            if MonoidalDirector::new(ReturnPathReducer::new())
+                .reduce_statement(statement.get())
+                .0
+            {
+                break;
+            }
         }
 
         Ok(results)
@@ -70,7 +70,14 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
             outer_indicator_string, inner_indicator_string
         );
         let branch_1_indicator = Boolean::and(
-            &mut cs.ns(|| format!("branch 1 {} {}:{}", span.text, &span.line, &span.start)),
+            &mut cs.ns(|| {
+                format!(
+                    "branch 1 {} {}:{}",
+                    span.lines().next().unwrap_or_default(),
+                    &span.line,
+                    &span.start
+                )
+            }),
             outer_indicator,
             &inner_indicator,
         )
@@ -91,7 +98,14 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
             outer_indicator_string, inner_indicator_string
         );
         let branch_2_indicator = Boolean::and(
-            &mut cs.ns(|| format!("branch 2 {} {}:{}", span.text, &span.line, &span.start)),
+            &mut cs.ns(|| {
+                format!(
+                    "branch 2 {} {}:{}",
+                    span.lines().next().unwrap_or_default(),
+                    &span.line,
+                    &span.start
+                )
+            }),
             &outer_indicator,
             &inner_indicator,
         )
@@ -49,16 +49,26 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
         let from = self.enforce_index(cs, statement.start.get(), &span)?;
         let to = self.enforce_index(cs, statement.stop.get(), &span)?;
 
+        // `for x in a` binds the elements of the array instead of the indices
+        let elements = match statement.array.get() {
+            Some(array) => match self.enforce_expression(cs, array)? {
+                ConstrainedValue::Array(elements) => Some(elements),
+                _ => unimplemented!("iteration over a non-array value"), // asg enforced
+            },
+            None => None,
+        };
+
         for i in from..to {
-            // Store index in current function scope.
-            // For loop scope is not implemented.
+            // Loops with bounds known to the asg are unrolled by the `loop_unrolling` pass, so this only
+            // runs for the others. Store index in current function scope.
             let variable = statement.variable.borrow();
 
             // todo: replace definition with var typed
-            self.store(
-                variable.id,
-                ConstrainedValue::Integer(Integer::U32(UInt32::constant(i as u32))),
-            );
+            let value = match &elements {
+                Some(elements) => elements[i].clone(),
+                None => ConstrainedValue::Integer(Integer::U32(UInt32::constant(i as u32))),
+            };
+            self.store(variable.id, value);
 
             // Evaluate statements and possibly return early
             let result = self.enforce_statement(
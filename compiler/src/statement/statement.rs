@@ -88,11 +88,11 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
             }
             Statement::Block(statement) => {
                 let span = statement.span.clone().unwrap_or_default();
-                let result = self.evaluate_block(
-                    &mut cs.ns(|| format!("block {}:{}", &span.line, &span.start)),
-                    indicator,
-                    statement,
-                )?;
+                let namespace = match statement.iteration {
+                    Some(i) => format!("for loop iteration {} {}:{}", i, &span.line, &span.start),
+                    None => format!("block {}:{}", &span.line, &span.start),
+                };
+                let result = self.evaluate_block(&mut cs.ns(|| namespace), indicator, statement)?;
 
                 results.extend(result);
             }
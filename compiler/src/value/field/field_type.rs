@@ -18,16 +18,22 @@
 
 use crate::{errors::FieldError, number_string_typing};
 use leo_ast::Span;
+use leo_gadgets::{
+    bits::{EvaluateLtGadget, RippleCarryAdder},
+    signed_integer::Int256,
+    unsigned_integer::UInt256,
+};
+use num_bigint::{BigInt, BigUint};
 
 use snarkvm_errors::gadgets::SynthesisError;
 use snarkvm_models::{
-    curves::PrimeField,
+    curves::{FpParameters, PrimeField},
     gadgets::{
         curves::{AllocatedFp, FieldGadget, FpGadget},
         r1cs::ConstraintSystem,
         utilities::{
             alloc::AllocGadget,
-            boolean::Boolean,
+            boolean::{AllocatedBit, Boolean},
             eq::{ConditionalEqGadget, EqGadget, EvaluateEqGadget},
             select::CondSelectGadget,
             uint::UInt8,
@@ -37,6 +43,8 @@ use snarkvm_models::{
     },
 };
 
+use snarkvm_utilities::bititerator::BitIteratorBE;
+
 use std::{borrow::Borrow, cmp::Ordering};
 
 #[derive(Clone, Debug)]
@@ -125,7 +133,9 @@ impl<F: PrimeField> FieldType<F> {
         }
     }
 
-    pub fn mul<CS: ConstraintSystem<F>>(&self, cs: CS, other: &Self, span: &Span) -> Result<Self, FieldError> {
+    pub fn mul<CS: ConstraintSystem<F>>(&self, mut cs: CS, other: &Self, span: &Span) -> Result<Self, FieldError> {
+        let unique_namespace = format!("enforce {} * {} {}:{}", self, other, span.line, span.start);
+        let cs = cs.ns(|| unique_namespace);
         match (self, other) {
             (FieldType::Constant(self_value), FieldType::Constant(other_value)) => {
                 Ok(FieldType::Constant(self_value.mul(other_value)))
@@ -149,24 +159,320 @@ impl<F: PrimeField> FieldType<F> {
     }
 
     pub fn div<CS: ConstraintSystem<F>>(&self, mut cs: CS, other: &Self, span: &Span) -> Result<Self, FieldError> {
-        let inverse = match other {
+        let unique_namespace = format!("enforce {} ÷ {} {}:{}", self, other, span.line, span.start);
+        let mut cs = cs.ns(|| unique_namespace);
+        let inverse = other.inv(&mut cs, span)?;
+
+        self.mul(cs, &inverse, span)
+    }
+
+    pub fn inv<CS: ConstraintSystem<F>>(&self, cs: CS, span: &Span) -> Result<Self, FieldError> {
+        match self {
             FieldType::Constant(constant) => {
                 let constant_inverse = constant
                     .inverse()
                     .ok_or_else(|| FieldError::no_inverse(constant.to_string(), span.to_owned()))?;
 
-                FieldType::Constant(constant_inverse)
+                Ok(FieldType::Constant(constant_inverse))
             }
             FieldType::Allocated(allocated) => {
+                if let Some(value) = allocated.get_value() {
+                    if value.is_zero() {
+                        return Err(FieldError::no_inverse(value.to_string(), span.to_owned()));
+                    }
+                }
                 let allocated_inverse = allocated
-                    .inverse(&mut cs)
-                    .map_err(|e| FieldError::binary_operation("+".to_string(), e, span.to_owned()))?;
+                    .inverse(cs)
+                    .map_err(|e| FieldError::intrinsic_operation("inv".to_string(), e, span.to_owned()))?;
+
+                Ok(FieldType::Allocated(allocated_inverse))
+            }
+        }
+    }
+
+    pub fn square<CS: ConstraintSystem<F>>(&self, cs: CS, span: &Span) -> Result<Self, FieldError> {
+        match self {
+            FieldType::Constant(constant) => Ok(FieldType::Constant(constant.square())),
+            FieldType::Allocated(allocated) => {
+                let result = allocated
+                    .square(cs)
+                    .map_err(|e| FieldError::intrinsic_operation("square".to_string(), e, span.to_owned()))?;
+
+                Ok(FieldType::Allocated(result))
+            }
+        }
+    }
+
+    pub fn double<CS: ConstraintSystem<F>>(&self, cs: CS, span: &Span) -> Result<Self, FieldError> {
+        match self {
+            FieldType::Constant(constant) => Ok(FieldType::Constant(constant.double())),
+            FieldType::Allocated(allocated) => {
+                let result = allocated
+                    .double(cs)
+                    .map_err(|e| FieldError::intrinsic_operation("double".to_string(), e, span.to_owned()))?;
+
+                Ok(FieldType::Allocated(result))
+            }
+        }
+    }
 
-                FieldType::Allocated(allocated_inverse)
+    pub fn pow<CS: ConstraintSystem<F>>(&self, mut cs: CS, exponent: &Self, span: &Span) -> Result<Self, FieldError> {
+        let map_err = |e| FieldError::intrinsic_operation("pow".to_string(), e, span.to_owned());
+
+        match (self, exponent) {
+            (FieldType::Constant(base), FieldType::Constant(exponent)) => {
+                Ok(FieldType::Constant(base.pow(exponent.into_repr())))
+            }
+
+            // Square and multiply over the bits of the known exponent.
+            (FieldType::Allocated(base), FieldType::Constant(exponent)) => {
+                let mut result: Option<FpGadget<F>> = None;
+                for (i, bit) in BitIteratorBE::new(exponent.into_repr()).enumerate() {
+                    if let Some(current) = result.as_mut() {
+                        *current = current.square(cs.ns(|| format!("square {}", i))).map_err(map_err)?;
+                    }
+                    if bit {
+                        result = Some(match result {
+                            Some(current) => current.mul(cs.ns(|| format!("mul {}", i)), base).map_err(map_err)?,
+                            None => base.clone(),
+                        });
+                    }
+                }
+
+                match result {
+                    Some(result) => Ok(FieldType::Allocated(result)),
+                    None => Ok(FieldType::Constant(F::one())),
+                }
             }
+
+            // Decompose the exponent into bits and select each power of the base by its bit.
+            (FieldType::Allocated(base), FieldType::Allocated(exponent)) => {
+                let bits = exponent.to_bits_strict(cs.ns(|| "exponent bits")).map_err(map_err)?;
+                let result = base.pow(cs.ns(|| "pow"), &bits).map_err(map_err)?;
+
+                Ok(FieldType::Allocated(result))
+            }
+
+            // Multiply the factors `bit ? base^(2^i) : 1`, which are linear in each bit.
+            (FieldType::Constant(base), FieldType::Allocated(exponent)) => {
+                let bits = exponent.to_bits_strict(cs.ns(|| "exponent bits")).map_err(map_err)?;
+                let mut power = *base;
+                let mut result: Option<FpGadget<F>> = None;
+                for (i, bit) in bits.iter().rev().enumerate() {
+                    let mut factor_lc = bit.lc(CS::one(), power - &F::one());
+                    factor_lc += (F::one(), CS::one());
+                    let factor = FpGadget::from(AllocatedFp {
+                        value: bit.get_value().map(|bit| if bit { power } else { F::one() }),
+                        variable: factor_lc.into(),
+                    });
+                    result = Some(match result {
+                        Some(current) => current.mul(cs.ns(|| format!("mul {}", i)), &factor).map_err(map_err)?,
+                        None => factor,
+                    });
+                    power.square_in_place();
+                }
+
+                match result {
+                    Some(result) => Ok(FieldType::Allocated(result)),
+                    None => Ok(FieldType::Constant(F::one())),
+                }
+            }
+        }
+    }
+
+    /// Returns the square root of `self` and whether it exists.
+    ///
+    /// The root is the canonical one, at most `(p - 1) / 2`, so constant and allocated values agree.
+    /// When no root exists, the returned root is zero. Allocated values still witness the root of `self`
+    /// scaled by a quadratic non-residue, which proves that `self` is not a square.
+    pub fn sqrt<CS: ConstraintSystem<F>>(&self, mut cs: CS, span: &Span) -> Result<(Self, Boolean), FieldError> {
+        let map_err = |e| FieldError::intrinsic_operation("sqrt".to_string(), e, span.to_owned());
+
+        match self {
+            FieldType::Constant(constant) => Ok(match field_sqrt(constant) {
+                Some(root) => (FieldType::Constant(root), Boolean::Constant(true)),
+                None => (FieldType::Constant(F::zero()), Boolean::Constant(false)),
+            }),
+            FieldType::Allocated(allocated) => {
+                let non_residue = F::multiplicative_generator();
+                let value = allocated.get_value();
+                let root_value = value.map(|value| match field_sqrt(&value) {
+                    Some(root) => (root, true),
+                    None => (field_sqrt(&(value * &non_residue)).unwrap_or_else(F::zero), false),
+                });
+
+                let is_valid = AllocatedBit::alloc(cs.ns(|| "is valid"), || {
+                    root_value
+                        .map(|(_, valid)| valid)
+                        .ok_or(SynthesisError::AssignmentMissing)
+                })
+                .map_err(map_err)?;
+                let is_valid = Boolean::from(is_valid);
+                let root = FpGadget::alloc(cs.ns(|| "root"), || {
+                    root_value
+                        .map(|(root, _)| root)
+                        .ok_or(SynthesisError::AssignmentMissing)
+                })
+                .map_err(map_err)?;
+
+                // root * root = is_valid ? self : non_residue * self
+                let scaled = allocated
+                    .mul_by_constant(cs.ns(|| "scale"), &non_residue)
+                    .map_err(map_err)?;
+                let square = FpGadget::conditionally_select(cs.ns(|| "select square"), &is_valid, allocated, &scaled)
+                    .map_err(map_err)?;
+                root.square_equals(cs.ns(|| "square root"), &square).map_err(map_err)?;
+
+                // Either root squares to `square`, so the witnessed root must be the smaller one
+                let half = BitIteratorBE::new(F::Parameters::MODULUS_MINUS_ONE_DIV_TWO)
+                    .fold(BigUint::from(0u8), |acc, bit| (acc << 1) + u8::from(bit));
+                let (root_bits, _) = FieldType::Allocated(root.clone())
+                    .to_integer_bits(cs.ns(|| "root bits"))
+                    .map_err(map_err)?;
+                constant_bits(&half)
+                    .less_than(cs.ns(|| "root above half"), &root_bits)
+                    .map_err(map_err)?
+                    .enforce_equal(cs.ns(|| "canonical root"), &Boolean::constant(false))
+                    .map_err(map_err)?;
+
+                // self * witness = 1 - is_valid, so an invalid root implies a nonzero `self`
+                let witness = FpGadget::alloc(cs.ns(|| "nonzero witness"), || match root_value {
+                    Some((_, true)) => Ok(F::zero()),
+                    Some((_, false)) => value
+                        .and_then(|value| value.inverse())
+                        .ok_or(SynthesisError::AssignmentMissing),
+                    None => Err(SynthesisError::AssignmentMissing),
+                })
+                .map_err(map_err)?;
+                let not_valid = is_valid.not().lc(CS::one(), F::one());
+                let self_variable = allocated.get_variable();
+                let witness_variable = witness.get_variable();
+                cs.enforce(
+                    || "nonzero",
+                    |lc| &self_variable + lc,
+                    |lc| &witness_variable + lc,
+                    |lc| lc + &not_valid,
+                );
+
+                let zero = FpGadget::zero(cs.ns(|| "zero")).map_err(map_err)?;
+                let root = FpGadget::conditionally_select(cs.ns(|| "select root"), &is_valid, &root, &zero)
+                    .map_err(map_err)?;
+
+                Ok((FieldType::Allocated(root), is_valid))
+            }
+        }
+    }
+
+    pub fn is_zero<CS: ConstraintSystem<F>>(&self, cs: CS, span: &Span) -> Result<Boolean, FieldError> {
+        self.evaluate_is_zero(cs)
+            .map_err(|e| FieldError::intrinsic_operation("is_zero".to_string(), e, span.to_owned()))
+    }
+
+    fn evaluate_is_zero<CS: ConstraintSystem<F>>(&self, mut cs: CS) -> Result<Boolean, SynthesisError> {
+        match self {
+            FieldType::Constant(constant) => Ok(Boolean::Constant(constant.is_zero())),
+            FieldType::Allocated(allocated) => {
+                let value = allocated.get_value();
+                let is_zero = Boolean::from(AllocatedBit::alloc(cs.ns(|| "is zero"), || {
+                    value
+                        .map(|value| value.is_zero())
+                        .ok_or(SynthesisError::AssignmentMissing)
+                })?);
+                let witness = FpGadget::alloc(cs.ns(|| "inverse witness"), || match value {
+                    Some(value) => Ok(value.inverse().unwrap_or_else(F::zero)),
+                    None => Err(SynthesisError::AssignmentMissing),
+                })?;
+
+                let self_variable = allocated.get_variable();
+                let witness_variable = witness.get_variable();
+                let not_zero = is_zero.not().lc(CS::one(), F::one());
+                let zero = is_zero.lc(CS::one(), F::one());
+
+                // self * witness = 1 - is_zero
+                cs.enforce(
+                    || "inverse",
+                    |lc| &self_variable + lc,
+                    |lc| &witness_variable + lc,
+                    |lc| lc + &not_zero,
+                );
+                // self * is_zero = 0
+                cs.enforce(|| "zero", |lc| &self_variable + lc, |lc| lc + &zero, |lc| lc);
+
+                Ok(is_zero)
+            }
+        }
+    }
+
+    pub fn to_u256<CS: ConstraintSystem<F>>(&self, cs: CS, span: &Span) -> Result<UInt256, FieldError> {
+        let (bits, value) = self
+            .to_integer_bits(cs)
+            .map_err(|e| FieldError::intrinsic_operation("to_u256".to_string(), e, span.to_owned()))?;
+
+        Ok(UInt256 { bits, value })
+    }
+
+    /// Interprets `self` as a signed value, so that field elements above `(p - 1) / 2` are
+    /// negative. This is the inverse of `i256.to_field()`.
+    pub fn to_i256<CS: ConstraintSystem<F>>(&self, mut cs: CS, span: &Span) -> Result<Int256, FieldError> {
+        let to_error = |e| FieldError::intrinsic_operation("to_i256".to_string(), e, span.to_owned());
+
+        let modulus =
+            BitIteratorBE::new(F::characteristic()).fold(BigUint::from(0u8), |acc, bit| (acc << 1) + u8::from(bit));
+        let half = (&modulus - 1u8) >> 1usize;
+        let wrap = (BigUint::from(1u8) << 256usize) - &modulus;
+
+        let (bits, value) = self.to_integer_bits(cs.ns(|| "bits")).map_err(to_error)?;
+
+        // Adding `2^256 - p` to a canonical value above `p / 2` yields `value - p` in two's complement.
+        let is_negative = constant_bits(&half)
+            .less_than(cs.ns(|| "is negative"), &bits)
+            .map_err(to_error)?;
+        let wrapped = bits
+            .add_bits(cs.ns(|| "wrap"), &constant_bits(&wrap))
+            .map_err(to_error)?;
+        let bits = wrapped
+            .iter()
+            .zip(bits.iter())
+            .enumerate()
+            .map(|(i, (negative, positive))| {
+                Boolean::conditionally_select(cs.ns(|| format!("select bit {}", i)), &is_negative, negative, positive)
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(to_error)?;
+
+        let value = value.map(|value| {
+            if value > half {
+                BigInt::from(value) - BigInt::from(modulus)
+            } else {
+                BigInt::from(value)
+            }
+        });
+
+        Ok(Int256 { bits, value })
+    }
+
+    /// Returns the 256 little-endian bits of the canonical representative of `self`.
+    ///
+    /// Every field element fits in 256 bits, so the top bits are always zero.
+    fn to_integer_bits<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+    ) -> Result<(Vec<Boolean>, Option<BigUint>), SynthesisError> {
+        let mut bits = match self {
+            FieldType::Constant(constant) => BitIteratorBE::new(constant.into_repr())
+                .map(Boolean::constant)
+                .collect::<Vec<_>>(),
+            FieldType::Allocated(allocated) => allocated.to_bits_strict(cs.ns(|| "to bits"))?,
         };
+        bits.reverse();
+        bits.resize(256, Boolean::constant(false));
+        bits.truncate(256);
 
-        self.mul(cs, &inverse, span)
+        let value = self.get_value().map(|value| {
+            BitIteratorBE::new(value.into_repr()).fold(BigUint::from(0u8), |acc, bit| (acc << 1) + u8::from(bit))
+        });
+
+        Ok((bits, value))
     }
 
     pub fn alloc_helper<Fn: FnOnce() -> Result<T, SynthesisError>, T: Borrow<String>>(
@@ -193,6 +499,46 @@ impl<F: PrimeField> FieldType<F> {
     }
 }
 
+/// Returns the smaller of the two square roots of `value`, if it is a quadratic residue.
+fn field_sqrt<F: PrimeField>(value: &F) -> Option<F> {
+    if value.is_zero() {
+        return Some(F::zero());
+    }
+    if !value.pow(F::Parameters::MODULUS_MINUS_ONE_DIV_TWO).is_one() {
+        return None;
+    }
+
+    // Tonelli-Shanks, where `qnr_to_t` is a `2^TWO_ADICITY` root of unity.
+    let mut z = F::qnr_to_t();
+    let mut w = value.pow(F::Parameters::T_MINUS_ONE_DIV_TWO);
+    let mut x = w * value;
+    let mut b = x * &w;
+    let mut v = F::Parameters::TWO_ADICITY as usize;
+    while !b.is_one() {
+        let mut k = 0usize;
+        let mut b2k = b;
+        while !b2k.is_one() {
+            b2k.square_in_place();
+            k += 1;
+        }
+        w = z;
+        for _ in 0..(v - k - 1) {
+            w.square_in_place();
+        }
+        z = w.square();
+        b *= &z;
+        x *= &w;
+        v = k;
+    }
+
+    let negated = -x;
+    Some(if negated.into_repr() < x.into_repr() {
+        negated
+    } else {
+        x
+    })
+}
+
 impl<F: PrimeField> AllocGadget<String, F> for FieldType<F> {
     fn alloc<Fn: FnOnce() -> Result<T, SynthesisError>, T: Borrow<String>, CS: ConstraintSystem<F>>(
         cs: CS,
@@ -234,17 +580,18 @@ impl<F: PrimeField> PartialOrd for FieldType<F> {
 }
 
 impl<F: PrimeField> EvaluateEqGadget<F> for FieldType<F> {
-    fn evaluate_equal<CS: ConstraintSystem<F>>(&self, mut _cs: CS, other: &Self) -> Result<Boolean, SynthesisError> {
+    fn evaluate_equal<CS: ConstraintSystem<F>>(&self, mut cs: CS, other: &Self) -> Result<Boolean, SynthesisError> {
         match (self, other) {
             (FieldType::Constant(first), FieldType::Constant(second)) => Ok(Boolean::constant(first.eq(second))),
-            _ => unimplemented!(),
-            // (FieldType::Allocated(first), FieldType::Allocated(second)) => first.evaluate_equal(cs, second),
-            // (FieldType::Constant(constant_value), FieldType::Allocated(allocated_value))
-            // | (FieldType::Allocated(allocated_value), FieldType::Constant(constant_value)) => {
-            //     let allocated_constant_value =
-            //         FpGadget::alloc(&mut cs.ns(|| format!("alloc constant for eq")), || Ok(constant_value))?;
-            //     allocated_value.evaluate_equal(cs, &allocated_constant_value)
-            // }
+            (FieldType::Allocated(first), FieldType::Allocated(second)) => {
+                let difference = first.sub(cs.ns(|| "difference"), second)?;
+                FieldType::Allocated(difference).evaluate_is_zero(cs.ns(|| "is zero"))
+            }
+            (FieldType::Constant(constant_value), FieldType::Allocated(allocated_value))
+            | (FieldType::Allocated(allocated_value), FieldType::Constant(constant_value)) => {
+                let difference = allocated_value.sub_constant(cs.ns(|| "difference"), constant_value)?;
+                FieldType::Allocated(difference).evaluate_is_zero(cs.ns(|| "is zero"))
+            }
         }
     }
 }
@@ -293,7 +640,11 @@ impl<F: PrimeField> CondSelectGadget<F> for FieldType<F> {
         second: &Self,
     ) -> Result<Self, SynthesisError> {
         if let Boolean::Constant(cond) = *cond {
-            if cond { Ok(first.clone()) } else { Ok(second.clone()) }
+            if cond {
+                Ok(first.clone())
+            } else {
+                Ok(second.clone())
+            }
         } else {
             let first_gadget = first.allocated(&mut cs)?;
             let second_gadget = second.allocated(&mut cs)?;
@@ -337,3 +688,15 @@ impl<F: PrimeField> std::fmt::Display for FieldType<F> {
         write!(f, "{:?}", self.get_value().ok_or(std::fmt::Error))
     }
 }
+
+/// Returns the 256 little-endian constant bits of `value`.
+fn constant_bits(value: &BigUint) -> Vec<Boolean> {
+    let mut bits = value
+        .to_radix_le(2)
+        .into_iter()
+        .map(|bit| Boolean::constant(bit == 1))
+        .collect::<Vec<_>>();
+    bits.resize(256, Boolean::constant(false));
+
+    bits
+}
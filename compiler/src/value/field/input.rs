@@ -61,13 +61,10 @@ pub(crate) fn field_from_input<'a, F: PrimeField, G: GroupType<F>, CS: Constrain
 ) -> Result<ConstrainedValue<'a, F, G>, FieldError> {
     // Check that the parameter value is the correct type
     let option = match input_value {
-        Some(input) => {
-            if let InputValue::Field(string) = input {
-                Some(string)
-            } else {
-                return Err(FieldError::invalid_field(input.to_string(), span.to_owned()));
-            }
-        }
+        Some(input) => match input {
+            InputValue::Field(string) | InputValue::Implicit(string) => Some(string),
+            input => return Err(FieldError::invalid_field(input.to_string(), span.to_owned())),
+        },
         None => None,
     };
 
@@ -0,0 +1,453 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! A data type that represents a fixed point decimal value
+
+use crate::{errors::FixedError, Integer};
+use leo_asg::{ConstFixed, ConstInt, FixedType, IntegerType, Rounding, Span};
+use leo_ast::InputValue;
+use leo_gadgets::bits::comparator::{ComparatorGadget, EvaluateLtGadget};
+use num_bigint::BigInt;
+
+use snarkvm_errors::gadgets::SynthesisError;
+use snarkvm_models::{
+    curves::PrimeField,
+    gadgets::{
+        r1cs::ConstraintSystem,
+        utilities::{
+            boolean::Boolean,
+            eq::{ConditionalEqGadget, EqGadget, EvaluateEqGadget},
+            select::CondSelectGadget,
+        },
+    },
+};
+use std::fmt;
+
+/// A fixed point value, stored as a signed integer counting multiples of `10^-scale`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Fixed {
+    pub type_: FixedType,
+    pub integer: Integer,
+}
+
+impl fmt::Display for Fixed {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.integer.get_value() {
+            Some(raw) => {
+                let value = ConstFixed {
+                    type_: self.type_.clone(),
+                    raw: raw.parse().unwrap(),
+                };
+                write!(f, "{}", value)
+            }
+            None => write!(f, "[input]{}", self.type_),
+        }
+    }
+}
+
+impl Fixed {
+    pub fn constant(value: &ConstFixed) -> Self {
+        Fixed {
+            type_: value.type_.clone(),
+            integer: integer_constant(&storage_type(&value.type_), &BigInt::from(value.raw)),
+        }
+    }
+
+    ///
+    /// Allocates the main function input `name` of type `type_`, given as a decimal such as `-1.25`.
+    ///
+    pub fn from_input<F: PrimeField, CS: ConstraintSystem<F>>(
+        cs: &mut CS,
+        type_: &FixedType,
+        name: &str,
+        input_value: Option<InputValue>,
+        span: &Span,
+    ) -> Result<Self, FixedError> {
+        // Check that the input value is the correct type
+        let raw = match input_value {
+            Some(InputValue::Fixed(_, value)) | Some(InputValue::Implicit(value)) => Some(
+                ConstFixed::parse(type_, &value, span)
+                    .map_err(|_| FixedError::invalid_fixed(value.clone(), span.to_owned()))?
+                    .raw
+                    .to_string(),
+            ),
+            Some(input) => return Err(FixedError::invalid_fixed(input.to_string(), span.to_owned())),
+            None => None,
+        };
+
+        let integer = Integer::allocate_type(cs, &storage_type(type_), name, raw, span)
+            .map_err(|_| FixedError::missing_fixed(format!("{}: {}", name, type_), span.to_owned()))?;
+
+        Ok(Fixed {
+            type_: type_.clone(),
+            integer,
+        })
+    }
+
+    ///
+    /// Returns the fixed point value of type `type_` with the same value as the given integer.
+    ///
+    pub fn from_integer<F: PrimeField, CS: ConstraintSystem<F>>(
+        cs: &mut CS,
+        type_: &FixedType,
+        integer: &Integer,
+        span: &Span,
+    ) -> Result<Self, FixedError> {
+        let mut cs = cs.ns(|| format!("{} to {} {}:{}", integer, type_, span.line, span.start));
+
+        let integer_type = storage_type(type_);
+        let whole = integer.cast(&mut cs.ns(|| "cast"), &integer_type, span)?;
+        let one = integer_constant(&integer_type, &ConstFixed::one(type_));
+        let integer = whole.mul(&mut cs.ns(|| "scale"), one, span)?;
+
+        Ok(Fixed {
+            type_: type_.clone(),
+            integer,
+        })
+    }
+
+    ///
+    /// Returns the whole part of this value, rounded towards zero, as an integer of type `integer_type`.
+    ///
+    pub fn to_integer<F: PrimeField, CS: ConstraintSystem<F>>(
+        &self,
+        cs: &mut CS,
+        integer_type: &IntegerType,
+        span: &Span,
+    ) -> Result<Integer, FixedError> {
+        let mut cs = cs.ns(|| format!("{} to {} {}:{}", self, integer_type, span.line, span.start));
+
+        let one = integer_constant(&storage_type(&self.type_), &ConstFixed::one(&self.type_));
+        let whole = self.integer.clone().div(&mut cs.ns(|| "unscale"), one, span)?;
+
+        let integer = whole.cast(&mut cs.ns(|| "cast"), integer_type, span)?;
+
+        Ok(integer)
+    }
+
+    pub fn negate<F: PrimeField, CS: ConstraintSystem<F>>(self, cs: &mut CS, span: &Span) -> Result<Self, FixedError> {
+        Ok(Fixed {
+            integer: self.integer.negate(cs, span)?,
+            type_: self.type_,
+        })
+    }
+
+    pub fn add<F: PrimeField, CS: ConstraintSystem<F>>(
+        self,
+        cs: &mut CS,
+        other: Self,
+        span: &Span,
+    ) -> Result<Self, FixedError> {
+        self.check_type(&other, "+", span)?;
+
+        Ok(Fixed {
+            integer: self.integer.add(cs, other.integer, span)?,
+            type_: self.type_,
+        })
+    }
+
+    pub fn sub<F: PrimeField, CS: ConstraintSystem<F>>(
+        self,
+        cs: &mut CS,
+        other: Self,
+        span: &Span,
+    ) -> Result<Self, FixedError> {
+        self.check_type(&other, "-", span)?;
+
+        Ok(Fixed {
+            integer: self.integer.sub(cs, other.integer, span)?,
+            type_: self.type_,
+        })
+    }
+
+    ///
+    /// Returns the product of the two values, rounded to the scale of their type with `rounding`.
+    ///
+    /// The exact product is computed in an integer of twice the width, so only the rounded result can overflow.
+    ///
+    pub fn mul<F: PrimeField, CS: ConstraintSystem<F>>(
+        self,
+        cs: &mut CS,
+        other: Self,
+        rounding: Rounding,
+        span: &Span,
+    ) -> Result<Self, FixedError> {
+        self.check_type(&other, "*", span)?;
+        let mut cs = cs.ns(|| format!("enforce {} * {} {}:{}", self, other, span.line, span.start));
+
+        let wide_type = self.wide_type();
+        let left = self.integer.cast(&mut cs.ns(|| "widen left"), &wide_type, span)?;
+        let right = other.integer.cast(&mut cs.ns(|| "widen right"), &wide_type, span)?;
+        let product = left.mul(&mut cs.ns(|| "product"), right, span)?;
+
+        let one = integer_constant(&wide_type, &ConstFixed::one(&self.type_));
+        let result = divide(&mut cs.ns(|| "rescale"), product, one, rounding, span)?;
+
+        self.narrow(&mut cs, result, span)
+    }
+
+    ///
+    /// Returns the quotient of the two values, rounded to the scale of their type with `rounding`.
+    ///
+    pub fn div<F: PrimeField, CS: ConstraintSystem<F>>(
+        self,
+        cs: &mut CS,
+        other: Self,
+        rounding: Rounding,
+        span: &Span,
+    ) -> Result<Self, FixedError> {
+        self.check_type(&other, "/", span)?;
+        if other.integer.get_value().as_deref() == Some("0") {
+            return Err(FixedError::division_by_zero(span.to_owned()));
+        }
+        let mut cs = cs.ns(|| format!("enforce {} ÷ {} {}:{}", self, other, span.line, span.start));
+
+        let wide_type = self.wide_type();
+        let left = self.integer.cast(&mut cs.ns(|| "widen left"), &wide_type, span)?;
+        let right = other.integer.cast(&mut cs.ns(|| "widen right"), &wide_type, span)?;
+
+        let one = integer_constant(&wide_type, &ConstFixed::one(&self.type_));
+        let scaled = left.mul(&mut cs.ns(|| "scale"), one, span)?;
+        let result = divide(&mut cs.ns(|| "quotient"), scaled, right, rounding, span)?;
+
+        self.narrow(&mut cs, result, span)
+    }
+
+    ///
+    /// Returns this value rounded to a whole number with `rounding`.
+    ///
+    pub fn round<F: PrimeField, CS: ConstraintSystem<F>>(
+        self,
+        cs: &mut CS,
+        rounding: Rounding,
+        span: &Span,
+    ) -> Result<Self, FixedError> {
+        let mut cs = cs.ns(|| format!("round {} {}:{}", self, span.line, span.start));
+
+        let one = integer_constant(&storage_type(&self.type_), &ConstFixed::one(&self.type_));
+        let whole = divide(&mut cs.ns(|| "unscale"), self.integer, one.clone(), rounding, span)?;
+        let integer = whole.mul(&mut cs.ns(|| "scale"), one, span)?;
+
+        Ok(Fixed {
+            type_: self.type_,
+            integer,
+        })
+    }
+
+    fn check_type(&self, other: &Self, operation: &str, span: &Span) -> Result<(), FixedError> {
+        if self.type_ != other.type_ {
+            return Err(FixedError::binary_operation(
+                operation.to_string(),
+                &self.type_,
+                &other.type_,
+                span.to_owned(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Returns the signed integer type with twice the width of this value.
+    fn wide_type(&self) -> IntegerType {
+        match storage_type(&self.type_) {
+            IntegerType::I8 => IntegerType::I16,
+            IntegerType::I16 => IntegerType::I32,
+            IntegerType::I32 => IntegerType::I64,
+            IntegerType::I64 => IntegerType::I128,
+            IntegerType::I128 => IntegerType::I256,
+            integer_type => unreachable!("{} does not store fixed point values", integer_type),
+        }
+    }
+
+    /// Returns the value with the raw integer `wide`, failing if it does not fit in the type.
+    fn narrow<F: PrimeField, CS: ConstraintSystem<F>>(
+        &self,
+        cs: &mut CS,
+        wide: Integer,
+        span: &Span,
+    ) -> Result<Self, FixedError> {
+        Ok(Fixed {
+            type_: self.type_.clone(),
+            integer: wide.cast(&mut cs.ns(|| "narrow"), &storage_type(&self.type_), span)?,
+        })
+    }
+}
+
+/// Returns the signed integer type storing values of type `type_`.
+fn storage_type(type_: &FixedType) -> IntegerType {
+    type_.integer_type().expect("invalid fixed point width") // asg enforced
+}
+
+/// Returns the constant integer of type `integer_type` with the given value.
+fn integer_constant(integer_type: &IntegerType, value: &BigInt) -> Integer {
+    let value = ConstInt::parse(integer_type, &value.to_string(), &Span::default()).expect("constant out of range");
+
+    Integer::new(&value)
+}
+
+/// Divides the signed integer `numerator` by `denominator`, rounding the quotient with `rounding`.
+fn divide<F: PrimeField, CS: ConstraintSystem<F>>(
+    cs: &mut CS,
+    numerator: Integer,
+    denominator: Integer,
+    rounding: Rounding,
+    span: &Span,
+) -> Result<Integer, FixedError> {
+    let quotient = numerator
+        .clone()
+        .div(&mut cs.ns(|| "quotient"), denominator.clone(), span)?;
+    if rounding == Rounding::Trunc {
+        return Ok(quotient);
+    }
+
+    let integer_type = numerator.get_type();
+    let zero = integer_constant(&integer_type, &BigInt::from(0u8));
+    let one = integer_constant(&integer_type, &BigInt::from(1u8));
+
+    // the remainder of the truncated division takes the sign of the numerator
+    let product = quotient
+        .clone()
+        .mul(&mut cs.ns(|| "product"), denominator.clone(), span)?;
+    let remainder = numerator.sub(&mut cs.ns(|| "remainder"), product, span)?;
+
+    let enforce = |error: SynthesisError| FixedError::cannot_enforce(format!("{:?}", rounding), error, span.to_owned());
+    let is_exact = remainder
+        .evaluate_equal(cs.ns(|| "remainder is zero"), &zero)
+        .map_err(enforce)?;
+    let remainder_negative = remainder
+        .less_than(cs.ns(|| "remainder is negative"), &zero)
+        .map_err(enforce)?;
+    let denominator_negative = denominator
+        .less_than(cs.ns(|| "denominator is negative"), &zero)
+        .map_err(enforce)?;
+    let negative = Boolean::xor(
+        cs.ns(|| "quotient is negative"),
+        &remainder_negative,
+        &denominator_negative,
+    )
+    .map_err(enforce)?;
+
+    let away = match rounding {
+        Rounding::Trunc => unreachable!(),
+        Rounding::Floor => negative,
+        Rounding::Ceil => negative.not(),
+        Rounding::Round => {
+            let remainder_magnitude = magnitude(
+                &mut cs.ns(|| "remainder magnitude"),
+                remainder,
+                remainder_negative,
+                span,
+            )?;
+            let denominator_magnitude = magnitude(
+                &mut cs.ns(|| "denominator magnitude"),
+                denominator,
+                denominator_negative,
+                span,
+            )?;
+            // `2 * remainder >= denominator` without doubling, which could overflow
+            let rest = denominator_magnitude.sub(
+                &mut cs.ns(|| "denominator minus remainder"),
+                remainder_magnitude.clone(),
+                span,
+            )?;
+            remainder_magnitude
+                .greater_than_or_equal(cs.ns(|| "at least half"), &rest)
+                .map_err(enforce)?
+        }
+    };
+    let away = Boolean::and(cs.ns(|| "inexact"), &away, &is_exact.not()).map_err(enforce)?;
+
+    let decremented = quotient.clone().sub(&mut cs.ns(|| "decrement"), one.clone(), span)?;
+    let incremented = quotient.clone().add(&mut cs.ns(|| "increment"), one, span)?;
+    let adjusted =
+        Integer::conditionally_select(cs.ns(|| "adjusted"), &negative, &decremented, &incremented).map_err(enforce)?;
+
+    Integer::conditionally_select(cs.ns(|| "rounded"), &away, &adjusted, &quotient).map_err(enforce)
+}
+
+/// Returns the absolute value of the signed integer `value` with the sign bit `negative`.
+fn magnitude<F: PrimeField, CS: ConstraintSystem<F>>(
+    cs: &mut CS,
+    value: Integer,
+    negative: Boolean,
+    span: &Span,
+) -> Result<Integer, FixedError> {
+    let negated = value.clone().negate(&mut cs.ns(|| "negate"), span)?;
+
+    Integer::conditionally_select(cs.ns(|| "select"), &negative, &negated, &value)
+        .map_err(|error| FixedError::cannot_enforce("abs".to_string(), error, span.to_owned()))
+}
+
+impl<F: PrimeField> EvaluateEqGadget<F> for Fixed {
+    fn evaluate_equal<CS: ConstraintSystem<F>>(&self, cs: CS, other: &Self) -> Result<Boolean, SynthesisError> {
+        if self.type_ != other.type_ {
+            return Err(SynthesisError::Unsatisfiable);
+        }
+        self.integer.evaluate_equal(cs, &other.integer)
+    }
+}
+
+impl<F: PrimeField> EvaluateLtGadget<F> for Fixed {
+    fn less_than<CS: ConstraintSystem<F>>(&self, cs: CS, other: &Self) -> Result<Boolean, SynthesisError> {
+        if self.type_ != other.type_ {
+            return Err(SynthesisError::Unsatisfiable);
+        }
+        self.integer.less_than(cs, &other.integer)
+    }
+}
+
+impl<F: PrimeField> ComparatorGadget<F> for Fixed {}
+
+impl<F: PrimeField> EqGadget<F> for Fixed {}
+
+impl<F: PrimeField> ConditionalEqGadget<F> for Fixed {
+    fn conditional_enforce_equal<CS: ConstraintSystem<F>>(
+        &self,
+        cs: CS,
+        other: &Self,
+        condition: &Boolean,
+    ) -> Result<(), SynthesisError> {
+        if self.type_ != other.type_ {
+            return Err(SynthesisError::Unsatisfiable);
+        }
+        self.integer.conditional_enforce_equal(cs, &other.integer, condition)
+    }
+
+    fn cost() -> usize {
+        unimplemented!() // cannot determine which integer we are enforcing
+    }
+}
+
+impl<F: PrimeField> CondSelectGadget<F> for Fixed {
+    fn conditionally_select<CS: ConstraintSystem<F>>(
+        cs: CS,
+        cond: &Boolean,
+        first: &Self,
+        second: &Self,
+    ) -> Result<Self, SynthesisError> {
+        if first.type_ != second.type_ {
+            return Err(SynthesisError::Unsatisfiable);
+        }
+
+        Ok(Fixed {
+            type_: first.type_.clone(),
+            integer: Integer::conditionally_select(cs, cond, &first.integer, &second.integer)?,
+        })
+    }
+
+    fn cost() -> usize {
+        unimplemented!() // cannot determine which integer we are enforcing
+    }
+}
@@ -15,20 +15,23 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 //! Conversion of integer declarations to constraints in Leo.
-use crate::{errors::IntegerError, IntegerTrait};
+use crate::{errors::IntegerError, FieldType, IntegerTrait};
 use leo_asg::{ConstInt, IntegerType, Span};
 use leo_ast::InputValue;
 use leo_gadgets::{
     arithmetic::*,
     bits::comparator::{ComparatorGadget, EvaluateLtGadget},
     signed_integer::*,
+    unsigned_integer::UInt256,
 };
+use num_bigint::{BigInt, BigUint, Sign};
 
 use snarkvm_errors::gadgets::SynthesisError;
 use snarkvm_models::{
-    curves::{Field, PrimeField},
+    curves::{Field, FpParameters, PrimeField},
     gadgets::{
-        r1cs::ConstraintSystem,
+        curves::{FieldGadget, FpGadget},
+        r1cs::{Assignment, ConstraintSystem, LinearCombination},
         utilities::{
             alloc::AllocGadget,
             boolean::Boolean,
@@ -38,6 +41,7 @@ use snarkvm_models::{
         },
     },
 };
+use snarkvm_utilities::bititerator::BitIteratorBE;
 use std::fmt;
 
 /// An integer type enum wrapping the integer value.
@@ -48,12 +52,14 @@ pub enum Integer {
     U32(UInt32),
     U64(UInt64),
     U128(UInt128),
+    U256(UInt256),
 
     I8(Int8),
     I16(Int16),
     I32(Int32),
     I64(Int64),
     I128(Int128),
+    I256(Int256),
 }
 
 impl fmt::Display for Integer {
@@ -80,11 +86,13 @@ impl Integer {
             ConstInt::U32(i) => Integer::U32(UInt32::constant(*i)),
             ConstInt::U64(i) => Integer::U64(UInt64::constant(*i)),
             ConstInt::U128(i) => Integer::U128(UInt128::constant(*i)),
+            ConstInt::U256(i) => Integer::U256(UInt256::constant(i.to_biguint().unwrap())),
             ConstInt::I8(i) => Integer::I8(Int8::constant(*i)),
             ConstInt::I16(i) => Integer::I16(Int16::constant(*i)),
             ConstInt::I32(i) => Integer::I32(Int32::constant(*i)),
             ConstInt::I64(i) => Integer::I64(Int64::constant(*i)),
             ConstInt::I128(i) => Integer::I128(Int128::constant(*i)),
+            ConstInt::I256(i) => Integer::I256(Int256::constant(i.clone())),
         }
     }
 
@@ -116,12 +124,14 @@ impl Integer {
             Integer::U32(_u32) => IntegerType::U32,
             Integer::U64(_u64) => IntegerType::U64,
             Integer::U128(_u128) => IntegerType::U128,
+            Integer::U256(_u256) => IntegerType::U256,
 
             Integer::I8(_u8) => IntegerType::I8,
             Integer::I16(_u16) => IntegerType::I16,
             Integer::I32(_u32) => IntegerType::I32,
             Integer::I64(_u64) => IntegerType::I64,
             Integer::I128(_u128) => IntegerType::I128,
+            Integer::I256(_u256) => IntegerType::I256,
         }
     }
 
@@ -203,6 +213,18 @@ impl Integer {
 
                 Integer::U128(u128_result)
             }
+            IntegerType::U256 => {
+                let u256_option = option
+                    .map(|s| Self::parse_wide(integer_type, s, span).map(|value| value.to_biguint().unwrap()))
+                    .transpose()?;
+                let u256_result = UInt256::alloc(
+                    cs.ns(|| format!("`{}: u256` {}:{}", name, span.line, span.start)),
+                    || u256_option.ok_or(SynthesisError::AssignmentMissing),
+                )
+                .map_err(|_| IntegerError::missing_integer(format!("{}: u256", name), span.to_owned()))?;
+
+                Integer::U256(u256_result)
+            }
 
             IntegerType::I8 => {
                 let i8_option = option.map(|s| {
@@ -273,9 +295,31 @@ impl Integer {
 
                 Integer::I128(i128_result)
             }
+            IntegerType::I256 => {
+                let i256_option = option.map(|s| Self::parse_wide(integer_type, s, span)).transpose()?;
+                let i256_result = Int256::alloc(
+                    cs.ns(|| format!("`{}: i256` {}:{}", name, span.line, span.start)),
+                    || i256_option.ok_or(SynthesisError::AssignmentMissing),
+                )
+                .map_err(|_| IntegerError::missing_integer(format!("{}: i256", name), span.to_owned()))?;
+
+                Integer::I256(i256_result)
+            }
         })
     }
 
+    /// Parses an input value of type `u256` or `i256`, which fails if it is out of range.
+    fn parse_wide(integer_type: &IntegerType, value: String, span: &Span) -> Result<BigInt, IntegerError> {
+        let parsed = value
+            .parse::<BigInt>()
+            .map_err(|_| IntegerError::invalid_integer(value.clone(), span.to_owned()))?;
+        if ConstInt::parse(integer_type, &value, span).is_err() {
+            return Err(IntegerError::integer_overflow(value, integer_type, span.to_owned()));
+        }
+
+        Ok(parsed)
+    }
+
     pub fn from_input<F: Field, CS: ConstraintSystem<F>>(
         cs: &mut CS,
         integer_type: &IntegerType,
@@ -285,13 +329,10 @@ impl Integer {
     ) -> Result<Self, IntegerError> {
         // Check that the input value is the correct type
         let option = match integer_value {
-            Some(input) => {
-                if let InputValue::Integer(_type_, number) = input {
-                    Some(number)
-                } else {
-                    return Err(IntegerError::invalid_integer(input.to_string(), span.to_owned()));
-                }
-            }
+            Some(input) => match input {
+                InputValue::Integer(_, number) | InputValue::Implicit(number) => Some(number),
+                input => return Err(IntegerError::invalid_integer(input.to_string(), span.to_owned())),
+            },
             None => None,
         };
 
@@ -391,6 +432,252 @@ impl Integer {
 
         result.ok_or_else(|| IntegerError::binary_operation("**".to_string(), span.to_owned()))
     }
+
+    ///
+    /// Returns the `field` element with the same value as this integer.
+    ///
+    /// Fails if the magnitude of the value is not smaller than the field modulus,
+    /// which is only possible for 256-bit integers.
+    ///
+    pub fn to_field<F: PrimeField, CS: ConstraintSystem<F>>(
+        &self,
+        cs: &mut CS,
+        span: &Span,
+    ) -> Result<FieldType<F>, IntegerError> {
+        let modulus =
+            BitIteratorBE::new(F::characteristic()).fold(BigUint::from(0u8), |acc, bit| (acc << 1) + u8::from(bit));
+        let value = self.get_value().map(|value| value.parse::<BigInt>().unwrap());
+
+        if let Some(value) = &value {
+            if *value.magnitude() >= modulus {
+                return Err(IntegerError::field_overflow(value.to_string(), span.to_owned()));
+            }
+        }
+        let field_value = value.as_ref().and_then(|value| {
+            let magnitude = F::from_str(&value.magnitude().to_string()).ok()?;
+            Some(if value.sign() == Sign::Minus {
+                -magnitude
+            } else {
+                magnitude
+            })
+        });
+
+        let bits = self.get_bits();
+        if bits.iter().all(|bit| matches!(bit, Boolean::Constant(_))) {
+            return Ok(FieldType::Constant(field_value.unwrap()));
+        }
+
+        let mut cs = cs.ns(|| format!("{} to field {}:{}", self.get_type(), span.line, span.start));
+        self.enforce_to_field(&mut cs, &bits, &modulus, field_value)
+            .map_err(|e| IntegerError::cannot_enforce("to_field".to_string(), e, span.to_owned()))
+    }
+
+    ///
+    /// Returns the integer of type `integer_type` with the same value as this integer.
+    ///
+    /// Fails if the value does not fit in `integer_type`.
+    ///
+    pub fn cast<F: PrimeField, CS: ConstraintSystem<F>>(
+        &self,
+        cs: &mut CS,
+        integer_type: &IntegerType,
+        span: &Span,
+    ) -> Result<Self, IntegerError> {
+        let value = self.get_value();
+        if let Some(value) = &value {
+            if ConstInt::parse(integer_type, value, span).is_err() {
+                return Err(IntegerError::integer_overflow(
+                    value.clone(),
+                    integer_type,
+                    span.to_owned(),
+                ));
+            }
+        }
+
+        let mut bits = self.get_bits();
+        let is_signed = self.get_type().is_signed();
+        let extension = if is_signed {
+            bits[bits.len() - 1]
+        } else {
+            Boolean::constant(false)
+        };
+
+        // every bit from the highest bit of the new type on must repeat the extension bit
+        let width = integer_type.bit_width();
+        let first = if integer_type.is_signed() { width - 1 } else { width };
+        let last = if is_signed { bits.len() - 1 } else { bits.len() };
+
+        let mut cs = cs.ns(|| {
+            format!(
+                "cast {} to {} {}:{}",
+                self.get_type(),
+                integer_type,
+                span.line,
+                span.start
+            )
+        });
+        for (i, bit) in bits.iter().enumerate().take(last).skip(first) {
+            bit.enforce_equal(cs.ns(|| format!("extension bit {}", i)), &extension)
+                .map_err(|e| IntegerError::cannot_enforce("cast".to_string(), e, span.to_owned()))?;
+        }
+        if is_signed && !integer_type.is_signed() {
+            extension
+                .enforce_equal(cs.ns(|| "non-negative"), &Boolean::constant(false))
+                .map_err(|e| IntegerError::cannot_enforce("cast".to_string(), e, span.to_owned()))?;
+        }
+
+        bits.resize(width, extension);
+        Ok(Self::from_bits(integer_type, bits, value))
+    }
+
+    ///
+    /// Returns the integer of type `integer_type` with the given little-endian bits.
+    ///
+    /// Signed integers are read in two's complement. Adds no constraints.
+    ///
+    pub fn from_bits_le(integer_type: &IntegerType, bits: Vec<Boolean>) -> Self {
+        assert_eq!(bits.len(), integer_type.bit_width()); // asg enforced
+
+        let value = bits
+            .iter()
+            .rev()
+            .map(|bit| bit.get_value())
+            .collect::<Option<Vec<_>>>()
+            .map(|bits| {
+                let magnitude = bits.iter().fold(BigInt::from(0u8), |value, bit| {
+                    (value << 1usize) + BigInt::from(*bit as u8)
+                });
+                if integer_type.is_signed() && bits[0] {
+                    (magnitude - (BigInt::from(1u8) << bits.len())).to_string()
+                } else {
+                    magnitude.to_string()
+                }
+            });
+
+        Self::from_bits(integer_type, bits, value)
+    }
+
+    /// Returns the integer of type `integer_type` with the given little-endian bits and value.
+    fn from_bits(integer_type: &IntegerType, bits: Vec<Boolean>, value: Option<String>) -> Self {
+        let value = value.as_deref();
+        match integer_type {
+            IntegerType::U8 => Integer::U8(UInt8 {
+                bits,
+                negated: false,
+                value: value.map(|value| value.parse().unwrap()),
+            }),
+            IntegerType::U16 => Integer::U16(UInt16 {
+                bits,
+                negated: false,
+                value: value.map(|value| value.parse().unwrap()),
+            }),
+            IntegerType::U32 => Integer::U32(UInt32 {
+                bits,
+                negated: false,
+                value: value.map(|value| value.parse().unwrap()),
+            }),
+            IntegerType::U64 => Integer::U64(UInt64 {
+                bits,
+                negated: false,
+                value: value.map(|value| value.parse().unwrap()),
+            }),
+            IntegerType::U128 => Integer::U128(UInt128 {
+                bits,
+                negated: false,
+                value: value.map(|value| value.parse().unwrap()),
+            }),
+            IntegerType::U256 => Integer::U256(UInt256 {
+                bits,
+                value: value.map(|value| value.parse().unwrap()),
+            }),
+            IntegerType::I8 => Integer::I8(Int8 {
+                bits,
+                value: value.map(|value| value.parse().unwrap()),
+            }),
+            IntegerType::I16 => Integer::I16(Int16 {
+                bits,
+                value: value.map(|value| value.parse().unwrap()),
+            }),
+            IntegerType::I32 => Integer::I32(Int32 {
+                bits,
+                value: value.map(|value| value.parse().unwrap()),
+            }),
+            IntegerType::I64 => Integer::I64(Int64 {
+                bits,
+                value: value.map(|value| value.parse().unwrap()),
+            }),
+            IntegerType::I128 => Integer::I128(Int128 {
+                bits,
+                value: value.map(|value| value.parse().unwrap()),
+            }),
+            IntegerType::I256 => Integer::I256(Int256 {
+                bits,
+                value: value.map(|value| value.parse().unwrap()),
+            }),
+        }
+    }
+
+    fn enforce_to_field<F: PrimeField, CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        bits: &[Boolean],
+        modulus: &BigUint,
+        field_value: Option<F>,
+    ) -> Result<FieldType<F>, SynthesisError> {
+        let is_signed = self.get_type().is_signed();
+
+        // split signed values into their sign and magnitude
+        let (sign, magnitude) = if is_signed {
+            let sign = bits[bits.len() - 1];
+            let negated = bits.to_vec().neg(cs.ns(|| "negate"))?;
+            let magnitude = negated
+                .iter()
+                .zip(bits.iter())
+                .enumerate()
+                .map(|(i, (first, second))| {
+                    Boolean::conditionally_select(cs.ns(|| format!("magnitude bit {}", i)), &sign, first, second)
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            (sign, magnitude)
+        } else {
+            (Boolean::constant(false), bits.to_vec())
+        };
+
+        // only integers with at least as many bits as the modulus can exceed it
+        if magnitude.len() >= F::Parameters::MODULUS_BITS as usize {
+            let mut modulus_bits: Vec<Boolean> = modulus
+                .to_radix_le(2)
+                .into_iter()
+                .map(|bit| Boolean::constant(bit == 1))
+                .collect();
+            modulus_bits.resize(magnitude.len(), Boolean::constant(false));
+
+            let fits = magnitude.less_than(cs.ns(|| "magnitude less than modulus"), &modulus_bits)?;
+            fits.enforce_equal(cs.ns(|| "enforce fits in field"), &Boolean::constant(true))?;
+        }
+
+        let mut magnitude_lc = LinearCombination::zero();
+        let mut coeff = F::one();
+        for bit in magnitude.iter() {
+            magnitude_lc = magnitude_lc + &bit.lc(CS::one(), coeff);
+            coeff.double_in_place();
+        }
+
+        // result = magnitude * (1 - 2 * sign)
+        let factor = sign.lc(CS::one(), -F::one().double()) + (F::one(), CS::one());
+
+        let result = FpGadget::alloc(cs.ns(|| "field"), || field_value.get())?;
+        let variable = result.get_variable();
+
+        cs.enforce(
+            || "pack bits",
+            |lc| lc + &magnitude_lc,
+            |lc| lc + &factor,
+            |lc| &variable + lc,
+        );
+
+        Ok(FieldType::Allocated(result))
+    }
 }
 
 impl<F: PrimeField> EvaluateEqGadget<F> for Integer {
@@ -451,11 +738,13 @@ impl<F: PrimeField> CondSelectGadget<F> for Integer {
             (Integer::U32(a), Integer::U32(b)) => Ok(Integer::U32(UInt32::conditionally_select(cs, cond, a, b)?)),
             (Integer::U64(a), Integer::U64(b)) => Ok(Integer::U64(UInt64::conditionally_select(cs, cond, a, b)?)),
             (Integer::U128(a), Integer::U128(b)) => Ok(Integer::U128(UInt128::conditionally_select(cs, cond, a, b)?)),
+            (Integer::U256(a), Integer::U256(b)) => Ok(Integer::U256(UInt256::conditionally_select(cs, cond, a, b)?)),
             (Integer::I8(a), Integer::I8(b)) => Ok(Integer::I8(Int8::conditionally_select(cs, cond, a, b)?)),
             (Integer::I16(a), Integer::I16(b)) => Ok(Integer::I16(Int16::conditionally_select(cs, cond, a, b)?)),
             (Integer::I32(a), Integer::I32(b)) => Ok(Integer::I32(Int32::conditionally_select(cs, cond, a, b)?)),
             (Integer::I64(a), Integer::I64(b)) => Ok(Integer::I64(Int64::conditionally_select(cs, cond, a, b)?)),
             (Integer::I128(a), Integer::I128(b)) => Ok(Integer::I128(Int128::conditionally_select(cs, cond, a, b)?)),
+            (Integer::I256(a), Integer::I256(b)) => Ok(Integer::I256(Int256::conditionally_select(cs, cond, a, b)?)),
 
             (_, _) => Err(SynthesisError::Unsatisfiable), // types do not match
         }
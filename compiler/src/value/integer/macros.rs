@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use leo_gadgets::signed_integer::*;
+use leo_gadgets::{signed_integer::*, unsigned_integer::UInt256};
 
 use snarkvm_models::gadgets::utilities::{
     boolean::Boolean,
@@ -45,6 +45,23 @@ macro_rules! integer_trait_impl {
 
 integer_trait_impl!(UInt8 UInt16 UInt32 UInt64 UInt128 Int8 Int16 Int32 Int64 Int128);
 
+macro_rules! wide_integer_trait_impl {
+    ($($gadget: ident)*) => ($(
+        impl IntegerTrait for $gadget {
+            fn get_value(&self) -> Option<String> {
+                self.value.as_ref().map(|num| num.to_string())
+            }
+
+            fn get_bits(&self) -> Vec<Boolean> {
+                self.bits.clone()
+            }
+        }
+
+    )*)
+}
+
+wide_integer_trait_impl!(UInt256 Int256);
+
 /// Useful macros to avoid duplicating `match` constructions.
 #[macro_export]
 macro_rules! match_integer {
@@ -55,12 +72,14 @@ macro_rules! match_integer {
             Integer::U32($integer) => $expression,
             Integer::U64($integer) => $expression,
             Integer::U128($integer) => $expression,
+            Integer::U256($integer) => $expression,
 
             Integer::I8($integer) => $expression,
             Integer::I16($integer) => $expression,
             Integer::I32($integer) => $expression,
             Integer::I64($integer) => $expression,
             Integer::I128($integer) => $expression,
+            Integer::I256($integer) => $expression,
         }
     };
 }
@@ -74,6 +93,7 @@ macro_rules! match_unsigned_integer {
             Integer::U32($integer) => $expression,
             Integer::U64($integer) => $expression,
             Integer::U128($integer) => $expression,
+            Integer::U256($integer) => $expression,
 
             _ => None,
         }
@@ -99,6 +119,9 @@ macro_rules! match_signed_integer {
             Integer::I128($integer) => Some(Integer::I128(
                 $expression.map_err(|e| IntegerError::signed(e, $span.to_owned()))?,
             )),
+            Integer::I256($integer) => Some(Integer::I256(
+                $expression.map_err(|e| IntegerError::signed(e, $span.to_owned()))?,
+            )),
 
             _ => None,
         }
@@ -114,12 +137,14 @@ macro_rules! match_integers {
             (Integer::U32($a), Integer::U32($b)) => Some($expression?),
             (Integer::U64($a), Integer::U64($b)) => Some($expression?),
             (Integer::U128($a), Integer::U128($b)) => Some($expression?),
+            (Integer::U256($a), Integer::U256($b)) => Some($expression?),
 
             (Integer::I8($a), Integer::I8($b)) => Some($expression?),
             (Integer::I16($a), Integer::I16($b)) => Some($expression?),
             (Integer::I32($a), Integer::I32($b)) => Some($expression?),
             (Integer::I64($a), Integer::I64($b)) => Some($expression?),
             (Integer::I128($a), Integer::I128($b)) => Some($expression?),
+            (Integer::I256($a), Integer::I256($b)) => Some($expression?),
             (_, _) => None,
         }
     };
@@ -144,6 +169,9 @@ macro_rules! match_integers_span {
             (Integer::U128($a), Integer::U128($b)) => Some(Integer::U128(
                 $expression.map_err(|e| IntegerError::synthesis(e, $span.to_owned()))?,
             )),
+            (Integer::U256($a), Integer::U256($b)) => Some(Integer::U256(
+                $expression.map_err(|e| IntegerError::unsigned(e, $span.to_owned()))?,
+            )),
 
             (Integer::I8($a), Integer::I8($b)) => Some(Integer::I8(
                 $expression.map_err(|e| IntegerError::signed(e, $span.to_owned()))?,
@@ -160,6 +188,9 @@ macro_rules! match_integers_span {
             (Integer::I128($a), Integer::I128($b)) => Some(Integer::I128(
                 $expression.map_err(|e| IntegerError::signed(e, $span.to_owned()))?,
             )),
+            (Integer::I256($a), Integer::I256($b)) => Some(Integer::I256(
+                $expression.map_err(|e| IntegerError::signed(e, $span.to_owned()))?,
+            )),
             (_, _) => None,
         }
     };
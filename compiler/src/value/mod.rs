@@ -24,6 +24,9 @@ pub mod boolean;
 pub mod field;
 pub use self::field::*;
 
+pub mod fixed;
+pub use self::fixed::*;
+
 pub mod group;
 pub use self::group::*;
 
@@ -16,7 +16,7 @@
 
 //! The in memory stored value for a defined name in a compiled Leo program.
 
-use crate::{errors::ValueError, Address, FieldType, GroupType, Integer};
+use crate::{errors::ValueError, Address, FieldType, Fixed, GroupType, Integer};
 use leo_asg::{Circuit, Identifier, Span, Type};
 
 use snarkvm_errors::gadgets::SynthesisError;
@@ -38,6 +38,7 @@ pub enum ConstrainedValue<'a, F: PrimeField, G: GroupType<F>> {
     Address(Address),
     Boolean(Boolean),
     Field(FieldType<F>),
+    Fixed(Fixed),
     Group(G),
     Integer(Integer),
 
@@ -58,6 +59,7 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedValue<'a, F, G> {
             ConstrainedValue::Address(_address) => Type::Address,
             ConstrainedValue::Boolean(_bool) => Type::Boolean,
             ConstrainedValue::Field(_field) => Type::Field,
+            ConstrainedValue::Fixed(fixed) => Type::Fixed(fixed.type_.clone()),
             ConstrainedValue::Group(_group) => Type::Group,
             ConstrainedValue::Integer(integer) => Type::Integer(integer.get_type()),
 
@@ -96,6 +98,7 @@ impl<'a, F: PrimeField, G: GroupType<F>> fmt::Display for ConstrainedValue<'a, F
                     .unwrap_or_else(|| "[allocated]".to_string())
             ),
             ConstrainedValue::Field(ref value) => write!(f, "{:?}", value),
+            ConstrainedValue::Fixed(ref value) => write!(f, "{}", value),
             ConstrainedValue::Group(ref value) => write!(f, "{:?}", value),
             ConstrainedValue::Integer(ref value) => write!(f, "{}", value),
 
@@ -152,6 +155,9 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConditionalEqGadget<F> for ConstrainedV
             (ConstrainedValue::Field(field_1), ConstrainedValue::Field(field_2)) => {
                 field_1.conditional_enforce_equal(cs, field_2, condition)
             }
+            (ConstrainedValue::Fixed(fixed_1), ConstrainedValue::Fixed(fixed_2)) => {
+                fixed_1.conditional_enforce_equal(cs, fixed_2, condition)
+            }
             (ConstrainedValue::Group(group_1), ConstrainedValue::Group(group_2)) => {
                 group_1.conditional_enforce_equal(cs, group_2, condition)
             }
@@ -196,6 +202,9 @@ impl<'a, F: PrimeField, G: GroupType<F>> CondSelectGadget<F> for ConstrainedValu
             (ConstrainedValue::Field(field_1), ConstrainedValue::Field(field_2)) => {
                 ConstrainedValue::Field(FieldType::conditionally_select(cs, cond, field_1, field_2)?)
             }
+            (ConstrainedValue::Fixed(fixed_1), ConstrainedValue::Fixed(fixed_2)) => {
+                ConstrainedValue::Fixed(Fixed::conditionally_select(cs, cond, fixed_1, fixed_2)?)
+            }
             (ConstrainedValue::Group(group_1), ConstrainedValue::Group(group_2)) => {
                 ConstrainedValue::Group(G::conditionally_select(cs, cond, group_1, group_2)?)
             }
@@ -539,3 +539,72 @@ fn test_variable_slice_fail() {
 
     expect_asg_error(error);
 }
+
+#[test]
+fn test_len() {
+    let program_string = include_str!("len.leo");
+    let input_string = include_str!("input/methods.in");
+    let program = parse_program_with_input(program_string, input_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_for_each() {
+    let program_string = include_str!("for_each.leo");
+    let input_string = include_str!("input/methods.in");
+    let program = parse_program_with_input(program_string, input_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_for_each_fail() {
+    let program_string = include_str!("for_each_fail.leo");
+    let error = parse_program(program_string).err().unwrap();
+
+    expect_asg_error(error);
+}
+
+#[test]
+fn test_reverse() {
+    let program_string = include_str!("reverse.leo");
+    let input_string = include_str!("input/methods.in");
+    let program = parse_program_with_input(program_string, input_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_contains() {
+    let program_string = include_str!("contains.leo");
+    let input_string = include_str!("input/methods.in");
+    let program = parse_program_with_input(program_string, input_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_concat() {
+    let program_string = include_str!("concat.leo");
+    let input_string = include_str!("input/methods.in");
+    let program = parse_program_with_input(program_string, input_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_concat_fail() {
+    let program_string = include_str!("concat_fail.leo");
+    let error = parse_program(program_string).err().unwrap();
+
+    expect_asg_error(error);
+}
+
+#[test]
+fn test_methods_const() {
+    let program_string = include_str!("methods_const.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
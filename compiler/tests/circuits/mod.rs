@@ -14,7 +14,16 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{assert_satisfied, expect_asg_error, parse_program};
+use crate::{assert_satisfied, expect_asg_error, expect_compiler_error, generate_main_input, parse_program};
+use leo_ast::InputValue;
+use leo_input::types::{IntegerType, U32Type, UnsignedIntegerType};
+
+fn u32(value: u32) -> Option<InputValue> {
+    Some(InputValue::Integer(
+        IntegerType::Unsigned(UnsignedIntegerType::U32Type(U32Type {})),
+        value.to_string(),
+    ))
+}
 
 // Expressions
 
@@ -52,6 +61,84 @@ fn test_member_variable() {
     assert_satisfied(program);
 }
 
+#[test]
+fn test_eq() {
+    let program_string = include_str!("eq.leo");
+    let mut program = parse_program(program_string).unwrap();
+
+    let main_input = generate_main_input(vec![("a", u32(1))]);
+    program.set_main_input(main_input);
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_eq_fail() {
+    let program_string = include_str!("eq.leo");
+    let mut program = parse_program(program_string).unwrap();
+
+    let main_input = generate_main_input(vec![("a", u32(2))]);
+    program.set_main_input(main_input);
+
+    expect_compiler_error(program);
+}
+
+#[test]
+fn test_eq_type_fail() {
+    let program_string = include_str!("eq_type_fail.leo");
+    let error = parse_program(program_string).err().unwrap();
+
+    expect_asg_error(error);
+}
+
+// Operators
+
+#[test]
+fn test_operators() {
+    let program_string = include_str!("operators.leo");
+    let mut program = parse_program(program_string).unwrap();
+
+    let main_input = generate_main_input(vec![("a", Some(InputValue::Field("2".to_string())))]);
+    program.set_main_input(main_input);
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_operators_fail() {
+    let program_string = include_str!("operators.leo");
+    let mut program = parse_program(program_string).unwrap();
+
+    let main_input = generate_main_input(vec![("a", Some(InputValue::Field("3".to_string())))]);
+    program.set_main_input(main_input);
+
+    expect_compiler_error(program);
+}
+
+#[test]
+fn test_operators_missing_fail() {
+    let program_string = include_str!("operators_missing_fail.leo");
+    let error = parse_program(program_string).err().unwrap();
+
+    expect_asg_error(error);
+}
+
+#[test]
+fn test_operators_static_fail() {
+    let program_string = include_str!("operators_static_fail.leo");
+    let error = parse_program(program_string).err().unwrap();
+
+    expect_asg_error(error);
+}
+
+#[test]
+fn test_operators_type_fail() {
+    let program_string = include_str!("operators_type_fail.leo");
+    let error = parse_program(program_string).err().unwrap();
+
+    expect_asg_error(error);
+}
+
 #[test]
 fn test_member_variable_fail() {
     let program_string = include_str!("member_variable_fail.leo");
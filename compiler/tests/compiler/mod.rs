@@ -15,6 +15,8 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{get_output, EdwardsTestCompiler};
+use leo_ast::Diagnostics;
+use leo_compiler::CompilerOptions;
 
 use std::{env::current_dir, path::PathBuf};
 
@@ -36,8 +38,15 @@ fn test_parse_program_from_string() {
     let mut local = current_dir().unwrap();
     local.push(MAIN_FILE_NAME);
 
-    let compiler_with_path =
-        EdwardsTestCompiler::parse_program_without_input("".to_string(), local, PathBuf::new(), context).unwrap();
+    let compiler_with_path = EdwardsTestCompiler::parse_program_without_input(
+        "".to_string(),
+        local,
+        PathBuf::new(),
+        context,
+        Diagnostics::new(),
+        CompilerOptions::default(),
+    )
+    .unwrap();
 
     // Compare output bytes.
     let expected_output = get_output(compiler_no_path);
@@ -0,0 +1,64 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{assert_satisfied, generate_main_input, parse_program};
+
+use leo_ast::InputValue;
+use leo_input::types::{IntegerType, U32Type, UnsignedIntegerType};
+use rand::Rng;
+use rand_core::SeedableRng;
+use rand_xorshift::XorShiftRng;
+
+#[test]
+fn test_all_any_count() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    let cases = vec![[false; 8], [true; 8], rng.gen(), rng.gen(), rng.gen()];
+    for values in cases {
+        let program_string = include_str!("all_any_count.leo");
+        let mut program = parse_program(program_string).unwrap();
+
+        let count = values.iter().filter(|value| **value).count();
+        let main_input = generate_main_input(vec![
+            (
+                "values",
+                Some(InputValue::Array(
+                    values.iter().map(|value| InputValue::Boolean(*value)).collect(),
+                )),
+            ),
+            ("all", Some(InputValue::Boolean(count == 8))),
+            ("any", Some(InputValue::Boolean(count > 0))),
+            (
+                "count",
+                Some(InputValue::Integer(
+                    IntegerType::Unsigned(UnsignedIntegerType::U32Type(U32Type {})),
+                    count.to_string(),
+                )),
+            ),
+        ]);
+        program.set_main_input(main_input);
+
+        assert_satisfied(program);
+    }
+}
+
+#[test]
+fn test_with_bits() {
+    let program_string = include_str!("with_bits.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
@@ -0,0 +1,88 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{assert_satisfied, generate_main_input, parse_program};
+
+use leo_ast::InputValue;
+use leo_input::types::{IntegerType, U64Type, U8Type, UnsignedIntegerType};
+use rand::Rng;
+use rand_core::SeedableRng;
+use rand_xorshift::XorShiftRng;
+
+#[test]
+fn test_xor() {
+    for (a, b) in [(false, false), (false, true), (true, false), (true, true)].iter() {
+        let program_string = include_str!("xor.leo");
+        let mut program = parse_program(program_string).unwrap();
+
+        let main_input = generate_main_input(vec![
+            ("a", Some(InputValue::Boolean(*a))),
+            ("b", Some(InputValue::Boolean(*b))),
+            ("c", Some(InputValue::Boolean(a != b))),
+        ]);
+        program.set_main_input(main_input);
+
+        assert_satisfied(program);
+    }
+}
+
+#[test]
+fn test_u8_bits() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..10 {
+        let a: u8 = rng.gen();
+        let bits = (0..8).map(|i| InputValue::Boolean((a >> i) & 1 == 1)).collect();
+
+        let program_string = include_str!("u8_bits.leo");
+        let mut program = parse_program(program_string).unwrap();
+
+        let main_input = generate_main_input(vec![
+            (
+                "a",
+                Some(InputValue::Integer(
+                    IntegerType::Unsigned(UnsignedIntegerType::U8Type(U8Type {})),
+                    a.to_string(),
+                )),
+            ),
+            ("bits", Some(InputValue::Array(bits))),
+        ]);
+        program.set_main_input(main_input);
+
+        assert_satisfied(program);
+    }
+}
+
+#[test]
+fn test_u64_roundtrip() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    let a: u64 = rng.gen();
+
+    let program_string = include_str!("u64_roundtrip.leo");
+    let mut program = parse_program(program_string).unwrap();
+
+    let main_input = generate_main_input(vec![(
+        "a",
+        Some(InputValue::Integer(
+            IntegerType::Unsigned(UnsignedIntegerType::U64Type(U64Type {})),
+            a.to_string(),
+        )),
+    )]);
+    program.set_main_input(main_input);
+
+    assert_satisfied(program);
+}
@@ -0,0 +1,93 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{assert_satisfied, expect_compiler_error, generate_main_input, parse_program};
+
+use leo_ast::InputValue;
+use leo_input::types::{I32Type, I8Type, IntegerType, SignedIntegerType, U32Type, UnsignedIntegerType};
+use rand::Rng;
+use rand_core::SeedableRng;
+use rand_xorshift::XorShiftRng;
+
+#[test]
+fn test_min_max() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..10 {
+        let a: u32 = rng.gen();
+        let b: u32 = rng.gen();
+
+        let program_string = include_str!("min_max.leo");
+        let mut program = parse_program(program_string).unwrap();
+
+        let u32_type = IntegerType::Unsigned(UnsignedIntegerType::U32Type(U32Type {}));
+        let main_input = generate_main_input(vec![
+            ("a", Some(InputValue::Integer(u32_type.clone(), a.to_string()))),
+            ("b", Some(InputValue::Integer(u32_type.clone(), b.to_string()))),
+            ("min", Some(InputValue::Integer(u32_type.clone(), a.min(b).to_string()))),
+            ("max", Some(InputValue::Integer(u32_type, a.max(b).to_string()))),
+        ]);
+        program.set_main_input(main_input);
+
+        assert_satisfied(program);
+    }
+}
+
+#[test]
+fn test_abs() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..10 {
+        let a: i32 = rng.gen_range(i32::MIN + 1..=i32::MAX);
+
+        let program_string = include_str!("abs.leo");
+        let mut program = parse_program(program_string).unwrap();
+
+        let i32_type = IntegerType::Signed(SignedIntegerType::I32Type(I32Type {}));
+        let main_input = generate_main_input(vec![
+            ("a", Some(InputValue::Integer(i32_type.clone(), a.to_string()))),
+            ("b", Some(InputValue::Integer(i32_type, a.abs().to_string()))),
+        ]);
+        program.set_main_input(main_input);
+
+        assert_satisfied(program);
+    }
+}
+
+#[test]
+fn test_abs_min_fail() {
+    let program_string = include_str!("abs_min_fail.leo");
+    let mut program = parse_program(program_string).unwrap();
+
+    let main_input = generate_main_input(vec![(
+        "a",
+        Some(InputValue::Integer(
+            IntegerType::Signed(SignedIntegerType::I8Type(I8Type {})),
+            i8::MIN.to_string(),
+        )),
+    )]);
+    program.set_main_input(main_input);
+
+    expect_compiler_error(program);
+}
+
+#[test]
+fn test_constant() {
+    let program_string = include_str!("constant.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
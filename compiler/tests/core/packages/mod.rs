@@ -14,4 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
+pub mod array;
+pub mod bits;
+pub mod math;
 pub mod unstable;
@@ -15,12 +15,14 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{assert_satisfied, expect_compiler_error, generate_main_input, parse_program};
+use leo_asg::FIELD_MODULUS;
 use leo_ast::InputValue;
 
 use snarkvm_curves::edwards_bls12::Fq;
+use snarkvm_models::curves::Field;
 use snarkvm_utilities::bytes::ToBytes;
 
-use num_bigint::BigUint;
+use num_bigint::{BigInt, BigUint};
 use rand::{Rng, SeedableRng};
 use rand_xorshift::XorShiftRng;
 
@@ -359,3 +361,229 @@ fn test_ternary() {
 //
 //     output_zero(program);
 // }
+
+#[test]
+fn test_inv() {
+    use snarkvm_models::curves::Field;
+
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..10 {
+        let a: Fq = rng.gen();
+        let b = a.inverse().unwrap();
+
+        let a_string = field_to_decimal_string(a);
+        let b_string = field_to_decimal_string(b);
+
+        let program_string = include_str!("inv.leo");
+        let mut program = parse_program(program_string).unwrap();
+
+        let main_input = generate_main_input(vec![
+            ("a", Some(InputValue::Field(a_string))),
+            ("b", Some(InputValue::Field(b_string))),
+        ]);
+        program.set_main_input(main_input);
+
+        assert_satisfied(program)
+    }
+}
+
+#[test]
+fn test_inv_zero_fail() {
+    let program_string = include_str!("inv_zero_fail.leo");
+    let program = parse_program(program_string).unwrap();
+
+    expect_compiler_error(program);
+}
+
+#[test]
+fn test_square() {
+    use snarkvm_models::curves::Field;
+
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..10 {
+        let a: Fq = rng.gen();
+        let b = a.square();
+
+        let a_string = field_to_decimal_string(a);
+        let b_string = field_to_decimal_string(b);
+
+        let program_string = include_str!("square.leo");
+        let mut program = parse_program(program_string).unwrap();
+
+        let main_input = generate_main_input(vec![
+            ("a", Some(InputValue::Field(a_string))),
+            ("b", Some(InputValue::Field(b_string))),
+        ]);
+        program.set_main_input(main_input);
+
+        assert_satisfied(program)
+    }
+}
+
+#[test]
+fn test_pow() {
+    use snarkvm_models::curves::{Field, PrimeField};
+
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..10 {
+        let a: Fq = rng.gen();
+        let b: Fq = rng.gen();
+        let c = a.pow(b.into_repr());
+
+        let a_string = field_to_decimal_string(a);
+        let b_string = field_to_decimal_string(b);
+        let c_string = field_to_decimal_string(c);
+
+        let program_string = include_str!("pow.leo");
+        let mut program = parse_program(program_string).unwrap();
+
+        let main_input = generate_main_input(vec![
+            ("a", Some(InputValue::Field(a_string))),
+            ("b", Some(InputValue::Field(b_string))),
+            ("c", Some(InputValue::Field(c_string))),
+        ]);
+        program.set_main_input(main_input);
+
+        assert_satisfied(program)
+    }
+}
+
+#[test]
+fn test_sqrt() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..10 {
+        let a: Fq = rng.gen();
+
+        let a_string = field_to_decimal_string(a);
+
+        let program_string = include_str!("sqrt.leo");
+        let mut program = parse_program(program_string).unwrap();
+
+        let main_input = generate_main_input(vec![("a", Some(InputValue::Field(a_string)))]);
+        program.set_main_input(main_input);
+
+        assert_satisfied(program)
+    }
+}
+
+#[test]
+fn test_sqrt_canonical() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..10 {
+        let a: Fq = rng.gen();
+        let square = a.square();
+
+        // Both `a` and `-a` are roots, the canonical one is the smaller representative
+        let roots = [a, -a];
+        let root = roots
+            .iter()
+            .min_by_key(|root| field_to_decimal_string(**root).parse::<BigUint>().unwrap())
+            .unwrap();
+
+        let program_string = include_str!("sqrt_canonical.leo");
+        let mut program = parse_program(program_string).unwrap();
+
+        let main_input = generate_main_input(vec![
+            ("a", Some(InputValue::Field(field_to_decimal_string(square)))),
+            ("b", Some(InputValue::Field(field_to_decimal_string(*root)))),
+        ]);
+        program.set_main_input(main_input);
+
+        assert_satisfied(program)
+    }
+}
+
+#[test]
+fn test_sqrt_matches_constant() {
+    let program_string = include_str!("sqrt_matches_constant.leo");
+    let mut program = parse_program(program_string).unwrap();
+
+    let main_input = generate_main_input(vec![("a", Some(InputValue::Field("4".to_string())))]);
+    program.set_main_input(main_input);
+
+    assert_satisfied(program)
+}
+
+#[test]
+fn test_sqrt_invalid() {
+    // 22 generates the multiplicative group, so it is a quadratic non-residue
+    let program_string = include_str!("sqrt_invalid.leo");
+    let mut program = parse_program(program_string).unwrap();
+
+    let main_input = generate_main_input(vec![("a", Some(InputValue::Field("22".to_string())))]);
+    program.set_main_input(main_input);
+
+    assert_satisfied(program)
+}
+
+#[test]
+fn test_is_zero() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..10 {
+        let a: Fq = rng.gen();
+
+        let a_string = field_to_decimal_string(a);
+
+        let program_string = include_str!("is_zero.leo");
+        let mut program = parse_program(program_string).unwrap();
+
+        let main_input = generate_main_input(vec![
+            ("a", Some(InputValue::Field(a_string))),
+            ("b", Some(InputValue::Boolean(false))),
+        ]);
+        program.set_main_input(main_input);
+
+        assert_satisfied(program)
+    }
+
+    let program_string = include_str!("is_zero.leo");
+    let mut program = parse_program(program_string).unwrap();
+
+    let main_input = generate_main_input(vec![
+        ("a", Some(InputValue::Field("0".to_string()))),
+        ("b", Some(InputValue::Boolean(true))),
+    ]);
+    program.set_main_input(main_input);
+
+    assert_satisfied(program)
+}
+
+#[test]
+fn test_intrinsics_const() {
+    let program_string = include_str!("intrinsics_const.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program)
+}
+
+#[test]
+fn test_eq_constant() {
+    for (a, b) in [("1", true), ("2", false)].iter() {
+        let program_string = include_str!("eq_constant.leo");
+        let mut program = parse_program(program_string).unwrap();
+
+        let main_input = generate_main_input(vec![
+            ("a", Some(InputValue::Field(a.to_string()))),
+            ("b", Some(InputValue::Boolean(*b))),
+        ]);
+        program.set_main_input(main_input);
+
+        assert_satisfied(program);
+    }
+}
+
+#[test]
+fn test_asg_field_modulus() {
+    let modulus = Fq::characteristic()
+        .iter()
+        .rev()
+        .fold(BigInt::from(0u8), |modulus, limb| (modulus << 64usize) + limb);
+
+    assert_eq!(*FIELD_MODULUS, modulus);
+}
@@ -0,0 +1,295 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{assert_satisfied, expect_asg_error, expect_compiler_error, generate_main_input, parse_program};
+use leo_ast::{FixedType, InputValue};
+use leo_input::types::{I32Type, IntegerType, SignedIntegerType, U8Type, UnsignedIntegerType};
+
+use std::{
+    io,
+    sync::{Arc, Mutex},
+};
+
+fn i32(value: i32) -> Option<InputValue> {
+    Some(InputValue::Integer(
+        IntegerType::Signed(SignedIntegerType::I32Type(I32Type {})),
+        value.to_string(),
+    ))
+}
+
+fn u8(value: u8) -> Option<InputValue> {
+    Some(InputValue::Integer(
+        IntegerType::Unsigned(UnsignedIntegerType::U8Type(U8Type {})),
+        value.to_string(),
+    ))
+}
+
+fn fixed(width: u32, scale: u32, value: &str) -> Option<InputValue> {
+    Some(InputValue::Fixed(FixedType { width, scale }, value.to_string()))
+}
+
+/// Collects the output of `console.log` statements.
+#[derive(Clone, Default)]
+struct LogBuffer(Arc<Mutex<Vec<u8>>>);
+
+impl io::Write for LogBuffer {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+fn test_unary(program_string: &str, a: i32) {
+    let mut program = parse_program(program_string).unwrap();
+
+    let main_input = generate_main_input(vec![("a", i32(a))]);
+    program.set_main_input(main_input);
+
+    assert_satisfied(program);
+}
+
+fn test_unary_fail(program_string: &str, a: i32) {
+    let mut program = parse_program(program_string).unwrap();
+
+    let main_input = generate_main_input(vec![("a", i32(a))]);
+    program.set_main_input(main_input);
+
+    expect_compiler_error(program);
+}
+
+#[test]
+fn test_const() {
+    let program_string = include_str!("const.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_arithmetic() {
+    let program_string = include_str!("arithmetic.leo");
+    let mut program = parse_program(program_string).unwrap();
+
+    let main_input = generate_main_input(vec![("a", i32(5)), ("b", i32(-3))]);
+    program.set_main_input(main_input);
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_rounding() {
+    let program_string = include_str!("rounding.leo");
+
+    test_unary(program_string, 3);
+}
+
+#[test]
+fn test_rounding_const() {
+    let program_string = include_str!("rounding_const.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_integer() {
+    let program_string = include_str!("integer.leo");
+    let mut program = parse_program(program_string).unwrap();
+
+    let main_input = generate_main_input(vec![("a", i32(-7)), ("b", u8(200))]);
+    program.set_main_input(main_input);
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_round_edge() {
+    let program_string = include_str!("round_edge.leo");
+
+    for (a, b) in [("0.99", "-0.99"), ("1.27", "-1.28"), ("0.50", "-0.50")].iter() {
+        let mut program = parse_program(program_string).unwrap();
+
+        let main_input = generate_main_input(vec![("a", fixed(8, 2, a)), ("b", fixed(8, 2, b))]);
+        program.set_main_input(main_input);
+
+        assert_satisfied(program);
+    }
+}
+
+#[test]
+fn test_round_edge_const() {
+    let program_string = include_str!("round_edge_const.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_input() {
+    let program_string = include_str!("input.leo");
+    let mut program = parse_program(program_string).unwrap();
+
+    let main_input = generate_main_input(vec![("a", fixed(16, 2, "-1.25"))]);
+    program.set_main_input(main_input);
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_input_precision_fail() {
+    let program_string = include_str!("input.leo");
+    let mut program = parse_program(program_string).unwrap();
+
+    let main_input = generate_main_input(vec![("a", fixed(16, 2, "-1.255"))]);
+    program.set_main_input(main_input);
+
+    expect_compiler_error(program);
+}
+
+#[test]
+fn test_unsigned() {
+    let program_string = include_str!("unsigned.leo");
+    let mut program = parse_program(program_string).unwrap();
+
+    let main_input = generate_main_input(vec![("a", fixed(16, 2, "200.75"))]);
+    program.set_main_input(main_input);
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_unsigned_negative() {
+    let program_string = include_str!("unsigned_negative.leo");
+    let mut program = parse_program(program_string).unwrap();
+
+    let main_input = generate_main_input(vec![("a", fixed(16, 2, "-1.50"))]);
+    program.set_main_input(main_input);
+
+    expect_compiler_error(program);
+}
+
+#[test]
+fn test_log() {
+    let program_string = include_str!("log.leo");
+    let mut program = parse_program(program_string).unwrap();
+
+    let main_input = generate_main_input(vec![("a", i32(3))]);
+    program.set_main_input(main_input);
+
+    let buffer = LogBuffer::default();
+    let writer = buffer.clone();
+    let subscriber = tracing_subscriber::fmt()
+        .with_writer(move || writer.clone())
+        .with_ansi(false)
+        .finish();
+    tracing::subscriber::with_default(subscriber, || assert_satisfied(program));
+
+    let output = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+    assert!(output.contains("x = 0.375\n"), "{}", output);
+    assert!(output.contains("-x = -0.375\n"), "{}", output);
+}
+
+#[test]
+fn test_overflow() {
+    let program_string = include_str!("overflow.leo");
+
+    test_unary(program_string, 0);
+    test_unary_fail(program_string, 1);
+}
+
+#[test]
+fn test_to_fixed_overflow() {
+    let program_string = include_str!("to_fixed_overflow.leo");
+
+    test_unary(program_string, -12);
+    test_unary_fail(program_string, 13);
+}
+
+#[test]
+fn test_to_integer_overflow() {
+    let program_string = include_str!("to_integer_overflow.leo");
+
+    test_unary(program_string, 127);
+    test_unary_fail(program_string, 200);
+}
+
+#[test]
+fn test_div_zero() {
+    let program_string = include_str!("div_zero.leo");
+
+    test_unary_fail(program_string, 1);
+}
+
+#[test]
+fn test_max_fail() {
+    let program_string = include_str!("max_fail.leo");
+    let error = parse_program(program_string).err().unwrap();
+
+    expect_asg_error(error);
+}
+
+#[test]
+fn test_precision_fail() {
+    let program_string = include_str!("precision_fail.leo");
+    let error = parse_program(program_string).err().unwrap();
+
+    expect_asg_error(error);
+}
+
+#[test]
+fn test_scale_fail() {
+    let program_string = include_str!("scale_fail.leo");
+    let error = parse_program(program_string).err().unwrap();
+
+    expect_asg_error(error);
+}
+
+#[test]
+fn test_mismatch_fail() {
+    let program_string = include_str!("mismatch_fail.leo");
+    let error = parse_program(program_string).err().unwrap();
+
+    expect_asg_error(error);
+}
+
+#[test]
+fn test_type_fail() {
+    let program_string = include_str!("type_fail.leo");
+    let error = parse_program(program_string).err().unwrap();
+
+    assert!(error.to_string().contains("invalid type 'fixed<16, 5>'"), "{}", error);
+    assert!(error.to_string().contains("2:"), "{}", error);
+}
+
+#[test]
+fn test_to_fixed_untyped_fail() {
+    let program_string = include_str!("to_fixed_untyped_fail.leo");
+    let error = parse_program(program_string).err().unwrap();
+
+    expect_asg_error(error);
+}
+
+#[test]
+fn test_untyped_fail() {
+    let program_string = include_str!("untyped_fail.leo");
+    let error = parse_program(program_string).err().unwrap();
+
+    expect_asg_error(error);
+}
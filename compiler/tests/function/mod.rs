@@ -14,7 +14,9 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{assert_satisfied, expect_asg_error, get_output, parse_program, parse_program_with_input};
+use crate::{assert_satisfied, expect_asg_error, get_output, new_compiler, parse_program, parse_program_with_input};
+use leo_ast::{Diagnostics, WarningLevel, ALL_WARNINGS};
+use leo_compiler::errors::CompilerError;
 
 #[test]
 fn test_conditional_return() {
@@ -66,6 +68,31 @@ fn test_newlines() {
     assert_eq!(expected_string, actual_string);
 }
 
+#[test]
+fn test_dead_code() {
+    let program_string = include_str!("dead_code.leo");
+    let program = parse_program(program_string).unwrap();
+
+    let warnings = program.diagnostics().warnings();
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].error.line, 3);
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_dead_code_denied() {
+    let program_string = include_str!("dead_code.leo");
+    let mut diagnostics = Diagnostics::new();
+    diagnostics.set_level(ALL_WARNINGS, WarningLevel::Deny);
+
+    let mut program = new_compiler();
+    program.set_diagnostics(diagnostics);
+    let error = program.parse_program_from_string(program_string).unwrap_err();
+
+    assert!(matches!(error, CompilerError::WarningsDenied(_)));
+}
+
 #[test]
 fn test_multiple_returns() {
     let program_string = include_str!("multiple_returns.leo");
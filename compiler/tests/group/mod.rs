@@ -15,8 +15,13 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-    assert_satisfied, expect_compiler_error, expect_synthesis_error, field::field_to_decimal_string,
-    generate_main_input, parse_program, parse_program_with_input,
+    assert_satisfied,
+    expect_compiler_error,
+    expect_synthesis_error,
+    field::field_to_decimal_string,
+    generate_main_input,
+    parse_program,
+    parse_program_with_input,
 };
 use leo_ast::{GroupCoordinate, GroupTuple, GroupValue, InputValue, Span};
 
@@ -31,12 +36,7 @@ pub fn group_element_to_input_value(g: EdwardsAffine) -> GroupValue {
 
     format!("({}, {})", x, y);
 
-    let fake_span = Span {
-        text: "".to_string(),
-        line: 0,
-        start: 0,
-        end: 0,
-    };
+    let fake_span = Span::default();
 
     GroupValue::Tuple(GroupTuple {
         x: GroupCoordinate::Number(x, fake_span.clone()),
@@ -394,6 +394,6 @@ fn test_positive_and_negative() {
     let program_string = include_str!("positive_and_negative.leo");
 
     let program = parse_program(program_string).unwrap();
-    
+
     assert_satisfied(program);
 }
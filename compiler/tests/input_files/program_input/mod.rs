@@ -14,8 +14,16 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{assert_satisfied, expect_compiler_error, parse_program_with_input, EdwardsTestCompiler};
-use leo_compiler::errors::CompilerError;
+use crate::{
+    assert_satisfied,
+    expect_compiler_error,
+    generate_main_input,
+    parse_program,
+    parse_program_with_input,
+    EdwardsTestCompiler,
+};
+use leo_ast::{FixedType, Identifier, InputValue};
+use leo_compiler::errors::{CompilerError, FunctionError};
 
 fn expect_fail(program: EdwardsTestCompiler) {
     match expect_compiler_error(program) {
@@ -24,6 +32,15 @@ fn expect_fail(program: EdwardsTestCompiler) {
     }
 }
 
+fn expect_input_fail(program: EdwardsTestCompiler, message: &str) {
+    match expect_compiler_error(program) {
+        CompilerError::FunctionError(FunctionError::InputError(error)) => {
+            assert!(error.to_string().contains(message), "{}", error)
+        }
+        err => panic!("expected input error, got {:?}", err),
+    }
+}
+
 #[test]
 fn test_input_pass() {
     let program_string = include_str!("main.leo");
@@ -113,3 +130,71 @@ fn test_group_input() {
 
     assert_satisfied(program);
 }
+
+#[test]
+fn test_circuit_input() {
+    let program_string = include_str!("main_circuit.leo");
+    let input_string = include_str!("input/main_circuit.in");
+
+    let program = parse_program_with_input(program_string, input_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_circuit_input_fixed_member() {
+    let program_string = include_str!("main_circuit_fixed.leo");
+    let mut program = parse_program(program_string).unwrap();
+
+    let scale = InputValue::Fixed(FixedType { width: 32, scale: 8 }, "1.5".to_string());
+    let main_input = generate_main_input(vec![(
+        "a",
+        Some(InputValue::Circuit(Identifier::new("Scaled".to_string()), vec![(
+            Identifier::new("scale".to_string()),
+            scale,
+        )])),
+    )]);
+    program.set_main_input(main_input);
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_circuit_input_fail_missing() {
+    let program_string = include_str!("main_circuit.leo");
+    let input_string = include_str!("input/main_circuit_fail_missing.in");
+
+    let program = parse_program_with_input(program_string, input_string).unwrap();
+
+    expect_input_fail(program, "Input circuit `Point` is missing member `y`");
+}
+
+#[test]
+fn test_circuit_input_fail_undefined() {
+    let program_string = include_str!("main_circuit.leo");
+    let input_string = include_str!("input/main_circuit_fail_undefined.in");
+
+    let program = parse_program_with_input(program_string, input_string).unwrap();
+
+    expect_input_fail(program, "Circuit `Point` has no member `z`");
+}
+
+#[test]
+fn test_circuit_input_fail_type() {
+    let program_string = include_str!("main_circuit.leo");
+    let input_string = include_str!("input/main_circuit_fail_type.in");
+
+    let program = parse_program_with_input(program_string, input_string).unwrap();
+
+    expect_input_fail(program, "Expected input circuit member `weights` of type `[u8; 2]`");
+}
+
+#[test]
+fn test_circuit_input_fail_name() {
+    let program_string = include_str!("main_circuit.leo");
+    let input_string = include_str!("input/main_circuit_fail_name.in");
+
+    let error = parse_program_with_input(program_string, input_string).err().unwrap();
+
+    assert!(matches!(error, CompilerError::InputParserError(_)));
+}
@@ -0,0 +1,232 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{assert_satisfied, expect_asg_error, expect_compiler_error, generate_main_input, parse_program};
+use leo_asg::FIELD_MODULUS;
+use leo_ast::InputValue;
+use leo_input::types::{I256Type, IntegerType, SignedIntegerType};
+
+use num_bigint::BigInt;
+
+fn i256(value: &BigInt) -> Option<InputValue> {
+    Some(InputValue::Integer(
+        IntegerType::Signed(SignedIntegerType::I256Type(I256Type {})),
+        value.to_string(),
+    ))
+}
+
+fn i256_max() -> BigInt {
+    (BigInt::from(1u8) << 255usize) - 1u8
+}
+
+fn i256_min() -> BigInt {
+    -(BigInt::from(1u8) << 255usize)
+}
+
+fn test_binary(program_string: &str, a: &BigInt, b: &BigInt, c: &BigInt) {
+    let mut program = parse_program(program_string).unwrap();
+
+    let main_input = generate_main_input(vec![("a", i256(a)), ("b", i256(b)), ("c", i256(c))]);
+    program.set_main_input(main_input);
+
+    assert_satisfied(program);
+}
+
+fn test_binary_fail(program_string: &str, a: &BigInt, b: &BigInt, c: &BigInt) {
+    let mut program = parse_program(program_string).unwrap();
+
+    let main_input = generate_main_input(vec![("a", i256(a)), ("b", i256(b)), ("c", i256(c))]);
+    program.set_main_input(main_input);
+
+    expect_compiler_error(program);
+}
+
+#[test]
+fn test_i256_min() {
+    let program_string = include_str!("min.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_i256_min_fail() {
+    let program_string = include_str!("min_fail.leo");
+    let error = parse_program(program_string).err().unwrap();
+
+    expect_asg_error(error);
+}
+
+#[test]
+fn test_i256_max() {
+    let program_string = include_str!("max.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_i256_max_fail() {
+    let program_string = include_str!("max_fail.leo");
+    let error = parse_program(program_string).err().unwrap();
+
+    expect_asg_error(error);
+}
+
+#[test]
+fn test_i256_negate() {
+    let program_string = include_str!("negate.leo");
+    let a = BigInt::from(i128::MIN) << 100usize;
+
+    let mut program = parse_program(program_string).unwrap();
+
+    let main_input = generate_main_input(vec![("a", i256(&a)), ("b", i256(&-&a))]);
+    program.set_main_input(main_input);
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_i256_negate_min_fail() {
+    let program_string = include_str!("negate.leo");
+    let a = i256_min();
+
+    let mut program = parse_program(program_string).unwrap();
+
+    let main_input = generate_main_input(vec![("a", i256(&a)), ("b", i256(&a))]);
+    program.set_main_input(main_input);
+
+    expect_compiler_error(program);
+}
+
+#[test]
+fn test_i256_add() {
+    let a = BigInt::from(i128::MIN) << 120usize;
+    let b = BigInt::from(u128::MAX) << 60usize;
+
+    test_binary(include_str!("add.leo"), &a, &b, &(&a + &b));
+}
+
+#[test]
+fn test_i256_add_overflow() {
+    let a = i256_max();
+    let b = BigInt::from(1u8);
+
+    test_binary_fail(include_str!("add.leo"), &a, &b, &BigInt::from(0u8));
+}
+
+#[test]
+fn test_i256_input_overflow() {
+    let program_string = include_str!("add.leo");
+    let a = -i256_min();
+    let mut program = parse_program(program_string).unwrap();
+
+    let main_input = generate_main_input(vec![("a", i256(&a)), ("b", i256(&a)), ("c", i256(&a))]);
+    program.set_main_input(main_input);
+
+    let error = expect_compiler_error(program);
+    assert!(error.to_string().contains(&format!("`{}` does not fit in `i256`", a)));
+}
+
+#[test]
+fn test_i256_sub() {
+    let a = BigInt::from(i128::MIN) << 64usize;
+    let b = i256_max() >> 1usize;
+
+    test_binary(include_str!("sub.leo"), &a, &b, &(&a - &b));
+}
+
+#[test]
+fn test_i256_sub_overflow() {
+    let a = i256_min();
+    let b = BigInt::from(1u8);
+
+    test_binary_fail(include_str!("sub.leo"), &a, &b, &BigInt::from(0u8));
+}
+
+#[test]
+fn test_i256_mul() {
+    let a = BigInt::from(i128::MIN + 1);
+    let b = BigInt::from(i128::MAX - 12345);
+
+    test_binary(include_str!("mul.leo"), &a, &b, &(&a * &b));
+}
+
+#[test]
+fn test_i256_mul_overflow() {
+    let a = BigInt::from(1u8) << 128usize;
+    let b = -(BigInt::from(1u8) << 128usize);
+
+    test_binary_fail(include_str!("mul.leo"), &a, &b, &BigInt::from(0u8));
+}
+
+#[test]
+fn test_i256_div() {
+    let a = i256_min() + 1u8;
+    let b = BigInt::from(u128::MAX) * 3u8;
+
+    // Leo division truncates towards zero, matching `BigInt` division.
+    test_binary(include_str!("div.leo"), &a, &b, &(&a / &b));
+}
+
+#[test]
+fn test_i256_div_zero() {
+    let a = i256_max();
+    let b = BigInt::from(0u8);
+
+    test_binary_fail(include_str!("div.leo"), &a, &b, &b);
+}
+
+#[test]
+fn test_i256_lt() {
+    let program_string = include_str!("lt.leo");
+    let a = BigInt::from(i128::MIN) << 120usize;
+
+    for (b, expected) in [(-&a, true), (a.clone(), false), (i256_min(), false)] {
+        let mut program = parse_program(program_string).unwrap();
+
+        let main_input = generate_main_input(vec![
+            ("a", i256(&a)),
+            ("b", i256(&b)),
+            ("c", Some(InputValue::Boolean(expected))),
+        ]);
+        program.set_main_input(main_input);
+
+        assert_satisfied(program);
+    }
+}
+
+#[test]
+fn test_i256_field() {
+    let program_string = include_str!("field.leo");
+    let a = BigInt::from(i128::MIN) << 100usize;
+    let b = &*FIELD_MODULUS + &a;
+
+    let mut program = parse_program(program_string).unwrap();
+
+    let main_input = generate_main_input(vec![("a", i256(&a)), ("b", Some(InputValue::Field(b.to_string())))]);
+    program.set_main_input(main_input);
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_i256_const() {
+    let program_string = include_str!("const.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
@@ -0,0 +1,219 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{assert_satisfied, expect_asg_error, expect_compiler_error, generate_main_input, parse_program};
+use leo_ast::InputValue;
+use leo_input::types::{IntegerType, U256Type, UnsignedIntegerType};
+
+use num_bigint::BigUint;
+
+fn u256(value: &BigUint) -> Option<InputValue> {
+    Some(InputValue::Integer(
+        IntegerType::Unsigned(UnsignedIntegerType::U256Type(U256Type {})),
+        value.to_string(),
+    ))
+}
+
+fn u256_max() -> BigUint {
+    (BigUint::from(1u8) << 256usize) - 1u8
+}
+
+fn test_binary(program_string: &str, a: &BigUint, b: &BigUint, c: &BigUint) {
+    let mut program = parse_program(program_string).unwrap();
+
+    let main_input = generate_main_input(vec![("a", u256(a)), ("b", u256(b)), ("c", u256(c))]);
+    program.set_main_input(main_input);
+
+    assert_satisfied(program);
+}
+
+fn test_binary_fail(program_string: &str, a: &BigUint, b: &BigUint, c: &BigUint) {
+    let mut program = parse_program(program_string).unwrap();
+
+    let main_input = generate_main_input(vec![("a", u256(a)), ("b", u256(b)), ("c", u256(c))]);
+    program.set_main_input(main_input);
+
+    expect_compiler_error(program);
+}
+
+#[test]
+fn test_u256_max() {
+    let program_string = include_str!("max.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_u256_max_fail() {
+    let program_string = include_str!("max_fail.leo");
+    let error = parse_program(program_string).err().unwrap();
+
+    expect_asg_error(error);
+}
+
+#[test]
+fn test_u256_min_fail() {
+    let program_string = include_str!("min_fail.leo");
+    let error = parse_program(program_string).err().unwrap();
+
+    expect_asg_error(error);
+}
+
+#[test]
+fn test_u256_add() {
+    let a = BigUint::from(u128::MAX) << 100usize;
+    let b = BigUint::from(u128::MAX);
+
+    test_binary(include_str!("add.leo"), &a, &b, &(&a + &b));
+}
+
+#[test]
+fn test_u256_add_overflow() {
+    let a = u256_max();
+    let b = BigUint::from(1u8);
+
+    test_binary_fail(include_str!("add.leo"), &a, &b, &BigUint::from(0u8));
+}
+
+#[test]
+fn test_u256_input_overflow() {
+    let program_string = include_str!("add.leo");
+    let a = u256_max() + 1u8;
+    let mut program = parse_program(program_string).unwrap();
+
+    let main_input = generate_main_input(vec![("a", u256(&a)), ("b", u256(&a)), ("c", u256(&a))]);
+    program.set_main_input(main_input);
+
+    let error = expect_compiler_error(program);
+    assert!(error.to_string().contains(&format!("`{}` does not fit in `u256`", a)));
+}
+
+#[test]
+fn test_u256_sub() {
+    let a = u256_max();
+    let b = BigUint::from(u128::MAX) << 64usize;
+
+    test_binary(include_str!("sub.leo"), &a, &b, &(&a - &b));
+}
+
+#[test]
+fn test_u256_sub_underflow() {
+    let a = BigUint::from(1u8);
+    let b = BigUint::from(2u8);
+
+    test_binary_fail(include_str!("sub.leo"), &a, &b, &BigUint::from(0u8));
+}
+
+#[test]
+fn test_u256_mul() {
+    let a = BigUint::from(u128::MAX);
+    let b = BigUint::from(u128::MAX - 12345);
+
+    test_binary(include_str!("mul.leo"), &a, &b, &(&a * &b));
+}
+
+#[test]
+fn test_u256_mul_overflow() {
+    let a = BigUint::from(1u8) << 128usize;
+
+    test_binary_fail(include_str!("mul.leo"), &a, &a, &BigUint::from(0u8));
+}
+
+#[test]
+fn test_u256_div() {
+    let a = u256_max();
+    let b = BigUint::from(u128::MAX) * 3u8;
+
+    test_binary(include_str!("div.leo"), &a, &b, &(&a / &b));
+}
+
+#[test]
+fn test_u256_div_zero() {
+    let a = u256_max();
+    let b = BigUint::from(0u8);
+
+    test_binary_fail(include_str!("div.leo"), &a, &b, &b);
+}
+
+#[test]
+fn test_u256_pow() {
+    let a = BigUint::from(3u8);
+    let b = BigUint::from(150u8);
+
+    test_binary(include_str!("pow.leo"), &a, &b, &a.pow(150));
+}
+
+#[test]
+fn test_u256_lt() {
+    let program_string = include_str!("lt.leo");
+    let a = BigUint::from(u128::MAX) << 120usize;
+
+    for (b, expected) in [(&a + 1u8, true), (a.clone(), false), (&a - 1u8, false)] {
+        let mut program = parse_program(program_string).unwrap();
+
+        let main_input = generate_main_input(vec![
+            ("a", u256(&a)),
+            ("b", u256(&b)),
+            ("c", Some(InputValue::Boolean(expected))),
+        ]);
+        program.set_main_input(main_input);
+
+        assert_satisfied(program);
+    }
+}
+
+#[test]
+fn test_u256_field() {
+    let program_string = include_str!("field.leo");
+    let a = BigUint::from(u128::MAX) << 120usize;
+
+    let mut program = parse_program(program_string).unwrap();
+
+    let main_input = generate_main_input(vec![("a", u256(&a)), ("b", Some(InputValue::Field(a.to_string())))]);
+    program.set_main_input(main_input);
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_u256_field_overflow() {
+    let program_string = include_str!("field.leo");
+    let a = u256_max();
+
+    let mut program = parse_program(program_string).unwrap();
+
+    let main_input = generate_main_input(vec![("a", u256(&a)), ("b", Some(InputValue::Field("0".to_string())))]);
+    program.set_main_input(main_input);
+
+    expect_compiler_error(program);
+}
+
+#[test]
+fn test_u256_to_field_fail() {
+    let program_string = include_str!("to_field_fail.leo");
+    let program = parse_program(program_string).unwrap();
+
+    expect_compiler_error(program);
+}
+
+#[test]
+fn test_u256_const() {
+    let program_string = include_str!("const.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
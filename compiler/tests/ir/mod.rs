@@ -0,0 +1,154 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{new_compiler, parse_program, parse_program_with_input};
+use leo_compiler::{errors::CompilerError, CompilerOptions};
+
+use snarkvm_curves::edwards_bls12::Fq;
+use snarkvm_models::gadgets::r1cs::TestConstraintSystem;
+
+fn lower(program_string: &str) -> String {
+    parse_program(program_string)
+        .unwrap()
+        .lower_to_ir()
+        .unwrap()
+        .to_string()
+}
+
+#[test]
+fn test_conditional_phi() {
+    let expected = r#"program test
+
+function main(%0 a: bool, %1 b: u32) -> u32 {
+block0:
+    branch %0, block1, block2
+block1:
+    %2 = const 1u32
+    %3 = add %1, %2
+    jump block3
+block2:
+    %4 = const 2u32
+    %5 = mul %1, %4
+    jump block3
+block3:
+    %6 = phi [%3, block1], [%5, block2]
+    return %6
+}
+"#;
+
+    assert_eq!(lower(include_str!("conditional.leo")), expected);
+}
+
+#[test]
+fn test_unrolled_loop() {
+    let expected = r#"program test
+
+function main(%0 a: [u8; 2]) -> [u8; 2] {
+block0:
+    %1 = const 1u8
+    %2 = const 0u32
+    %3 = array_get %0, %2
+    %4 = add %3, %1
+    %5 = array_set %0, %2, %4
+    %6 = const 1u8
+    %7 = const 1u32
+    %8 = array_get %5, %7
+    %9 = add %8, %6
+    %10 = array_set %5, %7, %9
+    return %10
+}
+"#;
+
+    assert_eq!(lower(include_str!("loop.leo")), expected);
+}
+
+#[test]
+fn test_loop_unrolling_required() {
+    let mut options = CompilerOptions::default();
+    options.passes.insert("loop_unrolling".to_string(), false);
+
+    let mut program = new_compiler();
+    program.set_options(options);
+
+    match program.parse_program_from_string(include_str!("loop.leo")) {
+        Err(CompilerError::RequiredPass(name)) => assert_eq!(name, "loop_unrolling"),
+        _ => panic!("expected a required pass error"),
+    }
+}
+
+/// Synthesizes a program from the asg and from the ir, and asserts that both are satisfied with the same
+/// number of constraints and the same output.
+fn assert_ir_matches_asg(program_string: &str, input_string: &str) {
+    let program = parse_program_with_input(program_string, input_string).unwrap();
+
+    let mut asg_cs = TestConstraintSystem::<Fq>::new();
+    let asg_output = program.compile_constraints(&mut asg_cs).unwrap();
+    let mut ir_cs = TestConstraintSystem::<Fq>::new();
+    let ir_output = program.compile_ir_constraints(&mut ir_cs).unwrap();
+
+    assert!(asg_cs.is_satisfied());
+    assert!(ir_cs.is_satisfied());
+    assert_eq!(asg_cs.num_constraints(), ir_cs.num_constraints());
+    assert_eq!(asg_output.bytes(), ir_output.bytes());
+}
+
+#[test]
+fn test_ir_constraints_loop() {
+    assert_ir_matches_asg(include_str!("loop.leo"), include_str!("loop.in"));
+}
+
+#[test]
+fn test_ir_constraints_parity() {
+    assert_ir_matches_asg(include_str!("parity.leo"), include_str!("parity.in"));
+}
+
+#[test]
+fn test_ir_constraints_failed_assertion() {
+    let input_string = include_str!("parity.in").replace("b: u32 = 9;", "b: u32 = 1;");
+    let program = parse_program_with_input(include_str!("parity.leo"), &input_string).unwrap();
+
+    let mut cs = TestConstraintSystem::<Fq>::new();
+    match program.compile_ir_constraints(&mut cs) {
+        Err(CompilerError::FunctionError(_)) => {}
+        _ => panic!("expected a failed assertion"),
+    }
+}
+
+#[test]
+fn test_ir_constraints_conditional() {
+    // the ir selects once at the phi node, where the asg selects at each assignment
+    let input_string = "[main]\na: bool = false;\nb: u32 = 3;\n\n[registers]\nr0: u32 = 0;\n";
+    let program = parse_program_with_input(include_str!("conditional.leo"), input_string).unwrap();
+
+    let mut asg_cs = TestConstraintSystem::<Fq>::new();
+    let asg_output = program.compile_constraints(&mut asg_cs).unwrap();
+    let mut ir_cs = TestConstraintSystem::<Fq>::new();
+    let ir_output = program.compile_ir_constraints(&mut ir_cs).unwrap();
+
+    assert!(ir_cs.is_satisfied());
+    assert!(ir_cs.num_constraints() < asg_cs.num_constraints());
+    assert_eq!(asg_output.bytes(), ir_output.bytes());
+}
+
+#[test]
+fn test_mut_self_call() {
+    let program = parse_program(include_str!("mut_self_call.leo")).unwrap();
+
+    match program.lower_to_ir() {
+        Err(CompilerError::IrError(_)) => {}
+        _ => panic!("expected an ir error"),
+    }
+}
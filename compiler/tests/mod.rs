@@ -25,13 +25,18 @@ pub mod compiler;
 pub mod console;
 pub mod core;
 pub mod definition;
-// pub mod field;
+pub mod field;
+pub mod fixed;
 pub mod function;
 // pub mod group;
 pub mod import;
 pub mod input_files;
 pub mod integers;
+pub mod interfaces;
+pub mod ir;
 pub mod mutability;
+pub mod optimizations;
+pub mod soundness;
 pub mod statements;
 pub mod syntax;
 pub mod tuples;
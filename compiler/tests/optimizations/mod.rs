@@ -0,0 +1,308 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{generate_main_input, generate_test_input_u32, new_compiler, EdwardsTestCompiler, EMPTY_FILE};
+use leo_ast::InputValue;
+use leo_compiler::{errors::CompilerError, CompilerOptions};
+use leo_grammar::Grammar;
+use leo_input::types::{I8Type, IntegerType, SignedIntegerType};
+
+use snarkvm_curves::edwards_bls12::Fq;
+use snarkvm_models::gadgets::r1cs::TestConstraintSystem;
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// The outcome of synthesizing a program: the number of constraints, whether they are satisfied, and the output.
+type Synthesis = (usize, bool, Vec<u8>);
+
+fn synthesize(
+    program_string: &str,
+    main_input: Vec<(&str, Option<InputValue>)>,
+    options: CompilerOptions,
+) -> Result<Synthesis, CompilerError> {
+    let mut program = new_compiler();
+    program.set_options(options);
+    program.parse_program_from_string(program_string)?;
+    program.set_main_input(generate_main_input(main_input));
+
+    constraints(program)
+}
+
+/// Synthesizes a program with the contents of its input file.
+fn synthesize_with_input_file(
+    program_string: &str,
+    input_string: &str,
+    options: CompilerOptions,
+) -> Result<Synthesis, CompilerError> {
+    let mut program = new_compiler();
+    let path = PathBuf::new();
+    program.set_options(options);
+    program.parse_input(input_string, &path, EMPTY_FILE, &path)?;
+    program.parse_program_from_string(program_string)?;
+
+    constraints(program)
+}
+
+fn constraints(program: EdwardsTestCompiler) -> Result<Synthesis, CompilerError> {
+    let mut cs = TestConstraintSystem::<Fq>::new();
+    let output = program.compile_constraints(&mut cs)?;
+
+    Ok((cs.num_constraints(), cs.is_satisfied(), output.bytes().clone()))
+}
+
+/// Returns the default options with the given pass switched on or off.
+fn with_pass(name: &str, enabled: bool) -> CompilerOptions {
+    let mut options = CompilerOptions::default();
+    options.passes.insert(name.to_string(), enabled);
+    options
+}
+
+/// Returns the options at `-O0`.
+fn unoptimized() -> CompilerOptions {
+    CompilerOptions {
+        optimize: false,
+        ..Default::default()
+    }
+}
+
+/// Returns the input file a compiler test runs the program at `path` with, if it has one.
+fn input_file(path: &Path) -> Option<PathBuf> {
+    let name = Path::new(path.file_stem()?).with_extension("in");
+    let directory = path.parent()?;
+
+    vec![directory.join("input").join(&name), directory.join(&name)]
+        .into_iter()
+        .find(|input| input.is_file())
+}
+
+#[test]
+fn test_constant_folding() {
+    let program_string = include_str!("constant_folding.leo");
+    let input = || vec![("a", Some(InputValue::Boolean(true)))];
+
+    let (folded_constraints, folded_satisfied, folded_output) =
+        synthesize(program_string, input(), with_pass("constant_folding", true)).unwrap();
+    let (constraints, satisfied, output) =
+        synthesize(program_string, input(), with_pass("constant_folding", false)).unwrap();
+
+    assert!(folded_satisfied && satisfied);
+    assert_eq!(folded_output, output);
+    assert!(folded_constraints < constraints);
+}
+
+#[test]
+fn test_optimized_constraint_counts() {
+    let files = Grammar::find_files(&Path::new(env!("CARGO_MANIFEST_DIR")).join("tests"), "leo").unwrap();
+
+    let mut synthesized = 0;
+    for path in files {
+        let program_string = fs::read_to_string(&path).unwrap();
+        let input_string = input_file(&path)
+            .map(|input| fs::read_to_string(input).unwrap())
+            .unwrap_or_default();
+        let run = |options| synthesize_with_input_file(&program_string, &input_string, options);
+
+        // programs that need imports, inputs given by their test, or fail on purpose are skipped
+        let (constraints, satisfied, output) = match run(unoptimized()) {
+            Ok(synthesis) => synthesis,
+            Err(_) => continue,
+        };
+
+        let (optimized_constraints, optimized_satisfied, optimized_output) = run(CompilerOptions::default())
+            .unwrap_or_else(|error| panic!("{:?} fails to compile after optimization: {}", path, error));
+
+        assert_eq!(satisfied, optimized_satisfied, "{:?}", path);
+        assert_eq!(output, optimized_output, "{:?}", path);
+        assert!(
+            optimized_constraints <= constraints,
+            "{:?} has {} constraints after optimization, {} before",
+            path,
+            optimized_constraints,
+            constraints
+        );
+        synthesized += 1;
+    }
+
+    assert!(synthesized > 0);
+}
+
+#[test]
+fn test_optimize_disabled() {
+    let program_string = include_str!("constant_folding.leo");
+    let input = || vec![("a", Some(InputValue::Boolean(true)))];
+
+    let mut program = new_compiler();
+    program.set_options(CompilerOptions {
+        optimize: false,
+        ..Default::default()
+    });
+    program.parse_program_from_string(program_string).unwrap();
+    program.set_main_input(generate_main_input(input()));
+    let mut cs = TestConstraintSystem::<Fq>::new();
+    program.compile_constraints(&mut cs).unwrap();
+
+    let mut options = with_pass("constant_folding", false);
+    options.passes.insert("dead_code_elimination".to_string(), false);
+    options.passes.insert("inlining".to_string(), false);
+    let (constraints, _, _) = synthesize(program_string, input(), options).unwrap();
+
+    // loops are unrolled even without optimizations
+    assert!(program
+        .pass_reports()
+        .iter()
+        .all(|report| report.duration.is_none() == (report.name != "loop_unrolling")));
+    assert_eq!(cs.num_constraints(), constraints);
+}
+
+#[test]
+fn test_dead_code_elimination() {
+    let program_string = include_str!("dead_code.leo");
+    let input = || vec![("a", Some(InputValue::Boolean(true)))];
+
+    let mut program = new_compiler();
+    program.parse_program_from_string(program_string).unwrap();
+
+    let report = program
+        .pass_reports()
+        .iter()
+        .find(|report| report.name == "dead_code_elimination")
+        .unwrap();
+    assert_eq!(report.changes, vec![
+        "removed unused function `unused`",
+        "removed unused circuit `Unused`",
+        "removed unused definition of `b` on line 10",
+        "removed unused definition of `c` on line 11",
+    ]);
+
+    let (eliminated_constraints, eliminated_satisfied, eliminated_output) =
+        synthesize(program_string, input(), CompilerOptions::default()).unwrap();
+    let (constraints, satisfied, output) =
+        synthesize(program_string, input(), with_pass("dead_code_elimination", false)).unwrap();
+
+    assert!(eliminated_satisfied && satisfied);
+    assert_eq!(eliminated_output, output);
+    assert!(eliminated_constraints < constraints);
+}
+
+#[test]
+fn test_inlining() {
+    let program_string = include_str!("inlining.leo");
+    let input = || vec![("a", Some(InputValue::Boolean(true)))];
+
+    let mut program = new_compiler();
+    program.parse_program_from_string(program_string).unwrap();
+
+    let report = program
+        .pass_reports()
+        .iter()
+        .find(|report| report.name == "inlining")
+        .unwrap();
+    assert_eq!(report.changes, vec!["inlined call to `both` on line 11"]);
+
+    let (inlined_constraints, inlined_satisfied, inlined_output) =
+        synthesize(program_string, input(), CompilerOptions::default()).unwrap();
+    let (constraints, satisfied, output) = synthesize(program_string, input(), with_pass("inlining", false)).unwrap();
+
+    assert!(inlined_satisfied && satisfied);
+    assert_eq!(inlined_output, output);
+    assert!(inlined_constraints <= constraints);
+}
+
+#[test]
+fn test_inlining_repeated() {
+    let program_string = include_str!("inlining_repeated.leo");
+    let input = || vec![("a", generate_test_input_u32(3)), ("b", generate_test_input_u32(4))];
+
+    let mut program = new_compiler();
+    program.parse_program_from_string(program_string).unwrap();
+
+    let report = program
+        .pass_reports()
+        .iter()
+        .find(|report| report.name == "inlining")
+        .unwrap();
+    assert_eq!(report.changes, vec![
+        "inlined call to `square` on line 6",
+        "inlined call to `square` on line 7",
+        "inlined call to `square` on line 8",
+        "inlined call to `square` on line 8",
+    ]);
+
+    // the copies of `x * x` share a position, but their constraints are namespaced apart
+    let (inlined_constraints, inlined_satisfied, inlined_output) =
+        synthesize(program_string, input(), CompilerOptions::default()).unwrap();
+    let (constraints, satisfied, output) = synthesize(program_string, input(), with_pass("inlining", false)).unwrap();
+
+    assert!(inlined_satisfied && satisfied);
+    assert_eq!(inlined_output, output);
+    assert!(inlined_constraints <= constraints);
+}
+
+#[test]
+fn test_inlining_unused_argument() {
+    let program_string = include_str!("inlining_unused_argument.leo");
+    let input = || {
+        vec![(
+            "a",
+            Some(InputValue::Integer(
+                IntegerType::Signed(SignedIntegerType::I8Type(I8Type {})),
+                "-128".to_string(),
+            )),
+        )]
+    };
+
+    // the overflow of the argument must still fail the proof when the call is inlined
+    for options in &[CompilerOptions::default(), unoptimized()] {
+        let synthesis = synthesize(program_string, input(), options.clone());
+        assert!(!matches!(synthesis, Ok((_, true, _))), "{:?}", synthesis);
+    }
+}
+
+#[test]
+fn test_loop_unrolling() {
+    let program_string = include_str!("loop_unrolling.leo");
+
+    let mut program = new_compiler();
+    program.set_options(unoptimized());
+    program.parse_program_from_string(program_string).unwrap();
+
+    let report = program
+        .pass_reports()
+        .iter()
+        .find(|report| report.name == "loop_unrolling")
+        .unwrap();
+    assert_eq!(report.changes, vec!["unrolled loop on line 3 into 4 iterations"]);
+
+    let (_, satisfied, _) = synthesize(program_string, vec![], unoptimized()).unwrap();
+    assert!(satisfied);
+}
+
+#[test]
+fn test_unknown_pass() {
+    let mut options = CompilerOptions::default();
+    options.passes.insert("unknown".to_string(), false);
+
+    let mut program = new_compiler();
+    program.set_options(options);
+
+    match program.parse_program_from_string(include_str!("constant_folding.leo")) {
+        Err(CompilerError::UnknownPass(name)) => assert_eq!(name, "unknown"),
+        _ => panic!("expected an unknown pass error"),
+    }
+}
@@ -0,0 +1,100 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{parse_program_with_input, EdwardsTestCompiler};
+use leo_compiler::soundness::{check_constraints, SoundnessIssue, SoundnessWarning};
+use leo_synthesizer::NamespacedSynthesizer;
+
+use snarkvm_curves::bls12_377::{Bls12_377, Fr};
+use snarkvm_models::{curves::One, gadgets::r1cs::ConstraintSystem};
+
+fn check_soundness(program: EdwardsTestCompiler) -> Vec<SoundnessWarning> {
+    let warnings = program.check_soundness::<Bls12_377>().unwrap();
+    for warning in warnings.iter() {
+        println!("{}", warning);
+    }
+    warnings
+}
+
+fn issues(warnings: &[SoundnessWarning]) -> Vec<(SoundnessIssue, usize)> {
+    warnings
+        .iter()
+        .map(|warning| (warning.issue, warning.error.line))
+        .collect()
+}
+
+#[test]
+fn test_sound() {
+    let program_string = include_str!("sound.leo");
+    let input_string = include_str!("input/u32_u32.in");
+    let program = parse_program_with_input(program_string, input_string).unwrap();
+
+    assert!(check_soundness(program).is_empty());
+}
+
+#[test]
+fn test_uninfluential_input() {
+    let program_string = include_str!("uninfluential_input.leo");
+    let input_string = include_str!("input/u32_u32.in");
+    let program = parse_program_with_input(program_string, input_string).unwrap();
+
+    let warnings = check_soundness(program);
+
+    assert_eq!(issues(&warnings), vec![(SoundnessIssue::UninfluentialInput, 1)]);
+    assert_eq!(warnings[0].error.start, 23);
+    assert_eq!(warnings[0].error.end, 24);
+}
+
+#[test]
+fn test_constant_output() {
+    let program_string = include_str!("constant_output.leo");
+    let input_string = include_str!("input/u32.in");
+    let program = parse_program_with_input(program_string, input_string).unwrap();
+
+    let warnings = check_soundness(program);
+
+    assert_eq!(issues(&warnings), vec![(SoundnessIssue::ConstantOutput, 3)]);
+}
+
+#[test]
+fn test_dependent_output() {
+    let program_string = include_str!("dependent_output.leo");
+    let input_string = include_str!("input/u32.in");
+    let program = parse_program_with_input(program_string, input_string).unwrap();
+
+    assert!(check_soundness(program).is_empty());
+}
+
+#[test]
+fn test_unconstrained_variable() {
+    let program_string = include_str!("uninfluential_input.leo");
+    let mut cs = NamespacedSynthesizer::<Bls12_377>::new();
+
+    {
+        let mut block = cs.ns(|| "block 2:5");
+        block.alloc(|| "x", || Ok(Fr::one())).unwrap();
+        block.alloc(|| "y", || Ok(Fr::one())).unwrap();
+    }
+
+    let warnings = check_constraints(&cs, program_string);
+
+    assert_eq!(issues(&warnings), vec![(SoundnessIssue::UnconstrainedVariable, 2)]);
+    assert_eq!(
+        warnings[0].error.message,
+        "2 private variables allocated here do not appear in any constraint"
+    );
+    assert_eq!(warnings[0].error.text, "     return a * a");
+}
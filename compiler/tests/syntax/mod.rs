@@ -14,7 +14,8 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{expect_asg_error, parse_input, parse_program};
+use crate::{assert_satisfied, expect_asg_error, parse_input, parse_program, parse_program_with_input};
+use leo_ast::{Diagnostic, ReportSpan};
 use leo_compiler::errors::CompilerError;
 use leo_grammar::ParserError;
 use leo_input::InputParserError;
@@ -33,6 +34,18 @@ fn test_semicolon() {
     }
 }
 
+#[test]
+fn test_semicolon_report() {
+    let program_string = include_str!("semicolon.leo");
+    let error = parse_program(program_string).err().unwrap();
+    let reports = error.reports();
+
+    assert_eq!(reports.len(), 1);
+    assert_eq!(reports[0].path.as_deref(), Some("/test/src/main.leo"));
+    assert!(reports[0].message.contains("expected"), "{}", reports[0].message);
+    assert_ne!(reports[0].message, "unexpected token");
+}
+
 #[test]
 fn test_undefined() {
     let program_string = include_str!("undefined.leo");
@@ -41,6 +54,26 @@ fn test_undefined() {
     expect_asg_error(error);
 }
 
+#[test]
+fn test_undefined_report() {
+    let program_string = include_str!("undefined.leo");
+    let error = parse_program(program_string).err().unwrap();
+    let reports = error.reports();
+
+    assert_eq!(reports.len(), 1);
+    assert_eq!(reports[0].code, "E0328");
+    assert_eq!(reports[0].path.as_deref(), Some("/test/src/main.leo"));
+    assert_eq!(
+        reports[0].span,
+        Some(ReportSpan {
+            line_start: 2,
+            column_start: 12,
+            line_end: 2,
+            column_end: 13,
+        })
+    );
+}
+
 #[test]
 fn input_syntax_error() {
     let input_string = include_str!("input_semicolon.leo");
@@ -53,6 +86,17 @@ fn input_syntax_error() {
     }
 }
 
+#[test]
+fn input_syntax_error_report() {
+    let input_string = include_str!("input_semicolon.leo");
+    let error = parse_input(input_string).err().unwrap();
+    let reports = error.reports();
+
+    assert_eq!(reports.len(), 1);
+    assert_eq!(reports[0].code, "E0504");
+    assert_eq!(reports[0].span.as_ref().map(|span| span.line_start), Some(2));
+}
+
 #[test]
 fn test_compare_mismatched_types() {
     let program_string = include_str!("compare_mismatched_types.leo");
@@ -61,3 +105,35 @@ fn test_compare_mismatched_types() {
     // Expect a type inference error.
     crate::expect_asg_error(error);
 }
+
+#[test]
+fn test_compare_mismatched_types_report() {
+    let program_string = include_str!("compare_mismatched_types.leo");
+    let error = parse_program(program_string).err().unwrap();
+    let reports = error.reports();
+
+    // A type mismatch is told apart from an undefined variable by its code.
+    assert_eq!(reports.len(), 1);
+    assert_ne!(reports[0].code, "E0328");
+    assert_ne!(reports[0].code, "E0300");
+}
+
+#[test]
+fn test_radix() {
+    let program_string = include_str!("radix.leo");
+    let input_string = include_str!("radix.in");
+    let program = parse_program_with_input(program_string, input_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_radix_fail() {
+    let program_string = include_str!("radix_fail.leo");
+    let error = parse_program(program_string).err().unwrap();
+
+    match error {
+        CompilerError::ParserError(ParserError::SyntaxError(_)) => {}
+        _ => panic!("an invalid binary digit should be a ParserError"),
+    }
+}
@@ -0,0 +1,156 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! A pretty printer laying out documents within a line width.
+//!
+//! A [`Doc::Group`] is printed on one line if it fits in the remaining width, and otherwise breaks
+//! each of its [`Doc::Line`]s, as described in Philip Wadler's "A prettier printer".
+
+#[derive(Clone, Debug)]
+pub enum Doc {
+    Text(String),
+    /// A space, or a line break if the enclosing group is broken
+    Line,
+    /// Nothing, or a line break if the enclosing group is broken
+    SoftLine,
+    /// A line break
+    HardLine,
+    /// An empty line
+    BlankLine,
+    /// Breaks the enclosing groups, as after a line comment
+    BreakParent,
+    Indent(Vec<Doc>),
+    Group(Vec<Doc>),
+    /// Documents always printed on one line, where the grammar allows no line break
+    Flat(Vec<Doc>),
+    /// Text printed only if the enclosing group is broken, such as a trailing comma
+    IfBreak(&'static str),
+}
+
+impl Doc {
+    pub fn text<S: Into<String>>(text: S) -> Self {
+        Doc::Text(text.into())
+    }
+
+    /// Returns whether the document always breaks the groups containing it.
+    fn is_broken(&self) -> bool {
+        match self {
+            Doc::HardLine | Doc::BlankLine | Doc::BreakParent => true,
+            Doc::Indent(docs) | Doc::Group(docs) => docs.iter().any(Doc::is_broken),
+            _ => false,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Flat,
+    Break,
+}
+
+///
+/// Prints `docs` in lines of at most `width` characters where possible.
+///
+pub fn print(docs: &[Doc], width: usize, indent_width: usize) -> String {
+    let mut output = String::new();
+    let mut column = 0;
+    let mut stack: Vec<(usize, Mode, &Doc)> = docs.iter().rev().map(|doc| (0, Mode::Break, doc)).collect();
+
+    while let Some((indent, mode, doc)) = stack.pop() {
+        match doc {
+            Doc::Text(text) => {
+                output.push_str(text);
+                column += text.chars().count();
+            }
+            Doc::Line | Doc::SoftLine if mode == Mode::Flat => {
+                if let Doc::Line = doc {
+                    output.push(' ');
+                    column += 1;
+                }
+            }
+            Doc::Line | Doc::SoftLine | Doc::HardLine | Doc::BlankLine => {
+                let trimmed = output.trim_end_matches(' ').len();
+                output.truncate(trimmed);
+                if let Doc::BlankLine = doc {
+                    output.push('\n');
+                }
+                output.push('\n');
+                output.push_str(&" ".repeat(indent));
+                column = indent;
+            }
+            Doc::BreakParent => {}
+            Doc::Indent(docs) => {
+                stack.extend(docs.iter().rev().map(|doc| (indent + indent_width, mode, doc)));
+            }
+            Doc::Group(docs) => {
+                let mode =
+                    if mode == Mode::Flat || (!doc.is_broken() && fits(docs, &stack, width.saturating_sub(column))) {
+                        Mode::Flat
+                    } else {
+                        Mode::Break
+                    };
+                stack.extend(docs.iter().rev().map(|doc| (indent, mode, doc)));
+            }
+            Doc::Flat(docs) => stack.extend(docs.iter().rev().map(|doc| (indent, Mode::Flat, doc))),
+            Doc::IfBreak(text) => {
+                if mode == Mode::Break {
+                    output.push_str(text);
+                    column += text.len();
+                }
+            }
+        }
+    }
+
+    output
+}
+
+///
+/// Returns whether the flat `docs`, and what follows them up to the next line break, fit in `width`.
+///
+fn fits(docs: &[Doc], rest: &[(usize, Mode, &Doc)], width: usize) -> bool {
+    let mut width = width.min(isize::MAX as usize) as isize;
+    let mut stack: Vec<(Mode, &Doc)> = docs.iter().rev().map(|doc| (Mode::Flat, doc)).collect();
+    let mut rest = rest.iter().rev();
+
+    loop {
+        let (mode, doc) = match stack.pop() {
+            Some(next) => next,
+            None => match rest.next() {
+                Some((_, mode, doc)) => (*mode, *doc),
+                None => return true,
+            },
+        };
+
+        match doc {
+            Doc::Text(text) => width -= text.chars().count() as isize,
+            Doc::Line if mode == Mode::Flat => width -= 1,
+            Doc::SoftLine if mode == Mode::Flat => {}
+            Doc::Line | Doc::SoftLine | Doc::HardLine | Doc::BlankLine => return true,
+            Doc::BreakParent => {}
+            Doc::Indent(docs) | Doc::Group(docs) => stack.extend(docs.iter().rev().map(|doc| (mode, doc))),
+            Doc::Flat(docs) => stack.extend(docs.iter().rev().map(|doc| (Mode::Flat, doc))),
+            Doc::IfBreak(text) => {
+                if mode == Mode::Break {
+                    width -= text.len() as isize;
+                }
+            }
+        }
+
+        if width < 0 {
+            return false;
+        }
+    }
+}
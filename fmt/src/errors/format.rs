@@ -0,0 +1,63 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::{Diagnostic, Report};
+use leo_grammar::ParserError;
+use leo_input::InputParserError;
+
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Error)]
+pub enum FormatError {
+    #[error("Cannot read from the provided file path - {:?}", _0)]
+    FileReadError(PathBuf),
+
+    #[error("{}", _0)]
+    InputParserError(#[from] InputParserError),
+
+    #[error("formatting {:?} would change its meaning, please report this as a bug", _0)]
+    InvalidOutput(PathBuf),
+
+    #[error("{}", _0)]
+    ParserError(#[from] ParserError),
+}
+
+impl FormatError {
+    pub fn set_path(&mut self, path: &Path) {
+        if let FormatError::ParserError(error) = self {
+            error.set_path(path)
+        }
+    }
+}
+
+impl Diagnostic for FormatError {
+    fn error_code(&self) -> &'static str {
+        match self {
+            FormatError::FileReadError(_) => "E2100",
+            FormatError::InputParserError(error) => error.error_code(),
+            FormatError::InvalidOutput(_) => "E2101",
+            FormatError::ParserError(error) => error.error_code(),
+        }
+    }
+
+    fn reports(&self) -> Vec<Report> {
+        match self {
+            FormatError::InputParserError(error) => error.reports(),
+            FormatError::ParserError(error) => error.reports(),
+            _ => vec![Report::new(self.error_code(), self.to_string())],
+        }
+    }
+}
@@ -0,0 +1,683 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    doc::{print, Doc},
+    lexer::{tokenize, Token, TokenKind},
+    FormatError,
+};
+use leo_grammar::Grammar;
+use leo_input::LeoInputParser;
+
+use std::{fs, path::Path};
+
+/// The keywords that are followed by a space, and after which `-` negates.
+const KEYWORDS: &[&str] = &[
+    "as",
+    "circuit",
+    "const",
+    "else",
+    "extern",
+    "for",
+    "function",
+    "if",
+    "implements",
+    "import",
+    "in",
+    "interface",
+    "let",
+    "mut",
+    "return",
+    "static",
+];
+
+/// The keywords that only start statements.
+const STATEMENT_KEYWORDS: &[&str] = &["console", "const", "for", "let", "return"];
+
+/// The file extension of program inputs.
+const INPUT_FILE_EXTENSION: &str = "in";
+
+///
+/// The settings of the formatter.
+///
+#[derive(Clone, Debug)]
+pub struct FormatConfig {
+    /// The width lists are wrapped at, when they do not fit on one line
+    pub max_width: usize,
+    pub indent_width: usize,
+}
+
+impl Default for FormatConfig {
+    fn default() -> Self {
+        Self {
+            max_width: 100,
+            indent_width: 4,
+        }
+    }
+}
+
+/// A token, or a list of trees between brackets.
+enum Tree<'a> {
+    Leaf(Token<'a>),
+    Group(Group<'a>),
+}
+
+struct Group<'a> {
+    open: Token<'a>,
+    trees: Vec<Tree<'a>>,
+    close: Token<'a>,
+}
+
+impl<'a> Tree<'a> {
+    fn first(&self) -> &Token<'a> {
+        match self {
+            Tree::Leaf(token) => token,
+            Tree::Group(group) => &group.open,
+        }
+    }
+
+    fn last(&self) -> &Token<'a> {
+        match self {
+            Tree::Leaf(token) => token,
+            Tree::Group(group) => &group.close,
+        }
+    }
+
+    fn is(&self, text: &str) -> bool {
+        matches!(self, Tree::Leaf(token) if token.is(text))
+    }
+
+    fn comment(&self) -> Option<&Token<'a>> {
+        match self {
+            Tree::Leaf(token) if token.is_comment() => Some(token),
+            _ => None,
+        }
+    }
+
+    fn is_keyword(&self) -> bool {
+        matches!(self, Tree::Leaf(token) if is_keyword(token))
+    }
+
+    fn is_name(&self) -> bool {
+        matches!(self, Tree::Leaf(token) if token.kind == TokenKind::Word && !is_keyword(token))
+    }
+}
+
+///
+/// Returns the trees of `tokens`, or `None` if their brackets are unbalanced.
+///
+fn parse_trees<'a, I: Iterator<Item = Token<'a>>>(tokens: &mut I, close: Option<&str>) -> Option<Vec<Tree<'a>>> {
+    let mut trees = vec![];
+    while let Some(token) = tokens.next() {
+        let expected_close = match token.text {
+            "(" => ")",
+            "[" => "]",
+            "{" => "}",
+            ")" | "]" | "}" if token.kind == TokenKind::Symbol => {
+                return if close == Some(token.text) {
+                    trees.push(Tree::Leaf(token));
+                    Some(trees)
+                } else {
+                    None
+                };
+            }
+            _ => {
+                trees.push(Tree::Leaf(token));
+                continue;
+            }
+        };
+        if token.kind != TokenKind::Symbol {
+            trees.push(Tree::Leaf(token));
+            continue;
+        }
+
+        let mut inner = parse_trees(tokens, Some(expected_close))?;
+        let close = match inner.pop() {
+            Some(Tree::Leaf(close)) => close,
+            _ => return None,
+        };
+        trees.push(Tree::Group(Group {
+            open: token,
+            trees: inner,
+            close,
+        }));
+    }
+
+    match close {
+        Some(_) => None,
+        None => Some(trees),
+    }
+}
+
+/// The kind of the statements in braces.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum BlockKind {
+    Block,
+    Circuit,
+    Interface,
+}
+
+enum Element<'t, 'a> {
+    Tree(&'t Tree<'a>),
+    Block(BlockKind, &'t Group<'a>),
+}
+
+impl<'t, 'a> Element<'t, 'a> {
+    fn first(&self) -> &'t Token<'a> {
+        match self {
+            Element::Tree(tree) => tree.first(),
+            Element::Block(_, group) => &group.open,
+        }
+    }
+
+    fn last(&self) -> &'t Token<'a> {
+        match self {
+            Element::Tree(tree) => tree.last(),
+            Element::Block(_, group) => &group.close,
+        }
+    }
+}
+
+/// A statement, definition or member laid out on its own lines.
+#[derive(Default)]
+struct Statement<'t, 'a> {
+    elements: Vec<Element<'t, 'a>>,
+    /// The comments after the statement on its last line
+    trailing: Vec<&'t Token<'a>>,
+    blank_before: bool,
+}
+
+/// An element of a list between brackets, with its comments.
+#[derive(Default)]
+struct Item<'t, 'a> {
+    leading: Vec<&'t Token<'a>>,
+    trees: Vec<&'t Tree<'a>>,
+    trailing: Vec<&'t Token<'a>>,
+}
+
+///
+/// Formats Leo programs and inputs, preserving their comments.
+///
+#[derive(Clone, Debug, Default)]
+pub struct Formatter {
+    config: FormatConfig,
+}
+
+impl Formatter {
+    pub fn new(config: FormatConfig) -> Self {
+        Self { config }
+    }
+
+    ///
+    /// Returns the formatted contents of the program or input file at `path`.
+    ///
+    pub fn format_file(&self, path: &Path) -> Result<String, FormatError> {
+        let source = fs::read_to_string(path).map_err(|_| FormatError::FileReadError(path.to_owned()))?;
+
+        self.format_source(path, &source)
+    }
+
+    ///
+    /// Returns the formatted `source` of the program or input file at `path`.
+    ///
+    pub fn format_source(&self, path: &Path, source: &str) -> Result<String, FormatError> {
+        match path.extension() {
+            Some(extension) if extension == INPUT_FILE_EXTENSION => self.format_input(path, source),
+            _ => self.format_program(path, source),
+        }
+    }
+
+    ///
+    /// Returns the formatted `source` of the program at `path`.
+    ///
+    pub fn format_program(&self, path: &Path, source: &str) -> Result<String, FormatError> {
+        Grammar::new(path, source)?;
+
+        let formatted = self
+            .format(source, false)
+            .ok_or_else(|| FormatError::InvalidOutput(path.to_owned()))?;
+
+        // Refuse to write a program that no longer parses.
+        Grammar::new(path, &formatted).map_err(|_| FormatError::InvalidOutput(path.to_owned()))?;
+
+        Ok(formatted)
+    }
+
+    ///
+    /// Returns the formatted `source` of the program inputs at `path`.
+    ///
+    /// Definitions of inputs are never wrapped, since they end at line breaks.
+    ///
+    pub fn format_input(&self, path: &Path, source: &str) -> Result<String, FormatError> {
+        LeoInputParser::parse_file(source)?;
+
+        let formatted = self
+            .format(source, true)
+            .ok_or_else(|| FormatError::InvalidOutput(path.to_owned()))?;
+
+        LeoInputParser::parse_file(&formatted).map_err(|_| FormatError::InvalidOutput(path.to_owned()))?;
+
+        Ok(formatted)
+    }
+
+    fn format(&self, source: &str, is_input: bool) -> Option<String> {
+        let trees = parse_trees(&mut tokenize(source).into_iter(), None)?;
+
+        let mut docs = vec![];
+        for (index, statement) in statements(&trees, None, is_input).iter().enumerate() {
+            if index > 0 {
+                docs.push(if statement.blank_before {
+                    Doc::BlankLine
+                } else {
+                    Doc::HardLine
+                });
+            }
+            docs.extend(self.statement(statement, None));
+        }
+
+        let width = if is_input { usize::MAX } else { self.config.max_width };
+        let formatted = print(&docs, width, self.config.indent_width);
+
+        Some(match formatted.trim_end() {
+            "" => String::new(),
+            formatted => format!("{}\n", formatted),
+        })
+    }
+
+    fn statement(&self, statement: &Statement, kind: Option<BlockKind>) -> Vec<Doc> {
+        let mut docs = self.sequence(&statement.elements);
+
+        // Circuit variables always end with a comma, so that members can be reordered.
+        if kind == Some(BlockKind::Circuit)
+            && is_circuit_variable(&statement.elements)
+            && !statement.elements.last().is_some_and(|element| element.last().is(","))
+        {
+            docs.push(Doc::text(","));
+        }
+
+        for comment in statement.trailing.iter() {
+            docs.push(Doc::text(" "));
+            docs.push(Doc::text(comment.text));
+        }
+
+        docs
+    }
+
+    fn block(&self, group: &Group, kind: BlockKind) -> Vec<Doc> {
+        let statements = statements(&group.trees, Some(kind), false);
+        if statements.is_empty() {
+            return vec![Doc::text("{}")];
+        }
+
+        let mut body = vec![];
+        for (index, statement) in statements.iter().enumerate() {
+            body.push(if index > 0 && statement.blank_before {
+                Doc::BlankLine
+            } else {
+                Doc::HardLine
+            });
+            body.extend(self.statement(statement, Some(kind)));
+        }
+
+        vec![Doc::text("{"), Doc::Indent(body), Doc::HardLine, Doc::text("}")]
+    }
+
+    ///
+    /// Returns the elements of a statement or list item separated by canonical spacing.
+    ///
+    fn sequence(&self, elements: &[Element]) -> Vec<Doc> {
+        let is_import = elements.first().is_some_and(|element| element.first().is("import"));
+        let mut docs = vec![];
+        let mut previous: Vec<&Token> = vec![];
+        let mut previous_unary = false;
+        let mut after_line_comment = false;
+        let mut ternaries = 0;
+
+        for (index, element) in elements.iter().enumerate() {
+            let first = element.first();
+
+            if let Element::Tree(Tree::Leaf(comment)) = element {
+                if comment.is_comment() {
+                    if index > 0 && !after_line_comment {
+                        docs.push(if comment.newlines > 0 {
+                            Doc::HardLine
+                        } else {
+                            Doc::text(" ")
+                        });
+                    }
+                    docs.push(Doc::text(comment.text));
+                    after_line_comment = comment.kind == TokenKind::LineComment;
+                    if after_line_comment {
+                        docs.push(Doc::BreakParent);
+                        if index + 1 < elements.len() {
+                            docs.push(Doc::HardLine);
+                        }
+                    }
+                    previous_unary = false;
+                    continue;
+                }
+            }
+
+            let is_ternary_colon = first.is(":") && ternaries > 0;
+            if let Some(last) = previous.last() {
+                if !after_line_comment && space_between(last, previous_unary, first, is_ternary_colon, is_import) {
+                    docs.push(Doc::text(" "));
+                }
+            }
+            after_line_comment = false;
+
+            previous_unary = first.is("!") || (first.is("-") && starts_operand(previous.last().copied()));
+            if first.is("?") {
+                ternaries += 1;
+            } else if is_ternary_colon {
+                ternaries -= 1;
+            }
+
+            match element {
+                Element::Tree(Tree::Leaf(token)) => docs.push(Doc::text(token.text)),
+                Element::Tree(Tree::Group(group)) => {
+                    let trailing_comma = allows_trailing_comma(group, &previous, is_import);
+                    let group_docs = self.group(group, trailing_comma);
+
+                    // An address literal, as in `address(aleo1...)`, is a single token.
+                    match previous.last() {
+                        Some(token) if token.is("address") => docs.push(Doc::Flat(vec![group_docs])),
+                        _ => docs.push(group_docs),
+                    }
+                }
+                Element::Block(kind, group) => docs.extend(self.block(group, *kind)),
+            }
+            previous.push(element.last());
+        }
+
+        docs
+    }
+
+    ///
+    /// Returns a group laid out on one line if it fits, or with an item per line otherwise.
+    ///
+    fn group(&self, group: &Group, trailing_comma: bool) -> Doc {
+        let items = items(&group.trees);
+        if items.is_empty() {
+            return Doc::text(format!("{}{}", group.open.text, group.close.text));
+        }
+
+        let line = || {
+            if group.open.is("{") {
+                Doc::Line
+            } else {
+                Doc::SoftLine
+            }
+        };
+
+        let mut inner = vec![line()];
+        for (index, item) in items.iter().enumerate() {
+            for comment in item.leading.iter() {
+                inner.push(Doc::text(comment.text));
+                if comment.kind == TokenKind::LineComment {
+                    inner.push(Doc::HardLine);
+                } else if !item.trees.is_empty() {
+                    inner.push(Doc::text(" "));
+                }
+            }
+
+            let elements: Vec<_> = item.trees.iter().map(|tree| Element::Tree(tree)).collect();
+            inner.extend(self.sequence(&elements));
+
+            let is_last = index + 1 == items.len();
+            if !is_last {
+                inner.push(Doc::text(","));
+            } else if trailing_comma {
+                inner.push(Doc::IfBreak(","));
+            }
+
+            for comment in item.trailing.iter() {
+                inner.push(if comment.newlines > 0 {
+                    Doc::HardLine
+                } else {
+                    Doc::text(" ")
+                });
+                inner.push(Doc::text(comment.text));
+                if comment.kind == TokenKind::LineComment {
+                    inner.push(Doc::BreakParent);
+                }
+            }
+
+            if !is_last {
+                inner.push(Doc::Line);
+            }
+        }
+
+        Doc::Group(vec![
+            Doc::text(group.open.text),
+            Doc::Indent(inner),
+            line(),
+            Doc::text(group.close.text),
+        ])
+    }
+}
+
+///
+/// Splits `trees` into statements, deciding which braces open blocks rather than circuit values.
+///
+/// `kind` is the kind of the enclosing block, or `None` for a whole file.
+///
+fn statements<'t, 'a>(trees: &'t [Tree<'a>], kind: Option<BlockKind>, is_input: bool) -> Vec<Statement<'t, 'a>> {
+    let mut statements: Vec<Statement> = vec![];
+    let mut current = Statement::default();
+    let mut expect_block = None;
+
+    for (index, tree) in trees.iter().enumerate() {
+        let next = trees.get(index + 1);
+
+        if current.elements.is_empty() {
+            if let Some(comment) = tree.comment() {
+                match statements.last_mut() {
+                    Some(statement) if comment.newlines == 0 => statement.trailing.push(comment),
+                    _ => statements.push(Statement {
+                        elements: vec![Element::Tree(tree)],
+                        trailing: vec![],
+                        blank_before: comment.newlines > 1,
+                    }),
+                }
+                continue;
+            }
+            current.blank_before = tree.first().newlines > 1;
+        } else if kind == Some(BlockKind::Block) && STATEMENT_KEYWORDS.iter().any(|keyword| tree.is(keyword)) {
+            // A statement without a semicolon, such as a return, ends before the next statement.
+            statements.push(std::mem::take(&mut current));
+            current.blank_before = tree.first().newlines > 1;
+            expect_block = None;
+        } else if kind == Some(BlockKind::Circuit)
+            && is_circuit_variable(&current.elements)
+            && (tree.is("function")
+                || tree.is("static")
+                || tree.is("extern")
+                || (tree.is_name() && next.is_some_and(|next| next.is(":"))))
+        {
+            // A circuit variable without a comma ends before the next member.
+            statements.push(std::mem::take(&mut current));
+            current.blank_before = tree.first().newlines > 1;
+            expect_block = None;
+        }
+
+        if let Tree::Group(group) = tree {
+            if group.open.is("{") && (expect_block.is_some() || current.elements.is_empty()) {
+                current
+                    .elements
+                    .push(Element::Block(expect_block.take().unwrap_or(BlockKind::Block), group));
+                if !next.is_some_and(|next| next.is("else")) {
+                    statements.push(std::mem::take(&mut current));
+                }
+                continue;
+            }
+        }
+
+        let follows_else = matches!(current.elements.last(), Some(element) if element.last().is("else"));
+        match tree.first().text {
+            _ if !tree.is_keyword() => {}
+            "function" | "else" => expect_block = Some(BlockKind::Block),
+            "circuit" => expect_block = Some(BlockKind::Circuit),
+            "interface" => expect_block = Some(BlockKind::Interface),
+            "if" | "for" if current.elements.is_empty() || follows_else => expect_block = Some(BlockKind::Block),
+            _ => {}
+        }
+        current.elements.push(Element::Tree(tree));
+
+        let is_annotation = current.elements.first().is_some_and(|element| element.first().is("@"))
+            && match current.elements.len() {
+                2 => !matches!(next, Some(Tree::Group(group)) if group.open.is("(")),
+                length => length == 3,
+            };
+        let is_section = is_input && current.elements.len() == 1 && tree.first().is("[");
+
+        if tree.is(";") || (kind == Some(BlockKind::Circuit) && tree.is(",")) || is_annotation || is_section {
+            statements.push(std::mem::take(&mut current));
+            expect_block = None;
+        }
+    }
+
+    if !current.elements.is_empty() {
+        statements.push(current);
+    }
+
+    statements
+}
+
+///
+/// Splits the trees between brackets at their commas, attaching comments to the closest item.
+///
+fn items<'t, 'a>(trees: &'t [Tree<'a>]) -> Vec<Item<'t, 'a>> {
+    let mut items: Vec<Item> = vec![];
+    let mut current = Item::default();
+    for tree in trees.iter() {
+        if tree.is(",") {
+            items.push(std::mem::take(&mut current));
+            continue;
+        }
+
+        match tree.comment() {
+            Some(comment) if current.trees.is_empty() => match items.last_mut() {
+                Some(item) if current.leading.is_empty() && comment.newlines == 0 => item.trailing.push(comment),
+                _ => current.leading.push(comment),
+            },
+            _ => current.trees.push(tree),
+        }
+    }
+
+    // Comments after the last comma belong to the last item, so that no comma is added after it.
+    match items.last_mut() {
+        Some(item) if current.trees.is_empty() => item.trailing.extend(current.leading),
+        _ if current.trees.is_empty() && current.leading.is_empty() => {}
+        _ => items.push(current),
+    }
+
+    for item in items.iter_mut() {
+        while let Some(comment) = item.trees.last().and_then(|tree| tree.comment()) {
+            item.trailing.insert(0, comment);
+            item.trees.pop();
+        }
+    }
+
+    items
+}
+
+fn is_keyword(token: &Token) -> bool {
+    token.kind == TokenKind::Word && KEYWORDS.contains(&token.text)
+}
+
+///
+/// Returns whether the elements define a circuit variable, as in `x: u32,`.
+///
+fn is_circuit_variable(elements: &[Element]) -> bool {
+    matches!(elements, [Element::Tree(name), Element::Tree(colon), ..] if name.is_name() && colon.is(":"))
+}
+
+///
+/// Returns whether a `-` after `previous` negates the operand that follows it.
+///
+fn starts_operand(previous: Option<&Token>) -> bool {
+    match previous {
+        None => true,
+        Some(token) => match token.kind {
+            TokenKind::Word => is_keyword(token),
+            TokenKind::Number | TokenKind::String => false,
+            _ => !matches!(token.text, ")" | "]" | "}"),
+        },
+    }
+}
+
+///
+/// Returns whether lists in the group may end with a comma, which the grammar only allows for the
+/// parameters of functions, imported symbols, annotation arguments and circuit values.
+///
+fn allows_trailing_comma(group: &Group, previous: &[&Token], is_import: bool) -> bool {
+    let mut previous = previous.iter().rev();
+    let last = previous.next();
+    let second_last = previous.next();
+
+    match group.open.text {
+        "{" => true,
+        "(" => match (last, second_last) {
+            (Some(_), Some(token)) if token.is("function") || token.is("@") => true,
+            (Some(token), _) => is_import && token.is("."),
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+///
+/// Returns whether a space separates the `previous` and `next` tokens on a line.
+///
+fn space_between(
+    previous: &Token,
+    previous_unary: bool,
+    next: &Token,
+    is_ternary_colon: bool,
+    is_import: bool,
+) -> bool {
+    if previous.is_comment() || next.is_comment() {
+        return true;
+    }
+    if previous_unary || (is_import && (previous.is("-") || next.is("-"))) {
+        return false;
+    }
+    if ["(", "[", ".", "::", "@", "..", "..."]
+        .iter()
+        .any(|symbol| previous.is(symbol))
+    {
+        return false;
+    }
+    if [")", "]", ",", ";", ".", "::", ".."]
+        .iter()
+        .any(|symbol| next.is(symbol))
+    {
+        return false;
+    }
+    if next.is(":") {
+        return is_ternary_colon;
+    }
+    if next.is("(") || next.is("[") {
+        return match previous.kind {
+            TokenKind::Word => is_keyword(previous),
+            _ => !(previous.is(")") || previous.is("]")),
+        };
+    }
+
+    // The group of a coordinate pair follows it, as in `(0, 1)group`.
+    !(previous.is(")") && next.is("group"))
+}
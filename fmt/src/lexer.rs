@@ -0,0 +1,114 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Splits Leo source into the tokens the formatter lays out, keeping comments.
+
+/// The symbols of Leo longer than one character, longest first.
+const SYMBOLS: &[&str] = &[
+    "**=", "...", "**", "*=", "+=", "-=", "/=", "==", "!=", "<=", ">=", "&&", "||", "->", "::", "..",
+];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TokenKind {
+    /// A keyword or an identifier
+    Word,
+    /// A number, with its type suffix
+    Number,
+    String,
+    Symbol,
+    LineComment,
+    BlockComment,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Token<'a> {
+    pub kind: TokenKind,
+    pub text: &'a str,
+    /// The number of line breaks between the previous token and this one
+    pub newlines: usize,
+}
+
+impl<'a> Token<'a> {
+    pub fn is_comment(&self) -> bool {
+        matches!(self.kind, TokenKind::LineComment | TokenKind::BlockComment)
+    }
+
+    pub fn is(&self, text: &str) -> bool {
+        self.kind != TokenKind::String && !self.is_comment() && self.text == text
+    }
+}
+
+///
+/// Returns the tokens of `source`.
+///
+pub fn tokenize(source: &str) -> Vec<Token<'_>> {
+    let mut tokens = vec![];
+    let mut newlines = 0;
+    let mut rest = source;
+
+    while let Some(c) = rest.chars().next() {
+        if c.is_whitespace() {
+            if c == '\n' {
+                newlines += 1;
+            }
+            rest = &rest[c.len_utf8()..];
+            continue;
+        }
+
+        let (kind, length) = if rest.starts_with("//") {
+            (TokenKind::LineComment, rest.find('\n').unwrap_or(rest.len()))
+        } else if let Some(body) = rest.strip_prefix("/*") {
+            let length = body.find("*/").map(|end| end + 4).unwrap_or(rest.len());
+            (TokenKind::BlockComment, length)
+        } else if c == '"' {
+            let length = rest[1..].find('"').map(|end| end + 2).unwrap_or_else(|| rest.len());
+            (TokenKind::String, length)
+        } else if c.is_ascii_digit() {
+            (TokenKind::Number, word_length(rest))
+        } else if c.is_ascii_alphabetic() || c == '_' {
+            let length = word_length(rest);
+
+            // The width and scale of a fixed point type are part of its name, as in `fixed<32, 8>`.
+            match rest[length..].trim_start().starts_with('<') && &rest[..length] == "fixed" {
+                true => (TokenKind::Word, rest.find('>').map(|end| end + 1).unwrap_or(length)),
+                false => (TokenKind::Word, length),
+            }
+        } else {
+            let length = SYMBOLS
+                .iter()
+                .find(|symbol| rest.starts_with(*symbol))
+                .map(|symbol| symbol.len())
+                .unwrap_or_else(|| c.len_utf8());
+            (TokenKind::Symbol, length)
+        };
+
+        tokens.push(Token {
+            kind,
+            text: rest[..length].trim_end(),
+            newlines,
+        });
+        newlines = 0;
+        rest = &rest[length..];
+    }
+
+    tokens
+}
+
+fn word_length(source: &str) -> usize {
+    source
+        .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+        .unwrap_or(source.len())
+}
@@ -0,0 +1,34 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The formatter of the Leo programming language.
+//!
+//! Programs and inputs are split into tokens, keeping their comments, and laid out with canonical
+//! spacing. Lists between brackets are kept on one line if they fit in the maximum width, and
+//! otherwise hold an item per line, with a trailing comma where the grammar allows one.
+
+#[macro_use]
+extern crate thiserror;
+
+pub mod doc;
+
+pub mod errors;
+pub use self::errors::*;
+
+pub mod formatter;
+pub use self::formatter::*;
+
+pub mod lexer;
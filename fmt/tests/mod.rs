@@ -0,0 +1,114 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::Ast;
+use leo_fmt::{FormatConfig, Formatter};
+use leo_grammar::Grammar;
+
+use std::{fs, path::Path};
+
+fn format(source: &str) -> String {
+    Formatter::default()
+        .format_program(Path::new("test.leo"), source)
+        .unwrap()
+}
+
+/// Returns the AST of a program printed without spans or whitespace, or `None` if it is not valid.
+fn ast_string(path: &Path, source: &str) -> Option<String> {
+    let grammar = Grammar::new(path, source).ok()?;
+    let ast = Ast::new("test", &grammar).ok()?;
+
+    // Console functions print their arguments as written.
+    Some(ast.as_repr().to_string().split_whitespace().collect())
+}
+
+#[test]
+fn test_compiler_tests_idempotence() {
+    let files = Grammar::find_files(&Path::new(env!("CARGO_MANIFEST_DIR")).join("../compiler/tests"), "leo").unwrap();
+    assert!(!files.is_empty());
+
+    let formatter = Formatter::default();
+    let mut formatted_files = 0;
+    for path in files {
+        let source = fs::read_to_string(&path).unwrap();
+
+        // Programs with syntax errors are tests of the parser.
+        let formatted = match formatter.format_program(&path, &source) {
+            Ok(formatted) => formatted,
+            Err(_) if Grammar::new(&path, &source).is_err() => continue,
+            Err(error) => panic!("{}: {}", path.display(), error),
+        };
+        let reformatted = formatter.format_program(&path, &formatted).unwrap();
+
+        assert_eq!(
+            formatted,
+            reformatted,
+            "formatting {} is not idempotent",
+            path.display()
+        );
+        assert_eq!(
+            ast_string(&path, &source),
+            ast_string(&path, &formatted),
+            "formatting {} changed its program",
+            path.display()
+        );
+        formatted_files += 1;
+    }
+    assert!(formatted_files > 0);
+}
+
+#[test]
+fn test_format() {
+    let source = include_str!("unformatted.leo");
+    let expected = include_str!("formatted.leo");
+
+    assert_eq!(format(source), expected);
+    assert_eq!(format(expected), expected);
+}
+
+#[test]
+fn test_max_width() {
+    let source = "function main() -> u32 {\n    return add(first_value, second_value)\n}\n";
+    let formatter = Formatter::new(FormatConfig {
+        max_width: 30,
+        ..Default::default()
+    });
+
+    assert_eq!(
+        formatter.format_program(Path::new("test.leo"), source).unwrap(),
+        "function main() -> u32 {\n    return add(\n        first_value,\n        second_value\n    )\n}\n"
+    );
+}
+
+#[test]
+fn test_format_input() {
+    let source = include_str!("unformatted.in");
+    let expected = include_str!("formatted.in");
+    let formatter = Formatter::default();
+
+    assert_eq!(formatter.format_input(Path::new("test.in"), source).unwrap(), expected);
+    assert_eq!(
+        formatter.format_input(Path::new("test.in"), expected).unwrap(),
+        expected
+    );
+}
+
+#[test]
+fn test_syntax_error() {
+    assert!(Formatter::default()
+        .format_program(Path::new("test.leo"), "function main( {")
+        .is_err());
+}
@@ -14,6 +14,8 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
+use crate::unsigned_integer::UInt256;
+
 use snarkvm_errors::gadgets::SynthesisError;
 use snarkvm_models::{
     curves::{Field, PrimeField},
@@ -50,43 +52,48 @@ where
     }
 }
 
+/*  Bitwise less than comparison of two unsigned n-bit numbers */
+impl<F: PrimeField> EvaluateLtGadget<F> for Vec<Boolean> {
+    fn less_than<CS: ConstraintSystem<F>>(&self, mut cs: CS, other: &Self) -> Result<Boolean, SynthesisError> {
+        let mut result = Boolean::constant(true);
+        let mut all_equal = Boolean::constant(true);
+
+        // msb -> lsb
+        for (i, (a, b)) in self.iter().rev().zip(other.iter().rev()).enumerate() {
+            // a == 0 & b == 1
+            let less = Boolean::and(cs.ns(|| format!("not a and b [{}]", i)), &a.not(), b)?;
+
+            // a == b = !(a ^ b)
+            let not_equal = Boolean::xor(cs.ns(|| format!("a XOR b [{}]", i)), a, b)?;
+            let equal = not_equal.not();
+
+            // evaluate a <= b
+            let less_or_equal = Boolean::or(cs.ns(|| format!("less or equal [{}]", i)), &less, &equal)?;
+
+            // select the current result if it is the first bit difference
+            result = Boolean::conditionally_select(
+                cs.ns(|| format!("select bit [{}]", i)),
+                &all_equal,
+                &less_or_equal,
+                &result,
+            )?;
+
+            // keep track of equal bits
+            all_equal = Boolean::and(cs.ns(|| format!("accumulate equal [{}]", i)), &all_equal, &equal)?;
+        }
+
+        result = Boolean::and(cs.ns(|| "false if all equal"), &result, &all_equal.not())?;
+
+        Ok(result)
+    }
+}
+
 macro_rules! uint_cmp_impl {
     ($($gadget: ident),*) => ($(
         /*  Bitwise less than comparison of two unsigned integers */
         impl<F: PrimeField> EvaluateLtGadget<F> for $gadget {
-            fn less_than<CS: ConstraintSystem<F>>(&self, mut cs: CS, other: &Self) -> Result<Boolean, SynthesisError> {
-
-                let mut result = Boolean::constant(true);
-                let mut all_equal = Boolean::constant(true);
-
-                // msb -> lsb
-                for (i, (a, b)) in self
-                    .bits
-                    .iter()
-                    .rev()
-                    .zip(other.bits.iter().rev())
-                    .enumerate()
-                {
-                    // a == 0 & b == 1
-                    let less = Boolean::and(cs.ns(|| format!("not a and b [{}]", i)), &a.not(), b)?;
-
-                    // a == b = !(a ^ b)
-                    let not_equal = Boolean::xor(cs.ns(|| format!("a XOR b [{}]", i)), a, b)?;
-                    let equal = not_equal.not();
-
-                    // evaluate a <= b
-                    let less_or_equal = Boolean::or(cs.ns(|| format!("less or equal [{}]", i)), &less, &equal)?;
-
-                    // select the current result if it is the first bit difference
-                    result = Boolean::conditionally_select(cs.ns(|| format!("select bit [{}]", i)), &all_equal, &less_or_equal, &result)?;
-
-                    // keep track of equal bits
-                    all_equal = Boolean::and(cs.ns(|| format!("accumulate equal [{}]", i)), &all_equal, &equal)?;
-                }
-
-                result = Boolean::and(cs.ns(|| format!("false if all equal")), &result, &all_equal.not())?;
-
-                Ok(result)
+            fn less_than<CS: ConstraintSystem<F>>(&self, cs: CS, other: &Self) -> Result<Boolean, SynthesisError> {
+                self.bits.less_than(cs, &other.bits)
             }
         }
 
@@ -95,4 +102,4 @@ macro_rules! uint_cmp_impl {
     )*)
 }
 
-uint_cmp_impl!(UInt8, UInt16, UInt32, UInt64, UInt128);
+uint_cmp_impl!(UInt8, UInt16, UInt32, UInt64, UInt128, UInt256);
@@ -0,0 +1,114 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::bits::{EvaluateLtGadget, LimbMultiplier, RippleCarryAdder};
+
+use num_bigint::BigUint;
+use snarkvm_errors::gadgets::SynthesisError;
+use snarkvm_models::{
+    curves::PrimeField,
+    gadgets::{
+        r1cs::{Assignment, ConstraintSystem},
+        utilities::{
+            alloc::AllocGadget,
+            boolean::{AllocatedBit, Boolean},
+            eq::EqGadget,
+        },
+    },
+};
+
+/// Returns the bitwise quotient and remainder of two unsigned n-bit numbers.
+///
+/// The quotient and remainder are witnessed and then constrained so that
+/// `quotient * divisor + remainder == dividend` and `remainder < divisor`
+pub trait LongDivider<F: PrimeField>
+where
+    Self: std::marker::Sized,
+{
+    fn div_rem_bits<CS: ConstraintSystem<F>>(&self, cs: CS, other: &Self) -> Result<(Self, Self), SynthesisError>;
+}
+
+impl<F: PrimeField> LongDivider<F> for Vec<Boolean> {
+    fn div_rem_bits<CS: ConstraintSystem<F>>(&self, mut cs: CS, other: &Self) -> Result<(Self, Self), SynthesisError> {
+        let size = self.len();
+
+        let (quotient_value, remainder_value) = match (bits_to_biguint(self), bits_to_biguint(other)) {
+            (Some(dividend), Some(divisor)) if divisor != BigUint::from(0u8) => {
+                (Some(&dividend / &divisor), Some(&dividend % &divisor))
+            }
+            _ => (None, None),
+        };
+
+        let quotient = alloc_bits(cs.ns(|| "quotient"), quotient_value, size)?;
+        let remainder = alloc_bits(cs.ns(|| "remainder"), remainder_value, size)?;
+
+        // remainder < divisor, which also rules out division by zero
+        let is_less = remainder.less_than(cs.ns(|| "remainder less than divisor"), other)?;
+        is_less.enforce_equal(
+            cs.ns(|| "enforce remainder less than divisor"),
+            &Boolean::constant(true),
+        )?;
+
+        // quotient * divisor + remainder == dividend, computed without truncation
+        let product = quotient.mul_bits(cs.ns(|| "quotient times divisor"), other, size * 2)?;
+
+        let mut extended_remainder = remainder.clone();
+        extended_remainder.resize(size * 2, Boolean::constant(false));
+
+        let sum = product.add_bits(cs.ns(|| "add remainder"), &extended_remainder)?;
+
+        let zero = Boolean::constant(false);
+        for (i, (actual, expected)) in sum.iter().zip(self.iter().chain(std::iter::repeat(&zero))).enumerate() {
+            actual.enforce_equal(cs.ns(|| format!("dividend bit {}", i)), expected)?;
+        }
+
+        Ok((quotient, remainder))
+    }
+}
+
+/// Returns the value of little-endian bits if all of them are known
+pub(crate) fn bits_to_biguint(bits: &[Boolean]) -> Option<BigUint> {
+    let mut value = BigUint::from(0u8);
+    for bit in bits.iter().rev() {
+        value <<= 1;
+        if bit.get_value()? {
+            value += 1u8;
+        }
+    }
+
+    Some(value)
+}
+
+fn alloc_bits<F: PrimeField, CS: ConstraintSystem<F>>(
+    mut cs: CS,
+    value: Option<BigUint>,
+    size: usize,
+) -> Result<Vec<Boolean>, SynthesisError> {
+    let bits = value.map(|value| {
+        let mut bits = value.to_radix_le(2);
+        bits.resize(size, 0);
+        bits
+    });
+
+    (0..size)
+        .map(|i| {
+            Ok(Boolean::from(AllocatedBit::alloc(
+                cs.ns(|| format!("allocated bit_gadget {}", i)),
+                || bits.as_ref().map(|bits| bits[i] == 1).get(),
+            )?))
+        })
+        .collect()
+}
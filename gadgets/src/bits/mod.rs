@@ -21,6 +21,12 @@ pub use self::adder::*;
 pub mod comparator;
 pub use self::comparator::*;
 
+pub mod divider;
+pub use self::divider::*;
+
+pub mod multiplier;
+pub use self::multiplier::*;
+
 pub mod rca;
 pub use self::rca::*;
 
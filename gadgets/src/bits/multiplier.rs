@@ -0,0 +1,190 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{bits::bits_to_biguint, unsigned_integer::to_bits_le};
+
+use snarkvm_errors::gadgets::SynthesisError;
+use snarkvm_models::{
+    curves::{Field, PrimeField},
+    gadgets::{
+        r1cs::{Assignment, ConstraintSystem, LinearCombination},
+        utilities::{
+            alloc::AllocGadget,
+            boolean::{AllocatedBit, Boolean},
+            select::CondSelectGadget,
+        },
+    },
+};
+
+/// The number of bits in each limb of a `LimbMultiplier` operand
+const LIMB_SIZE: usize = 64;
+
+/// The number of bits carried from one column of limb products into the next
+const CARRY_SIZE: usize = 72;
+
+/// Returns the bitwise product of two n-bit numbers, truncated to `width` bits.
+///
+/// The operands are split into 64-bit limbs which are multiplied in the field.
+/// Each column of limb products is then decomposed back into bits, so a product
+/// costs a few hundred constraints instead of one full adder per pair of bits.
+pub trait LimbMultiplier<F: Field>
+where
+    Self: std::marker::Sized,
+{
+    fn mul_bits<CS: ConstraintSystem<F>>(&self, cs: CS, other: &Self, width: usize) -> Result<Self, SynthesisError>;
+}
+
+impl<F: PrimeField> LimbMultiplier<F> for Vec<Boolean> {
+    fn mul_bits<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        other: &Self,
+        width: usize,
+    ) -> Result<Self, SynthesisError> {
+        assert!(self.len() % LIMB_SIZE == 0 && other.len() % LIMB_SIZE == 0 && width % LIMB_SIZE == 0);
+
+        // constant operands are multiplied outside of the constraint system
+        if is_constant(self) && is_constant(other) {
+            let product = bits_to_biguint(self).unwrap() * bits_to_biguint(other).unwrap();
+
+            return Ok(to_bits_le(&product, width).into_iter().map(Boolean::constant).collect());
+        }
+
+        let self_limbs: Vec<&[Boolean]> = self.chunks(LIMB_SIZE).collect();
+        let other_limbs: Vec<&[Boolean]> = other.chunks(LIMB_SIZE).collect();
+
+        let mut result = Vec::with_capacity(width);
+        let mut carry: Vec<Boolean> = Vec::new();
+
+        for k in 0..width / LIMB_SIZE {
+            let mut column = limb_lc::<F, CS>(&carry, F::one());
+            let mut column_value = bits_to_biguint(&carry);
+
+            for (i, a) in self_limbs.iter().enumerate().take(k + 1) {
+                let b = match other_limbs.get(k - i) {
+                    Some(b) => b,
+                    None => continue,
+                };
+
+                let a_value = bits_to_biguint(a).map(|value| value.to_u64_digits().first().copied().unwrap_or(0));
+                let b_value = bits_to_biguint(b).map(|value| value.to_u64_digits().first().copied().unwrap_or(0));
+                let product_value = a_value.and_then(|a| b_value.map(|b| a as u128 * b as u128));
+
+                if is_constant(a) {
+                    column = column + &limb_lc::<F, CS>(b, F::from(a_value.unwrap()));
+                } else if is_constant(b) {
+                    column = column + &limb_lc::<F, CS>(a, F::from(b_value.unwrap()));
+                } else {
+                    let product = cs.alloc(
+                        || format!("limb product {} {}", i, k - i),
+                        || product_value.map(F::from).get(),
+                    )?;
+
+                    cs.enforce(
+                        || format!("enforce limb product {} {}", i, k - i),
+                        |lc| lc + &limb_lc::<F, CS>(a, F::one()),
+                        |lc| lc + &limb_lc::<F, CS>(b, F::one()),
+                        |lc| lc + product,
+                    );
+
+                    column = column + product;
+                }
+
+                column_value = column_value.and_then(|value| product_value.map(|product| value + product));
+            }
+
+            // decompose the column into the next limb of the result and the carry into the next column
+            let column_bits = column_value.map(|value| to_bits_le(&value, LIMB_SIZE + CARRY_SIZE));
+
+            let bits = (0..LIMB_SIZE + CARRY_SIZE)
+                .map(|i| {
+                    Ok(Boolean::from(AllocatedBit::alloc(
+                        cs.ns(|| format!("column {} bit {}", k, i)),
+                        || column_bits.as_ref().map(|bits| bits[i]).get(),
+                    )?))
+                })
+                .collect::<Result<Vec<_>, SynthesisError>>()?;
+
+            cs.enforce(
+                || format!("column {} decomposition", k),
+                |lc| lc + &column,
+                |lc| lc + CS::one(),
+                |lc| lc + &limb_lc::<F, CS>(&bits, F::one()),
+            );
+
+            result.extend_from_slice(&bits[..LIMB_SIZE]);
+            carry = bits[LIMB_SIZE..].to_vec();
+        }
+
+        Ok(result)
+    }
+}
+
+fn is_constant(bits: &[Boolean]) -> bool {
+    bits.iter().all(|bit| matches!(bit, Boolean::Constant(_)))
+}
+
+/// Returns the linear combination of little-endian bits, scaled by `coeff`
+fn limb_lc<F: Field, CS: ConstraintSystem<F>>(bits: &[Boolean], mut coeff: F) -> LinearCombination<F> {
+    let mut lc = LinearCombination::zero();
+    for bit in bits {
+        lc = lc + &bit.lc(CS::one(), coeff);
+        coeff.double_in_place();
+    }
+
+    lc
+}
+
+/// Returns the bitwise exponentiation of a n-bit number using square-and-multiply.
+/// The result is truncated to n bits
+pub trait SquareMultiplyExponentiator<F: Field>
+where
+    Self: std::marker::Sized,
+{
+    fn pow_bits<CS: ConstraintSystem<F>>(&self, cs: CS, exponent: &Self) -> Result<Self, SynthesisError>;
+}
+
+impl<F: PrimeField> SquareMultiplyExponentiator<F> for Vec<Boolean> {
+    fn pow_bits<CS: ConstraintSystem<F>>(&self, mut cs: CS, exponent: &Self) -> Result<Self, SynthesisError> {
+        let width = self.len();
+
+        let mut result = vec![Boolean::constant(false); width];
+        result[0] = Boolean::constant(true);
+
+        // msb -> lsb
+        for (i, bit) in exponent.iter().rev().enumerate() {
+            result = result.mul_bits(cs.ns(|| format!("square {}", i)), &result, width)?;
+
+            // constant zero bits leave the result unchanged
+            if let Boolean::Constant(false) = bit {
+                continue;
+            }
+
+            let product = result.mul_bits(cs.ns(|| format!("multiply by self {}", i)), self, width)?;
+
+            result = product
+                .iter()
+                .zip(result.iter())
+                .enumerate()
+                .map(|(j, (first, second))| {
+                    Boolean::conditionally_select(cs.ns(|| format!("select {} {}", i, j)), bit, first, second)
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+        }
+
+        Ok(result)
+    }
+}
@@ -25,3 +25,6 @@ pub mod errors;
 
 pub mod signed_integer;
 pub use self::signed_integer::*;
+
+pub mod unsigned_integer;
+pub use self::unsigned_integer::*;
@@ -21,6 +21,7 @@ use crate::{
     Int,
     Int128,
     Int16,
+    Int256,
     Int32,
     Int64,
     Int8,
@@ -155,3 +156,23 @@ macro_rules! add_int_impl {
 }
 
 add_int_impl!(Int8 Int16 Int32 Int64 Int128);
+
+impl<F: PrimeField> Add<F> for Int256 {
+    type ErrorType = SignedIntegerError;
+
+    fn add<CS: ConstraintSystem<F>>(&self, mut cs: CS, other: &Self) -> Result<Self, Self::ErrorType> {
+        let value = match (&self.value, &other.value) {
+            (Some(a), Some(b)) => Some(Self::checked(a + b)?),
+            _ => None,
+        };
+
+        // The bits cannot be packed into a single field element, so the sum is
+        // taken directly from the ripple carry adder
+        let mut bits = self.add_bits(cs.ns(|| "bits"), other)?;
+
+        // we discard the carry since we check for overflow above
+        let _carry = bits.pop();
+
+        Ok(Self { bits, value })
+    }
+}
@@ -16,15 +16,17 @@
 
 use crate::{
     arithmetic::{Add, Div, Neg, Sub},
-    bits::ComparatorGadget,
+    bits::{ComparatorGadget, LongDivider},
     errors::SignedIntegerError,
     Int,
     Int128,
     Int16,
+    Int256,
     Int32,
     Int64,
     Int8,
 };
+use num_bigint::Sign;
 use snarkvm_models::{
     curves::PrimeField,
     gadgets::{
@@ -262,3 +264,64 @@ macro_rules! div_int_impl {
 }
 
 div_int_impl!(Int8, Int16, Int32, Int64, Int128);
+
+impl<F: PrimeField> Div<F> for Int256 {
+    type ErrorType = SignedIntegerError;
+
+    fn div<CS: ConstraintSystem<F>>(&self, mut cs: CS, other: &Self) -> Result<Self, Self::ErrorType> {
+        let value = match (&self.value, &other.value) {
+            (_, Some(b)) if b.sign() == Sign::NoSign => return Err(SignedIntegerError::DivisionByZero),
+            (Some(a), Some(b)) => Some(Self::checked(a / b)?),
+            _ => None,
+        };
+
+        // constant division does not need to witness a quotient
+        if let (true, Some(value)) = (Self::result_is_constant(self, other), &value) {
+            return Ok(Self::constant(value.clone()));
+        }
+
+        // divide the magnitudes, then restore the sign of the quotient
+        let self_negative = &self.bits[<Self as Int>::SIZE - 1];
+        let other_negative = &other.bits[<Self as Int>::SIZE - 1];
+
+        let self_negated = self.bits.neg(cs.ns(|| "negate dividend"))?;
+        let self_magnitude = select_bits(cs.ns(|| "dividend magnitude"), self_negative, &self_negated, &self.bits)?;
+
+        let other_negated = other.bits.neg(cs.ns(|| "negate divisor"))?;
+        let other_magnitude = select_bits(
+            cs.ns(|| "divisor magnitude"),
+            other_negative,
+            &other_negated,
+            &other.bits,
+        )?;
+
+        let (quotient, _remainder) = self_magnitude.div_rem_bits(cs.ns(|| "divide magnitudes"), &other_magnitude)?;
+
+        let negative = Boolean::xor(cs.ns(|| "quotient sign"), self_negative, other_negative)?;
+        let quotient_negated = quotient.neg(cs.ns(|| "negate quotient"))?;
+        let bits = select_bits(cs.ns(|| "signed quotient"), &negative, &quotient_negated, &quotient)?;
+
+        Ok(Self { bits, value })
+    }
+}
+
+fn select_bits<F: PrimeField, CS: ConstraintSystem<F>>(
+    mut cs: CS,
+    cond: &Boolean,
+    first: &[Boolean],
+    second: &[Boolean],
+) -> Result<Vec<Boolean>, SignedIntegerError> {
+    first
+        .iter()
+        .zip(second.iter())
+        .enumerate()
+        .map(|(i, (a, b))| {
+            Ok(Boolean::conditionally_select(
+                cs.ns(|| format!("select bit {}", i)),
+                cond,
+                a,
+                b,
+            )?)
+        })
+        .collect()
+}
@@ -16,11 +16,12 @@
 
 use crate::{
     arithmetic::Mul,
-    bits::{RippleCarryAdder, SignExtend},
+    bits::{LimbMultiplier, RippleCarryAdder, SignExtend},
     errors::SignedIntegerError,
     Int,
     Int128,
     Int16,
+    Int256,
     Int32,
     Int64,
     Int8,
@@ -221,3 +222,19 @@ macro_rules! mul_int_impl {
 }
 
 mul_int_impl!(Int8 Int16 Int32 Int64 Int128);
+
+impl<F: PrimeField> Mul<F> for Int256 {
+    type ErrorType = SignedIntegerError;
+
+    fn mul<CS: ConstraintSystem<F>>(&self, mut cs: CS, other: &Self) -> Result<Self, Self::ErrorType> {
+        let value = match (&self.value, &other.value) {
+            (Some(a), Some(b)) => Some(Self::checked(a * b)?),
+            _ => None,
+        };
+
+        // The low bits of the product are the same for two's complement operands
+        let bits = self.bits.mul_bits(cs.ns(|| "bits"), &other.bits, <Self as Int>::SIZE)?;
+
+        Ok(Self { bits, value })
+    }
+}
@@ -50,3 +50,19 @@ macro_rules! neg_int_impl {
 }
 
 neg_int_impl!(Int8 Int16 Int32 Int64 Int128);
+
+impl<F: PrimeField> Neg<F> for Int256 {
+    type ErrorType = SignedIntegerError;
+
+    fn neg<CS: ConstraintSystem<F>>(&self, cs: CS) -> Result<Self, Self::ErrorType> {
+        let value = match &self.value {
+            Some(val) => Some(Self::checked(-val)?),
+            None => None,
+        };
+
+        // calculate two's complement
+        let bits = self.bits.neg(cs)?;
+
+        Ok(Self { bits, value })
+    }
+}
@@ -16,15 +16,18 @@
 
 use crate::{
     arithmetic::{Mul, Pow},
+    bits::SquareMultiplyExponentiator,
     errors::SignedIntegerError,
     Int,
     Int128,
     Int16,
+    Int256,
     Int32,
     Int64,
     Int8,
 };
 
+use num_bigint::{BigInt, Sign};
 use snarkvm_models::{
     curves::PrimeField,
     gadgets::{
@@ -32,6 +35,7 @@ use snarkvm_models::{
         utilities::{alloc::AllocGadget, boolean::Boolean, select::CondSelectGadget},
     },
 };
+use std::convert::TryFrom;
 
 macro_rules! pow_int_impl {
     ($($gadget:ty)*) => ($(
@@ -84,3 +88,43 @@ macro_rules! pow_int_impl {
 }
 
 pow_int_impl!(Int8 Int16 Int32 Int64 Int128);
+
+impl<F: PrimeField> Pow<F> for Int256 {
+    type ErrorType = SignedIntegerError;
+
+    fn pow<CS: ConstraintSystem<F>>(&self, mut cs: CS, other: &Self) -> Result<Self, Self::ErrorType> {
+        let value = match (&self.value, &other.value) {
+            (Some(a), Some(b)) => Some(checked_pow(a, b)?),
+            _ => None,
+        };
+
+        let bits = self.bits.pow_bits(cs.ns(|| "bits"), &other.bits)?;
+
+        Ok(Self { bits, value })
+    }
+}
+
+/// Raises `base` to the power `exponent`, without computing results that cannot fit in 256 bits
+fn checked_pow(base: &BigInt, exponent: &BigInt) -> Result<BigInt, SignedIntegerError> {
+    if exponent.sign() == Sign::Minus {
+        return Err(SignedIntegerError::Overflow);
+    }
+
+    // 0, 1 and -1 never overflow
+    if base.magnitude().bits() <= 1 {
+        let is_even = exponent % 2u8 == BigInt::from(0u8);
+
+        let result = if exponent.sign() == Sign::NoSign || (base.sign() == Sign::Minus && is_even) {
+            BigInt::from(1u8)
+        } else {
+            base.clone()
+        };
+
+        return Ok(result);
+    }
+
+    match u32::try_from(exponent) {
+        Ok(exponent) if exponent as usize <= <Int256 as Int>::SIZE => Int256::checked(base.pow(exponent)),
+        _ => Err(SignedIntegerError::Overflow),
+    }
+}
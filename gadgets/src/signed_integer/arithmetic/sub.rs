@@ -19,6 +19,7 @@ use crate::{
     errors::SignedIntegerError,
     Int128,
     Int16,
+    Int256,
     Int32,
     Int64,
     Int8,
@@ -41,4 +42,4 @@ macro_rules! sub_int_impl {
     )*)
 }
 
-sub_int_impl!(Int8 Int16 Int32 Int64 Int128);
+sub_int_impl!(Int8 Int16 Int32 Int64 Int128 Int256);
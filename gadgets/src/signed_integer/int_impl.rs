@@ -14,6 +14,9 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
+use crate::{errors::SignedIntegerError, unsigned_integer::to_bits_le};
+
+use num_bigint::{BigInt, BigUint, Sign};
 use snarkvm_models::gadgets::utilities::boolean::Boolean;
 
 use std::fmt::Debug;
@@ -94,3 +97,63 @@ int_impl!(Int16, i16, 16);
 int_impl!(Int32, i32, 32);
 int_impl!(Int64, i64, 64);
 int_impl!(Int128, i128, 128);
+
+/// A 256-bit signed integer gadget.
+///
+/// The value does not fit in a primitive type, so it is tracked as a `BigInt`.
+#[derive(Clone, Debug)]
+pub struct Int256 {
+    pub bits: Vec<Boolean>,
+    pub value: Option<BigInt>,
+}
+
+impl Int256 {
+    pub fn constant(value: BigInt) -> Self {
+        let bits = to_bits_le(&Self::twos_complement(&value), <Self as Int>::SIZE)
+            .into_iter()
+            .map(Boolean::constant)
+            .collect();
+
+        Self {
+            bits,
+            value: Some(value),
+        }
+    }
+
+    /// Returns the two's complement representation of the value
+    pub fn twos_complement(value: &BigInt) -> BigUint {
+        match value.sign() {
+            Sign::Minus => (BigUint::from(1u8) << <Self as Int>::SIZE) - value.magnitude(),
+            _ => value.magnitude().clone(),
+        }
+    }
+
+    /// Returns the value if it can be represented in 256 bits
+    pub fn checked(value: BigInt) -> Result<BigInt, SignedIntegerError> {
+        let bound = BigInt::from(1u8) << (<Self as Int>::SIZE - 1);
+        if value < -bound.clone() || value >= bound {
+            return Err(SignedIntegerError::Overflow);
+        }
+
+        Ok(value)
+    }
+}
+
+impl Int for Int256 {
+    type IntegerType = BigInt;
+
+    const SIZE: usize = 256;
+
+    fn one() -> Self {
+        Self::constant(BigInt::from(1u8))
+    }
+
+    fn zero() -> Self {
+        Self::constant(BigInt::from(0u8))
+    }
+
+    fn is_constant(&self) -> bool {
+        // If any bits of self are allocated bits, return false
+        self.bits.iter().all(|bit| matches!(bit, Boolean::Constant(_)))
+    }
+}
@@ -18,6 +18,7 @@ use crate::{
     bits::{ComparatorGadget, EvaluateLtGadget},
     Int128,
     Int16,
+    Int256,
     Int32,
     Int64,
     Int8,
@@ -95,4 +96,4 @@ macro_rules! cmp_gadget_impl {
     )*)
 }
 
-cmp_gadget_impl!(Int8 Int16 Int32 Int64 Int128);
+cmp_gadget_impl!(Int8 Int16 Int32 Int64 Int128 Int256);
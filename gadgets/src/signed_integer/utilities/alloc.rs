@@ -14,9 +14,10 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Int, Int128, Int16, Int32, Int64, Int8};
+use crate::{unsigned_integer::to_bits_le, Int, Int128, Int16, Int256, Int32, Int64, Int8};
 
 use core::{borrow::Borrow, iter};
+use num_bigint::BigInt;
 use snarkvm_errors::gadgets::SynthesisError;
 use snarkvm_models::{
     curves::Field,
@@ -113,3 +114,43 @@ macro_rules! alloc_int_impl {
 }
 
 alloc_int_impl!(Int8 Int16 Int32 Int64 Int128);
+
+impl Int256 {
+    fn alloc_value<F: Field, CS: ConstraintSystem<F>>(
+        mut cs: CS,
+        value: Result<BigInt, SynthesisError>,
+    ) -> Result<Self, SynthesisError> {
+        let bits = match &value {
+            Ok(val) => {
+                let val = Self::checked(val.clone()).map_err(|_| SynthesisError::Unsatisfiable)?;
+                let v = to_bits_le(&Self::twos_complement(&val), <Self as Int>::SIZE);
+                create_value(&mut cs, v.into_iter().map(Some))
+            }
+            Err(_) => {
+                let i = iter::repeat(None::<bool>).take(<Self as Int>::SIZE);
+                create_value(&mut cs, i)
+            }
+        }?;
+
+        Ok(Self {
+            bits,
+            value: value.ok(),
+        })
+    }
+}
+
+impl<F: Field> AllocGadget<BigInt, F> for Int256 {
+    fn alloc<Fn: FnOnce() -> Result<T, SynthesisError>, T: Borrow<BigInt>, CS: ConstraintSystem<F>>(
+        cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        Self::alloc_value(cs, value_gen().map(|val| val.borrow().clone()))
+    }
+
+    fn alloc_input<Fn: FnOnce() -> Result<T, SynthesisError>, T: Borrow<BigInt>, CS: ConstraintSystem<F>>(
+        cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        Self::alloc_value(cs, value_gen().map(|val| val.borrow().clone()))
+    }
+}
@@ -52,4 +52,4 @@ macro_rules! cond_eq_int_impl {
     )*)
 }
 
-cond_eq_int_impl!(Int8, Int16, Int32, Int64, Int128);
+cond_eq_int_impl!(Int8, Int16, Int32, Int64, Int128, Int256);
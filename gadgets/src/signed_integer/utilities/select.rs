@@ -74,3 +74,49 @@ macro_rules! select_int_impl {
 }
 
 select_int_impl!(Int8 Int16 Int32 Int64 Int128);
+
+impl<F: PrimeField> CondSelectGadget<F> for Int256 {
+    fn conditionally_select<CS: ConstraintSystem<F>>(
+        mut cs: CS,
+        cond: &Boolean,
+        first: &Self,
+        second: &Self,
+    ) -> Result<Self, SynthesisError> {
+        if let Boolean::Constant(cond) = *cond {
+            if cond {
+                Ok(first.clone())
+            } else {
+                Ok(second.clone())
+            }
+        } else {
+            let result_val = cond
+                .get_value()
+                .and_then(|c| if c { first.value.clone() } else { second.value.clone() });
+
+            let result = Self::alloc(cs.ns(|| "cond_select_result"), || result_val.get())?;
+
+            for (i, ((bit1, bit2), actual)) in first
+                .bits
+                .iter()
+                .zip(second.bits.iter())
+                .zip(result.bits.iter())
+                .enumerate()
+            {
+                let expected = Boolean::conditionally_select(
+                    &mut cs.ns(|| format!("{}_cond_select_{}", <Self as Int>::SIZE, i)),
+                    cond,
+                    bit1,
+                    bit2,
+                )?;
+
+                actual.enforce_equal(&mut cs.ns(|| format!("selected_result_bit_{}", i)), &expected)?;
+            }
+
+            Ok(result)
+        }
+    }
+
+    fn cost() -> usize {
+        unimplemented!();
+    }
+}
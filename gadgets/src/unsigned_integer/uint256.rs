@@ -0,0 +1,339 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    arithmetic::{Add, Div, Mul, Neg, Pow, Sub},
+    bits::{LimbMultiplier, LongDivider, RippleCarryAdder, SquareMultiplyExponentiator},
+    errors::UnsignedIntegerError,
+};
+
+use num_bigint::BigUint;
+use snarkvm_errors::gadgets::SynthesisError;
+use snarkvm_models::{
+    curves::{Field, PrimeField},
+    gadgets::{
+        r1cs::{Assignment, ConstraintSystem},
+        utilities::{
+            alloc::AllocGadget,
+            boolean::{AllocatedBit, Boolean},
+            eq::{ConditionalEqGadget, EqGadget, EvaluateEqGadget},
+            select::CondSelectGadget,
+        },
+    },
+};
+use std::{borrow::Borrow, cmp::Ordering, convert::TryFrom};
+
+/// A 256-bit unsigned integer gadget.
+///
+/// Unlike the smaller unsigned integers, the value does not fit in a primitive type
+/// and the bits cannot be packed into a single field element.
+#[derive(Clone, Debug)]
+pub struct UInt256 {
+    pub bits: Vec<Boolean>,
+    pub value: Option<BigUint>,
+}
+
+impl UInt256 {
+    pub const SIZE: usize = 256;
+
+    pub fn constant(value: BigUint) -> Self {
+        let bits = to_bits_le(&value, Self::SIZE)
+            .into_iter()
+            .map(Boolean::constant)
+            .collect();
+
+        Self {
+            bits,
+            value: Some(value),
+        }
+    }
+
+    /// Returns true if all bits in this `UInt256` are constant
+    pub fn is_constant(&self) -> bool {
+        self.bits.iter().all(|bit| matches!(bit, Boolean::Constant(_)))
+    }
+
+    /// Returns true if both `UInt256` objects have constant bits
+    pub fn result_is_constant(first: &Self, second: &Self) -> bool {
+        first.is_constant() && second.is_constant()
+    }
+
+    /// Returns the value if it can be represented in 256 bits
+    fn checked(value: BigUint) -> Result<BigUint, UnsignedIntegerError> {
+        if value.bits() > Self::SIZE as u64 {
+            return Err(UnsignedIntegerError::Overflow);
+        }
+
+        Ok(value)
+    }
+
+    fn alloc_value<F: Field, CS: ConstraintSystem<F>>(
+        mut cs: CS,
+        value: Option<BigUint>,
+    ) -> Result<Self, SynthesisError> {
+        if let Some(value) = &value {
+            if value.bits() > Self::SIZE as u64 {
+                return Err(SynthesisError::Unsatisfiable);
+            }
+        }
+
+        let bit_values = value.as_ref().map(|value| to_bits_le(value, Self::SIZE));
+
+        let bits = (0..Self::SIZE)
+            .map(|i| {
+                Ok(Boolean::from(AllocatedBit::alloc(
+                    &mut cs.ns(|| format!("allocated bit_gadget {}", i)),
+                    || bit_values.as_ref().map(|bits| bits[i]).get(),
+                )?))
+            })
+            .collect::<Result<Vec<_>, SynthesisError>>()?;
+
+        Ok(Self { bits, value })
+    }
+}
+
+/// Returns the little-endian bits of the value, padded or truncated to `size` bits
+pub(crate) fn to_bits_le(value: &BigUint, size: usize) -> Vec<bool> {
+    let mut bits: Vec<bool> = value.to_radix_le(2).into_iter().map(|bit| bit == 1).collect();
+    bits.resize(size, false);
+    bits
+}
+
+impl<F: Field> AllocGadget<BigUint, F> for UInt256 {
+    fn alloc<Fn: FnOnce() -> Result<T, SynthesisError>, T: Borrow<BigUint>, CS: ConstraintSystem<F>>(
+        cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        let value = value_gen().map(|value| value.borrow().clone()).ok();
+
+        Self::alloc_value(cs, value)
+    }
+
+    fn alloc_input<Fn: FnOnce() -> Result<T, SynthesisError>, T: Borrow<BigUint>, CS: ConstraintSystem<F>>(
+        cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        let value = value_gen().map(|value| value.borrow().clone()).ok();
+
+        Self::alloc_value(cs, value)
+    }
+}
+
+impl<F: PrimeField> CondSelectGadget<F> for UInt256 {
+    fn conditionally_select<CS: ConstraintSystem<F>>(
+        mut cs: CS,
+        cond: &Boolean,
+        first: &Self,
+        second: &Self,
+    ) -> Result<Self, SynthesisError> {
+        if let Boolean::Constant(cond) = *cond {
+            if cond {
+                Ok(first.clone())
+            } else {
+                Ok(second.clone())
+            }
+        } else {
+            let result_val = cond
+                .get_value()
+                .and_then(|c| if c { first.value.clone() } else { second.value.clone() });
+
+            let result = Self::alloc(cs.ns(|| "cond_select_result"), || result_val.get())?;
+
+            for (i, ((bit1, bit2), actual)) in first
+                .bits
+                .iter()
+                .zip(second.bits.iter())
+                .zip(result.bits.iter())
+                .enumerate()
+            {
+                let expected =
+                    Boolean::conditionally_select(&mut cs.ns(|| format!("256_cond_select_{}", i)), cond, bit1, bit2)?;
+
+                actual.enforce_equal(&mut cs.ns(|| format!("selected_result_bit_{}", i)), &expected)?;
+            }
+
+            Ok(result)
+        }
+    }
+
+    fn cost() -> usize {
+        unimplemented!();
+    }
+}
+
+impl<F: PrimeField> EvaluateEqGadget<F> for UInt256 {
+    fn evaluate_equal<CS: ConstraintSystem<F>>(&self, mut cs: CS, other: &Self) -> Result<Boolean, SynthesisError> {
+        let mut result = Boolean::constant(true);
+        for (i, (a, b)) in self.bits.iter().zip(&other.bits).enumerate() {
+            let equal = a.evaluate_equal(&mut cs.ns(|| format!("256 evaluate equality for {}-th bit", i)), b)?;
+
+            result = Boolean::and(
+                &mut cs.ns(|| format!("256 and result for {}-th bit", i)),
+                &equal,
+                &result,
+            )?;
+        }
+
+        Ok(result)
+    }
+}
+
+impl<F: PrimeField> ConditionalEqGadget<F> for UInt256 {
+    fn conditional_enforce_equal<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        other: &Self,
+        condition: &Boolean,
+    ) -> Result<(), SynthesisError> {
+        for (i, (a, b)) in self.bits.iter().zip(&other.bits).enumerate() {
+            a.conditional_enforce_equal(
+                &mut cs.ns(|| format!("256 equality check for the {}-th bit", i)),
+                b,
+                condition,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    fn cost() -> usize {
+        Self::SIZE * <Boolean as ConditionalEqGadget<F>>::cost()
+    }
+}
+
+impl<F: PrimeField> EqGadget<F> for UInt256 {}
+
+impl PartialEq for UInt256 {
+    fn eq(&self, other: &Self) -> bool {
+        // self.value == other.value means that other.value.is_some() too
+        self.value.is_some() && self.value == other.value
+    }
+}
+
+impl Eq for UInt256 {}
+
+impl PartialOrd for UInt256 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Option::from(self.value.cmp(&other.value))
+    }
+}
+
+impl<F: PrimeField> Add<F> for UInt256 {
+    type ErrorType = UnsignedIntegerError;
+
+    fn add<CS: ConstraintSystem<F>>(&self, mut cs: CS, other: &Self) -> Result<Self, Self::ErrorType> {
+        let value = match (&self.value, &other.value) {
+            (Some(a), Some(b)) => Some(Self::checked(a + b)?),
+            _ => None,
+        };
+
+        let mut bits = self.bits.add_bits(cs.ns(|| "bits"), &other.bits)?;
+
+        // we discard the carry since we check for overflow above
+        let _carry = bits.pop();
+
+        Ok(Self { bits, value })
+    }
+}
+
+impl<F: PrimeField> Sub<F> for UInt256 {
+    type ErrorType = UnsignedIntegerError;
+
+    fn sub<CS: ConstraintSystem<F>>(&self, mut cs: CS, other: &Self) -> Result<Self, Self::ErrorType> {
+        let value = match (&self.value, &other.value) {
+            (Some(a), Some(b)) if a < b => return Err(UnsignedIntegerError::Overflow),
+            (Some(a), Some(b)) => Some(a - b),
+            _ => None,
+        };
+
+        // add the two's complement of the subtrahend
+        let negated = other.bits.neg(cs.ns(|| "negate"))?;
+
+        let mut bits = self.bits.add_bits(cs.ns(|| "bits"), &negated)?;
+
+        // we discard the carry since we check for underflow above
+        let _carry = bits.pop();
+
+        Ok(Self { bits, value })
+    }
+}
+
+impl<F: PrimeField> Mul<F> for UInt256 {
+    type ErrorType = UnsignedIntegerError;
+
+    fn mul<CS: ConstraintSystem<F>>(&self, mut cs: CS, other: &Self) -> Result<Self, Self::ErrorType> {
+        let value = match (&self.value, &other.value) {
+            (Some(a), Some(b)) => Some(Self::checked(a * b)?),
+            _ => None,
+        };
+
+        let bits = self.bits.mul_bits(cs.ns(|| "bits"), &other.bits, Self::SIZE)?;
+
+        Ok(Self { bits, value })
+    }
+}
+
+impl<F: PrimeField> Div<F> for UInt256 {
+    type ErrorType = UnsignedIntegerError;
+
+    fn div<CS: ConstraintSystem<F>>(&self, mut cs: CS, other: &Self) -> Result<Self, Self::ErrorType> {
+        let value = match (&self.value, &other.value) {
+            (_, Some(b)) if *b == BigUint::from(0u8) => return Err(UnsignedIntegerError::DivisionByZero),
+            (Some(a), Some(b)) => Some(a / b),
+            _ => None,
+        };
+
+        // constant division does not need to witness a quotient
+        if let (true, Some(value)) = (Self::result_is_constant(self, other), &value) {
+            return Ok(Self::constant(value.clone()));
+        }
+
+        let (bits, _remainder) = self.bits.div_rem_bits(cs.ns(|| "bits"), &other.bits)?;
+
+        Ok(Self { bits, value })
+    }
+}
+
+impl<F: PrimeField> Pow<F> for UInt256 {
+    type ErrorType = UnsignedIntegerError;
+
+    fn pow<CS: ConstraintSystem<F>>(&self, mut cs: CS, other: &Self) -> Result<Self, Self::ErrorType> {
+        let value = match (&self.value, &other.value) {
+            (Some(a), Some(b)) => Some(Self::checked(checked_pow(a, b)?)?),
+            _ => None,
+        };
+
+        let bits = self.bits.pow_bits(cs.ns(|| "bits"), &other.bits)?;
+
+        Ok(Self { bits, value })
+    }
+}
+
+/// Raises `base` to the power `exponent`, without computing results that cannot fit in 256 bits
+fn checked_pow(base: &BigUint, exponent: &BigUint) -> Result<BigUint, UnsignedIntegerError> {
+    if base.bits() <= 1 {
+        return Ok(if *exponent == BigUint::from(0u8) {
+            BigUint::from(1u8)
+        } else {
+            base.clone()
+        });
+    }
+
+    match u32::try_from(exponent) {
+        Ok(exponent) if exponent as usize <= UInt256::SIZE => Ok(base.pow(exponent)),
+        _ => Err(UnsignedIntegerError::Overflow),
+    }
+}
@@ -0,0 +1,304 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_gadgets::{arithmetic::*, bits::EvaluateLtGadget, Int256};
+
+use num_bigint::{BigInt, Sign};
+use snarkvm_models::{
+    curves::{One, Zero},
+    gadgets::{
+        r1cs::{ConstraintSystem, Fr, TestConstraintSystem},
+        utilities::{alloc::AllocGadget, boolean::Boolean},
+    },
+};
+
+use rand::Rng;
+use rand_core::SeedableRng;
+use rand_xorshift::XorShiftRng;
+
+fn random_i256(rng: &mut XorShiftRng) -> BigInt {
+    BigInt::from_signed_bytes_le(&rng.gen::<[u8; 32]>())
+}
+
+fn random_i128(rng: &mut XorShiftRng) -> BigInt {
+    BigInt::from(rng.gen::<i128>())
+}
+
+fn expected_bits(expected: &BigInt) -> Vec<bool> {
+    let mut bytes = expected.to_signed_bytes_le();
+    let fill = if expected.sign() == Sign::Minus { 0xff } else { 0 };
+    bytes.resize(32, fill);
+
+    bytes
+        .iter()
+        .flat_map(|byte| (0..8).map(move |i| (byte >> i) & 1 == 1))
+        .collect()
+}
+
+fn check_all_constant_bits(expected: &BigInt, actual: Int256) {
+    for (b, bit) in actual.bits.iter().zip(expected_bits(expected)) {
+        match *b {
+            Boolean::Is(_) => panic!(),
+            Boolean::Not(_) => panic!(),
+            Boolean::Constant(b) => assert_eq!(b, bit),
+        }
+    }
+}
+
+fn check_all_allocated_bits(expected: &BigInt, actual: Int256) {
+    for (b, bit) in actual.bits.iter().zip(expected_bits(expected)) {
+        assert_eq!(b.get_value().unwrap(), bit);
+    }
+}
+
+#[test]
+fn test_int256_constant_and_alloc() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..100 {
+        let mut cs = TestConstraintSystem::<Fr>::new();
+
+        let a = random_i256(&mut rng);
+
+        let a_const = Int256::constant(a.clone());
+
+        assert!(a_const.value == Some(a.clone()));
+
+        check_all_constant_bits(&a, a_const);
+
+        let a_bit = Int256::alloc(cs.ns(|| "a_bit"), || Ok(a.clone())).unwrap();
+
+        assert!(cs.is_satisfied());
+        assert!(a_bit.value == Some(a.clone()));
+
+        check_all_allocated_bits(&a, a_bit);
+    }
+}
+
+#[test]
+fn test_int256_add() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..10 {
+        let mut cs = TestConstraintSystem::<Fr>::new();
+
+        let a = random_i256(&mut rng) >> 1usize;
+        let b = random_i256(&mut rng) >> 1usize;
+
+        let expected = &a + &b;
+
+        let a_bit = Int256::alloc(cs.ns(|| "a_bit"), || Ok(a)).unwrap();
+        let b_bit = Int256::alloc(cs.ns(|| "b_bit"), || Ok(b)).unwrap();
+
+        let r = a_bit.add(cs.ns(|| "addition"), &b_bit).unwrap();
+
+        assert!(cs.is_satisfied());
+
+        assert!(r.value == Some(expected.clone()));
+
+        check_all_allocated_bits(&expected, r);
+    }
+}
+
+#[test]
+fn test_int256_add_overflow() {
+    let mut cs = TestConstraintSystem::<Fr>::new();
+
+    let max = (BigInt::from(1u8) << 255usize) - 1u8;
+
+    let a_bit = Int256::constant(max);
+    let b_bit = Int256::constant(BigInt::from(1u8));
+
+    assert!(a_bit.add(cs.ns(|| "addition"), &b_bit).is_err());
+}
+
+#[test]
+fn test_int256_sub() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..10 {
+        let mut cs = TestConstraintSystem::<Fr>::new();
+
+        let a = random_i256(&mut rng) >> 1usize;
+        let b = random_i256(&mut rng) >> 1usize;
+
+        let expected = &a - &b;
+
+        let a_bit = Int256::alloc(cs.ns(|| "a_bit"), || Ok(a)).unwrap();
+        let b_bit = Int256::alloc(cs.ns(|| "b_bit"), || Ok(b)).unwrap();
+
+        let r = a_bit.sub(cs.ns(|| "subtraction"), &b_bit).unwrap();
+
+        assert!(cs.is_satisfied());
+
+        assert!(r.value == Some(expected.clone()));
+
+        check_all_allocated_bits(&expected, r);
+    }
+}
+
+#[test]
+fn test_int256_neg() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..10 {
+        let mut cs = TestConstraintSystem::<Fr>::new();
+
+        let a = random_i256(&mut rng) >> 1usize;
+
+        let expected = -&a;
+
+        let a_bit = Int256::alloc(cs.ns(|| "a_bit"), || Ok(a)).unwrap();
+
+        let r = a_bit.neg(cs.ns(|| "negation")).unwrap();
+
+        assert!(cs.is_satisfied());
+
+        assert!(r.value == Some(expected.clone()));
+
+        check_all_allocated_bits(&expected, r);
+    }
+}
+
+#[test]
+fn test_int256_mul() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..2 {
+        let mut cs = TestConstraintSystem::<Fr>::new();
+
+        let a = random_i128(&mut rng);
+        let b = random_i128(&mut rng);
+
+        let expected = &a * &b;
+
+        let a_bit = Int256::alloc(cs.ns(|| "a_bit"), || Ok(a)).unwrap();
+        let b_bit = Int256::alloc(cs.ns(|| "b_bit"), || Ok(b)).unwrap();
+
+        let r = a_bit.mul(cs.ns(|| "multiplication"), &b_bit).unwrap();
+
+        assert!(cs.is_satisfied());
+
+        assert!(r.value == Some(expected.clone()));
+
+        check_all_allocated_bits(&expected, r);
+    }
+}
+
+#[test]
+fn test_int256_div_constants() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..100 {
+        let mut cs = TestConstraintSystem::<Fr>::new();
+
+        let a = random_i256(&mut rng);
+        let b = random_i128(&mut rng);
+
+        let expected = &a / &b;
+
+        let a_bit = Int256::constant(a);
+        let b_bit = Int256::constant(b);
+
+        let r = a_bit.div(cs.ns(|| "division"), &b_bit).unwrap();
+
+        assert!(r.value == Some(expected.clone()));
+
+        check_all_constant_bits(&expected, r);
+    }
+}
+
+#[test]
+fn test_int256_div() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..2 {
+        let mut cs = TestConstraintSystem::<Fr>::new();
+
+        let a = random_i256(&mut rng);
+        let b = random_i128(&mut rng);
+
+        let expected = &a / &b;
+
+        let a_bit = Int256::alloc(cs.ns(|| "a_bit"), || Ok(a)).unwrap();
+        let b_bit = Int256::alloc(cs.ns(|| "b_bit"), || Ok(b)).unwrap();
+
+        let r = a_bit.div(cs.ns(|| "division"), &b_bit).unwrap();
+
+        assert!(cs.is_satisfied());
+
+        assert!(r.value == Some(expected.clone()));
+
+        check_all_allocated_bits(&expected, r);
+
+        // Flip a quotient bit_gadget and see if the division constraints still hold
+        let path = "division/divide magnitudes/quotient/allocated bit_gadget 0/boolean";
+        if cs.get(path).is_zero() {
+            cs.set(path, Fr::one());
+        } else {
+            cs.set(path, Fr::zero());
+        }
+
+        assert!(!cs.is_satisfied());
+    }
+}
+
+#[test]
+fn test_int256_pow() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..2 {
+        let mut cs = TestConstraintSystem::<Fr>::new();
+
+        let a = BigInt::from(rng.gen::<i64>());
+
+        let expected = a.pow(3);
+
+        let a_bit = Int256::alloc(cs.ns(|| "a_bit"), || Ok(a)).unwrap();
+        let b_bit = Int256::constant(BigInt::from(3u8));
+
+        let r = a_bit.pow(cs.ns(|| "exponentiation"), &b_bit).unwrap();
+
+        assert!(cs.is_satisfied());
+
+        assert!(r.value == Some(expected.clone()));
+
+        check_all_allocated_bits(&expected, r);
+    }
+}
+
+#[test]
+fn test_int256_less_than() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..10 {
+        let mut cs = TestConstraintSystem::<Fr>::new();
+
+        let a = random_i256(&mut rng);
+        let b = random_i256(&mut rng);
+
+        let expected = a < b;
+
+        let a_bit = Int256::alloc(cs.ns(|| "a_bit"), || Ok(a)).unwrap();
+        let b_bit = Int256::alloc(cs.ns(|| "b_bit"), || Ok(b)).unwrap();
+
+        let r = a_bit.less_than(cs.ns(|| "less than"), &b_bit).unwrap();
+
+        assert!(cs.is_satisfied());
+
+        assert_eq!(r.get_value(), Some(expected));
+    }
+}
@@ -16,6 +16,7 @@
 
 pub mod i128;
 pub mod i16;
+pub mod i256;
 pub mod i32;
 pub mod i64;
 pub mod i8;
@@ -0,0 +1,354 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_gadgets::{arithmetic::*, bits::EvaluateLtGadget, UInt256};
+
+use num_bigint::BigUint;
+use snarkvm_models::{
+    curves::{One, Zero},
+    gadgets::{
+        r1cs::{ConstraintSystem, Fr, TestConstraintSystem},
+        utilities::{alloc::AllocGadget, boolean::Boolean},
+    },
+};
+
+use rand::Rng;
+use rand_core::SeedableRng;
+use rand_xorshift::XorShiftRng;
+
+fn random_u256(rng: &mut XorShiftRng) -> BigUint {
+    BigUint::from_bytes_le(&rng.gen::<[u8; 32]>())
+}
+
+fn random_u128(rng: &mut XorShiftRng) -> BigUint {
+    BigUint::from(rng.gen::<u128>())
+}
+
+fn expected_bits(expected: &BigUint) -> Vec<bool> {
+    let mut bits: Vec<bool> = expected.to_radix_le(2).into_iter().map(|bit| bit == 1).collect();
+    bits.resize(256, false);
+    bits
+}
+
+fn check_all_constant_bits(expected: &BigUint, actual: UInt256) {
+    for (b, bit) in actual.bits.iter().zip(expected_bits(expected)) {
+        match *b {
+            Boolean::Is(_) => panic!(),
+            Boolean::Not(_) => panic!(),
+            Boolean::Constant(b) => assert_eq!(b, bit),
+        }
+    }
+}
+
+fn check_all_allocated_bits(expected: &BigUint, actual: UInt256) {
+    for (b, bit) in actual.bits.iter().zip(expected_bits(expected)) {
+        assert_eq!(b.get_value().unwrap(), bit);
+    }
+}
+
+#[test]
+fn test_uint256_constant_and_alloc() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..100 {
+        let mut cs = TestConstraintSystem::<Fr>::new();
+
+        let a = random_u256(&mut rng);
+
+        let a_const = UInt256::constant(a.clone());
+
+        assert!(a_const.value == Some(a.clone()));
+
+        check_all_constant_bits(&a, a_const);
+
+        let a_bit = UInt256::alloc(cs.ns(|| "a_bit"), || Ok(a.clone())).unwrap();
+
+        assert!(cs.is_satisfied());
+        assert!(a_bit.value == Some(a.clone()));
+
+        check_all_allocated_bits(&a, a_bit);
+    }
+}
+
+#[test]
+fn test_uint256_add_constants() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..100 {
+        let mut cs = TestConstraintSystem::<Fr>::new();
+
+        let a = random_u256(&mut rng) >> 1usize;
+        let b = random_u256(&mut rng) >> 1usize;
+
+        let expected = &a + &b;
+
+        let a_bit = UInt256::constant(a);
+        let b_bit = UInt256::constant(b);
+
+        let r = a_bit.add(cs.ns(|| "addition"), &b_bit).unwrap();
+
+        assert!(r.value == Some(expected.clone()));
+
+        check_all_constant_bits(&expected, r);
+    }
+}
+
+#[test]
+fn test_uint256_add() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..10 {
+        let mut cs = TestConstraintSystem::<Fr>::new();
+
+        let a = random_u256(&mut rng) >> 1usize;
+        let b = random_u256(&mut rng) >> 1usize;
+
+        let expected = &a + &b;
+
+        let a_bit = UInt256::alloc(cs.ns(|| "a_bit"), || Ok(a)).unwrap();
+        let b_bit = UInt256::alloc(cs.ns(|| "b_bit"), || Ok(b)).unwrap();
+
+        let r = a_bit.add(cs.ns(|| "addition"), &b_bit).unwrap();
+
+        assert!(cs.is_satisfied());
+
+        assert!(r.value == Some(expected.clone()));
+
+        check_all_allocated_bits(&expected, r);
+    }
+}
+
+#[test]
+fn test_uint256_add_overflow() {
+    let mut cs = TestConstraintSystem::<Fr>::new();
+
+    let max = (BigUint::from(1u8) << 256) - 1u8;
+
+    let a_bit = UInt256::alloc(cs.ns(|| "a_bit"), || Ok(max)).unwrap();
+    let b_bit = UInt256::alloc(cs.ns(|| "b_bit"), || Ok(BigUint::from(1u8))).unwrap();
+
+    assert!(a_bit.add(cs.ns(|| "addition"), &b_bit).is_err());
+}
+
+#[test]
+fn test_uint256_sub() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..10 {
+        let mut cs = TestConstraintSystem::<Fr>::new();
+
+        let a = random_u256(&mut rng);
+        let b = random_u256(&mut rng);
+
+        let (a, b) = if a < b { (b, a) } else { (a, b) };
+
+        let expected = &a - &b;
+
+        let a_bit = UInt256::alloc(cs.ns(|| "a_bit"), || Ok(a)).unwrap();
+        let b_bit = UInt256::alloc(cs.ns(|| "b_bit"), || Ok(b)).unwrap();
+
+        let r = a_bit.sub(cs.ns(|| "subtraction"), &b_bit).unwrap();
+
+        assert!(cs.is_satisfied());
+
+        assert!(r.value == Some(expected.clone()));
+
+        check_all_allocated_bits(&expected, r);
+    }
+}
+
+#[test]
+fn test_uint256_sub_underflow() {
+    let mut cs = TestConstraintSystem::<Fr>::new();
+
+    let a_bit = UInt256::constant(BigUint::from(1u8));
+    let b_bit = UInt256::constant(BigUint::from(2u8));
+
+    assert!(a_bit.sub(cs.ns(|| "subtraction"), &b_bit).is_err());
+}
+
+#[test]
+fn test_uint256_mul_constants() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..100 {
+        let mut cs = TestConstraintSystem::<Fr>::new();
+
+        let a = random_u128(&mut rng);
+        let b = random_u128(&mut rng);
+
+        let expected = &a * &b;
+
+        let a_bit = UInt256::constant(a);
+        let b_bit = UInt256::constant(b);
+
+        let r = a_bit.mul(cs.ns(|| "multiplication"), &b_bit).unwrap();
+
+        assert!(r.value == Some(expected.clone()));
+
+        check_all_constant_bits(&expected, r);
+    }
+}
+
+#[test]
+fn test_uint256_mul() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..10 {
+        let mut cs = TestConstraintSystem::<Fr>::new();
+
+        let a = random_u128(&mut rng);
+        let b = random_u128(&mut rng);
+
+        let expected = &a * &b;
+
+        let a_bit = UInt256::alloc(cs.ns(|| "a_bit"), || Ok(a)).unwrap();
+        let b_bit = UInt256::alloc(cs.ns(|| "b_bit"), || Ok(b)).unwrap();
+
+        let r = a_bit.mul(cs.ns(|| "multiplication"), &b_bit).unwrap();
+
+        assert!(cs.is_satisfied());
+
+        assert!(r.value == Some(expected.clone()));
+
+        check_all_allocated_bits(&expected, r);
+
+        // Flip a result bit_gadget and see if the multiplication constraints still hold
+        let path = "multiplication/bits/column 0 bit 0/boolean";
+        if cs.get(path).is_zero() {
+            cs.set(path, Fr::one());
+        } else {
+            cs.set(path, Fr::zero());
+        }
+
+        assert!(!cs.is_satisfied());
+    }
+}
+
+#[test]
+fn test_uint256_div_constants() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..100 {
+        let mut cs = TestConstraintSystem::<Fr>::new();
+
+        let a = random_u256(&mut rng);
+        let b = random_u128(&mut rng);
+
+        let expected = &a / &b;
+
+        let a_bit = UInt256::constant(a);
+        let b_bit = UInt256::constant(b);
+
+        let r = a_bit.div(cs.ns(|| "division"), &b_bit).unwrap();
+
+        assert!(r.value == Some(expected.clone()));
+
+        check_all_constant_bits(&expected, r);
+    }
+}
+
+#[test]
+fn test_uint256_div() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..10 {
+        let mut cs = TestConstraintSystem::<Fr>::new();
+
+        let a = random_u256(&mut rng);
+        let b = random_u128(&mut rng);
+
+        let expected = &a / &b;
+
+        let a_bit = UInt256::alloc(cs.ns(|| "a_bit"), || Ok(a)).unwrap();
+        let b_bit = UInt256::alloc(cs.ns(|| "b_bit"), || Ok(b)).unwrap();
+
+        let r = a_bit.div(cs.ns(|| "division"), &b_bit).unwrap();
+
+        assert!(cs.is_satisfied());
+
+        assert!(r.value == Some(expected.clone()));
+
+        check_all_allocated_bits(&expected, r);
+
+        // Flip a quotient bit_gadget and see if the division constraints still hold
+        let path = "division/bits/quotient/allocated bit_gadget 0/boolean";
+        if cs.get(path).is_zero() {
+            cs.set(path, Fr::one());
+        } else {
+            cs.set(path, Fr::zero());
+        }
+
+        assert!(!cs.is_satisfied());
+    }
+}
+
+#[test]
+fn test_uint256_div_by_zero() {
+    let mut cs = TestConstraintSystem::<Fr>::new();
+
+    let a_bit = UInt256::alloc(cs.ns(|| "a_bit"), || Ok(BigUint::from(1u8))).unwrap();
+    let b_bit = UInt256::alloc(cs.ns(|| "b_bit"), || Ok(BigUint::from(0u8))).unwrap();
+
+    assert!(a_bit.div(cs.ns(|| "division"), &b_bit).is_err());
+}
+
+#[test]
+fn test_uint256_pow() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..2 {
+        let mut cs = TestConstraintSystem::<Fr>::new();
+
+        let a = BigUint::from(rng.gen::<u64>());
+
+        let expected = a.pow(3);
+
+        let a_bit = UInt256::alloc(cs.ns(|| "a_bit"), || Ok(a)).unwrap();
+        let b_bit = UInt256::constant(BigUint::from(3u8));
+
+        let r = a_bit.pow(cs.ns(|| "exponentiation"), &b_bit).unwrap();
+
+        assert!(cs.is_satisfied());
+
+        assert!(r.value == Some(expected.clone()));
+
+        check_all_allocated_bits(&expected, r);
+    }
+}
+
+#[test]
+fn test_uint256_less_than() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..10 {
+        let mut cs = TestConstraintSystem::<Fr>::new();
+
+        let a = random_u256(&mut rng);
+        let b = random_u256(&mut rng);
+
+        let expected = a < b;
+
+        let a_bit = UInt256::alloc(cs.ns(|| "a_bit"), || Ok(a)).unwrap();
+        let b_bit = UInt256::alloc(cs.ns(|| "b_bit"), || Ok(b)).unwrap();
+
+        let r = a_bit.less_than(cs.ns(|| "less than"), &b_bit).unwrap();
+
+        assert!(cs.is_satisfied());
+
+        assert_eq!(r.get_value(), Some(expected));
+    }
+}
@@ -23,8 +23,19 @@ use serde::Serialize;
 #[derive(Clone, Debug, FromPest, PartialEq, Serialize)]
 #[pest_ast(rule(Rule::annotation_name))]
 pub enum AnnotationName<'ast> {
+    Allow(Allow<'ast>),
     Context(Context<'ast>),
     Test(Test<'ast>),
+    Inline(Inline<'ast>),
+    NoInline(NoInline<'ast>),
+}
+
+#[derive(Clone, Debug, FromPest, PartialEq, Serialize)]
+#[pest_ast(rule(Rule::allow))]
+pub struct Allow<'ast> {
+    #[pest_ast(outer())]
+    #[serde(with = "SpanDef")]
+    pub span: Span<'ast>,
 }
 
 #[derive(Clone, Debug, FromPest, PartialEq, Serialize)]
@@ -42,3 +53,19 @@ pub struct Test<'ast> {
     #[serde(with = "SpanDef")]
     pub span: Span<'ast>,
 }
+
+#[derive(Clone, Debug, FromPest, PartialEq, Serialize)]
+#[pest_ast(rule(Rule::inline))]
+pub struct Inline<'ast> {
+    #[pest_ast(outer())]
+    #[serde(with = "SpanDef")]
+    pub span: Span<'ast>,
+}
+
+#[derive(Clone, Debug, FromPest, PartialEq, Serialize)]
+#[pest_ast(rule(Rule::noinline))]
+pub struct NoInline<'ast> {
+    #[pest_ast(outer())]
+    #[serde(with = "SpanDef")]
+    pub span: Span<'ast>,
+}
@@ -33,6 +33,7 @@ use crate::{
 
 use crate::expressions::TupleExpression;
 use from_pest::{ConversionError, FromPest, Void};
+use num_bigint::BigUint;
 use pest::{
     error::Error,
     iterators::{Pair, Pairs},
@@ -53,6 +54,26 @@ pub(crate) fn span_into_string(span: Span) -> String {
     span.as_str().to_string()
 }
 
+/// Returns the decimal representation of a number, which may have a `0x`, `0o` or `0b` prefix and
+/// `_` separators.
+pub(crate) fn number_into_string(span: Span) -> String {
+    let number = span.as_str().replace('_', "");
+    let (sign, digits) = match number.strip_prefix('-') {
+        Some(digits) => ("-", digits),
+        None => ("", number.as_str()),
+    };
+    let radix = match digits.get(..2) {
+        Some("0x") => 16,
+        Some("0o") => 8,
+        Some("0b") => 2,
+        _ => return number,
+    };
+    let value =
+        BigUint::parse_bytes(&digits.as_bytes()[2..], radix).expect("the grammar only admits digits of the radix");
+
+    format!("{}{}", sign, value)
+}
+
 lazy_static! {
     static ref PRECEDENCE_CLIMBER: PrecClimber<Rule> = precedence_climber();
 }
@@ -24,6 +24,7 @@ use serde::Serialize;
 #[pest_ast(rule(Rule::circuit))]
 pub struct Circuit<'ast> {
     pub identifier: Identifier<'ast>,
+    pub implements: Vec<Identifier<'ast>>,
     pub members: Vec<CircuitMember<'ast>>,
     #[pest_ast(outer())]
     #[serde(with = "SpanDef")]
@@ -14,7 +14,11 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{ast::Rule, circuits::CircuitVariableDefinition, functions::Function};
+use crate::{
+    ast::Rule,
+    circuits::CircuitVariableDefinition,
+    functions::{ExternFunction, Function},
+};
 
 use pest_ast::FromPest;
 use serde::Serialize;
@@ -24,4 +28,5 @@ use serde::Serialize;
 pub enum CircuitMember<'ast> {
     CircuitVariableDefinition(CircuitVariableDefinition<'ast>),
     CircuitFunction(Function<'ast>),
+    CircuitExternFunction(ExternFunction<'ast>),
 }
@@ -18,8 +18,9 @@ use crate::{
     ast::Rule,
     circuits::Circuit,
     definitions::{AnnotatedDefinition, Deprecated},
-    functions::Function,
+    functions::{ExternFunction, Function},
     imports::Import,
+    interfaces::Interface,
 };
 
 use pest_ast::FromPest;
@@ -31,6 +32,8 @@ pub enum Definition<'ast> {
     Annotated(AnnotatedDefinition<'ast>),
     Import(Import<'ast>),
     Circuit(Circuit<'ast>),
+    Interface(Interface<'ast>),
     Function(Function<'ast>),
+    ExternFunction(ExternFunction<'ast>),
     Deprecated(Deprecated<'ast>),
 }
@@ -36,23 +36,30 @@ pub enum ParserError {
     #[error("{}", _0)]
     SyntaxError(#[from] SyntaxError),
 
+    #[error("aborting due to {} syntax errors", _0.len())]
+    SyntaxErrors(Vec<SyntaxError>),
+
     #[error("Unable to construct program abstract syntax tree")]
     SyntaxTreeError,
 }
 
 impl ParserError {
     pub fn set_path(&mut self, path: &Path) {
-        if let ParserError::SyntaxError(error) = self {
-            let new_error: Error<Rule> = match error {
-                SyntaxError::Error(error) => {
-                    let new_error = error.clone();
-                    new_error.with_path(path.to_str().unwrap())
-                }
-            };
-
-            tracing::error!("{}", new_error);
+        match self {
+            ParserError::SyntaxError(error) => error.set_path(path),
+            ParserError::SyntaxErrors(errors) => errors.iter_mut().for_each(|error| error.set_path(path)),
+            _ => {}
+        }
+    }
 
-            *error = SyntaxError::Error(new_error);
+    ///
+    /// Returns a single syntax error as is, and several syntax errors together.
+    ///
+    pub fn from_errors(mut errors: Vec<SyntaxError>) -> Self {
+        if errors.len() == 1 {
+            ParserError::SyntaxError(errors.remove(0))
+        } else {
+            ParserError::SyntaxErrors(errors)
         }
     }
 }
@@ -17,11 +17,37 @@
 use crate::ast::Rule;
 
 use pest::error::Error;
+use std::path::{Path, PathBuf};
 
+/// A syntax error and the path of the file it occurred in, if known.
 #[derive(Debug, Error)]
 pub enum SyntaxError {
     #[error("aborting due to syntax error")]
-    Error(Error<Rule>),
+    Error(Error<Rule>, Option<PathBuf>),
+}
+
+impl SyntaxError {
+    pub fn set_path(&mut self, path: &Path) {
+        let SyntaxError::Error(error, error_path) = self;
+        let new_error = error.clone().with_path(path.to_str().unwrap());
+
+        tracing::error!("{}", new_error);
+
+        *error = new_error;
+        *error_path = Some(path.to_owned());
+    }
+
+    /// Returns the error with the path of the file it occurred in.
+    pub fn with_path(self, path: &Path) -> Self {
+        let SyntaxError::Error(error, _) = self;
+        SyntaxError::Error(error.with_path(path.to_str().unwrap()), Some(path.to_owned()))
+    }
+
+    /// Returns the path of the file the error occurred in, if it is set.
+    pub fn path(&self) -> Option<&Path> {
+        let SyntaxError::Error(_, path) = self;
+        path.as_deref()
+    }
 }
 
 impl From<Error<Rule>> for SyntaxError {
@@ -59,6 +85,6 @@ impl From<Error<Rule>> for SyntaxError {
             rule => format!("{:?}", rule),
         });
 
-        SyntaxError::Error(error)
+        SyntaxError::Error(error, None)
     }
 }
@@ -14,6 +14,9 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
+pub mod extern_function;
+pub use extern_function::*;
+
 pub mod function;
 pub use function::*;
 
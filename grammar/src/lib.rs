@@ -39,6 +39,7 @@ pub mod expressions;
 pub mod files;
 pub mod functions;
 pub mod imports;
+pub mod interfaces;
 pub mod operations;
 pub mod statements;
 pub mod types;
@@ -47,11 +48,18 @@ pub mod values;
 pub mod errors;
 pub use errors::*;
 
-pub(crate) mod span;
-pub(crate) use span::*;
+pub(crate) mod recovery;
+
+pub mod span;
+pub(crate) use span::SpanDef;
+pub use span::{FileId, SourceSpan};
 
 use from_pest::FromPest;
-use std::{fs, path::Path};
+use std::{
+    fs,
+    io,
+    path::{Path, PathBuf},
+};
 
 ///
 /// The grammar of a Leo program, encoded as an abstract syntax tree (AST).
@@ -64,6 +72,7 @@ use std::{fs, path::Path};
 ///
 pub struct Grammar<'ast> {
     ast: files::File<'ast>,
+    file_id: FileId,
 }
 
 impl<'ast> Grammar<'ast> {
@@ -74,15 +83,24 @@ impl<'ast> Grammar<'ast> {
         // TODO (howardwu): Turn this check back on after fixing the testing module.
         // assert_eq!(program_string, fs::read_to_string(file_path).map_err(|_| ParserError::FileReadError(file_path.clone()))?);
 
-        // Parse the file using leo.pest
-        let file = &mut ast::parse(&program_string)
-            .map_err(|error| ParserError::from(error.with_path(file_path.to_str().unwrap())))?;
+        // Parse the file using leo.pest, recovering from syntax errors to report all of them
+        let file = &mut ast::parse(program_string).map_err(|error| {
+            let errors = recovery::recover_errors(program_string, error)
+                .into_iter()
+                .map(|error| SyntaxError::from(error).with_path(file_path))
+                .collect();
+
+            ParserError::from_errors(errors)
+        })?;
 
         // Builds the abstract syntax tree using pest derivation.
         let ast = files::File::<'ast>::from_pest(file).map_err(|_| ParserError::SyntaxTreeError)?;
         tracing::debug!("{:#?}", ast);
 
-        Ok(Self { ast })
+        Ok(Self {
+            ast,
+            file_id: FileId::new(file_path),
+        })
     }
 
     // TODO (howardwu): Remove this in favor of a dedicated file loader to verify checksums
@@ -94,6 +112,36 @@ impl<'ast> Grammar<'ast> {
         fs::read_to_string(file_path).map_err(|_| ParserError::FileReadError(file_path.to_owned()))
     }
 
+    ///
+    /// Returns the paths of the files with the given extension in `directory` and its subdirectories, sorted.
+    ///
+    /// A directory that does not exist contains no files.
+    ///
+    pub fn find_files(directory: &Path, extension: &str) -> io::Result<Vec<PathBuf>> {
+        let mut file_paths = vec![];
+        if !directory.is_dir() {
+            return Ok(file_paths);
+        }
+
+        let mut entries = fs::read_dir(directory)?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<io::Result<Vec<_>>>()?;
+        entries.sort();
+
+        for path in entries {
+            if path.is_dir() {
+                file_paths.extend(Self::find_files(&path, extension)?);
+            } else if path
+                .extension()
+                .is_some_and(|file_extension| file_extension == extension.trim_start_matches('.'))
+            {
+                file_paths.push(path);
+            }
+        }
+
+        Ok(file_paths)
+    }
+
     ///
     /// Returns a reference to the inner abstract syntax tree representation.
     ///
@@ -101,6 +149,13 @@ impl<'ast> Grammar<'ast> {
         &self.ast
     }
 
+    ///
+    /// Returns the id of the file the grammar was parsed from.
+    ///
+    pub fn file_id(&self) -> &FileId {
+        &self.file_id
+    }
+
     ///
     /// Serializes the abstract syntax tree into a JSON string.
     ///
@@ -0,0 +1,105 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Recovers from syntax errors to report the errors of every top-level definition.
+
+use crate::ast::{parse, Rule};
+
+use pest::error::{Error, InputLocation};
+
+/// The maximum number of syntax errors reported for a single file.
+const MAX_ERRORS: usize = 32;
+
+/// The keywords a top-level definition starts with, when written at the start of a line.
+const DEFINITION_KEYWORDS: &[&str] = &[
+    "@",
+    "circuit ",
+    "extern ",
+    "function ",
+    "import ",
+    "interface ",
+    "test ",
+];
+
+/// Returns the byte offset at which `error` occurred.
+fn error_position(error: &Error<Rule>) -> usize {
+    match error.location {
+        InputLocation::Pos(position) => position,
+        InputLocation::Span((start, _)) => start,
+    }
+}
+
+/// Returns the byte offsets of the lines starting a top-level definition in `program_string`.
+fn definition_starts(program_string: &str) -> Vec<usize> {
+    let mut offset = 0;
+    let mut starts = vec![];
+
+    for line in program_string.split_inclusive('\n') {
+        if DEFINITION_KEYWORDS.iter().any(|keyword| line.starts_with(keyword)) {
+            starts.push(offset);
+        }
+        offset += line.len();
+    }
+
+    starts
+}
+
+///
+/// Returns `error` followed by the syntax errors in the definitions after the one it occurred in.
+///
+/// The definition holding an error is blanked out, keeping its line breaks so later errors keep
+/// their positions, and the program is parsed again until no error is left or no definition
+/// follows the erroneous one.
+///
+pub(crate) fn recover_errors(program_string: &str, error: Error<Rule>) -> Vec<Error<Rule>> {
+    let starts = definition_starts(program_string);
+    let mut program_string = program_string.to_string();
+    let mut errors = vec![error];
+
+    while errors.len() < MAX_ERRORS {
+        let position = error_position(errors.last().unwrap());
+
+        // Skip from the definition holding the error to the next definition.
+        let start = starts
+            .iter()
+            .rev()
+            .find(|start| **start <= position)
+            .copied()
+            .unwrap_or(0);
+        let end = match starts.iter().find(|start| **start > position) {
+            Some(end) => *end,
+            None => break,
+        };
+
+        let blank = program_string[start..end]
+            .chars()
+            .map(|character| match character {
+                '\n' | '\r' => character.to_string(),
+                _ => " ".repeat(character.len_utf8()),
+            })
+            .collect::<String>();
+        program_string.replace_range(start..end, &blank);
+
+        match parse(&program_string) {
+            Ok(_) => break,
+            // Errors before the skipped definition are caused by the recovery itself.
+            Err(error) if error_position(&error) < end => break,
+            Err(error) => errors.push(error),
+        }
+    }
+
+    errors
+}
@@ -15,7 +15,138 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use pest::Span;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::{
+    cell::RefCell,
+    mem,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+thread_local! {
+    static CURRENT_FILE: RefCell<FileId> = RefCell::new(FileId::default());
+}
+
+///
+/// Identifies the file a span points into by its path.
+///
+/// The default file id stands for an unknown file, such as an internal or input string.
+/// Cloning a file id shares its path instead of copying it.
+///
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct FileId(Option<Arc<PathBuf>>);
+
+impl FileId {
+    ///
+    /// Returns the id of the file at the given path.
+    ///
+    pub fn new(path: &Path) -> Self {
+        FileId(Some(Arc::new(path.to_owned())))
+    }
+
+    /// Returns the path of the file, if it is known.
+    pub fn path(&self) -> Option<&Path> {
+        self.0.as_deref().map(PathBuf::as_path)
+    }
+
+    /// Returns `true` if the file is not known.
+    pub fn is_unknown(&self) -> bool {
+        self.0.is_none()
+    }
+
+    ///
+    /// Returns the file whose spans are currently being converted.
+    ///
+    pub fn current() -> Self {
+        CURRENT_FILE.with(|file| file.borrow().clone())
+    }
+
+    ///
+    /// Runs `f` with spans converted in it pointing into this file.
+    ///
+    /// The previous file is restored when `f` returns or panics.
+    ///
+    pub fn enter<T, F: FnOnce() -> T>(&self, f: F) -> T {
+        let _previous = CurrentFileGuard(CURRENT_FILE.with(|file| file.replace(self.clone())));
+
+        f()
+    }
+}
+
+/// Restores the file that was current before [`FileId::enter`] when dropped.
+struct CurrentFileGuard(FileId);
+
+impl Drop for CurrentFileGuard {
+    fn drop(&mut self) {
+        let previous = mem::take(&mut self.0);
+        CURRENT_FILE.with(|file| file.replace(previous));
+    }
+}
+
+impl Serialize for FileId {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.path().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for FileId {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(FileId(Option::<PathBuf>::deserialize(deserializer)?.map(Arc::new)))
+    }
+}
+
+///
+/// The location of a pest span in its file.
+///
+/// Lines and columns are one-based, byte offsets are zero-based, and the stop column and byte offset
+/// are exclusive. A span ending right after a line break stops at the end of the line it breaks.
+///
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SourceSpan<'ast> {
+    pub file: FileId,
+    pub byte_start: usize,
+    pub byte_stop: usize,
+    pub line_start: usize,
+    pub col_start: usize,
+    pub line_stop: usize,
+    pub col_stop: usize,
+    /// The lines the span covers, without line breaks
+    pub lines: Vec<&'ast str>,
+}
+
+impl<'ast> From<&Span<'ast>> for SourceSpan<'ast> {
+    fn from(span: &Span<'ast>) -> Self {
+        let (line_start, col_start) = span.start_pos().line_col();
+        let text = span.as_str();
+        let trimmed = text.trim_end_matches(&['\n', '\r'][..]);
+        let parts = trimmed.split('\n').map(str::trim_end).collect::<Vec<_>>();
+        let line_stop = line_start + parts.len() - 1;
+
+        let mut lines = vec![span.start_pos().line_of().trim_end()];
+        let col_stop = if parts.len() == 1 {
+            col_start + trimmed.chars().count()
+        } else {
+            lines.extend(&parts[1..parts.len() - 1]);
+            lines.push(match trimmed.len() < text.len() {
+                true => parts[parts.len() - 1],
+                false => span.end_pos().line_of().trim_end(),
+            });
+
+            parts[parts.len() - 1].chars().count() + 1
+        };
+
+        Self {
+            file: FileId::current(),
+            byte_start: span.start(),
+            byte_stop: span.start() + trimmed.len(),
+            line_start,
+            col_start,
+            line_stop,
+            col_stop,
+            lines,
+        }
+    }
+}
 
 // Provide getters for every private field of the remote struct. The getter must
 // return either `T` or `&T` where `T` is the type of the field.
@@ -67,3 +198,36 @@ fn test_span_def() {
     let expected_output = "{\"span\":{\"input\":\"ello\",\"start\":1,\"end\":5}}";
     assert_eq!(expected_output, output);
 }
+
+#[test]
+fn test_source_span() {
+    let input = "function main() {\n    return 1 + 1\n}\n";
+    let path = Path::new("source_span.leo");
+
+    let span = FileId::new(path).enter(|| SourceSpan::from(&Span::new(input, 0, input.len()).unwrap()));
+
+    assert_eq!(span.file.path(), Some(path));
+    assert_eq!((span.byte_start, span.byte_stop), (0, input.len() - 1));
+    assert_eq!((span.line_start, span.col_start), (1, 1));
+    assert_eq!((span.line_stop, span.col_stop), (3, 2));
+    assert_eq!(span.lines, vec!["function main() {", "    return 1 + 1", "}"]);
+    assert_eq!(FileId::current(), FileId::default());
+}
+
+#[test]
+fn test_enter_restores_on_panic() {
+    let result = std::panic::catch_unwind(|| FileId::new(Path::new("panic.leo")).enter(|| panic!("conversion failed")));
+
+    assert!(result.is_err());
+    assert_eq!(FileId::current(), FileId::default());
+}
+
+#[test]
+fn test_file_id_serde() {
+    let file = FileId::new(Path::new("src/main.leo"));
+    let json = serde_json::to_string(&file).unwrap();
+
+    assert_eq!(json, "\"src/main.leo\"");
+    assert_eq!(serde_json::from_str::<FileId>(&json).unwrap(), file);
+    assert_eq!(serde_json::from_str::<FileId>("null").unwrap(), FileId::default());
+}
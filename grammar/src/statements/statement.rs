@@ -28,6 +28,7 @@ pub enum Statement<'ast> {
     Assign(AssignStatement<'ast>),
     Conditional(ConditionalStatement<'ast>),
     Iteration(ForStatement<'ast>),
+    ArrayIteration(ForEachStatement<'ast>),
     Console(ConsoleFunctionCall<'ast>),
     Expression(ExpressionStatement<'ast>),
     Block(Block<'ast>),
@@ -41,6 +42,7 @@ impl<'ast> fmt::Display for Statement<'ast> {
             Statement::Assign(ref statement) => write!(f, "{}", statement),
             Statement::Conditional(ref statement) => write!(f, "{}", statement),
             Statement::Iteration(ref statement) => write!(f, "{}", statement),
+            Statement::ArrayIteration(ref statement) => write!(f, "{}", statement),
             Statement::Console(ref statement) => write!(f, "{}", statement),
             Statement::Expression(ref statement) => write!(f, "{}", statement.expression),
             Statement::Block(ref block) => write!(f, "{}", block),
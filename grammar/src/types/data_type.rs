@@ -16,7 +16,7 @@
 
 use crate::{
     ast::Rule,
-    types::{AddressType, BooleanType, FieldType, GroupType, IntegerType},
+    types::{AddressType, BooleanType, FieldType, FixedType, GroupType, IntegerType},
 };
 
 use pest_ast::FromPest;
@@ -28,6 +28,7 @@ pub enum DataType {
     Address(AddressType),
     Boolean(BooleanType),
     Field(FieldType),
+    Fixed(FixedType),
     Group(GroupType),
     Integer(IntegerType),
 }
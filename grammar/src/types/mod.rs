@@ -35,6 +35,9 @@ pub use data_type::*;
 pub mod field_type;
 pub use field_type::*;
 
+pub mod fixed_type;
+pub use fixed_type::*;
+
 pub mod group_type;
 pub use group_type::*;
 
@@ -28,6 +28,7 @@ pub enum UnsignedIntegerType {
     U32Type(U32Type),
     U64Type(U64Type),
     U128Type(U128Type),
+    U256Type(U256Type),
 }
 
 #[derive(Clone, Debug, FromPest, PartialEq, Serialize)]
@@ -50,6 +51,10 @@ pub struct U64Type {}
 #[pest_ast(rule(Rule::type_u128))]
 pub struct U128Type {}
 
+#[derive(Clone, Debug, FromPest, PartialEq, Serialize)]
+#[pest_ast(rule(Rule::type_u256))]
+pub struct U256Type {}
+
 impl fmt::Display for UnsignedIntegerType {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
@@ -58,6 +63,7 @@ impl fmt::Display for UnsignedIntegerType {
             UnsignedIntegerType::U32Type(_) => write!(f, "u32"),
             UnsignedIntegerType::U64Type(_) => write!(f, "u64"),
             UnsignedIntegerType::U128Type(_) => write!(f, "u128"),
+            UnsignedIntegerType::U256Type(_) => write!(f, "u256"),
         }
     }
 }
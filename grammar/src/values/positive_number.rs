@@ -15,7 +15,7 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-    ast::{span_into_string, Rule},
+    ast::{number_into_string, Rule},
     span::SpanDef,
 };
 
@@ -27,7 +27,7 @@ use std::fmt;
 #[derive(Clone, Debug, FromPest, PartialEq, Serialize)]
 #[pest_ast(rule(Rule::number_positive))]
 pub struct PositiveNumber<'ast> {
-    #[pest_ast(outer(with(span_into_string)))]
+    #[pest_ast(outer(with(number_into_string)))]
     pub value: String,
     #[pest_ast(outer())]
     #[serde(with = "SpanDef")]
@@ -41,3 +41,22 @@ fn test_annotation_context_test() {
         ]
     }
 }
+
+#[test]
+fn test_annotation_allow() {
+    parses_to! {
+        parser: LanguageParser,
+        input:  "@allow(unused_variables, shadowed_names)",
+        rule:   Rule::annotation,
+        tokens: [
+            annotation(0, 40, [annotation_symbol(0, 1, []), annotation_name(1, 6, [allow(1, 6, [])]), annotation_arguments(6, 40, [annotation_argument(7, 23, []), annotation_argument(25, 39, [])])])
+        ]
+    }
+}
+
+#[test]
+fn test_annotated_extern_function() {
+    let result = LanguageParser::parse(Rule::definition_annotated, "@test extern function f();");
+
+    assert!(result.is_err());
+}
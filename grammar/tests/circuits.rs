@@ -114,3 +114,39 @@ fn circuit_instantiation() {
         ]
     }
 }
+
+#[test]
+fn circuit_implements() {
+    parses_to! {
+        parser: LanguageParser,
+        input:  "circuit Foo implements Bar, Baz {}",
+        rule:   Rule::circuit,
+        tokens: [
+            circuit(0, 34, [
+                identifier(8, 11, []),
+                identifier(23, 26, []),
+                identifier(28, 31, []),
+            ])
+        ]
+    }
+}
+
+#[test]
+fn interface_definition() {
+    parses_to! {
+        parser: LanguageParser,
+        input:  "interface Hasher { function hash(self) -> u32; }",
+        rule:   Rule::interface,
+        tokens: [
+            interface(0, 48, [
+                identifier(10, 16, []),
+                interface_function(19, 46, [
+                    identifier(28, 32, []),
+                    input(33, 37, [self_keyword(33, 37, [])]),
+                    type_(42, 45, [type_data(42, 45, [type_integer(42, 45, [type_integer_unsigned(42, 45, [type_u32(42, 45, [])])])])]),
+                    LINE_END(45, 46, []),
+                ])
+            ])
+        ]
+    }
+}
@@ -53,3 +53,20 @@ fn multiple_redundant_parens() {
         ]
     }
 }
+
+#[test]
+fn underscore_identifier() {
+    parses_to! {
+        parser: LanguageParser,
+        input:  "_a",
+        rule:   Rule::identifier,
+        tokens: [
+            identifier(0, 2, [])
+        ]
+    }
+}
+
+#[test]
+fn lone_underscore_is_not_identifier() {
+    assert!(LanguageParser::parse(Rule::identifier, "_").is_err());
+}
@@ -80,6 +80,22 @@ fn empty_def() {
     }
 }
 
+#[test]
+fn extern_def() {
+    parses_to! {
+        parser: LanguageParser,
+        input:  "extern function x() -> u8;",
+        rule:   Rule::extern_function,
+        tokens: [
+            extern_function(0, 26, [
+                identifier(16, 17, []),
+                type_(23, 25, [type_data(23, 25, [type_integer(23, 25, [type_integer_unsigned(23, 25, [type_u8(23, 25, [])])])])]),
+                LINE_END(25, 26, [])
+            ])
+        ]
+    }
+}
+
 #[test]
 fn returning_unit_type() {
     parses_to! {
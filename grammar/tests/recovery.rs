@@ -0,0 +1,79 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_grammar::{Grammar, ParserError, SyntaxError};
+
+use pest::error::LineColLocation;
+use std::path::Path;
+
+const TEST_PATH: &str = "test.leo";
+
+fn error_lines(errors: &[SyntaxError]) -> Vec<usize> {
+    errors
+        .iter()
+        .map(|SyntaxError::Error(error, _)| match error.line_col {
+            LineColLocation::Pos((line, _)) | LineColLocation::Span((line, _), _) => line,
+        })
+        .collect()
+}
+
+#[test]
+fn test_recover_after_definition() {
+    let program_string = r#"
+function one() -> u32 {
+    return 1u32 +
+}
+
+function two() -> u32 {
+    return 2u32
+}
+
+circuit Foo {
+    x: u32,,
+}
+
+function three() -> u32 {
+    let = 3u32;
+    return 3u32
+}
+"#;
+
+    match Grammar::new(Path::new(TEST_PATH), program_string) {
+        Err(ParserError::SyntaxErrors(errors)) => assert_eq!(error_lines(&errors), vec![4, 11, 15]),
+        _ => panic!("expected several syntax errors"),
+    }
+}
+
+#[test]
+fn test_recover_single_error() {
+    let program_string = r#"
+function one() -> u32 {
+    return 1u32
+}
+
+function two() -> u32 {
+    return 2u32 +
+}
+"#;
+
+    match Grammar::new(Path::new(TEST_PATH), program_string) {
+        Err(ParserError::SyntaxError(error)) => {
+            assert_eq!(error.path(), Some(Path::new(TEST_PATH)));
+            assert_eq!(error_lines(&[error]), vec![8]);
+        }
+        _ => panic!("expected a single syntax error"),
+    }
+}
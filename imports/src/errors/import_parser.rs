@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 use leo_asg::AsgConvertError;
-use leo_ast::{AstError, DeprecatedError, Error as FormattedError, Identifier, Span};
+use leo_ast::{AstError, DeprecatedError, Diagnostic, Error as FormattedError, Identifier, Report, Span};
 use leo_grammar::ParserError;
 
 use std::{io, path::Path};
@@ -44,13 +44,33 @@ impl Into<AsgConvertError> for ImportParserError {
     }
 }
 
+impl Diagnostic for ImportParserError {
+    fn error_code(&self) -> &'static str {
+        match self {
+            ImportParserError::DeprecatedError(error) => error.error_code(),
+            ImportParserError::Error(error) => error.code.unwrap_or("E0400"),
+            ImportParserError::ParserError(error) => error.error_code(),
+            ImportParserError::AsgConvertError(error) => error.error_code(),
+        }
+    }
+
+    fn reports(&self) -> Vec<Report> {
+        match self {
+            ImportParserError::DeprecatedError(error) => error.reports(),
+            ImportParserError::Error(error) => vec![Report::from_error(self.error_code(), error)],
+            ImportParserError::ParserError(error) => error.reports(),
+            ImportParserError::AsgConvertError(error) => error.reports(),
+        }
+    }
+}
+
 impl ImportParserError {
-    fn new_from_span(message: String, span: Span) -> Self {
-        ImportParserError::Error(FormattedError::new_from_span(message, span))
+    fn new_from_span(code: &'static str, message: String, span: Span) -> Self {
+        ImportParserError::Error(FormattedError::new_from_span(message, span).with_code(code))
     }
 
-    fn new_from_span_with_path(message: String, span: Span, path: &Path) -> Self {
-        ImportParserError::Error(FormattedError::new_from_span_with_path(message, span, path))
+    fn new_from_span_with_path(code: &'static str, message: String, span: Span, path: &Path) -> Self {
+        ImportParserError::Error(FormattedError::new_from_span_with_path(message, span, path).with_code(code))
     }
 
     ///
@@ -59,13 +79,13 @@ impl ImportParserError {
     pub fn conflicting_imports(identifier: Identifier) -> Self {
         let message = format!("conflicting imports found for `{}`.", identifier.name);
 
-        Self::new_from_span(message, identifier.span)
+        Self::new_from_span("E0401", message, identifier.span)
     }
 
     pub fn recursive_imports(package: &str, span: &Span) -> Self {
         let message = format!("recursive imports for `{}`.", package);
 
-        Self::new_from_span(message, span.clone())
+        Self::new_from_span("E0402", message, span.clone())
     }
 
     ///
@@ -74,7 +94,7 @@ impl ImportParserError {
     pub fn duplicate_core_package(identifier: Identifier) -> Self {
         let message = format!("Duplicate core_package import `{}`.", identifier.name);
 
-        Self::new_from_span(message, identifier.span)
+        Self::new_from_span("E0403", message, identifier.span)
     }
 
     ///
@@ -83,22 +103,17 @@ impl ImportParserError {
     pub fn convert_os_string(span: Span) -> Self {
         let message = "Failed to convert file string name, maybe an illegal character?".to_string();
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0404", message, span)
     }
 
     ///
     /// Failed to find the directory of the current file.
     ///
     pub fn current_directory_error(error: io::Error) -> Self {
-        let span = Span {
-            text: "".to_string(),
-            line: 0,
-            start: 0,
-            end: 0,
-        };
+        let span = Span::default();
         let message = format!("Compilation failed trying to find current directory - {:?}.", error);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0405", message, span)
     }
 
     ///
@@ -107,7 +122,7 @@ impl ImportParserError {
     pub fn directory_error(error: io::Error, span: Span, path: &Path) -> Self {
         let message = format!("Compilation failed due to directory error - {:?}.", error);
 
-        Self::new_from_span_with_path(message, span, path)
+        Self::new_from_span_with_path("E0406", message, span, path)
     }
 
     ///
@@ -116,7 +131,7 @@ impl ImportParserError {
     pub fn star(path: &Path, span: Span) -> Self {
         let message = format!("Cannot import `*` from path `{:?}`.", path);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0407", message, span)
     }
 
     ///
@@ -128,7 +143,7 @@ impl ImportParserError {
             entry, span.text
         );
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0408", message, span)
     }
 
     ///
@@ -140,6 +155,6 @@ impl ImportParserError {
             identifier.name
         );
 
-        Self::new_from_span(message, identifier.span)
+        Self::new_from_span("E0409", message, identifier.span)
     }
 }
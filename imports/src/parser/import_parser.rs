@@ -18,7 +18,7 @@ use crate::errors::ImportParserError;
 use leo_asg::{AsgContext, AsgConvertError, ImportResolver, Program, Span};
 
 use indexmap::{IndexMap, IndexSet};
-use std::env::current_dir;
+use std::{env::current_dir, path::PathBuf};
 
 /// Stores imported packages.
 ///
@@ -26,10 +26,24 @@ use std::env::current_dir;
 /// directory, foreign in the imports directory, or part of the core package list.
 #[derive(Clone, Default)]
 pub struct ImportParser<'a> {
+    package_path: Option<PathBuf>,
     partial_imports: IndexSet<String>,
     imports: IndexMap<String, Program<'a>>,
 }
 
+impl<'a> ImportParser<'a> {
+    ///
+    /// Returns an import parser looking for packages in the package at `package_path`,
+    /// rather than in the current directory.
+    ///
+    pub fn new(package_path: PathBuf) -> Self {
+        Self {
+            package_path: Some(package_path),
+            ..Default::default()
+        }
+    }
+}
+
 //todo: handle relative imports relative to file...
 impl<'a> ImportResolver<'a> for ImportParser<'a> {
     fn resolve_package(
@@ -45,9 +59,15 @@ impl<'a> ImportResolver<'a> for ImportParser<'a> {
         if let Some(program) = self.imports.get(&full_path) {
             return Ok(Some(program.clone()));
         }
-        let mut imports = Self::default();
-        let path =
-            current_dir().map_err(|x| -> AsgConvertError { ImportParserError::current_directory_error(x).into() })?;
+        let mut imports = Self {
+            package_path: self.package_path.clone(),
+            ..Default::default()
+        };
+        let path = match &self.package_path {
+            Some(path) => path.clone(),
+            None => current_dir()
+                .map_err(|x| -> AsgConvertError { ImportParserError::current_directory_error(x).into() })?,
+        };
 
         self.partial_imports.insert(full_path.clone());
         let program = imports
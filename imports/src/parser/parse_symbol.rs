@@ -55,6 +55,6 @@ impl<'a> ImportParser<'a> {
         let ast = &Grammar::new(&file_path, &program_string)?;
 
         // Build the package Leo syntax tree from the package abstract syntax tree.
-        Ok(Program::from(&file_name, ast.as_repr())?)
+        Ok(ast.file_id().enter(|| Program::from(&file_name, ast.as_repr()))?)
     }
 }
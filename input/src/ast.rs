@@ -15,6 +15,7 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 //! Abstract syntax tree (ast) representation from leo-input.pest.
+use num_bigint::BigUint;
 use pest::{error::Error, iterators::Pairs, Parser, Span};
 
 #[derive(Parser)]
@@ -28,3 +29,23 @@ pub fn parse(input: &str) -> Result<Pairs<Rule>, Error<Rule>> {
 pub fn span_into_string(span: Span) -> String {
     span.as_str().to_string()
 }
+
+/// Returns the decimal representation of a number, which may have a `0x`, `0o` or `0b` prefix and
+/// `_` separators.
+pub fn number_into_string(span: Span) -> String {
+    let number = span.as_str().replace('_', "");
+    let (sign, digits) = match number.strip_prefix('-') {
+        Some(digits) => ("-", digits),
+        None => ("", number.as_str()),
+    };
+    let radix = match digits.get(..2) {
+        Some("0x") => 16,
+        Some("0o") => 8,
+        Some("0b") => 2,
+        _ => return number,
+    };
+    let value =
+        BigUint::parse_bytes(&digits.as_bytes()[2..], radix).expect("the grammar only admits digits of the radix");
+
+    format!("{}{}", sign, value)
+}
@@ -59,7 +59,7 @@ impl InputParserError {
     pub fn set_path(&mut self, path: &Path) {
         if let InputParserError::SyntaxError(error) = self {
             let new_error: Error<Rule> = match error {
-                InputSyntaxError::Error(error) => {
+                InputSyntaxError::Error(error, _) => {
                     let new_error = error.clone();
                     new_error.with_path(path.to_str().unwrap())
                 }
@@ -67,7 +67,7 @@ impl InputParserError {
 
             tracing::error!("{}", new_error);
 
-            *error = InputSyntaxError::Error(new_error);
+            *error = InputSyntaxError::Error(new_error, Some(path.to_owned()));
         }
     }
 
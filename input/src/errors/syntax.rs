@@ -17,11 +17,21 @@
 use crate::ast::Rule;
 
 use pest::error::Error;
+use std::path::{Path, PathBuf};
 
+/// A syntax error and the path of the file it occurred in, if known.
 #[derive(Debug, Error)]
 pub enum SyntaxError {
     #[error("aborting due to syntax error")]
-    Error(Error<Rule>),
+    Error(Error<Rule>, Option<PathBuf>),
+}
+
+impl SyntaxError {
+    /// Returns the path of the file the error occurred in, if it is set.
+    pub fn path(&self) -> Option<&Path> {
+        let SyntaxError::Error(_, path) = self;
+        path.as_deref()
+    }
 }
 
 impl From<Error<Rule>> for SyntaxError {
@@ -39,6 +49,6 @@ impl From<Error<Rule>> for SyntaxError {
             rule => format!("{:?}", rule),
         });
 
-        SyntaxError::Error(error)
+        SyntaxError::Error(error, None)
     }
 }
@@ -20,6 +20,12 @@ pub use array_initializer_expression::*;
 pub mod array_inline_expression;
 pub use array_inline_expression::*;
 
+pub mod circuit_inline_expression;
+pub use circuit_inline_expression::*;
+
+pub mod circuit_variable;
+pub use circuit_variable::*;
+
 pub mod expression;
 pub use expression::*;
 
@@ -16,7 +16,7 @@
 
 use crate::{
     ast::Rule,
-    types::{BooleanType, FieldType, GroupType, IntegerType},
+    types::{BooleanType, FieldType, FixedType, GroupType, IntegerType},
 };
 
 use crate::types::AddressType;
@@ -28,6 +28,7 @@ pub enum DataType {
     Address(AddressType),
     Boolean(BooleanType),
     Field(FieldType),
+    Fixed(FixedType),
     Group(GroupType),
     Integer(IntegerType),
 }
@@ -38,6 +39,7 @@ impl std::fmt::Display for DataType {
             DataType::Address(_) => write!(f, "address"),
             DataType::Boolean(_) => write!(f, "bool"),
             DataType::Field(_) => write!(f, "field"),
+            DataType::Fixed(ref fixed) => write!(f, "{}", fixed),
             DataType::Group(_) => write!(f, "group"),
             DataType::Integer(ref integer) => write!(f, "{}", integer),
         }
@@ -26,12 +26,18 @@ pub use array_type::*;
 pub mod boolean_type;
 pub use boolean_type::*;
 
+pub mod circuit_type;
+pub use circuit_type::*;
+
 pub mod data_type;
 pub use data_type::*;
 
 pub mod field_type;
 pub use field_type::*;
 
+pub mod fixed_type;
+pub use fixed_type::*;
+
 pub mod group_type;
 pub use group_type::*;
 
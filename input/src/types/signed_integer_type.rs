@@ -27,6 +27,7 @@ pub enum SignedIntegerType {
     I32Type(I32Type),
     I64Type(I64Type),
     I128Type(I128Type),
+    I256Type(I256Type),
 }
 
 #[derive(Clone, Debug, FromPest, PartialEq, Eq)]
@@ -49,6 +50,10 @@ pub struct I64Type {}
 #[pest_ast(rule(Rule::type_i128))]
 pub struct I128Type {}
 
+#[derive(Clone, Debug, FromPest, PartialEq, Eq)]
+#[pest_ast(rule(Rule::type_i256))]
+pub struct I256Type {}
+
 impl fmt::Display for SignedIntegerType {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
@@ -57,6 +62,7 @@ impl fmt::Display for SignedIntegerType {
             SignedIntegerType::I32Type(_) => write!(f, "i32"),
             SignedIntegerType::I64Type(_) => write!(f, "i64"),
             SignedIntegerType::I128Type(_) => write!(f, "i128"),
+            SignedIntegerType::I256Type(_) => write!(f, "i256"),
         }
     }
 }
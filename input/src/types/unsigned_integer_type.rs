@@ -27,6 +27,7 @@ pub enum UnsignedIntegerType {
     U32Type(U32Type),
     U64Type(U64Type),
     U128Type(U128Type),
+    U256Type(U256Type),
 }
 
 #[derive(Clone, Debug, FromPest, PartialEq, Eq)]
@@ -49,6 +50,10 @@ pub struct U64Type {}
 #[pest_ast(rule(Rule::type_u128))]
 pub struct U128Type {}
 
+#[derive(Clone, Debug, FromPest, PartialEq, Eq)]
+#[pest_ast(rule(Rule::type_u256))]
+pub struct U256Type {}
+
 impl fmt::Display for UnsignedIntegerType {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
@@ -57,6 +62,7 @@ impl fmt::Display for UnsignedIntegerType {
             UnsignedIntegerType::U32Type(_) => write!(f, "u32"),
             UnsignedIntegerType::U64Type(_) => write!(f, "u64"),
             UnsignedIntegerType::U128Type(_) => write!(f, "u128"),
+            UnsignedIntegerType::U256Type(_) => write!(f, "u256"),
         }
     }
 }
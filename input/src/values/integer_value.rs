@@ -16,6 +16,7 @@
 
 use crate::{
     ast::Rule,
+    types::IntegerType,
     values::{SignedIntegerValue, UnsignedIntegerValue},
 };
 
@@ -37,6 +38,13 @@ impl<'ast> IntegerValue<'ast> {
             IntegerValue::Unsigned(integer) => &integer.span,
         }
     }
+
+    pub fn type_(&self) -> IntegerType {
+        match self {
+            IntegerValue::Signed(integer) => IntegerType::Signed(integer.type_.clone()),
+            IntegerValue::Unsigned(integer) => IntegerType::Unsigned(integer.type_.clone()),
+        }
+    }
 }
 
 impl<'ast> fmt::Display for IntegerValue<'ast> {
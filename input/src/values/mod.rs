@@ -26,6 +26,9 @@ pub use address_value::*;
 pub mod boolean_value;
 pub use boolean_value::*;
 
+pub mod decimal_value;
+pub use decimal_value::*;
+
 pub mod field_value;
 pub use field_value::*;
 
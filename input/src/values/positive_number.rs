@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::ast::{span_into_string, Rule};
+use crate::ast::{number_into_string, Rule};
 
 use pest::Span;
 use pest_ast::FromPest;
@@ -23,7 +23,7 @@ use std::fmt;
 #[derive(Clone, Debug, FromPest, PartialEq, Eq)]
 #[pest_ast(rule(Rule::number_positive))]
 pub struct PositiveNumber<'ast> {
-    #[pest_ast(outer(with(span_into_string)))]
+    #[pest_ast(outer(with(number_into_string)))]
     pub value: String,
     #[pest_ast(outer())]
     pub span: Span<'ast>,
@@ -14,14 +14,16 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{commands::Command, context::Context};
+use crate::{commands::Command, context::Context, diagnostics::emit_warning};
+use leo_ast::{Diagnostics, Report, WarningLevel};
 use leo_compiler::{
     compiler::{thread_leaked_context, Compiler},
     group::targets::edwards_bls12::EdwardsGroupType,
+    CompilerOptions,
 };
 use leo_package::{
     inputs::*,
-    outputs::{ChecksumFile, CircuitFile, OutputsDirectory, OUTPUTS_DIRECTORY_NAME},
+    outputs::{ChecksumFile, CircuitFile, IrFile, OutputsDirectory, OUTPUTS_DIRECTORY_NAME},
     source::{LibraryFile, MainFile, LIBRARY_FILENAME, MAIN_FILENAME, SOURCE_DIRECTORY_NAME},
 };
 use leo_synthesizer::{CircuitSynthesizer, SerializedCircuit};
@@ -32,10 +34,68 @@ use snarkvm_models::gadgets::r1cs::ConstraintSystem;
 use structopt::StructOpt;
 use tracing::span::Span;
 
+use std::collections::BTreeMap;
+
 /// Compile and build program command
-#[derive(StructOpt, Debug)]
+#[derive(StructOpt, Debug, Default)]
 #[structopt(setting = structopt::clap::AppSettings::ColoredHelp)]
-pub struct Build {}
+pub struct Build {
+    #[structopt(short = "A", long = "allow", name = "allow", help = "Allow the given warning")]
+    pub(crate) allow: Vec<String>,
+
+    #[structopt(
+        short = "D",
+        long = "deny",
+        name = "deny",
+        help = "Deny the given warning, or all warnings with `-D warnings`"
+    )]
+    pub(crate) deny: Vec<String>,
+
+    #[structopt(
+        short = "O",
+        long = "opt-level",
+        name = "opt-level",
+        possible_values = &["0", "1"],
+        help = "Optimization level, `-O0` skips the passes that optimize the program"
+    )]
+    pub(crate) opt_level: Option<u8>,
+
+    #[structopt(
+        long = "emit",
+        name = "emit",
+        possible_values = &["ir"],
+        help = "Write the given intermediate representation of the main program to the outputs directory"
+    )]
+    pub(crate) emit: Option<String>,
+
+    #[structopt(
+        long = "compare-inlining",
+        help = "Also compile the program without inlining and report its number of constraints"
+    )]
+    pub(crate) compare_inlining: bool,
+}
+
+impl Build {
+    /// Returns diagnostics with the warning levels set on the command line.
+    fn diagnostics(&self) -> Diagnostics {
+        let mut diagnostics = Diagnostics::new();
+        for name in self.allow.iter() {
+            diagnostics.set_level(name, WarningLevel::Allow);
+        }
+        for name in self.deny.iter() {
+            diagnostics.set_level(name, WarningLevel::Deny);
+        }
+        diagnostics
+    }
+
+    /// Returns the passes switched on or off in the manifest, at the optimization level set on the command line.
+    fn options(&self, passes: BTreeMap<String, bool>) -> CompilerOptions {
+        CompilerOptions {
+            optimize: self.opt_level != Some(0),
+            passes,
+        }
+    }
+}
 
 impl Command for Build {
     type Input = ();
@@ -51,7 +111,9 @@ impl Command for Build {
 
     fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
         let path = context.dir()?;
-        let package_name = context.manifest()?.get_package_name();
+        let manifest = context.manifest()?;
+        let package_name = manifest.get_package_name();
+        let options = self.options(manifest.get_package_optimizations());
 
         // Sanitize the package path to the root directory
         let mut package_path = path.clone();
@@ -76,12 +138,17 @@ impl Command for Build {
             tracing::info!("Compiling library... ({:?})", lib_file_path);
 
             // Compile the library file but do not output
-            let _program = Compiler::<Fq, EdwardsGroupType>::parse_program_without_input(
+            let program = Compiler::<Fq, EdwardsGroupType>::parse_program_without_input(
                 package_name.clone(),
                 lib_file_path,
                 output_directory.clone(),
                 thread_leaked_context(),
+                self.diagnostics(),
+                options.clone(),
             )?;
+            for warning in program.diagnostics().warnings() {
+                emit_warning(warning, Report::from_warning(warning));
+            }
             tracing::info!("Complete");
         };
 
@@ -107,14 +174,35 @@ impl Command for Build {
             // Load the program at `main_file_path`
             let program = Compiler::<Fq, EdwardsGroupType>::parse_program_with_input(
                 package_name.clone(),
-                main_file_path,
-                output_directory,
+                main_file_path.clone(),
+                output_directory.clone(),
                 &input_string,
                 &input_path,
                 &state_string,
                 &state_path,
                 thread_leaked_context(),
+                self.diagnostics(),
+                options.clone(),
             )?;
+            for warning in program.diagnostics().warnings() {
+                emit_warning(warning, Report::from_warning(warning));
+            }
+            for report in program.pass_reports() {
+                match report.duration {
+                    Some(duration) => tracing::info!("Pass `{}` took {:?}", report.name, duration),
+                    None => tracing::info!("Pass `{}` skipped", report.name),
+                }
+                for change in report.changes.iter() {
+                    tracing::info!("  {}", change);
+                }
+            }
+
+            // Write the intermediate representation to the outputs directory
+            if self.emit.is_some() {
+                let ir = program.lower_to_ir()?;
+                IrFile::new(&package_name).write_to(&path, ir.to_string())?;
+                tracing::info!("Intermediate representation written to the outputs directory");
+            }
 
             // Compute the current program checksum
             let program_checksum = program.checksum()?;
@@ -134,6 +222,40 @@ impl Command for Build {
                 tracing::debug!("Compiled output - {:#?}", output);
                 tracing::info!("Number of constraints - {:#?}", cs.num_constraints());
 
+                // Compare against the program compiled without inlining, if asked to and any call was inlined
+                let inlined = program
+                    .pass_reports()
+                    .iter()
+                    .any(|report| report.name == "inlining" && !report.changes.is_empty());
+                if self.compare_inlining && inlined {
+                    let mut options = options;
+                    options.passes.insert("inlining".to_string(), false);
+                    let baseline = Compiler::<Fq, EdwardsGroupType>::parse_program_with_input(
+                        package_name.clone(),
+                        main_file_path,
+                        output_directory,
+                        &input_string,
+                        &input_path,
+                        &state_string,
+                        &state_path,
+                        thread_leaked_context(),
+                        Diagnostics::new(),
+                        options,
+                    )?;
+                    let mut baseline_cs = CircuitSynthesizer::<Bls12_377> {
+                        at: vec![],
+                        bt: vec![],
+                        ct: vec![],
+                        public_variables: vec![],
+                        private_variables: vec![],
+                    };
+                    baseline.compile_constraints(&mut baseline_cs)?;
+                    tracing::info!(
+                        "Number of constraints without inlining - {:#?}",
+                        baseline_cs.num_constraints()
+                    );
+                }
+
                 // Serialize the circuit
                 let circuit_object = SerializedCircuit::from(cs);
                 let json = circuit_object.to_json_string().unwrap();
@@ -0,0 +1,71 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    commands::{Build, Command},
+    context::Context,
+    diagnostics::emit_warning,
+};
+
+use anyhow::{anyhow, Result};
+use snarkvm_curves::bls12_377::Bls12_377;
+use structopt::StructOpt;
+use tracing::span::Span;
+
+/// Check the program for potential issues command
+#[derive(StructOpt, Debug)]
+#[structopt(setting = structopt::clap::AppSettings::ColoredHelp)]
+pub struct Check {
+    #[structopt(long = "soundness", help = "Detect witnesses the circuit leaves under-constrained")]
+    pub(crate) soundness: bool,
+}
+
+impl Command for Check {
+    type Input = <Build as Command>::Output;
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Checking")
+    }
+
+    fn prelude(&self) -> Result<Self::Input> {
+        (Build::default()).execute()
+    }
+
+    fn apply(self, _context: Context, input: Self::Input) -> Result<Self::Output> {
+        let program = match input {
+            Some((program, _)) => program,
+            None => return Err(anyhow!("Unable to check the package, main file not found")),
+        };
+
+        if self.soundness {
+            tracing::info!("Checking soundness...");
+
+            let warnings = program.check_soundness::<Bls12_377>()?;
+            for warning in warnings.iter() {
+                emit_warning(warning, warning.report());
+            }
+
+            if !warnings.is_empty() {
+                return Err(anyhow!("Soundness check found {} potential issue(s)", warnings.len()));
+            }
+        }
+
+        tracing::info!("Complete");
+
+        Ok(())
+    }
+}
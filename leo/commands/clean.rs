@@ -16,7 +16,7 @@
 
 use crate::{commands::Command, context::Context};
 use leo_compiler::OutputFile;
-use leo_package::outputs::{ChecksumFile, CircuitFile, ProofFile, ProvingKeyFile, VerificationKeyFile};
+use leo_package::outputs::{ChecksumFile, CircuitFile, IrFile, ProofFile, ProvingKeyFile, VerificationKeyFile};
 
 use anyhow::Result;
 use structopt::StructOpt;
@@ -49,6 +49,9 @@ impl Command for Clean {
         // Remove the serialized circuit from the output directory
         CircuitFile::new(&package_name).remove(&path)?;
 
+        // Remove the intermediate representation from the output directory
+        IrFile::new(&package_name).remove(&path)?;
+
         // Remove the program output file from the output directory
         OutputFile::new(&package_name).remove(&path)?;
 
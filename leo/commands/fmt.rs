@@ -0,0 +1,102 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{commands::Command, context::Context};
+use leo_fmt::Formatter;
+use leo_grammar::Grammar;
+use leo_package::{
+    inputs::{INPUTS_DIRECTORY_NAME, INPUT_FILE_EXTENSION},
+    source::{SOURCE_DIRECTORY_NAME, SOURCE_FILE_EXTENSION},
+};
+
+use anyhow::{anyhow, Result};
+use std::fs;
+use structopt::StructOpt;
+use tracing::span::Span;
+
+/// Format Leo source and input files command
+#[derive(StructOpt, Debug)]
+#[structopt(setting = structopt::clap::AppSettings::ColoredHelp)]
+pub struct Fmt {
+    #[structopt(long, help = "Check that the files are formatted, without changing them")]
+    check: bool,
+}
+
+impl Command for Fmt {
+    type Input = ();
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Formatting")
+    }
+
+    fn prelude(&self) -> Result<Self::Input> {
+        Ok(())
+    }
+
+    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        let path = context.dir()?;
+
+        // Sanitize the package path to the root directory
+        let mut package_path = path;
+        if package_path.is_file() {
+            package_path.pop();
+        }
+
+        let mut file_paths = Grammar::find_files(&package_path.join(SOURCE_DIRECTORY_NAME), SOURCE_FILE_EXTENSION)?;
+        file_paths.extend(Grammar::find_files(
+            &package_path.join(INPUTS_DIRECTORY_NAME),
+            INPUT_FILE_EXTENSION,
+        )?);
+
+        let formatter = Formatter::default();
+        let mut unformatted = 0;
+        for file_path in file_paths {
+            let source = fs::read_to_string(&file_path)?;
+            let formatted = formatter.format_source(&file_path, &source).map_err(|mut error| {
+                error.set_path(&file_path);
+                error
+            })?;
+            if formatted == source {
+                continue;
+            }
+
+            if self.check {
+                let line = source
+                    .lines()
+                    .zip(formatted.lines())
+                    .take_while(|(source, formatted)| source == formatted)
+                    .count();
+                tracing::info!("Diff in {:?} at line {}", file_path, line + 1);
+                unformatted += 1;
+            } else {
+                fs::write(&file_path, formatted)?;
+                tracing::info!("Formatted {:?}", file_path);
+            }
+        }
+
+        if unformatted > 0 {
+            return Err(anyhow!(
+                "{} file(s) are not formatted, run `leo fmt` to format them",
+                unformatted
+            ));
+        }
+
+        tracing::info!("Complete");
+
+        Ok(())
+    }
+}
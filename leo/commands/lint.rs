@@ -14,9 +14,11 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{commands::Command, context::Context};
+use crate::{commands::Command, context::Context, diagnostics::emit_warning};
+use leo_linter::{LintConfig, Linter};
+use leo_package::source::{LibraryFile, MainFile, LIBRARY_FILENAME, MAIN_FILENAME, SOURCE_DIRECTORY_NAME};
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use structopt::StructOpt;
 use tracing::span::Span;
 
@@ -37,7 +39,51 @@ impl Command for Lint {
         Ok(())
     }
 
-    fn apply(self, _: Context, _: Self::Input) -> Result<Self::Output> {
-        unimplemented!("Lint command has not been implemented yet");
+    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        let path = context.dir()?;
+        let manifest = context.manifest()?;
+        let package_name = manifest.get_package_name();
+
+        // Read the lint levels from the `[lint]` section of the manifest
+        let settings = manifest.get_package_lint();
+        let linter = Linter::new(LintConfig::new(&settings.allow, &settings.deny)?);
+
+        // Sanitize the package path to the root directory
+        let mut package_path = path;
+        if package_path.is_file() {
+            package_path.pop();
+        }
+
+        let mut file_names = vec![];
+        if LibraryFile::exists_at(&package_path) {
+            file_names.push(LIBRARY_FILENAME);
+        }
+        if MainFile::exists_at(&package_path) {
+            file_names.push(MAIN_FILENAME);
+        }
+
+        let mut denied = 0;
+        for file_name in file_names {
+            let mut file_path = package_path.clone();
+            file_path.push(SOURCE_DIRECTORY_NAME);
+            file_path.push(file_name);
+
+            tracing::info!("Linting {:?}", file_path);
+
+            for lint in linter.lint_file(&package_name, &file_path)? {
+                if lint.is_denied() {
+                    denied += 1;
+                }
+                emit_warning(&lint, lint.report());
+            }
+        }
+
+        if denied > 0 {
+            return Err(anyhow!("Linting failed with {} denied lint(s)", denied));
+        }
+
+        tracing::info!("Complete");
+
+        Ok(())
     }
 }
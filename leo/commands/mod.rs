@@ -24,18 +24,27 @@ use tracing::span::Span;
 pub mod build;
 pub use build::Build;
 
+pub mod check;
+pub use check::Check;
+
 pub mod clean;
 pub use clean::Clean;
 
 pub mod deploy;
 pub use deploy::Deploy;
 
+pub mod fmt;
+pub use fmt::Fmt;
+
 pub mod init;
 pub use init::Init;
 
 pub mod lint;
 pub use lint::Lint;
 
+pub mod lsp;
+pub use lsp::Lsp;
+
 pub mod new;
 pub use new::New;
 
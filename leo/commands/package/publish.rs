@@ -47,7 +47,7 @@ impl Command for Publish {
 
     /// Build program before publishing
     fn prelude(&self) -> Result<Self::Input> {
-        (Build {}).execute()
+        (Build::default()).execute()
     }
 
     fn apply(self, context: Context, _input: Self::Input) -> Result<Self::Output> {
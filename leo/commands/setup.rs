@@ -51,7 +51,7 @@ impl Command for Setup {
     }
 
     fn prelude(&self) -> Result<Self::Input> {
-        (Build {}).execute()
+        (Build::default()).execute()
     }
 
     fn apply(self, context: Context, input: Self::Input) -> Result<Self::Output> {
@@ -14,10 +14,12 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{commands::Command, context::Context};
+use crate::{commands::Command, context::Context, diagnostics::emit_warning};
+use leo_ast::{Diagnostics, Report};
 use leo_compiler::{
     compiler::{thread_leaked_context, Compiler},
     group::targets::edwards_bls12::EdwardsGroupType,
+    CompilerOptions,
 };
 use leo_package::{
     inputs::*,
@@ -107,7 +109,12 @@ impl Command for Test {
                 file_path,
                 output_directory.clone(),
                 thread_leaked_context(),
+                Diagnostics::new(),
+                CompilerOptions::default(),
             )?;
+            for warning in program.diagnostics().warnings() {
+                emit_warning(warning, Report::from_warning(warning));
+            }
 
             let temporary_program = program;
             let (passed, failed) = temporary_program.compile_test_constraints(input_pairs)?;
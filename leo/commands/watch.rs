@@ -64,7 +64,7 @@ impl Command for Watch {
             match rx.recv() {
                 // See changes on the write event
                 Ok(DebouncedEvent::Write(_write)) => {
-                    match (Build {}).execute() {
+                    match (Build::default()).execute() {
                         Ok(_output) => {
                             tracing::info!("Built successfully");
                         }
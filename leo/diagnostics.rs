@@ -0,0 +1,113 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Printing of errors and warnings in the format chosen with `--message-format`.
+
+use leo_ast::{AstError, Diagnostic, Report, Severity};
+use leo_compiler::errors::CompilerError;
+use leo_fmt::FormatError;
+use leo_imports::ImportParserError;
+use leo_input::InputParserError;
+
+use anyhow::Error;
+use std::{
+    fmt,
+    str::FromStr,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+/// Code of errors raised by the CLI itself rather than by the compiler.
+pub const CLI_ERROR_CODE: &str = "E0001";
+
+static JSON_MESSAGES: AtomicBool = AtomicBool::new(false);
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MessageFormat {
+    /// Messages formatted for reading in a terminal
+    Human,
+    /// One JSON report per line on stdout
+    Json,
+}
+
+impl MessageFormat {
+    pub fn set(self) {
+        JSON_MESSAGES.store(self == MessageFormat::Json, Ordering::Relaxed);
+    }
+
+    pub fn get() -> Self {
+        match JSON_MESSAGES.load(Ordering::Relaxed) {
+            false => MessageFormat::Human,
+            true => MessageFormat::Json,
+        }
+    }
+}
+
+impl FromStr for MessageFormat {
+    type Err = String;
+
+    fn from_str(format: &str) -> Result<Self, Self::Err> {
+        match format {
+            "human" => Ok(MessageFormat::Human),
+            "json" => Ok(MessageFormat::Json),
+            _ => Err(format!(
+                "unknown message format `{}`, expected `human` or `json`",
+                format
+            )),
+        }
+    }
+}
+
+///
+/// Prints a warning in the current message format.
+///
+/// Reports with error severity are denied warnings and are logged as errors.
+///
+pub fn emit_warning(warning: &dyn fmt::Display, report: Report) {
+    match MessageFormat::get() {
+        MessageFormat::Human if report.severity == Severity::Error => tracing::error!("{}\n", warning),
+        MessageFormat::Human => tracing::warn!("{}\n", warning),
+        MessageFormat::Json => println!("{}", report.to_json()),
+    }
+}
+
+/// Prints the error a command failed with in the current message format.
+pub fn emit_error(error: &Error) {
+    match MessageFormat::get() {
+        MessageFormat::Human => eprintln!("Error: {}", error),
+        MessageFormat::Json => {
+            for report in reports(error) {
+                println!("{}", report.to_json());
+            }
+        }
+    }
+}
+
+/// Returns the reports of the compiler error behind `error`, or a single report of the CLI error.
+pub fn reports(error: &Error) -> Vec<Report> {
+    if let Some(error) = error.downcast_ref::<CompilerError>() {
+        error.reports()
+    } else if let Some(error) = error.downcast_ref::<ImportParserError>() {
+        error.reports()
+    } else if let Some(error) = error.downcast_ref::<InputParserError>() {
+        error.reports()
+    } else if let Some(error) = error.downcast_ref::<AstError>() {
+        error.reports()
+    } else if let Some(error) = error.downcast_ref::<FormatError>() {
+        error.reports()
+    } else {
+        vec![Report::new(CLI_ERROR_CODE, error.to_string())]
+    }
+}
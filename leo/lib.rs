@@ -18,6 +18,7 @@ pub mod api;
 pub mod commands;
 pub mod config;
 pub mod context;
+pub mod diagnostics;
 pub mod logger;
 pub mod updater;
 
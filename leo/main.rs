@@ -18,17 +18,21 @@ pub mod api;
 pub mod commands;
 pub mod config;
 pub mod context;
+pub mod diagnostics;
 pub mod logger;
 pub mod updater;
 
 use commands::{
     package::{Add, Clone, Login, Logout, Publish, Remove},
     Build,
+    Check,
     Clean,
     Command,
     Deploy,
+    Fmt,
     Init,
     Lint,
+    Lsp,
     New,
     Prove,
     Run,
@@ -37,6 +41,7 @@ use commands::{
     Update,
     Watch,
 };
+use diagnostics::MessageFormat;
 
 use anyhow::Error;
 use std::process::exit;
@@ -52,6 +57,14 @@ struct Opt {
     #[structopt(short, long, help = "Suppress CLI output")]
     quiet: bool,
 
+    #[structopt(
+        long = "message-format",
+        default_value = "human",
+        possible_values = &["human", "json"],
+        help = "Print errors and warnings for humans, or as one JSON object per line"
+    )]
+    message_format: MessageFormat,
+
     #[structopt(subcommand)]
     command: CommandOpts,
 }
@@ -78,6 +91,12 @@ enum CommandOpts {
         command: Build,
     },
 
+    #[structopt(about = "Check the current package for potential issues")]
+    Check {
+        #[structopt(flatten)]
+        command: Check,
+    },
+
     #[structopt(about = "Run a program setup")]
     Setup {
         #[structopt(flatten)]
@@ -156,12 +175,24 @@ enum CommandOpts {
         command: Remove,
     },
 
-    #[structopt(about = "Lints the Leo files in the package (*)")]
+    #[structopt(about = "Format the Leo source and input files in the package")]
+    Fmt {
+        #[structopt(flatten)]
+        command: Fmt,
+    },
+
+    #[structopt(about = "Lints the Leo files in the package")]
     Lint {
         #[structopt(flatten)]
         command: Lint,
     },
 
+    #[structopt(about = "Start the Leo language server over stdio")]
+    Lsp {
+        #[structopt(flatten)]
+        command: Lsp,
+    },
+
     #[structopt(about = "Deploy the current package as a program to the network (*)")]
     Deploy {
         #[structopt(flatten)]
@@ -173,7 +204,11 @@ fn main() {
     // read command line arguments
     let opt = Opt::from_args();
 
-    if !opt.quiet {
+    opt.message_format.set();
+
+    // JSON messages and the language server protocol are the only output, so they can be parsed
+    let is_lsp = matches!(opt.command, CommandOpts::Lsp { .. });
+    if !opt.quiet && !is_lsp && opt.message_format == MessageFormat::Human {
         // init logger with optional debug flag
         logger::init_logger("leo", match opt.debug {
             false => 1,
@@ -185,6 +220,7 @@ fn main() {
         CommandOpts::Init { command } => command.try_execute(),
         CommandOpts::New { command } => command.try_execute(),
         CommandOpts::Build { command } => command.try_execute(),
+        CommandOpts::Check { command } => command.try_execute(),
         CommandOpts::Setup { command } => command.try_execute(),
         CommandOpts::Prove { command } => command.try_execute(),
         CommandOpts::Test { command } => command.try_execute(),
@@ -200,7 +236,9 @@ fn main() {
         CommandOpts::Publish { command } => command.try_execute(),
         CommandOpts::Remove { command } => command.try_execute(),
 
+        CommandOpts::Fmt { command } => command.try_execute(),
         CommandOpts::Lint { command } => command.try_execute(),
+        CommandOpts::Lsp { command } => command.try_execute(),
         CommandOpts::Deploy { command } => command.try_execute(),
     });
 }
@@ -209,7 +247,7 @@ fn handle_error<T>(res: Result<T, Error>) -> T {
     match res {
         Ok(t) => t,
         Err(err) => {
-            eprintln!("Error: {}", err);
+            diagnostics::emit_error(&err);
             exit(1);
         }
     }
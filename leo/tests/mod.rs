@@ -17,12 +17,15 @@
 use std::path::PathBuf;
 
 use anyhow::Result;
+use leo_package::outputs::IrFile;
 
 use crate::{
     commands::{
         package::{Login, Logout},
         Build,
+        Check,
         Command,
+        Lint,
         Prove,
         Run,
         Setup,
@@ -39,13 +42,54 @@ const PEDERSEN_HASH_PATH: &str = "./examples/pedersen-hash/";
 
 #[test]
 pub fn build_pedersen_hash() -> Result<()> {
-    (Build {}).apply(context()?, ())?;
+    (Build::default()).apply(context()?, ())?;
+    Ok(())
+}
+
+#[test]
+pub fn build_pedersen_hash_emit_ir() -> Result<()> {
+    let build = Build {
+        emit: Some("ir".to_string()),
+        ..Default::default()
+    };
+    build.apply(context()?, ())?;
+
+    let ir_file = IrFile::new("pedersen-hash");
+    assert!(ir_file.exists_at(&PathBuf::from(PEDERSEN_HASH_PATH)));
+    ir_file.remove(&PathBuf::from(PEDERSEN_HASH_PATH))?;
+
+    Ok(())
+}
+
+#[test]
+pub fn build_pedersen_hash_compare_inlining() -> Result<()> {
+    let build = Build {
+        compare_inlining: true,
+        ..Default::default()
+    };
+    build.apply(context()?, ())?;
+    Ok(())
+}
+
+#[test]
+pub fn lint_pedersen_hash() -> Result<()> {
+    (Lint {}).apply(context()?, ())?;
+    Ok(())
+}
+
+#[test]
+pub fn check_pedersen_hash() -> Result<()> {
+    let build = (Build::default()).apply(context()?, ())?;
+    (Check { soundness: false }).apply(context()?, build.clone())?;
+
+    // The example hashes constant bits, so its output does not depend on any input.
+    assert!((Check { soundness: true }).apply(context()?, build).is_err());
     Ok(())
 }
 
 #[test]
 pub fn setup_pedersen_hash() -> Result<()> {
-    let build = (Build {}).apply(context()?, ())?;
+    let build = (Build::default()).apply(context()?, ())?;
     (Setup { skip_key_check: false }).apply(context()?, build.clone())?;
     (Setup { skip_key_check: true }).apply(context()?, build)?;
     Ok(())
@@ -53,7 +97,7 @@ pub fn setup_pedersen_hash() -> Result<()> {
 
 #[test]
 pub fn prove_pedersen_hash() -> Result<()> {
-    let build = (Build {}).apply(context()?, ())?;
+    let build = (Build::default()).apply(context()?, ())?;
     let setup = (Setup { skip_key_check: false }).apply(context()?, build)?;
     (Prove { skip_key_check: false }).apply(context()?, setup.clone())?;
     (Prove { skip_key_check: true }).apply(context()?, setup)?;
@@ -62,7 +106,7 @@ pub fn prove_pedersen_hash() -> Result<()> {
 
 #[test]
 pub fn run_pedersen_hash() -> Result<()> {
-    let build = (Build {}).apply(context()?, ())?;
+    let build = (Build::default()).apply(context()?, ())?;
     let setup = (Setup { skip_key_check: false }).apply(context()?, build)?;
     let prove = (Prove { skip_key_check: false }).apply(context()?, setup)?;
     (Run { skip_key_check: false }).apply(context()?, prove.clone())?;
@@ -0,0 +1,53 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{LintError, LintLevel, LintRule};
+
+/// The package-wide lint levels, read from the `[lint]` section of `Leo.toml`.
+///
+/// Rules in `allow` are not checked, rules in `deny` are reported as errors.
+#[derive(Clone, Debug, Default)]
+pub struct LintConfig {
+    allow: Vec<LintRule>,
+    deny: Vec<LintRule>,
+}
+
+impl LintConfig {
+    pub fn new(allow: &[String], deny: &[String]) -> Result<Self, LintError> {
+        let parse = |names: &[String]| {
+            names
+                .iter()
+                .map(|name| LintRule::from_name(name).ok_or_else(|| LintError::UnknownRule(name.clone())))
+                .collect::<Result<Vec<_>, _>>()
+        };
+
+        Ok(Self {
+            allow: parse(allow)?,
+            deny: parse(deny)?,
+        })
+    }
+
+    /// Returns the level of `rule`, or `None` if the rule is allowed.
+    pub fn level(&self, rule: LintRule) -> Option<LintLevel> {
+        if self.allow.contains(&rule) {
+            None
+        } else if self.deny.contains(&rule) {
+            Some(LintLevel::Deny)
+        } else {
+            Some(LintLevel::Warn)
+        }
+    }
+}
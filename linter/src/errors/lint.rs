@@ -0,0 +1,65 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_asg::AsgConvertError;
+use leo_ast::{AstError, Error as FormattedError, Span};
+use leo_grammar::ParserError;
+
+use std::path::Path;
+
+#[derive(Debug, Error)]
+pub enum LintError {
+    #[error("{}", _0)]
+    AsgConvertError(#[from] AsgConvertError),
+
+    #[error("{}", _0)]
+    AstError(#[from] AstError),
+
+    #[error("{}", _0)]
+    Error(#[from] FormattedError),
+
+    #[error("{}", _0)]
+    ParserError(#[from] ParserError),
+
+    #[error("unknown lint rule `{}`", _0)]
+    UnknownRule(String),
+}
+
+impl LintError {
+    pub fn set_path(&mut self, path: &Path) {
+        match self {
+            LintError::Error(error) => error.set_path(path),
+            LintError::ParserError(error) => error.set_path(path),
+            _ => {}
+        }
+    }
+
+    fn new_from_span(message: String, span: Span) -> Self {
+        LintError::Error(FormattedError::new_from_span(message, span))
+    }
+
+    pub fn unknown_annotated_rule(name: &str, span: Span) -> Self {
+        let message = format!("unknown lint rule `{}`", name);
+
+        Self::new_from_span(message, span)
+    }
+
+    pub fn missing_allowed_rules(span: Span) -> Self {
+        let message = "`@allow` expects the lint rules to allow, e.g. `@allow(unused_variables)`".to_string();
+
+        Self::new_from_span(message, span)
+    }
+}
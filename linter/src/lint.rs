@@ -0,0 +1,71 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::LintRule;
+use leo_ast::{Error as FormattedError, Report, Severity, Span};
+
+use std::{fmt, path::Path};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LintLevel {
+    Warn,
+    Deny,
+}
+
+/// A lint reported by a rule, pointing at the offending code.
+#[derive(Clone, Debug)]
+pub struct Lint {
+    pub rule: LintRule,
+    pub level: LintLevel,
+    pub error: FormattedError,
+}
+
+impl Lint {
+    pub fn new(rule: LintRule, message: String, span: Span) -> Self {
+        Self {
+            rule,
+            level: LintLevel::Warn,
+            error: FormattedError::new_from_span(format!("{} [{}]", message, rule), span),
+        }
+    }
+
+    pub fn set_path(&mut self, path: &Path) {
+        self.error.set_path(path);
+    }
+
+    pub fn is_denied(&self) -> bool {
+        self.level == LintLevel::Deny
+    }
+
+    /// Returns the lint as a report, coded by the name of its rule.
+    pub fn report(&self) -> Report {
+        let severity = match self.level {
+            LintLevel::Warn => Severity::Warning,
+            LintLevel::Deny => Severity::Error,
+        };
+
+        Report {
+            severity,
+            ..Report::from_error(self.rule.name(), &self.error)
+        }
+    }
+}
+
+impl fmt::Display for Lint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.error)
+    }
+}
@@ -0,0 +1,156 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{rules, Lint, LintConfig, LintError, LintRule};
+use leo_asg::{new_alloc_context, new_context, Asg};
+use leo_ast::{Ast, Diagnostics, Span};
+use leo_grammar::{
+    annotations::AnnotationName,
+    definitions::{AnnotatedDefinition, Definition},
+    Grammar,
+};
+use leo_imports::ImportParser;
+
+use std::path::Path;
+
+/// The lines of a definition annotated with `@allow(rule)`.
+struct AllowedRegion {
+    rule: LintRule,
+    start_line: usize,
+    end_line: usize,
+}
+
+impl AllowedRegion {
+    fn allows(&self, lint: &Lint) -> bool {
+        lint.rule == self.rule && self.start_line <= lint.error.line && lint.error.line <= self.end_line
+    }
+
+    fn from_definition(definition: &AnnotatedDefinition) -> Result<Vec<Self>, LintError> {
+        let mut regions = match &*definition.definition {
+            Definition::Annotated(definition) => Self::from_definition(definition)?,
+            _ => vec![],
+        };
+
+        let annotation = &definition.annotation;
+        if !matches!(annotation.name, AnnotationName::Allow(_)) {
+            return Ok(regions);
+        }
+        let arguments = annotation
+            .arguments
+            .as_ref()
+            .ok_or_else(|| LintError::missing_allowed_rules(Span::from(annotation.span.clone())))?;
+
+        let (start_line, _) = definition.span.start_pos().line_col();
+        let (end_line, _) = definition.span.end_pos().line_col();
+        arguments
+            .arguments
+            .iter()
+            .map(|argument| {
+                let rule = LintRule::from_name(&argument.value).ok_or_else(|| {
+                    LintError::unknown_annotated_rule(&argument.value, Span::from(argument.span.clone()))
+                })?;
+
+                Ok(Self {
+                    rule,
+                    start_line,
+                    end_line,
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map(|allowed| {
+                regions.extend(allowed);
+                regions
+            })
+    }
+}
+
+/// Runs the lint rules on a Leo program.
+#[derive(Clone, Debug, Default)]
+pub struct Linter {
+    config: LintConfig,
+}
+
+impl Linter {
+    pub fn new(config: LintConfig) -> Self {
+        Self { config }
+    }
+
+    ///
+    /// Returns the lints of the program file at `file_path`, in the order of their position.
+    ///
+    pub fn lint_file(&self, program_name: &str, file_path: &Path) -> Result<Vec<Lint>, LintError> {
+        let program_string = Grammar::load_file(file_path)?;
+
+        self.lint_string(program_name, file_path, &program_string)
+    }
+
+    ///
+    /// Equivalent to `lint_file` but uses the given program string instead of reading the file.
+    ///
+    pub fn lint_string(
+        &self,
+        program_name: &str,
+        file_path: &Path,
+        program_string: &str,
+    ) -> Result<Vec<Lint>, LintError> {
+        self.lint(program_name, file_path, program_string).map_err(|mut error| {
+            error.set_path(file_path);
+
+            error
+        })
+    }
+
+    fn lint(&self, program_name: &str, file_path: &Path, program_string: &str) -> Result<Vec<Lint>, LintError> {
+        let grammar = Grammar::new(file_path, program_string)?;
+
+        let mut allowed_regions = vec![];
+        for definition in grammar.as_repr().definitions.iter() {
+            if let Definition::Annotated(definition) = definition {
+                allowed_regions.extend(AllowedRegion::from_definition(definition)?);
+            }
+        }
+
+        let ast = Ast::new(program_name, &grammar)?;
+
+        let arena = new_alloc_context();
+        let asg = Asg::new(
+            new_context(&arena),
+            &ast,
+            &mut ImportParser::default(),
+            &mut Diagnostics::new(),
+        )?;
+        let program = asg.as_repr();
+
+        let mut lints = vec![];
+        for rule in LintRule::ALL.iter().copied() {
+            let level = match self.config.level(rule) {
+                Some(level) => level,
+                None => continue,
+            };
+            for mut lint in rules::check(rule, &program, ast.as_repr()) {
+                if allowed_regions.iter().any(|region| region.allows(&lint)) {
+                    continue;
+                }
+                lint.level = level;
+                lint.set_path(file_path);
+                lints.push(lint);
+            }
+        }
+        lints.sort_by_key(|lint| (lint.error.line, lint.error.start));
+
+        Ok(lints)
+    }
+}
@@ -0,0 +1,60 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use std::fmt;
+
+/// A lint rule, named in `@allow(...)` and in the `[lint]` section of `Leo.toml`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum LintRule {
+    UnusedVariables,
+    UnusedImports,
+    ShadowedNames,
+    RedundantMut,
+    ConstantConditions,
+    ConstantLoops,
+}
+
+impl LintRule {
+    pub const ALL: [LintRule; 6] = [
+        LintRule::UnusedVariables,
+        LintRule::UnusedImports,
+        LintRule::ShadowedNames,
+        LintRule::RedundantMut,
+        LintRule::ConstantConditions,
+        LintRule::ConstantLoops,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            LintRule::UnusedVariables => "unused_variables",
+            LintRule::UnusedImports => "unused_imports",
+            LintRule::ShadowedNames => "shadowed_names",
+            LintRule::RedundantMut => "redundant_mut",
+            LintRule::ConstantConditions => "constant_conditions",
+            LintRule::ConstantLoops => "constant_loops",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<LintRule> {
+        Self::ALL.iter().copied().find(|rule| rule.name() == name)
+    }
+}
+
+impl fmt::Display for LintRule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
@@ -0,0 +1,64 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Lint, LintRule};
+use leo_asg::{
+    ConditionalStatement,
+    ConstValue,
+    Expression,
+    ExpressionNode,
+    ExpressionVisitor,
+    Node,
+    ProgramVisitor,
+    Span,
+    StatementVisitor,
+    TernaryExpression,
+    VisitResult,
+};
+
+/// Reports `if` statements and ternary expressions whose condition is known at compile time.
+#[derive(Default)]
+pub struct ConstantConditions {
+    pub lints: Vec<Lint>,
+}
+
+impl ConstantConditions {
+    fn check_condition(&mut self, condition: &Expression, span: Option<&Span>) {
+        if let Some(ConstValue::Boolean(value)) = condition.const_value() {
+            self.lints.push(Lint::new(
+                LintRule::ConstantConditions,
+                format!("condition is always `{}`", value),
+                condition.span().or(span).cloned().unwrap_or_default(),
+            ));
+        }
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for ConstantConditions {
+    fn visit_ternary_expression(&mut self, input: &TernaryExpression<'a>) -> VisitResult {
+        self.check_condition(input.condition.get(), input.span.as_ref());
+        Default::default()
+    }
+}
+
+impl<'a> StatementVisitor<'a> for ConstantConditions {
+    fn visit_conditional_statement(&mut self, input: &ConditionalStatement<'a>) -> VisitResult {
+        self.check_condition(input.condition.get(), input.span.as_ref());
+        Default::default()
+    }
+}
+
+impl<'a> ProgramVisitor<'a> for ConstantConditions {}
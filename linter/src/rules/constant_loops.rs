@@ -0,0 +1,68 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Lint, LintRule};
+use leo_asg::{
+    ConstValue,
+    ExpressionNode,
+    ExpressionVisitor,
+    IterationStatement,
+    ProgramVisitor,
+    StatementVisitor,
+    VisitResult,
+};
+
+/// Reports loops over a constant range that run their body at most once.
+#[derive(Default)]
+pub struct ConstantLoops {
+    pub lints: Vec<Lint>,
+}
+
+impl<'a> ExpressionVisitor<'a> for ConstantLoops {}
+
+impl<'a> StatementVisitor<'a> for ConstantLoops {
+    fn visit_iteration(&mut self, input: &IterationStatement<'a>) -> VisitResult {
+        // Loops over the elements of an array get their bounds from its length.
+        if input.array.get().is_some() {
+            return Default::default();
+        }
+        let bound = |value: Option<ConstValue>| match value {
+            Some(ConstValue::Int(value)) => value.to_usize(),
+            _ => None,
+        };
+        let (start, stop) = match (
+            bound(input.start.get().const_value()),
+            bound(input.stop.get().const_value()),
+        ) {
+            (Some(start), Some(stop)) => (start, stop),
+            _ => return Default::default(),
+        };
+
+        let message = match stop.saturating_sub(start) {
+            0 => "loop body is never run",
+            1 => "loop body is only run once, the loop can be replaced by its body",
+            _ => return Default::default(),
+        };
+        self.lints.push(Lint::new(
+            LintRule::ConstantLoops,
+            message.to_string(),
+            input.span.clone().unwrap_or_default(),
+        ));
+        Default::default()
+    }
+}
+
+impl<'a> ProgramVisitor<'a> for ConstantLoops {}
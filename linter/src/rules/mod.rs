@@ -0,0 +1,69 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The lint rules, each a visitor over the ASG of the linted program.
+
+pub mod constant_conditions;
+pub use self::constant_conditions::*;
+
+pub mod constant_loops;
+pub use self::constant_loops::*;
+
+pub mod redundant_mut;
+pub use self::redundant_mut::*;
+
+pub mod shadowed_names;
+pub use self::shadowed_names::*;
+
+pub mod unused_imports;
+pub use self::unused_imports::*;
+
+pub mod unused_variables;
+pub use self::unused_variables::*;
+
+use crate::{Lint, LintRule};
+use leo_asg::{Program, ProgramVisitor, VisitorDirector};
+
+///
+/// Visits the functions and circuits declared in `program`, leaving out its imported modules.
+///
+pub fn visit_program<'a, V: ProgramVisitor<'a>>(program: &Program<'a>, visitor: V) -> V {
+    let mut director = VisitorDirector::new(visitor);
+    for (function, _) in program.test_functions.values() {
+        let _ = director.visit_function(function);
+    }
+    for function in program.functions.values() {
+        let _ = director.visit_function(function);
+    }
+    for circuit in program.circuits.values() {
+        let _ = director.visit_circuit(circuit);
+    }
+    director.visitor()
+}
+
+///
+/// Returns the lints of `rule` in `program`.
+///
+pub fn check<'a>(rule: LintRule, program: &Program<'a>, ast: &leo_ast::Program) -> Vec<Lint> {
+    match rule {
+        LintRule::UnusedVariables => visit_program(program, UnusedVariables::default()).lints,
+        LintRule::UnusedImports => UnusedImports::check(program, ast),
+        LintRule::ShadowedNames => visit_program(program, ShadowedNames::default()).lints,
+        LintRule::RedundantMut => visit_program(program, RedundantMut::default()).lints(),
+        LintRule::ConstantConditions => visit_program(program, ConstantConditions::default()).lints,
+        LintRule::ConstantLoops => visit_program(program, ConstantLoops::default()).lints,
+    }
+}
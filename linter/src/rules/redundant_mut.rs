@@ -0,0 +1,107 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Lint, LintRule};
+use leo_asg::{
+    CallExpression,
+    DefinitionStatement,
+    Expression,
+    ExpressionVisitor,
+    Function,
+    FunctionQualifier,
+    ProgramVisitor,
+    Statement,
+    StatementVisitor,
+    Variable,
+    VisitResult,
+};
+
+/// Reports `mut` variables and parameters that are never assigned to or mutated through a `mut self` call.
+#[derive(Default)]
+pub struct RedundantMut<'a> {
+    mutable: Vec<&'a Variable<'a>>,
+    mutated: Vec<u32>,
+}
+
+/// Returns the variable holding the value `expression` accesses, if any.
+fn accessed_variable<'a>(expression: &'a Expression<'a>) -> Option<&'a Variable<'a>> {
+    match expression {
+        Expression::VariableRef(variable_ref) => Some(variable_ref.variable),
+        Expression::ArrayAccess(access) => accessed_variable(access.array.get()),
+        Expression::ArrayRangeAccess(access) => accessed_variable(access.array.get()),
+        Expression::CircuitAccess(access) => access.target.get().and_then(accessed_variable),
+        Expression::TupleAccess(access) => accessed_variable(access.tuple_ref.get()),
+        _ => None,
+    }
+}
+
+impl<'a> RedundantMut<'a> {
+    pub fn lints(self) -> Vec<Lint> {
+        let mutated = self.mutated;
+        self.mutable
+            .into_iter()
+            .map(|variable| variable.borrow())
+            .filter(|variable| {
+                !mutated.contains(&variable.id)
+                    && variable
+                        .assignments
+                        .iter()
+                        .all(|statement| matches!(statement, Statement::Definition(_)))
+            })
+            .map(|variable| {
+                Lint::new(
+                    LintRule::RedundantMut,
+                    format!("variable `{}` does not need to be mutable", variable.name.name),
+                    variable.name.span.clone(),
+                )
+            })
+            .collect()
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for RedundantMut<'a> {
+    fn visit_call(&mut self, input: &CallExpression<'a>) -> VisitResult {
+        if input.function.get().qualifier == FunctionQualifier::MutSelfRef {
+            if let Some(variable) = input.target.get().and_then(accessed_variable) {
+                self.mutated.push(variable.borrow().id);
+            }
+        }
+        Default::default()
+    }
+}
+
+impl<'a> StatementVisitor<'a> for RedundantMut<'a> {
+    fn visit_definition(&mut self, input: &DefinitionStatement<'a>) -> VisitResult {
+        self.mutable
+            .extend(input.variables.iter().filter(|variable| variable.borrow().mutable));
+        Default::default()
+    }
+}
+
+impl<'a> ProgramVisitor<'a> for RedundantMut<'a> {
+    fn visit_function(&mut self, input: &'a Function<'a>) -> VisitResult {
+        if input.body.get().is_some() {
+            self.mutable.extend(
+                input
+                    .arguments
+                    .values()
+                    .map(|argument| argument.get())
+                    .filter(|variable| variable.borrow().mutable),
+            );
+        }
+        Default::default()
+    }
+}
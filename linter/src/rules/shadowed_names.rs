@@ -0,0 +1,113 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Lint, LintRule};
+use leo_asg::{
+    BlockStatement,
+    ExpressionVisitor,
+    Function,
+    Identifier,
+    ProgramVisitor,
+    Statement,
+    StatementVisitor,
+    VisitResult,
+};
+
+use std::collections::HashMap;
+
+/// Reports variables declared with the name of a variable that is still in scope.
+#[derive(Default)]
+pub struct ShadowedNames {
+    pub lints: Vec<Lint>,
+
+    /// The variables in scope at the start of each block, by the id of the block scope.
+    visible: HashMap<u32, Vec<Identifier>>,
+}
+
+impl ShadowedNames {
+    fn check(&mut self, visible: &[Identifier], name: &Identifier) {
+        if let Some(shadowed) = visible.iter().rev().find(|visible| visible.name == name.name) {
+            self.lints.push(Lint::new(
+                LintRule::ShadowedNames,
+                format!(
+                    "`{}` shadows the variable declared on line {}",
+                    name.name, shadowed.span.line
+                ),
+                name.span.clone(),
+            ));
+        }
+    }
+
+    /// Records `visible` as the variables in scope at the start of the blocks nested in `statement`.
+    fn enter(&mut self, statement: &Statement, visible: &[Identifier]) {
+        match statement {
+            Statement::Block(block) => {
+                self.visible.insert(block.scope.id, visible.to_vec());
+            }
+            Statement::Conditional(conditional) => {
+                self.enter(conditional.result.get(), visible);
+                if let Some(next) = conditional.next.get() {
+                    self.enter(next, visible);
+                }
+            }
+            Statement::Iteration(iteration) => {
+                let mut visible = visible.to_vec();
+                visible.push(iteration.variable.borrow().name.clone());
+                self.enter(iteration.body.get(), &visible);
+            }
+            _ => {}
+        }
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for ShadowedNames {}
+
+impl<'a> StatementVisitor<'a> for ShadowedNames {
+    fn visit_block(&mut self, input: &BlockStatement<'a>) -> VisitResult {
+        let mut visible = self.visible.remove(&input.scope.id).unwrap_or_default();
+        for statement in input.statements.iter() {
+            match statement.get() {
+                Statement::Definition(definition) => {
+                    for variable in definition.variables.iter() {
+                        let name = variable.borrow().name.clone();
+                        self.check(&visible, &name);
+                        visible.push(name);
+                    }
+                }
+                Statement::Iteration(iteration) => {
+                    self.check(&visible, &iteration.variable.borrow().name);
+                }
+                _ => {}
+            }
+            self.enter(statement.get(), &visible);
+        }
+        Default::default()
+    }
+}
+
+impl<'a> ProgramVisitor<'a> for ShadowedNames {
+    fn visit_function(&mut self, input: &'a Function<'a>) -> VisitResult {
+        if let Some(body) = input.body.get() {
+            let arguments = input
+                .arguments
+                .values()
+                .map(|argument| argument.get().borrow().name.clone())
+                .collect::<Vec<_>>();
+            self.enter(body, &arguments);
+        }
+        Default::default()
+    }
+}
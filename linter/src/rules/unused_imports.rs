@@ -0,0 +1,169 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{rules::visit_program, Lint, LintRule};
+use leo_asg::{
+    resolve_import_package,
+    CallExpression,
+    Circuit,
+    CircuitAccessExpression,
+    CircuitInitExpression,
+    CircuitMember,
+    DefinitionStatement,
+    ExpressionVisitor,
+    Function,
+    ImportSymbol,
+    Program,
+    ProgramVisitor,
+    StatementVisitor,
+    Type,
+    VisitResult,
+};
+
+use std::collections::HashSet;
+
+/// Reports imported symbols that the program never uses.
+#[derive(Default)]
+pub struct UnusedImports {
+    functions: HashSet<u32>,
+    circuits: HashSet<u32>,
+    interfaces: HashSet<u32>,
+}
+
+impl UnusedImports {
+    pub fn check<'a>(program: &Program<'a>, ast: &leo_ast::Program) -> Vec<Lint> {
+        let used = visit_program(program, UnusedImports::default());
+
+        let mut imported_symbols = vec![];
+        for import in ast.imports.iter() {
+            resolve_import_package(&mut imported_symbols, vec![], &import.package_or_packages);
+        }
+
+        let mut lints = vec![];
+        for (package, symbol, span) in imported_symbols {
+            let package = package.join(".");
+            let module = match program.imported_modules.get(&package) {
+                Some(module) => module,
+                None => continue,
+            };
+            let (name, is_used) = match symbol {
+                ImportSymbol::Direct(name) | ImportSymbol::Alias(name, _) => {
+                    let is_used = used.uses(module, Some(&name));
+                    (format!("{}.{}", package, name), is_used)
+                }
+                ImportSymbol::All => (format!("{}.*", package), used.uses(module, None)),
+            };
+            if !is_used {
+                lints.push(Lint::new(
+                    LintRule::UnusedImports,
+                    format!("unused import `{}`", name),
+                    span,
+                ));
+            }
+        }
+        lints
+    }
+
+    /// Returns `true` if a definition of `module` named `name`, or any of them if `None`, is used.
+    fn uses(&self, module: &Program, name: Option<&str>) -> bool {
+        let named = |definition: &String| name.map(|name| name == definition).unwrap_or(true);
+
+        let functions = module.functions.iter().filter(|(key, _)| named(key));
+        let circuits = module.circuits.iter().filter(|(key, _)| named(key));
+        let mut interfaces = module.interfaces.iter().filter(|(key, _)| named(key));
+
+        // calls to a function with interface parameters are resolved to one of its instances
+        functions.map(|(_, function)| function).any(|function| {
+            self.functions.contains(&function.id)
+                || function
+                    .instances
+                    .borrow()
+                    .values()
+                    .any(|instance| self.functions.contains(&instance.id))
+        }) || circuits
+            .map(|(_, circuit)| circuit)
+            .any(|circuit| self.circuits.contains(&circuit.id))
+            || interfaces.any(|(_, interface)| self.interfaces.contains(&interface.id))
+    }
+
+    fn use_type(&mut self, type_: &Type) {
+        match type_ {
+            Type::Array(item, _) => self.use_type(item),
+            Type::Tuple(items) => items.iter().for_each(|item| self.use_type(item)),
+            Type::Circuit(circuit) => self.use_circuit(circuit),
+            Type::Interface(interface) => {
+                self.interfaces.insert(interface.id);
+            }
+            _ => {}
+        }
+    }
+
+    fn use_circuit(&mut self, circuit: &Circuit) {
+        self.circuits.insert(circuit.id);
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for UnusedImports {
+    fn visit_call(&mut self, input: &CallExpression<'a>) -> VisitResult {
+        let function = input.function.get();
+        self.functions.insert(function.id);
+        if let Some(circuit) = function.circuit.get() {
+            self.use_circuit(circuit);
+        }
+        Default::default()
+    }
+
+    fn visit_circuit_access(&mut self, input: &CircuitAccessExpression<'a>) -> VisitResult {
+        self.use_circuit(input.circuit.get());
+        Default::default()
+    }
+
+    fn visit_circuit_init(&mut self, input: &CircuitInitExpression<'a>) -> VisitResult {
+        self.use_circuit(input.circuit.get());
+        Default::default()
+    }
+}
+
+impl<'a> StatementVisitor<'a> for UnusedImports {
+    fn visit_definition(&mut self, input: &DefinitionStatement<'a>) -> VisitResult {
+        for variable in input.variables.iter() {
+            self.use_type(&variable.borrow().type_);
+        }
+        Default::default()
+    }
+}
+
+impl<'a> ProgramVisitor<'a> for UnusedImports {
+    fn visit_function(&mut self, input: &'a Function<'a>) -> VisitResult {
+        for argument in input.arguments.values() {
+            self.use_type(&argument.get().borrow().type_);
+        }
+        self.use_type(&input.output);
+        Default::default()
+    }
+
+    fn visit_circuit(&mut self, input: &'a Circuit<'a>) -> VisitResult {
+        for member in input.members.borrow().values() {
+            if let CircuitMember::Variable(type_) = member {
+                self.use_type(type_);
+            }
+        }
+        for interface in input.implements.borrow().iter() {
+            self.interfaces.insert(interface.id);
+        }
+        Default::default()
+    }
+}
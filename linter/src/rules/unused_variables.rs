@@ -0,0 +1,74 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Lint, LintRule};
+use leo_asg::{
+    DefinitionStatement,
+    ExpressionVisitor,
+    Function,
+    IterationStatement,
+    ProgramVisitor,
+    StatementVisitor,
+    Variable,
+    VisitResult,
+};
+
+/// Reports variables and parameters that are never read, unless their name starts with `_`.
+#[derive(Default)]
+pub struct UnusedVariables {
+    pub lints: Vec<Lint>,
+}
+
+impl UnusedVariables {
+    fn check_variable(&mut self, variable: &Variable) {
+        let variable = variable.borrow();
+        if variable.references.is_empty() && !variable.name.name.starts_with('_') {
+            self.lints.push(Lint::new(
+                LintRule::UnusedVariables,
+                format!("unused variable `{}`", variable.name.name),
+                variable.name.span.clone(),
+            ));
+        }
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for UnusedVariables {}
+
+impl<'a> StatementVisitor<'a> for UnusedVariables {
+    fn visit_definition(&mut self, input: &DefinitionStatement<'a>) -> VisitResult {
+        for variable in input.variables.iter() {
+            self.check_variable(variable);
+        }
+        Default::default()
+    }
+
+    fn visit_iteration(&mut self, input: &IterationStatement<'a>) -> VisitResult {
+        self.check_variable(input.variable);
+        Default::default()
+    }
+}
+
+impl<'a> ProgramVisitor<'a> for UnusedVariables {
+    fn visit_function(&mut self, input: &'a Function<'a>) -> VisitResult {
+        // Templates and extern functions have no body to read their parameters in.
+        if input.body.get().is_some() {
+            for argument in input.arguments.values() {
+                self.check_variable(argument.get());
+            }
+        }
+        Default::default()
+    }
+}
@@ -0,0 +1,159 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_linter::{Lint, LintConfig, LintError, LintLevel, LintRule, Linter};
+
+use std::path::Path;
+
+const TEST_PROGRAM_NAME: &str = "test";
+
+fn lint_with(linter: &Linter, program_string: &str) -> Result<Vec<Lint>, LintError> {
+    linter.lint_string(TEST_PROGRAM_NAME, Path::new("test.leo"), program_string)
+}
+
+/// Returns the rule and line of each lint in the program.
+fn lint(program_string: &str) -> Vec<(LintRule, usize)> {
+    lint_with(&Linter::default(), program_string)
+        .unwrap()
+        .into_iter()
+        .map(|lint| (lint.rule, lint.error.line))
+        .collect()
+}
+
+#[test]
+fn test_unused_variables() {
+    let program_string = include_str!("unused_variables.leo");
+
+    assert_eq!(lint(program_string), vec![
+        (LintRule::UnusedVariables, 1),
+        (LintRule::UnusedVariables, 3),
+        (LintRule::UnusedVariables, 4),
+        (LintRule::UnusedVariables, 5),
+    ]);
+}
+
+#[test]
+fn test_unused_variables_prefixed() {
+    let program_string = include_str!("unused_variables_prefixed.leo");
+
+    assert_eq!(lint(program_string), vec![]);
+}
+
+#[test]
+fn test_unused_imports() {
+    let program_string = include_str!("unused_imports.leo");
+
+    assert_eq!(lint(program_string), vec![
+        (LintRule::UnusedImports, 1),
+        (LintRule::UnusedImports, 2),
+    ]);
+}
+
+#[test]
+fn test_shadowed_names() {
+    let program_string = include_str!("shadowed_names.leo");
+
+    assert_eq!(lint(program_string), vec![
+        (LintRule::ShadowedNames, 3),
+        (LintRule::ShadowedNames, 5),
+        (LintRule::UnusedVariables, 8),
+        (LintRule::ShadowedNames, 8),
+        (LintRule::ShadowedNames, 9),
+    ]);
+}
+
+#[test]
+fn test_redundant_mut() {
+    let program_string = include_str!("redundant_mut.leo");
+
+    assert_eq!(lint(program_string), vec![
+        (LintRule::RedundantMut, 9),
+        (LintRule::RedundantMut, 10),
+        (LintRule::UnusedVariables, 11),
+        (LintRule::RedundantMut, 11),
+    ]);
+}
+
+#[test]
+fn test_constant_conditions() {
+    let program_string = include_str!("constant_conditions.leo");
+
+    assert_eq!(lint(program_string), vec![
+        (LintRule::ConstantConditions, 3),
+        (LintRule::ConstantConditions, 6),
+    ]);
+}
+
+#[test]
+fn test_constant_loops() {
+    let program_string = include_str!("constant_loops.leo");
+
+    assert_eq!(lint(program_string), vec![
+        (LintRule::ConstantLoops, 3),
+        (LintRule::ConstantLoops, 6),
+    ]);
+}
+
+#[test]
+fn test_allow() {
+    let program_string = include_str!("allow.leo");
+
+    assert_eq!(lint(program_string), vec![
+        (LintRule::UnusedVariables, 10),
+        (LintRule::RedundantMut, 11),
+    ]);
+}
+
+#[test]
+fn test_allow_stacked() {
+    let program_string = include_str!("allow_stacked.leo");
+
+    assert_eq!(lint(program_string), vec![]);
+}
+
+#[test]
+fn test_allow_unknown_fail() {
+    let program_string = include_str!("allow_unknown_fail.leo");
+
+    let error = lint_with(&Linter::default(), program_string).unwrap_err();
+    assert!(matches!(error, LintError::Error(_)));
+}
+
+#[test]
+fn test_allow_empty_fail() {
+    let program_string = include_str!("allow_empty_fail.leo");
+
+    let error = lint_with(&Linter::default(), program_string).unwrap_err();
+    assert!(matches!(error, LintError::Error(_)));
+}
+
+#[test]
+fn test_config() {
+    let program_string = include_str!("allow.leo");
+    let config = LintConfig::new(&["unused_variables".to_string()], &["redundant_mut".to_string()]).unwrap();
+
+    let lints = lint_with(&Linter::new(config), program_string).unwrap();
+    assert_eq!(lints.len(), 1);
+    assert_eq!(lints[0].rule, LintRule::RedundantMut);
+    assert_eq!(lints[0].level, LintLevel::Deny);
+}
+
+#[test]
+fn test_config_unknown_fail() {
+    let error = LintConfig::new(&[], &["unused_everything".to_string()]).unwrap_err();
+
+    assert!(matches!(error, LintError::UnknownRule(_)));
+}
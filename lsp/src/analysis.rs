@@ -0,0 +1,326 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The analysis of a document, rebuilt from its text on every change.
+
+use crate::index::{members, IndexBuilder, Member, Reference, VariableInfo};
+use leo_asg::{new_alloc_context, new_context, Asg, Program, VisitorDirector};
+use leo_ast::{Ast, Diagnostic, Diagnostics, Identifier, PackageAccess, PackageOrPackages, Report, Span};
+use leo_grammar::Grammar;
+use leo_imports::ImportParser;
+
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+/// The manifest file marking the root of a package.
+const MANIFEST_FILE_NAME: &str = "Leo.toml";
+
+/// The kind of a definition listed in the outline of a document.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SymbolKind {
+    Function,
+    Circuit,
+    Interface,
+    Test,
+    Method,
+    Field,
+}
+
+/// A definition listed in the outline of a document.
+#[derive(Clone, Debug)]
+pub struct Symbol {
+    pub name: String,
+    pub kind: SymbolKind,
+    /// Span of the whole definition
+    pub span: Span,
+    /// Span of the name of the definition
+    pub selection: Span,
+    pub children: Vec<Symbol>,
+}
+
+/// The diagnostics, symbols and references of a document.
+///
+/// The ASG of the document is built in an arena owned by the analysis, which is dropped once the
+/// information needed by the server has been copied out of it.
+#[derive(Clone, Debug, Default)]
+pub struct Analysis {
+    pub diagnostics: Vec<Report>,
+    /// Whether the document was converted to an ASG, so that references are known
+    pub resolved: bool,
+    pub symbols: Vec<Symbol>,
+    pub references: Vec<Reference>,
+    pub variables: Vec<VariableInfo>,
+    /// The members of the circuits in scope, by name
+    pub circuits: BTreeMap<String, Vec<Member>>,
+    /// The names of the functions, circuits and imported symbols in scope
+    pub names: Vec<String>,
+}
+
+impl Analysis {
+    ///
+    /// Analyzes the `text` of the document at `path`.
+    ///
+    pub fn new(path: &Path, text: &str) -> Self {
+        let mut analysis = Self::default();
+        let program_name = path.file_stem().unwrap_or_default().to_string_lossy().to_string();
+
+        let grammar = match Grammar::new(path, text) {
+            Ok(grammar) => grammar,
+            Err(mut error) => {
+                error.set_path(path);
+                analysis.add_reports(path, error.reports());
+                return analysis;
+            }
+        };
+        let ast = match Ast::new(&program_name, &grammar) {
+            Ok(ast) => ast,
+            Err(error) => {
+                analysis.add_reports(path, error.reports());
+                return analysis;
+            }
+        };
+        analysis.add_symbols(ast.as_repr());
+
+        let arena = new_alloc_context();
+        let mut import_parser = ImportParser::new(package_root(path));
+        let mut diagnostics = Diagnostics::new();
+        let asg = Asg::new(new_context(&arena), &ast, &mut import_parser, &mut diagnostics);
+        diagnostics.set_path(path);
+        analysis.add_reports(path, diagnostics.warnings().iter().map(Report::from_warning).collect());
+
+        match asg {
+            Ok(asg) => analysis.add_references(&asg.as_repr(), ast.as_repr()),
+            Err(mut error) => {
+                error.set_path(path);
+                analysis.add_reports(path, error.reports());
+            }
+        }
+
+        analysis
+    }
+
+    ///
+    /// Adds the `reports`, pointing those found in imported files at the start of the document.
+    ///
+    fn add_reports(&mut self, path: &Path, reports: Vec<Report>) {
+        let path = path.display().to_string();
+        for mut report in reports {
+            match &report.path {
+                Some(report_path) if *report_path != path => {
+                    report.message = format!("{}: {}", report_path, report.message);
+                    report.span = None;
+                }
+                _ => {}
+            }
+            self.diagnostics.push(report);
+        }
+    }
+
+    fn add_symbols(&mut self, program: &leo_ast::Program) {
+        for function in program.functions.values() {
+            self.symbols.push(function_symbol(function, SymbolKind::Function));
+        }
+        for test in program.tests.values() {
+            self.symbols.push(function_symbol(&test.function, SymbolKind::Test));
+        }
+        for circuit in program.circuits.values() {
+            let mut span = circuit.circuit_name.span.clone();
+            let children = circuit
+                .members
+                .iter()
+                .map(|member| match member {
+                    leo_ast::CircuitMember::CircuitVariable(name, _) => {
+                        symbol(name, SymbolKind::Field, name.span.clone())
+                    }
+                    leo_ast::CircuitMember::CircuitFunction(function) => function_symbol(function, SymbolKind::Method),
+                })
+                .inspect(|member| span = join(&span, &member.span))
+                .collect();
+
+            self.symbols.push(Symbol {
+                children,
+                ..symbol(&circuit.circuit_name, SymbolKind::Circuit, span)
+            });
+        }
+        for interface in program.interfaces.values() {
+            let mut span = interface.interface_name.span.clone();
+            let children = interface
+                .functions
+                .iter()
+                .map(|function| function_symbol(function, SymbolKind::Method))
+                .inspect(|function| span = join(&span, &function.span))
+                .collect();
+
+            self.symbols.push(Symbol {
+                children,
+                ..symbol(&interface.interface_name, SymbolKind::Interface, span)
+            });
+        }
+        self.symbols.sort_by_key(|symbol| symbol.span.byte_start);
+    }
+
+    fn add_references(&mut self, program: &Program, ast: &leo_ast::Program) {
+        self.resolved = true;
+
+        let mut director = VisitorDirector::new(IndexBuilder::default());
+        for (function, _) in program.test_functions.values() {
+            let _ = director.visit_function(function);
+        }
+        for function in program.functions.values() {
+            let _ = director.visit_function(function);
+        }
+        for circuit in program.circuits.values() {
+            let _ = director.visit_circuit(circuit);
+        }
+        let index = director.visitor();
+        self.references = index.references;
+        self.variables = index.variables;
+
+        for circuit in program.circuits.values() {
+            self.circuits
+                .insert(circuit.name.borrow().name.clone(), members(circuit));
+        }
+        self.names = program
+            .functions
+            .keys()
+            .chain(program.circuits.keys())
+            .cloned()
+            .collect();
+
+        for import in ast.imports.iter() {
+            match &import.package_or_packages {
+                PackageOrPackages::Package(package) => {
+                    self.add_import(program, vec![package.name.name.clone()], &package.access)
+                }
+                PackageOrPackages::Packages(packages) => {
+                    for access in packages.accesses.iter() {
+                        self.add_import(program, vec![packages.name.name.clone()], access);
+                    }
+                }
+            }
+        }
+    }
+
+    ///
+    /// Adds references from the symbols of an import to their definitions in the imported module.
+    ///
+    fn add_import(&mut self, program: &Program, mut path: Vec<String>, access: &PackageAccess) {
+        match access {
+            PackageAccess::Star(_) => {}
+            PackageAccess::SubPackage(package) => {
+                path.push(package.name.name.clone());
+                self.add_import(program, path, &package.access);
+            }
+            PackageAccess::Multiple(packages) => {
+                path.push(packages.name.name.clone());
+                for access in packages.accesses.iter() {
+                    self.add_import(program, path.clone(), access);
+                }
+            }
+            PackageAccess::Symbol(import) => {
+                let name = import.alias.as_ref().unwrap_or(&import.symbol).name.clone();
+                let module = match program.imported_modules.get(&path.join(".")) {
+                    Some(module) => module,
+                    None => return,
+                };
+
+                let (definition, hover) = if let Some(function) = module.functions.get(&import.symbol.name) {
+                    (function.name.borrow().span.clone(), crate::index::signature(function))
+                } else if let Some(circuit) = module.circuits.get(&import.symbol.name) {
+                    self.circuits.insert(name.clone(), members(circuit));
+                    (
+                        circuit.name.borrow().span.clone(),
+                        format!("circuit {}", circuit.name.borrow().name),
+                    )
+                } else {
+                    return;
+                };
+
+                self.references.push(Reference {
+                    span: import.span.clone(),
+                    definition: Some(definition),
+                    hover,
+                });
+                self.names.push(name);
+            }
+        }
+    }
+
+    ///
+    /// Returns the innermost reference containing the one-based `line` and `column`.
+    ///
+    pub fn reference_at(&self, line: usize, column: usize) -> Option<&Reference> {
+        self.references
+            .iter()
+            .filter(|reference| contains(&reference.span, line, column))
+            .min_by_key(|reference| reference.span.byte_end - reference.span.byte_start)
+    }
+
+    ///
+    /// Returns the name of the circuit of the variable named `name` closest before `line`.
+    ///
+    pub fn circuit_of(&self, name: &str, line: usize) -> Option<&str> {
+        self.variables
+            .iter()
+            .filter(|variable| variable.name == name && variable.span.line <= line)
+            .max_by_key(|variable| variable.span.line)
+            .and_then(|variable| variable.circuit.as_deref())
+    }
+}
+
+///
+/// Returns the directory of the package containing `path`, or the directory of `path` itself.
+///
+pub fn package_root(path: &Path) -> PathBuf {
+    let directory = path.parent().unwrap_or_else(|| Path::new("."));
+
+    directory
+        .ancestors()
+        .find(|ancestor| ancestor.join(MANIFEST_FILE_NAME).exists())
+        .unwrap_or(directory)
+        .to_path_buf()
+}
+
+fn contains(span: &Span, line: usize, column: usize) -> bool {
+    let line_end = span.line_end.max(span.line);
+
+    (span.line, span.start) <= (line, column) && (line, column) < (line_end, span.end)
+}
+
+fn join(first: &Span, second: &Span) -> Span {
+    Span {
+        line_end: second.line_end.max(second.line),
+        end: second.end,
+        byte_end: second.byte_end,
+        ..first.clone()
+    }
+}
+
+fn symbol(name: &Identifier, kind: SymbolKind, span: Span) -> Symbol {
+    Symbol {
+        name: name.name.clone(),
+        kind,
+        span,
+        selection: name.span.clone(),
+        children: vec![],
+    }
+}
+
+fn function_symbol(function: &leo_ast::Function, kind: SymbolKind) -> Symbol {
+    symbol(&function.identifier, kind, function.span.clone())
+}
@@ -0,0 +1,253 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The index of the definitions referred to in a document, built by visiting its ASG.
+
+use leo_asg::{
+    CallExpression,
+    Circuit,
+    CircuitAccessExpression,
+    CircuitInitExpression,
+    CircuitMember,
+    DefinitionStatement,
+    ExpressionVisitor,
+    Function,
+    FunctionQualifier,
+    IterationStatement,
+    ProgramVisitor,
+    StatementVisitor,
+    Type,
+    Variable,
+    VariableRef,
+    VisitResult,
+};
+use leo_ast::Span;
+
+/// A span of the document that refers to a definition, or has a type to show on hover.
+#[derive(Clone, Debug)]
+pub struct Reference {
+    pub span: Span,
+    /// Span of the referred definition, possibly in another file
+    pub definition: Option<Span>,
+    pub hover: String,
+}
+
+/// A variable of the document, used to complete the members of its circuit type.
+#[derive(Clone, Debug)]
+pub struct VariableInfo {
+    pub name: String,
+    pub span: Span,
+    /// Name of the circuit the variable is an instance of
+    pub circuit: Option<String>,
+}
+
+/// A variable or function of a circuit.
+#[derive(Clone, Debug)]
+pub struct Member {
+    pub name: String,
+    pub detail: String,
+    pub is_function: bool,
+    pub is_static: bool,
+}
+
+/// Collects references and variables from the functions and circuits of a program.
+#[derive(Default)]
+pub struct IndexBuilder {
+    pub references: Vec<Reference>,
+    pub variables: Vec<VariableInfo>,
+}
+
+impl IndexBuilder {
+    fn add_variable(&mut self, variable: &Variable) {
+        let variable = variable.borrow();
+
+        self.references.push(Reference {
+            span: variable.name.span.clone(),
+            definition: Some(variable.name.span.clone()),
+            hover: format!("{}: {}", variable.name.name, variable.type_),
+        });
+        self.variables.push(VariableInfo {
+            name: variable.name.name.clone(),
+            span: variable.name.span.clone(),
+            circuit: match &variable.type_ {
+                Type::Circuit(circuit) => Some(circuit.name.borrow().name.clone()),
+                _ => None,
+            },
+        });
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for IndexBuilder {
+    fn visit_call(&mut self, input: &CallExpression<'a>) -> VisitResult {
+        if let Some(span) = &input.span {
+            let function = input.function.get();
+            self.references.push(Reference {
+                span: name_span(span, &function.name.borrow().name),
+                definition: Some(function.name.borrow().span.clone()),
+                hover: signature(function),
+            });
+        }
+        Default::default()
+    }
+
+    fn visit_circuit_access(&mut self, input: &CircuitAccessExpression<'a>) -> VisitResult {
+        let circuit = input.circuit.get();
+        let (definition, hover) = match circuit.members.borrow().get(&input.member.name) {
+            Some(CircuitMember::Function(function)) => (function.name.borrow().span.clone(), signature(function)),
+            Some(CircuitMember::Variable(type_)) => (
+                circuit.name.borrow().span.clone(),
+                format!("{}.{}: {}", circuit.name.borrow().name, input.member.name, type_),
+            ),
+            None => return Default::default(),
+        };
+
+        self.references.push(Reference {
+            span: input.member.span.clone(),
+            definition: Some(definition),
+            hover,
+        });
+        Default::default()
+    }
+
+    fn visit_circuit_init(&mut self, input: &CircuitInitExpression<'a>) -> VisitResult {
+        if let Some(span) = &input.span {
+            let circuit = input.circuit.get();
+            self.references.push(Reference {
+                span: span.clone(),
+                definition: Some(circuit.name.borrow().span.clone()),
+                hover: format!("circuit {}", circuit.name.borrow().name),
+            });
+        }
+        Default::default()
+    }
+
+    fn visit_variable_ref(&mut self, input: &VariableRef<'a>) -> VisitResult {
+        if let Some(span) = &input.span {
+            let variable = input.variable.borrow();
+            self.references.push(Reference {
+                span: span.clone(),
+                definition: Some(variable.name.span.clone()),
+                hover: format!("{}: {}", variable.name.name, variable.type_),
+            });
+        }
+        Default::default()
+    }
+}
+
+impl<'a> StatementVisitor<'a> for IndexBuilder {
+    fn visit_definition(&mut self, input: &DefinitionStatement<'a>) -> VisitResult {
+        for variable in input.variables.iter() {
+            self.add_variable(variable);
+        }
+        Default::default()
+    }
+
+    fn visit_iteration(&mut self, input: &IterationStatement<'a>) -> VisitResult {
+        self.add_variable(input.variable);
+        Default::default()
+    }
+}
+
+impl<'a> ProgramVisitor<'a> for IndexBuilder {
+    fn visit_function(&mut self, input: &'a Function<'a>) -> VisitResult {
+        let name = input.name.borrow();
+        self.references.push(Reference {
+            span: name.span.clone(),
+            definition: Some(name.span.clone()),
+            hover: signature(input),
+        });
+        for argument in input.arguments.values() {
+            self.add_variable(argument.get());
+        }
+        Default::default()
+    }
+
+    fn visit_circuit(&mut self, input: &'a Circuit<'a>) -> VisitResult {
+        let name = input.name.borrow();
+        self.references.push(Reference {
+            span: name.span.clone(),
+            definition: Some(name.span.clone()),
+            hover: format!("circuit {}", name.name),
+        });
+        Default::default()
+    }
+}
+
+///
+/// Returns the span of the function name right before the arguments of a call, which the span of
+/// the call starts at.
+///
+fn name_span(arguments: &Span, name: &str) -> Span {
+    Span {
+        line_end: arguments.line,
+        start: arguments.start.saturating_sub(name.len()),
+        end: arguments.start,
+        byte_start: arguments.byte_start.saturating_sub(name.len()),
+        byte_end: arguments.byte_start,
+        ..arguments.clone()
+    }
+}
+
+///
+/// Returns the members of a circuit, in declaration order.
+///
+pub fn members(circuit: &Circuit) -> Vec<Member> {
+    circuit
+        .members
+        .borrow()
+        .iter()
+        .map(|(name, member)| match member {
+            CircuitMember::Function(function) => Member {
+                name: name.clone(),
+                detail: signature(function),
+                is_function: true,
+                is_static: function.qualifier == FunctionQualifier::Static,
+            },
+            CircuitMember::Variable(type_) => Member {
+                name: name.clone(),
+                detail: type_.to_string(),
+                is_function: false,
+                is_static: false,
+            },
+        })
+        .collect()
+}
+
+///
+/// Returns the signature of a function, as in `function add(a: u32, b: u32) -> u32`.
+///
+pub fn signature(function: &Function) -> String {
+    let receiver = match function.qualifier {
+        FunctionQualifier::SelfRef => Some("self".to_string()),
+        FunctionQualifier::MutSelfRef => Some("mut self".to_string()),
+        FunctionQualifier::Static => None,
+    };
+    let arguments = receiver
+        .into_iter()
+        .chain(
+            function
+                .arguments
+                .iter()
+                .map(|(name, variable)| format!("{}: {}", name, variable.get().borrow().type_)),
+        )
+        .collect::<Vec<_>>();
+    let signature = format!("function {}({})", function.name.borrow().name, arguments.join(", "));
+
+    match &function.output {
+        Type::Tuple(types) if types.is_empty() => signature,
+        output => format!("{} -> {}", signature, output),
+    }
+}
@@ -0,0 +1,44 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The language server of the Leo programming language.
+//!
+//! The server speaks the Language Server Protocol over stdio. Each change to a document runs the
+//! grammar, AST and ASG passes again to publish diagnostics, and to index the definitions, types and
+//! circuit members used by go-to-definition, hover, completion and document symbols.
+
+pub mod analysis;
+pub use self::analysis::*;
+
+pub mod index;
+pub use self::index::*;
+
+pub mod protocol;
+
+pub mod server;
+pub use self::server::*;
+
+use std::io;
+
+///
+/// Runs the language server on stdin and stdout until the editor exits.
+///
+pub fn run_stdio() -> io::Result<()> {
+    let stdin = io::stdin();
+    let stdout = io::stdout();
+
+    Server::new(stdout.lock()).run(&mut stdin.lock())
+}
@@ -0,0 +1,107 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The JSON-RPC messages of the Language Server Protocol, framed by `Content-Length` headers.
+
+use leo_ast::Span;
+
+use serde_json::{json, Value};
+use std::io::{self, BufRead, Write};
+
+/// The error code of requests for methods the server does not implement.
+pub const METHOD_NOT_FOUND: i64 = -32601;
+
+/// The error code of requests received after a shutdown request.
+pub const INVALID_REQUEST: i64 = -32600;
+
+///
+/// Reads the next message, or returns `None` once the input is closed.
+///
+pub fn read_message<R: BufRead>(reader: &mut R) -> io::Result<Option<Value>> {
+    let mut content_length = None;
+    loop {
+        let mut header = String::new();
+        if reader.read_line(&mut header)? == 0 {
+            return Ok(None);
+        }
+
+        let header = header.trim_end();
+        if header.is_empty() && content_length.is_some() {
+            break;
+        }
+        if let Some(length) = header.strip_prefix("Content-Length:") {
+            content_length = Some(length.trim().parse::<usize>().map_err(invalid_data)?);
+        }
+    }
+
+    let mut content = vec![0; content_length.unwrap_or_default()];
+    reader.read_exact(&mut content)?;
+
+    serde_json::from_slice(&content).map(Some).map_err(invalid_data)
+}
+
+///
+/// Writes a message with its `Content-Length` header.
+///
+pub fn write_message<W: Write>(writer: &mut W, message: &Value) -> io::Result<()> {
+    let content = message.to_string();
+    write!(writer, "Content-Length: {}\r\n\r\n{}", content.len(), content)?;
+
+    writer.flush()
+}
+
+pub fn response(id: Value, result: Value) -> Value {
+    json!({ "jsonrpc": "2.0", "id": id, "result": result })
+}
+
+pub fn error_response(id: Value, code: i64, message: &str) -> Value {
+    json!({ "jsonrpc": "2.0", "id": id, "error": { "code": code, "message": message } })
+}
+
+pub fn notification(method: &str, params: Value) -> Value {
+    json!({ "jsonrpc": "2.0", "method": method, "params": params })
+}
+
+///
+/// Returns the range of a span.
+///
+/// Positions of the protocol are zero-based, while lines and columns of spans are one-based.
+///
+pub fn range(span: &Span) -> Value {
+    range_of(span.line, span.start, span.line_end.max(span.line), span.end)
+}
+
+pub fn range_of(line_start: usize, column_start: usize, line_end: usize, column_end: usize) -> Value {
+    json!({
+        "start": { "line": line_start.saturating_sub(1), "character": column_start.saturating_sub(1) },
+        "end": { "line": line_end.saturating_sub(1), "character": column_end.saturating_sub(1) },
+    })
+}
+
+///
+/// Returns the one-based line and column of the position in the parameters of a request.
+///
+pub fn position(params: &Value) -> Option<(usize, usize)> {
+    let position = &params["position"];
+    let line = position["line"].as_u64()? as usize;
+    let character = position["character"].as_u64()? as usize;
+
+    Some((line + 1, character + 1))
+}
+
+fn invalid_data<E: Into<Box<dyn std::error::Error + Send + Sync>>>(error: E) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, error)
+}
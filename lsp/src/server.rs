@@ -0,0 +1,387 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The language server, answering the requests of an editor for the documents it opens.
+
+use crate::{
+    analysis::{Analysis, Symbol, SymbolKind},
+    protocol::*,
+};
+use leo_asg::{CORE_LIBRARY_VERSION, CORE_MODULES};
+use leo_ast::{Report, Severity, Span};
+
+use serde_json::{json, Value};
+use std::{
+    collections::HashMap,
+    io::{self, BufRead, Write},
+    path::PathBuf,
+};
+use url::Url;
+
+/// The characters after which editors request completions.
+const TRIGGER_CHARACTERS: &[&str] = &[".", ":"];
+
+/// An open document.
+struct Document {
+    path: PathBuf,
+    text: String,
+    analysis: Analysis,
+    /// The latest analysis that resolved references, kept while the document does not compile
+    resolved: Option<Analysis>,
+}
+
+impl Document {
+    fn references(&self) -> &Analysis {
+        self.resolved.as_ref().unwrap_or(&self.analysis)
+    }
+}
+
+/// A language server communicating over a reader and a writer, usually stdin and stdout.
+pub struct Server<W: Write> {
+    writer: W,
+    documents: HashMap<String, Document>,
+    shutdown: bool,
+}
+
+impl<W: Write> Server<W> {
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            documents: HashMap::new(),
+            shutdown: false,
+        }
+    }
+
+    ///
+    /// Handles the messages from `reader` until an `exit` notification, or the end of the input.
+    ///
+    pub fn run<R: BufRead>(&mut self, reader: &mut R) -> io::Result<()> {
+        while let Some(message) = read_message(reader)? {
+            if message["method"] == "exit" {
+                break;
+            }
+            self.handle(message)?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the writer the server sends messages to.
+    pub fn into_writer(self) -> W {
+        self.writer
+    }
+
+    fn handle(&mut self, message: Value) -> io::Result<()> {
+        let method = message["method"].as_str().unwrap_or_default().to_string();
+        let params = &message["params"];
+
+        let id = match message.get("id") {
+            Some(id) => id.clone(),
+            None => return self.handle_notification(&method, params),
+        };
+        if self.shutdown {
+            return write_message(
+                &mut self.writer,
+                &error_response(id, INVALID_REQUEST, "server is shut down"),
+            );
+        }
+
+        let result = match method.as_str() {
+            "initialize" => initialize(),
+            "shutdown" => {
+                self.shutdown = true;
+                Value::Null
+            }
+            "textDocument/definition" => self.definition(params),
+            "textDocument/hover" => self.hover(params),
+            "textDocument/completion" => self.completion(params),
+            "textDocument/documentSymbol" => self.document_symbols(params),
+            _ => {
+                let message = format!("unknown method {}", method);
+                return write_message(&mut self.writer, &error_response(id, METHOD_NOT_FOUND, &message));
+            }
+        };
+
+        write_message(&mut self.writer, &response(id, result))
+    }
+
+    fn handle_notification(&mut self, method: &str, params: &Value) -> io::Result<()> {
+        let uri = params["textDocument"]["uri"].as_str().unwrap_or_default().to_string();
+
+        match method {
+            "textDocument/didOpen" => {
+                let text = params["textDocument"]["text"].as_str().unwrap_or_default().to_string();
+                self.update(uri, text)
+            }
+            // Documents are synchronized in full, so the last change holds the whole text.
+            "textDocument/didChange" => match params["contentChanges"].as_array().and_then(|changes| changes.last()) {
+                Some(change) => {
+                    let text = change["text"].as_str().unwrap_or_default().to_string();
+                    self.update(uri, text)
+                }
+                None => Ok(()),
+            },
+            "textDocument/didClose" => {
+                self.documents.remove(&uri);
+                self.publish_diagnostics(&uri, vec![])
+            }
+            _ => Ok(()),
+        }
+    }
+
+    ///
+    /// Analyzes the new `text` of a document and publishes its diagnostics.
+    ///
+    fn update(&mut self, uri: String, text: String) -> io::Result<()> {
+        let path = to_path(&uri);
+        let analysis = Analysis::new(&path, &text);
+
+        let resolved = match self.documents.remove(&uri) {
+            _ if analysis.resolved => Some(analysis.clone()),
+            Some(document) => document.resolved,
+            None => None,
+        };
+        let diagnostics = analysis.diagnostics.iter().map(diagnostic).collect();
+
+        self.documents.insert(uri.clone(), Document {
+            path,
+            text,
+            analysis,
+            resolved,
+        });
+        self.publish_diagnostics(&uri, diagnostics)
+    }
+
+    fn publish_diagnostics(&mut self, uri: &str, diagnostics: Vec<Value>) -> io::Result<()> {
+        let params = json!({ "uri": uri, "diagnostics": diagnostics });
+
+        write_message(
+            &mut self.writer,
+            &notification("textDocument/publishDiagnostics", params),
+        )
+    }
+
+    fn definition(&self, params: &Value) -> Value {
+        let (document, line, column) = match self.document_at(params) {
+            Some(position) => position,
+            None => return Value::Null,
+        };
+
+        match document.references().reference_at(line, column) {
+            Some(reference) => match &reference.definition {
+                Some(definition) => location(document, definition),
+                None => Value::Null,
+            },
+            None => Value::Null,
+        }
+    }
+
+    fn hover(&self, params: &Value) -> Value {
+        let (document, line, column) = match self.document_at(params) {
+            Some(position) => position,
+            None => return Value::Null,
+        };
+
+        match document.references().reference_at(line, column) {
+            Some(reference) => json!({
+                "contents": { "kind": "markdown", "value": format!("```leo\n{}\n```", reference.hover) },
+                "range": range(&reference.span),
+            }),
+            None => Value::Null,
+        }
+    }
+
+    fn completion(&self, params: &Value) -> Value {
+        let (document, line, column) = match self.document_at(params) {
+            Some(position) => position,
+            None => return json!([]),
+        };
+        let analysis = document.references();
+        let prefix: String = document
+            .text
+            .lines()
+            .nth(line - 1)
+            .unwrap_or_default()
+            .chars()
+            .take(column - 1)
+            .collect();
+
+        // `import core.` completes the core modules.
+        if let Some(module) = prefix.trim_start().strip_prefix("import core.") {
+            return CORE_MODULES
+                .iter()
+                .filter(|name| name.starts_with(module))
+                .map(|name| {
+                    let detail = match name.starts_with("unstable.") {
+                        true => "core unstable".to_string(),
+                        false => format!("core {}", CORE_LIBRARY_VERSION),
+                    };
+                    json!({ "label": name, "kind": COMPLETION_MODULE, "detail": detail })
+                })
+                .collect();
+        }
+
+        let word_start = prefix.trim_end_matches(is_identifier_char);
+        let (static_access, target) = if let Some(rest) = word_start.strip_suffix("::") {
+            (true, rest)
+        } else if let Some(rest) = word_start.strip_suffix('.') {
+            (false, rest)
+        } else {
+            return analysis
+                .names
+                .iter()
+                .map(|name| {
+                    let kind = if analysis.circuits.contains_key(name) {
+                        COMPLETION_STRUCT
+                    } else {
+                        COMPLETION_FUNCTION
+                    };
+                    json!({ "label": name, "kind": kind })
+                })
+                .collect();
+        };
+
+        // `Circuit::` completes static functions, and `value.` the other members of the circuit.
+        let target = &target[target.trim_end_matches(is_identifier_char).len()..];
+        let circuit = if static_access {
+            Some(target)
+        } else if target == "self" {
+            analysis
+                .symbols
+                .iter()
+                .find(|symbol| {
+                    symbol.kind == SymbolKind::Circuit
+                        && (symbol.span.line..=symbol.span.line_end.max(symbol.span.line)).contains(&line)
+                })
+                .map(|symbol| symbol.name.as_str())
+        } else {
+            analysis.circuit_of(target, line)
+        };
+
+        match circuit.and_then(|circuit| analysis.circuits.get(circuit)) {
+            Some(members) => members
+                .iter()
+                .filter(|member| member.is_static == static_access)
+                .map(|member| {
+                    let kind = if member.is_function {
+                        COMPLETION_METHOD
+                    } else {
+                        COMPLETION_FIELD
+                    };
+                    json!({ "label": member.name, "kind": kind, "detail": member.detail })
+                })
+                .collect(),
+            None => json!([]),
+        }
+    }
+
+    fn document_symbols(&self, params: &Value) -> Value {
+        let uri = params["textDocument"]["uri"].as_str().unwrap_or_default();
+
+        match self.documents.get(uri) {
+            Some(document) => document.analysis.symbols.iter().map(document_symbol).collect(),
+            None => json!([]),
+        }
+    }
+
+    ///
+    /// Returns the document and the one-based position of a request.
+    ///
+    fn document_at(&self, params: &Value) -> Option<(&Document, usize, usize)> {
+        let uri = params["textDocument"]["uri"].as_str()?;
+        let (line, column) = position(params)?;
+
+        Some((self.documents.get(uri)?, line, column))
+    }
+}
+
+// The kinds of completion items and symbols defined by the protocol.
+const COMPLETION_METHOD: u64 = 2;
+const COMPLETION_FUNCTION: u64 = 3;
+const COMPLETION_FIELD: u64 = 5;
+const COMPLETION_MODULE: u64 = 9;
+const COMPLETION_STRUCT: u64 = 22;
+
+fn initialize() -> Value {
+    json!({
+        "capabilities": {
+            "textDocumentSync": 1,
+            "definitionProvider": true,
+            "hoverProvider": true,
+            "completionProvider": { "triggerCharacters": TRIGGER_CHARACTERS },
+            "documentSymbolProvider": true,
+        },
+        "serverInfo": { "name": "leo", "version": env!("CARGO_PKG_VERSION") },
+    })
+}
+
+fn diagnostic(report: &Report) -> Value {
+    let range = match &report.span {
+        Some(span) => range_of(span.line_start, span.column_start, span.line_end, span.column_end),
+        None => range_of(1, 1, 1, 1),
+    };
+    let severity = match report.severity {
+        Severity::Error => 1,
+        Severity::Warning => 2,
+    };
+    let mut message = report.message.clone();
+    for note in report.notes.iter() {
+        message.push_str(&format!("\nnote: {}", note));
+    }
+
+    json!({ "range": range, "severity": severity, "code": report.code, "source": "leo", "message": message })
+}
+
+fn document_symbol(symbol: &Symbol) -> Value {
+    let kind = match symbol.kind {
+        SymbolKind::Function | SymbolKind::Test => 12,
+        SymbolKind::Circuit => 23,
+        SymbolKind::Interface => 11,
+        SymbolKind::Method => 6,
+        SymbolKind::Field => 8,
+    };
+
+    json!({
+        "name": symbol.name,
+        "kind": kind,
+        "range": range(&symbol.span),
+        "selectionRange": range(&symbol.selection),
+        "children": symbol.children.iter().map(document_symbol).collect::<Vec<_>>(),
+    })
+}
+
+///
+/// Returns the location of a definition, in its own file if it was imported.
+///
+fn location(document: &Document, definition: &Span) -> Value {
+    let path = definition.path().filter(|path| path.exists()).unwrap_or(&document.path);
+    let uri = Url::from_file_path(path)
+        .map(|url| url.to_string())
+        .unwrap_or_else(|_| path.display().to_string());
+
+    json!({ "uri": uri, "range": range(definition) })
+}
+
+fn to_path(uri: &str) -> PathBuf {
+    Url::parse(uri)
+        .ok()
+        .and_then(|url| url.to_file_path().ok())
+        .unwrap_or_else(|| PathBuf::from(uri))
+}
+
+fn is_identifier_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
@@ -0,0 +1,241 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_lsp::{protocol::read_message, Server};
+
+use serde_json::{json, Value};
+use std::io::Cursor;
+
+const DOCUMENT: &str = include_str!("document.leo");
+
+fn uri() -> String {
+    format!("file://{}/tests/document.leo", env!("CARGO_MANIFEST_DIR"))
+}
+
+/// Runs a session of the server on `messages`, returning the messages it sent back.
+fn session(messages: Vec<Value>) -> Vec<Value> {
+    let mut input = Vec::new();
+    for (id, mut message) in messages.into_iter().enumerate() {
+        message["jsonrpc"] = json!("2.0");
+        if message.get("params").is_some() && !message["method"].as_str().unwrap().contains("/did") {
+            message["id"] = json!(id);
+        }
+        let content = message.to_string();
+        input.extend(format!("Content-Length: {}\r\n\r\n{}", content.len(), content).into_bytes());
+    }
+
+    let mut server = Server::new(Vec::new());
+    server.run(&mut Cursor::new(input)).unwrap();
+
+    let mut output = Cursor::new(server.into_writer());
+    let mut messages = vec![];
+    while let Some(message) = read_message(&mut output).unwrap() {
+        messages.push(message);
+    }
+    messages
+}
+
+fn open(text: &str) -> Value {
+    json!({
+        "method": "textDocument/didOpen",
+        "params": { "textDocument": { "uri": uri(), "languageId": "leo", "version": 1, "text": text } },
+    })
+}
+
+fn change(text: &str) -> Value {
+    json!({
+        "method": "textDocument/didChange",
+        "params": { "textDocument": { "uri": uri(), "version": 2 }, "contentChanges": [{ "text": text }] },
+    })
+}
+
+/// A request at the zero-based `line` and `character` of the document.
+fn request(method: &str, line: usize, character: usize) -> Value {
+    json!({
+        "method": method,
+        "params": { "textDocument": { "uri": uri() }, "position": { "line": line, "character": character } },
+    })
+}
+
+fn labels(result: &Value) -> Vec<&str> {
+    let mut labels: Vec<_> = result
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|item| item["label"].as_str().unwrap())
+        .collect();
+    labels.sort_unstable();
+    labels
+}
+
+#[test]
+fn test_initialize() {
+    let messages = session(vec![
+        json!({ "method": "initialize", "params": { "capabilities": {} } }),
+        json!({ "method": "shutdown", "params": null }),
+        json!({ "method": "textDocument/hover", "params": {} }),
+        json!({ "method": "exit" }),
+    ]);
+
+    let capabilities = &messages[0]["result"]["capabilities"];
+    assert_eq!(capabilities["textDocumentSync"], 1);
+    assert_eq!(capabilities["hoverProvider"], true);
+    assert_eq!(capabilities["definitionProvider"], true);
+    assert_eq!(capabilities["documentSymbolProvider"], true);
+    assert_eq!(messages[1]["result"], Value::Null);
+    assert_eq!(messages[2]["error"]["code"], -32600);
+}
+
+#[test]
+fn test_unknown_method() {
+    let messages = session(vec![json!({ "method": "textDocument/rename", "params": {} })]);
+
+    assert_eq!(messages[0]["id"], 0);
+    assert_eq!(messages[0]["error"]["code"], -32601);
+}
+
+#[test]
+fn test_diagnostics() {
+    let messages = session(vec![open("function main() {\n    let a = b;\n}\n"), change(DOCUMENT)]);
+
+    assert_eq!(messages[0]["method"], "textDocument/publishDiagnostics");
+    let diagnostics = messages[0]["params"]["diagnostics"].as_array().unwrap();
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0]["severity"], 1);
+    assert_eq!(diagnostics[0]["code"], "E0328");
+    assert_eq!(diagnostics[0]["range"]["start"], json!({ "line": 1, "character": 12 }));
+
+    assert_eq!(messages[1]["params"]["diagnostics"], json!([]));
+}
+
+#[test]
+fn test_syntax_error_diagnostics() {
+    let messages = session(vec![open("function main() {\n    let a = ;\n}\n")]);
+
+    let diagnostics = messages[0]["params"]["diagnostics"].as_array().unwrap();
+    assert_eq!(diagnostics[0]["severity"], 1);
+    assert_eq!(diagnostics[0]["range"]["start"]["line"], 1);
+}
+
+#[test]
+fn test_hover() {
+    let messages = session(vec![
+        open(DOCUMENT),
+        request("textDocument/hover", 18, 26),
+        request("textDocument/hover", 19, 11),
+        request("textDocument/hover", 18, 0),
+    ]);
+
+    assert_eq!(
+        messages[1]["result"]["contents"]["value"],
+        "```leo\nfunction double(a: u32) -> u32\n```"
+    );
+    assert_eq!(messages[2]["result"]["contents"]["value"], "```leo\np: Point\n```");
+    assert_eq!(messages[3]["result"], Value::Null);
+}
+
+#[test]
+fn test_definition() {
+    let messages = session(vec![
+        open(DOCUMENT),
+        request("textDocument/definition", 18, 26),
+        request("textDocument/definition", 19, 13),
+    ]);
+
+    assert_eq!(messages[1]["result"]["uri"], uri());
+    assert_eq!(
+        messages[1]["result"]["range"]["start"],
+        json!({ "line": 13, "character": 9 })
+    );
+    assert_eq!(
+        messages[2]["result"]["range"]["start"],
+        json!({ "line": 8, "character": 13 })
+    );
+}
+
+#[test]
+fn test_document_symbols() {
+    let messages = session(vec![
+        open(DOCUMENT),
+        json!({
+            "method": "textDocument/documentSymbol",
+            "params": { "textDocument": { "uri": uri() } },
+        }),
+    ]);
+
+    let symbols = messages[1]["result"].as_array().unwrap();
+    let names: Vec<_> = symbols.iter().map(|symbol| symbol["name"].as_str().unwrap()).collect();
+    assert_eq!(names, ["Point", "double", "main"]);
+    assert_eq!(symbols[0]["kind"], 23);
+    assert_eq!(symbols[0]["children"].as_array().unwrap().len(), 4);
+    assert_eq!(symbols[1]["kind"], 12);
+    assert_eq!(
+        symbols[1]["selectionRange"]["start"],
+        json!({ "line": 13, "character": 9 })
+    );
+}
+
+#[test]
+fn test_completion() {
+    let members = DOCUMENT.replace("return p.sum()", "return p.");
+    let statics = DOCUMENT.replace("return p.sum()", "return Point::");
+    let messages = session(vec![
+        open(DOCUMENT),
+        request("textDocument/completion", 19, 4),
+        change(&members),
+        request("textDocument/completion", 19, 13),
+        change(&statics),
+        request("textDocument/completion", 19, 18),
+        change("import core.\n"),
+        request("textDocument/completion", 0, 12),
+    ]);
+
+    assert_eq!(labels(&messages[1]["result"]), ["Point", "double", "main"]);
+    assert_eq!(labels(&messages[3]["result"]), ["sum", "x", "y"]);
+    assert_eq!(labels(&messages[5]["result"]), ["new"]);
+    assert_eq!(labels(&messages[7]["result"]), [
+        "array",
+        "bits",
+        "math",
+        "unstable.blake2s"
+    ]);
+    assert_eq!(messages[7]["result"][1]["detail"], "core 1.0.0");
+    assert_eq!(messages[7]["result"][3]["detail"], "core unstable");
+}
+
+#[test]
+fn test_import_definition() {
+    let directory = format!("{}/tests/package/src", env!("CARGO_MANIFEST_DIR"));
+    let main_uri = format!("file://{}/main.leo", directory);
+    let text = std::fs::read_to_string(format!("{}/main.leo", directory)).unwrap();
+    let messages = session(vec![
+        json!({
+            "method": "textDocument/didOpen",
+            "params": { "textDocument": { "uri": main_uri, "languageId": "leo", "version": 1, "text": text } },
+        }),
+        json!({
+            "method": "textDocument/definition",
+            "params": { "textDocument": { "uri": main_uri }, "position": { "line": 0, "character": 13 } },
+        }),
+    ]);
+
+    assert_eq!(messages[0]["params"]["diagnostics"], json!([]));
+    assert_eq!(messages[1]["result"]["uri"], format!("file://{}/point.leo", directory));
+    assert_eq!(
+        messages[1]["result"]["range"]["start"],
+        json!({ "line": 0, "character": 8 })
+    );
+}
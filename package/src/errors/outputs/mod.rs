@@ -23,6 +23,9 @@ pub use checksum::*;
 pub mod directory;
 pub use self::directory::*;
 
+pub mod ir;
+pub use ir::*;
+
 pub mod proof;
 pub use proof::*;
 
@@ -23,6 +23,9 @@ pub use self::checksum::*;
 pub mod directory;
 pub use directory::*;
 
+pub mod ir;
+pub use self::ir::*;
+
 pub mod proof;
 pub use self::proof::*;
 
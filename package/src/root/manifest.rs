@@ -19,6 +19,7 @@ use crate::{errors::ManifestError, package::Package};
 use serde::Deserialize;
 use std::{
     borrow::Cow,
+    collections::BTreeMap,
     convert::TryFrom,
     fs::File,
     io::{Read, Write},
@@ -32,10 +33,22 @@ pub struct Remote {
     pub author: String,
 }
 
+/// The `[lint]` section, naming the lint rules to silence or to treat as errors.
+#[derive(Clone, Default, Deserialize)]
+pub struct LintSettings {
+    #[serde(default)]
+    pub allow: Vec<String>,
+    #[serde(default)]
+    pub deny: Vec<String>,
+}
+
 #[derive(Deserialize)]
 pub struct Manifest {
     pub project: Package,
     pub remote: Option<Remote>,
+    pub lint: Option<LintSettings>,
+    /// The `[optimizations]` section, switching asg passes on or off by name.
+    pub optimizations: Option<BTreeMap<String, bool>>,
 }
 
 impl Manifest {
@@ -43,6 +56,8 @@ impl Manifest {
         Ok(Self {
             project: Package::new(package_name)?,
             remote: None,
+            lint: None,
+            optimizations: None,
         })
     }
 
@@ -78,6 +93,14 @@ impl Manifest {
         self.remote.clone()
     }
 
+    pub fn get_package_lint(&self) -> LintSettings {
+        self.lint.clone().unwrap_or_default()
+    }
+
+    pub fn get_package_optimizations(&self) -> BTreeMap<String, bool> {
+        self.optimizations.clone().unwrap_or_default()
+    }
+
     pub fn write_to(self, path: &Path) -> Result<(), ManifestError> {
         let mut path = Cow::from(path);
         if path.is_dir() {
@@ -167,3 +167,27 @@ fn test_manifest_refactors() {
     // Check that the manifest file remote has been updated.
     assert!(remote_is_updated(&manifest_path));
 }
+
+#[test]
+fn test_manifest_optimizations() {
+    let mut manifest_path = test_dir();
+    manifest_path.push(MANIFEST_FILENAME);
+
+    let mut file = File::create(&manifest_path).unwrap();
+    file.write_all(
+        br#"[project]
+name = "test-package"
+version = "0.1.0"
+
+[optimizations]
+constant_folding = false
+"#,
+    )
+    .unwrap();
+
+    let manifest = Manifest::try_from(manifest_path.as_path()).unwrap();
+    let optimizations = manifest.get_package_optimizations();
+
+    assert_eq!(optimizations.len(), 1);
+    assert_eq!(optimizations.get("constant_folding"), Some(&false));
+}
@@ -17,6 +17,9 @@
 pub mod circuit_synthesizer;
 pub use self::circuit_synthesizer::*;
 
+pub mod namespaced_synthesizer;
+pub use self::namespaced_synthesizer::*;
+
 pub mod serialized_circuit;
 pub use self::serialized_circuit::*;
 
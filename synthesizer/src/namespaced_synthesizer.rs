@@ -0,0 +1,139 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::CircuitSynthesizer;
+
+use snarkvm_errors::gadgets::SynthesisError;
+use snarkvm_models::{
+    curves::PairingEngine,
+    gadgets::r1cs::{ConstraintSystem, LinearCombination, Variable},
+};
+
+/// A circuit synthesizer that also records the namespace path of every variable and constraint.
+///
+/// Each path holds the names of the enclosing namespaces, followed by the annotation of the allocation
+/// or constraint itself.
+pub struct NamespacedSynthesizer<E: PairingEngine> {
+    pub circuit: CircuitSynthesizer<E>,
+
+    pub public_names: Vec<Vec<String>>,
+    pub private_names: Vec<Vec<String>>,
+    pub constraint_names: Vec<Vec<String>>,
+
+    namespace: Vec<String>,
+}
+
+impl<E: PairingEngine> NamespacedSynthesizer<E> {
+    pub fn new() -> Self {
+        Self {
+            circuit: CircuitSynthesizer {
+                at: vec![],
+                bt: vec![],
+                ct: vec![],
+                public_variables: vec![],
+                private_variables: vec![],
+            },
+            public_names: vec![],
+            private_names: vec![],
+            constraint_names: vec![],
+            namespace: vec![],
+        }
+    }
+
+    fn path(&self, annotation: &str) -> Vec<String> {
+        let mut path = self.namespace.clone();
+        path.push(annotation.to_string());
+        path
+    }
+}
+
+impl<E: PairingEngine> Default for NamespacedSynthesizer<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E: PairingEngine> ConstraintSystem<E::Fr> for NamespacedSynthesizer<E> {
+    type Root = Self;
+
+    #[inline]
+    fn alloc<F, A, AR>(&mut self, annotation: A, f: F) -> Result<Variable, SynthesisError>
+    where
+        F: FnOnce() -> Result<E::Fr, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+    {
+        let path = self.path(annotation().as_ref());
+        let variable = self.circuit.alloc(|| "", f)?;
+        self.private_names.push(path);
+        Ok(variable)
+    }
+
+    #[inline]
+    fn alloc_input<F, A, AR>(&mut self, annotation: A, f: F) -> Result<Variable, SynthesisError>
+    where
+        F: FnOnce() -> Result<E::Fr, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+    {
+        let path = self.path(annotation().as_ref());
+        let variable = self.circuit.alloc_input(|| "", f)?;
+        self.public_names.push(path);
+        Ok(variable)
+    }
+
+    #[inline]
+    fn enforce<A, AR, LA, LB, LC>(&mut self, annotation: A, a: LA, b: LB, c: LC)
+    where
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+        LA: FnOnce(LinearCombination<E::Fr>) -> LinearCombination<E::Fr>,
+        LB: FnOnce(LinearCombination<E::Fr>) -> LinearCombination<E::Fr>,
+        LC: FnOnce(LinearCombination<E::Fr>) -> LinearCombination<E::Fr>,
+    {
+        let path = self.path(annotation().as_ref());
+        self.circuit.enforce(|| "", a, b, c);
+        self.constraint_names.push(path);
+    }
+
+    fn push_namespace<NR, N>(&mut self, name_fn: N)
+    where
+        NR: AsRef<str>,
+        N: FnOnce() -> NR,
+    {
+        self.namespace.push(name_fn().as_ref().to_string());
+    }
+
+    fn pop_namespace(&mut self) {
+        self.namespace.pop();
+    }
+
+    fn get_root(&mut self) -> &mut Self::Root {
+        self
+    }
+
+    fn num_constraints(&self) -> usize {
+        self.circuit.num_constraints()
+    }
+
+    fn num_public_variables(&self) -> usize {
+        self.circuit.num_public_variables()
+    }
+
+    fn num_private_variables(&self) -> usize {
+        self.circuit.num_private_variables()
+    }
+}